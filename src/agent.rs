@@ -68,8 +68,15 @@ impl Agent {
             .route("/api/processes", get(get_processes))
             .with_state(self.state.clone());
 
-        let addr = format!("0.0.0.0:{}", self.port);
-        let listener = tokio::net::TcpListener::bind(&addr).await?;
+        // [::] also accepts IPv4 on dual-stack hosts; fall back to IPv4 only where IPv6 is disabled
+        let (addr, listener) = match tokio::net::TcpListener::bind(format!("[::]:{}", self.port)).await {
+            Ok(listener) => (format!("[::]:{}", self.port), listener),
+            Err(_) => {
+                let addr = format!("0.0.0.0:{}", self.port);
+                let listener = tokio::net::TcpListener::bind(&addr).await?;
+                (addr, listener)
+            }
+        };
         
         println!("Agent server listening on {}", addr);
         
@@ -1,7 +1,7 @@
 //! Alerts and notifications for process thresholds
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 use std::fs;
 use std::time::{SystemTime, Duration};
@@ -19,6 +19,81 @@ pub enum AlertCondition {
     MemoryGreaterThan { threshold_mb: u64, duration_secs: u64 },
     IoGreaterThan { threshold_mb_per_sec: f64, duration_secs: u64 },
     ProcessDied { pattern: String },
+    StuckInDState { duration_secs: u64 }, // Continuously in uninterruptible sleep (disk/NFS wait)
+    AnomalyVsBaseline { factor: f32, duration_secs: u64 }, // CPU or memory over `factor` x the learned baseline for the process name
+    CpuSpike { delta_percent: f32 }, // CPU rose by more than this between two consecutive refreshes
+    ProcessCountOutsideRange { pattern: String, min: usize, max: usize }, // Number of matching processes left [min, max]
+}
+
+impl AlertTarget {
+    /// Whether a process is covered by this target
+    pub fn matches(&self, process: &crate::process::ProcessInfo) -> bool {
+        match self {
+            AlertTarget::All => true,
+            AlertTarget::Pattern(pattern) => process.name.contains(pattern),
+            AlertTarget::Pid(pid) => process.pid == *pid,
+        }
+    }
+}
+
+impl AlertCondition {
+    /// Whether a process is over the threshold right now, ignoring the duration requirement
+    /// (always false for conditions that are not per-process thresholds; baseline anomalies
+    /// need the learned baselines and are checked by AlertManager)
+    pub fn threshold_met(&self, process: &crate::process::ProcessInfo) -> bool {
+        match self {
+            AlertCondition::CpuGreaterThan { threshold, .. } => process.cpu_usage > *threshold,
+            AlertCondition::MemoryGreaterThan { threshold_mb, .. } => process.memory_usage / (1024 * 1024) > *threshold_mb,
+            AlertCondition::StuckInDState { .. } => crate::process::is_disk_sleep(process),
+            AlertCondition::IoGreaterThan { .. }
+            | AlertCondition::ProcessDied { .. }
+            | AlertCondition::AnomalyVsBaseline { .. }
+            | AlertCondition::CpuSpike { .. }
+            | AlertCondition::ProcessCountOutsideRange { .. } => false,
+        }
+    }
+
+    /// How serious a firing alert of this kind is, from its type and how high its threshold sits
+    pub fn severity(&self) -> Severity {
+        match self {
+            AlertCondition::ProcessDied { .. } | AlertCondition::ProcessCountOutsideRange { .. } => Severity::Critical,
+            AlertCondition::CpuGreaterThan { threshold, .. } => Severity::from_level(*threshold as f64, 30.0, 80.0),
+            AlertCondition::MemoryGreaterThan { threshold_mb, .. } => Severity::from_level(*threshold_mb as f64, 512.0, 2048.0),
+            AlertCondition::IoGreaterThan { threshold_mb_per_sec, .. } => Severity::from_level(*threshold_mb_per_sec, 10.0, 100.0),
+            AlertCondition::StuckInDState { duration_secs } => Severity::from_level(*duration_secs as f64, 0.0, 60.0),
+            AlertCondition::AnomalyVsBaseline { factor, .. } => Severity::from_level(*factor as f64, 0.0, 5.0),
+            AlertCondition::CpuSpike { delta_percent } => Severity::from_level(*delta_percent as f64, 0.0, 80.0),
+        }
+    }
+}
+
+/// Severity of an alert, ordered from least to most serious
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    // Info below `warning`, Critical from `critical` up
+    fn from_level(level: f64, warning: f64, critical: f64) -> Self {
+        if level >= critical {
+            Severity::Critical
+        } else if level >= warning {
+            Severity::Warning
+        } else {
+            Severity::Info
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +102,12 @@ pub struct Alert {
     pub condition: AlertCondition,
     pub target: AlertTarget,
     pub enabled: bool,
+    #[serde(default = "default_notify_recovery")]
+    pub notify_recovery: bool, // Emit a "recovered" event (toast + history) when the condition clears
+}
+
+fn default_notify_recovery() -> bool {
+    true
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +117,56 @@ pub struct ActiveAlert {
     pub process_pid: Option<u32>,
     pub process_name: Option<String>,
     pub message: String,
+    pub recovered_at: Option<SystemTime>, // Condition cleared; kept briefly to show the recovered state
+}
+
+impl ActiveAlert {
+    pub fn is_firing(&self) -> bool {
+        self.recovered_at.is_none()
+    }
+}
+
+/// One step of an incident: an alert firing or recovering
+#[derive(Debug, Clone)]
+pub struct AlertEvent {
+    pub at: SystemTime,
+    pub message: String,
+    pub recovered: bool,
+}
+
+// Events kept in the alert history
+const ALERT_HISTORY_LIMIT: usize = 200;
+// How long a recovered alert stays listed before it is dropped
+const RECOVERED_DISPLAY_SECS: u64 = 10;
+
+/// Result of evaluating one alert against a single process snapshot
+#[derive(Debug, Clone, Default)]
+pub struct AlertTestReport {
+    pub matches: Vec<(u32, String, String)>, // (pid, name, current value) over the threshold now
+    pub sustained: usize, // Matches that have also been over it for the full duration (would fire)
+    pub note: Option<String>, // Why a condition can't be tested this way
+}
+
+/// Normal usage of one process name, averaged over a learning window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessBaseline {
+    pub avg_cpu: f32,
+    pub avg_memory_mb: f64,
+    pub samples: u64, // Process snapshots the averages were taken from
+}
+
+// A spike must end at or above this CPU%, so 1% -> 10% doesn't count as a storm
+const CPU_SPIKE_MIN_PERCENT: f32 = 25.0;
+
+// Baselines are never compared against less than this, so a process that idles at 0% CPU
+// doesn't fire on its first blip
+const BASELINE_MIN_CPU: f32 = 1.0;
+const BASELINE_MIN_MEMORY_MB: f64 = 1.0;
+
+// Running sums while a baseline window is open
+struct BaselineLearning {
+    until: SystemTime,
+    sums: HashMap<String, (f64, f64, u64)>, // name -> (CPU sum, memory MiB sum, samples)
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -43,11 +174,24 @@ struct AlertConfig {
     alerts: Vec<Alert>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineConfig {
+    baselines: HashMap<String, ProcessBaseline>,
+}
+
 pub struct AlertManager {
     alerts: Vec<Alert>,
     active_alerts: Vec<ActiveAlert>,
     condition_tracking: HashMap<String, (SystemTime, u32)>, // (alert_name, process_pid) -> (start_time, count)
     config_path: PathBuf,
+    baselines: HashMap<String, ProcessBaseline>, // Process name -> learned usage
+    baseline_path: PathBuf,
+    learning: Option<BaselineLearning>,
+    history: VecDeque<AlertEvent>, // Fired and recovered events, oldest first
+    pending_recoveries: Vec<String>, // Recovery messages not yet shown by the UI
+    last_cpu: HashMap<u32, f32>, // PID -> CPU% at the latest check
+    spike_origins: HashMap<String, f32>, // (alert_name, process_pid) -> CPU% the firing spike jumped from
+    cpu_before: HashMap<u32, f32>, // PID -> CPU% at the check before that (for testing spike alerts)
 }
 
 impl AlertManager {
@@ -66,10 +210,19 @@ impl AlertManager {
             active_alerts: Vec::new(),
             condition_tracking: HashMap::new(),
             config_path,
+            baselines: HashMap::new(),
+            baseline_path: config_dir.join("baselines.toml"),
+            learning: None,
+            history: VecDeque::new(),
+            pending_recoveries: Vec::new(),
+            last_cpu: HashMap::new(),
+            spike_origins: HashMap::new(),
+            cpu_before: HashMap::new(),
         };
         
-        // Load alerts from file
+        // Load alerts and learned baselines from file
         let _ = manager.load_alerts();
+        let _ = manager.load_baselines();
         
         manager
     }
@@ -111,6 +264,34 @@ impl AlertManager {
         &self.active_alerts
     }
 
+    /// Severity of an active alert, from the alert that raised it (Warning if it was since deleted)
+    pub fn severity_of(&self, active: &ActiveAlert) -> Severity {
+        self.alerts.iter()
+            .find(|alert| alert.name == active.alert_name)
+            .map(|alert| alert.condition.severity())
+            .unwrap_or(Severity::Warning)
+    }
+
+    /// Turn recovery events on or off for one alert
+    pub fn toggle_recovery_notification(&mut self, index: usize) -> bool {
+        if let Some(alert) = self.alerts.get_mut(index) {
+            alert.notify_recovery = !alert.notify_recovery;
+            let _ = self.save_alerts();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn get_history(&self) -> &VecDeque<AlertEvent> {
+        &self.history
+    }
+
+    /// Recovery messages produced since the last call
+    pub fn take_recoveries(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.pending_recoveries)
+    }
+
     pub fn clear_active_alert(&mut self, index: usize) {
         if index < self.active_alerts.len() {
             self.active_alerts.remove(index);
@@ -121,10 +302,142 @@ impl AlertManager {
         self.active_alerts.clear();
     }
 
+    pub fn get_baselines(&self) -> &HashMap<String, ProcessBaseline> {
+        &self.baselines
+    }
+
+    /// Start recording per-name averages; they replace the stored baselines when the window ends
+    pub fn start_baseline_learning(&mut self, window: Duration) {
+        self.learning = Some(BaselineLearning {
+            until: SystemTime::now() + window,
+            sums: HashMap::new(),
+        });
+    }
+
+    /// Time left in the current learning window (None when not learning)
+    pub fn baseline_learning_remaining(&self) -> Option<Duration> {
+        self.learning
+            .as_ref()
+            .map(|learning| learning.until.duration_since(SystemTime::now()).unwrap_or(Duration::ZERO))
+    }
+
+    // Add one snapshot to the open window, and store the averages once it has ended
+    fn record_baseline_sample(&mut self, processes: &[crate::process::ProcessInfo], now: SystemTime) {
+        let Some(learning) = &mut self.learning else {
+            return;
+        };
+        for process in processes {
+            let sums = learning.sums.entry(process.name.clone()).or_insert((0.0, 0.0, 0));
+            sums.0 += process.cpu_usage as f64;
+            sums.1 += process.memory_usage as f64 / (1024.0 * 1024.0);
+            sums.2 += 1;
+        }
+        if now < learning.until {
+            return;
+        }
+        if let Some(learning) = self.learning.take() {
+            // Names not seen in this window keep their previous baseline
+            for (name, (cpu, memory_mb, samples)) in learning.sums {
+                self.baselines.insert(name, ProcessBaseline {
+                    avg_cpu: (cpu / samples as f64) as f32,
+                    avg_memory_mb: memory_mb / samples as f64,
+                    samples,
+                });
+            }
+            let _ = self.save_baselines();
+        }
+    }
+
+    /// Evaluate an alert once against the current processes without recording or firing anything
+    pub fn test_alert(&self, alert: &Alert, processes: &[crate::process::ProcessInfo]) -> AlertTestReport {
+        let now = SystemTime::now();
+        let mut report = AlertTestReport::default();
+        match &alert.condition {
+            AlertCondition::ProcessDied { pattern } => {
+                // Nothing has died yet - report what would be watched instead
+                report.matches = processes
+                    .iter()
+                    .filter(|p| pattern_matches(pattern, &p.name))
+                    .map(|p| (p.pid, p.name.clone(), "running".to_string()))
+                    .collect();
+                report.note = Some("Fires when one of these exits".to_string());
+                return report;
+            }
+            AlertCondition::ProcessCountOutsideRange { pattern, min, max } => {
+                report.matches = processes
+                    .iter()
+                    .filter(|p| pattern_matches(pattern, &p.name))
+                    .map(|p| (p.pid, p.name.clone(), "running".to_string()))
+                    .collect();
+                let count = report.matches.len();
+                let outside = count < *min || count > *max;
+                if outside {
+                    report.sustained = count;
+                }
+                report.note = Some(format!(
+                    "{} running, expected {}-{} - {}",
+                    count, min, max, if outside { "would fire now" } else { "within range" }
+                ));
+                return report;
+            }
+            AlertCondition::IoGreaterThan { .. } => {
+                report.note = Some("I/O alerts are not evaluated yet".to_string());
+                return report;
+            }
+            AlertCondition::AnomalyVsBaseline { .. } if self.baselines.is_empty() => {
+                report.note = Some("No baselines learned yet - press [b] to learn normal usage".to_string());
+                return report;
+            }
+            AlertCondition::CpuGreaterThan { .. }
+            | AlertCondition::MemoryGreaterThan { .. }
+            | AlertCondition::StuckInDState { .. }
+            | AlertCondition::AnomalyVsBaseline { .. }
+            | AlertCondition::CpuSpike { .. } => {}
+        }
+
+        let duration_secs = match &alert.condition {
+            AlertCondition::CpuGreaterThan { duration_secs, .. }
+            | AlertCondition::MemoryGreaterThan { duration_secs, .. }
+            | AlertCondition::StuckInDState { duration_secs }
+            | AlertCondition::AnomalyVsBaseline { duration_secs, .. } => *duration_secs,
+            _ => 0,
+        };
+        for process in processes {
+            let met = match &alert.condition {
+                AlertCondition::AnomalyVsBaseline { factor, .. } => exceeds_baseline(&self.baselines, process, *factor),
+                AlertCondition::CpuSpike { delta_percent } => is_cpu_spike(&self.cpu_before, process, *delta_percent),
+                condition => condition.threshold_met(process),
+            };
+            if !alert.target.matches(process) || !met {
+                continue;
+            }
+            let value = match &alert.condition {
+                AlertCondition::CpuGreaterThan { .. } => format!("{:.1}% CPU", process.cpu_usage),
+                AlertCondition::StuckInDState { .. } => process.status.trim().to_string(),
+                AlertCondition::AnomalyVsBaseline { .. } => baseline_comparison(&self.baselines, process),
+                AlertCondition::CpuSpike { .. } => {
+                    format!("{:.1}% -> {:.1}% CPU", self.cpu_before.get(&process.pid).copied().unwrap_or(0.0), process.cpu_usage)
+                }
+                _ => format!("{} MiB", process.memory_usage / (1024 * 1024)),
+            };
+            // Time over the threshold so far, as tracked by check_alerts for the saved alert
+            let over_for = self.condition_tracking
+                .get(&format!("{}:{}", alert.name, process.pid))
+                .and_then(|(since, _)| now.duration_since(*since).ok())
+                .map_or(0, |elapsed| elapsed.as_secs());
+            if over_for >= duration_secs {
+                report.sustained += 1;
+            }
+            report.matches.push((process.pid, process.name.clone(), value));
+        }
+        report
+    }
+
     /// Check alert conditions against process data
     pub fn check_alerts(&mut self, processes: &[crate::process::ProcessInfo], prev_processes: &std::collections::HashMap<u32, String>) {
         let now = SystemTime::now();
         let current_pids: std::collections::HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+        self.record_baseline_sample(processes, now);
         
         // Check for process death alerts
         for alert in &self.alerts {
@@ -136,22 +449,23 @@ impl AlertManager {
                 for (pid, name) in prev_processes {
                     if !current_pids.contains(pid) {
                         // Process died - check if it matches pattern
-                        let matches = if pattern == "*" {
-                            true
-                        } else {
-                            name.contains(pattern)
-                        };
-                        
-                        if matches {
+                        if pattern_matches(pattern, name) {
                             // Check if we already have an active alert for this death
                             // We use a unique key for the death event based on alert name and PID
                             if !self.active_alerts.iter().any(|a| a.alert_name == alert.name && a.process_pid == Some(*pid)) {
+                                let message = format!("Process {} ({}) died", name, pid);
+                                push_event(&mut self.history, AlertEvent {
+                                    at: now,
+                                    message: message.clone(),
+                                    recovered: false,
+                                });
                                 self.active_alerts.push(ActiveAlert {
                                     alert_name: alert.name.clone(),
                                     triggered_at: now,
                                     process_pid: Some(*pid),
                                     process_name: Some(name.clone()),
-                                    message: format!("Process {} ({}) died", name, pid),
+                                    message,
+                                    recovered_at: None,
                                 });
                             }
                         }
@@ -168,20 +482,14 @@ impl AlertManager {
             
             for process in processes {
                 // Check if process matches target
-                let matches_target = match &alert.target {
-                    AlertTarget::All => true,
-                    AlertTarget::Pattern(pattern) => process.name.contains(pattern),
-                    AlertTarget::Pid(pid) => process.pid == *pid,
-                };
-                
-                if !matches_target {
+                if !alert.target.matches(process) {
                     continue;
                 }
                 
                 let key = format!("{}:{}", alert.name, process.pid);
                 let should_trigger = match &alert.condition {
-                    AlertCondition::CpuGreaterThan { threshold, duration_secs } => {
-                        if process.cpu_usage > *threshold {
+                    AlertCondition::CpuGreaterThan { duration_secs, .. } => {
+                        if alert.condition.threshold_met(process) {
                             let entry = self.condition_tracking.entry(key.clone())
                                 .or_insert_with(|| (now, 0));
                             entry.1 += 1;
@@ -197,9 +505,25 @@ impl AlertManager {
                             false
                         }
                     }
-                    AlertCondition::MemoryGreaterThan { threshold_mb, duration_secs } => {
-                        let memory_mb = process.memory_usage / (1024 * 1024);
-                        if memory_mb > *threshold_mb {
+                    AlertCondition::MemoryGreaterThan { duration_secs, .. } | AlertCondition::StuckInDState { duration_secs } => {
+                        // Leaving D state clears the tracking, so only a continuous stretch counts
+                        if alert.condition.threshold_met(process) {
+                            let entry = self.condition_tracking.entry(key.clone())
+                                .or_insert_with(|| (now, 0));
+                            entry.1 += 1;
+                            
+                            if let Ok(elapsed) = now.duration_since(entry.0) {
+                                elapsed.as_secs() >= *duration_secs
+                            } else {
+                                false
+                            }
+                        } else {
+                            self.condition_tracking.remove(&key);
+                            false
+                        }
+                    }
+                    AlertCondition::AnomalyVsBaseline { factor, duration_secs } => {
+                        if exceeds_baseline(&self.baselines, process, *factor) {
                             let entry = self.condition_tracking.entry(key.clone())
                                 .or_insert_with(|| (now, 0));
                             entry.1 += 1;
@@ -214,44 +538,173 @@ impl AlertManager {
                             false
                         }
                     }
+                    // Fires on the refresh the jump is seen; there is no duration to wait for. The spike
+                    // holds until CPU is no longer more than `delta_percent` above where it jumped from
+                    AlertCondition::CpuSpike { delta_percent } => {
+                        if is_cpu_spike(&self.last_cpu, process, *delta_percent) {
+                            let before = self.last_cpu.get(&process.pid).copied().unwrap_or(0.0);
+                            self.spike_origins.entry(key.clone()).or_insert(before);
+                            true
+                        } else {
+                            if self.spike_origins.get(&key).is_some_and(|before| process.cpu_usage - before <= *delta_percent) {
+                                self.spike_origins.remove(&key);
+                            }
+                            false
+                        }
+                    }
                     AlertCondition::IoGreaterThan { .. } => {
                         // I/O monitoring would require additional tracking
                         false
                     }
-                    AlertCondition::ProcessDied { .. } => false, // Handled above
+                    AlertCondition::ProcessDied { .. } | AlertCondition::ProcessCountOutsideRange { .. } => false, // Handled above
                 };
                 
                 if should_trigger {
                     // Check if alert already active for this process
-                    if !self.active_alerts.iter().any(|a| a.alert_name == alert.name && a.process_pid == Some(process.pid)) {
+                    if !self.active_alerts.iter().any(|a| a.alert_name == alert.name && a.process_pid == Some(process.pid) && a.is_firing()) {
                         let message = match &alert.condition {
                             AlertCondition::CpuGreaterThan { threshold, .. } => {
                                 format!("{}: Process {} (PID: {}) CPU > {}% for threshold duration",
                                     alert.name, process.name, process.pid, threshold)
                             }
                             AlertCondition::MemoryGreaterThan { threshold_mb, .. } => {
-                                format!("{}: Process {} (PID: {}) Memory > {}MB for threshold duration",
+                                format!("{}: Process {} (PID: {}) Memory > {} MiB for threshold duration",
                                     alert.name, process.name, process.pid, threshold_mb)
                             }
+                            AlertCondition::StuckInDState { duration_secs } => {
+                                format!("{}: Process {} (PID: {}) stuck in D state for {}s",
+                                    alert.name, process.name, process.pid, duration_secs)
+                            }
+                            AlertCondition::CpuSpike { delta_percent } => {
+                                format!("{}: Process {} (PID: {}) CPU jumped {:.1}% -> {:.1}% (> +{}%)",
+                                    alert.name, process.name, process.pid,
+                                    self.last_cpu.get(&process.pid).copied().unwrap_or(0.0), process.cpu_usage, delta_percent)
+                            }
+                            AlertCondition::AnomalyVsBaseline { factor, .. } => {
+                                format!("{}: Process {} (PID: {}) over {}x its baseline: {}",
+                                    alert.name, process.name, process.pid, factor, baseline_comparison(&self.baselines, process))
+                            }
                             _ => format!("{}: Alert triggered", alert.name),
                         };
                         
+                        push_event(&mut self.history, AlertEvent {
+                            at: now,
+                            message: message.clone(),
+                            recovered: false,
+                        });
                         self.active_alerts.push(ActiveAlert {
                             alert_name: alert.name.clone(),
                             triggered_at: now,
                             process_pid: Some(process.pid),
                             process_name: Some(process.name.clone()),
                             message,
+                            recovered_at: None,
                         });
                     }
                 }
             }
         }
         
-        // Clean up old active alerts (older than 5 minutes)
+        // Firing threshold alerts whose condition has cleared (or whose process exited) recover
+        for active in self.active_alerts.iter_mut().filter(|a| a.is_firing()) {
+            let (Some(pid), Some(alert)) = (active.process_pid, self.alerts.iter().find(|a| a.name == active.alert_name)) else {
+                continue;
+            };
+            let key = format!("{}:{}", alert.name, pid);
+            let exited = !current_pids.contains(&pid);
+            let recovered = match &alert.condition {
+                AlertCondition::CpuGreaterThan { .. }
+                | AlertCondition::MemoryGreaterThan { .. }
+                | AlertCondition::StuckInDState { .. }
+                | AlertCondition::AnomalyVsBaseline { .. } => exited || !self.condition_tracking.contains_key(&key),
+                AlertCondition::CpuSpike { .. } => exited || !self.spike_origins.contains_key(&key),
+                // One-off events: nothing to recover from
+                AlertCondition::ProcessDied { .. } | AlertCondition::IoGreaterThan { .. } => false,
+                // Not per-process; recovers in the count check
+                AlertCondition::ProcessCountOutsideRange { .. } => false,
+            };
+            if !recovered {
+                continue;
+            }
+            if exited {
+                self.condition_tracking.remove(&key);
+                self.spike_origins.remove(&key);
+            }
+            active.recovered_at = Some(now);
+            if alert.notify_recovery {
+                let process_name = active.process_name.clone().unwrap_or_default();
+                let message = if exited {
+                    format!("{}: {} (PID: {}) exited", alert.name, process_name, pid)
+                } else {
+                    format!("{}: {} (PID: {}) {}", alert.name, process_name, pid, recovery_text(&alert.condition))
+                };
+                push_event(&mut self.history, AlertEvent {
+                    at: now,
+                    message: message.clone(),
+                    recovered: true,
+                });
+                self.pending_recoveries.push(message);
+            }
+        }
+
+        // Count alerts fire while the number of matching processes is outside the range, and
+        // recover once it is back inside
+        for alert in &self.alerts {
+            let AlertCondition::ProcessCountOutsideRange { pattern, min, max } = &alert.condition else {
+                continue;
+            };
+            if !alert.enabled {
+                continue;
+            }
+            let count = processes.iter().filter(|p| pattern_matches(pattern, &p.name)).count();
+            let outside = count < *min || count > *max;
+            let firing = self.active_alerts.iter_mut().find(|a| a.alert_name == alert.name && a.is_firing());
+            match (outside, firing) {
+                (true, Some(active)) => {
+                    // Keep the message current while it stays out of range
+                    active.message = count_message(&alert.name, pattern, *min, *max, count);
+                }
+                (true, None) => {
+                    let message = count_message(&alert.name, pattern, *min, *max, count);
+                    push_event(&mut self.history, AlertEvent {
+                        at: now,
+                        message: message.clone(),
+                        recovered: false,
+                    });
+                    self.active_alerts.push(ActiveAlert {
+                        alert_name: alert.name.clone(),
+                        triggered_at: now,
+                        process_pid: None,
+                        process_name: None,
+                        message,
+                        recovered_at: None,
+                    });
+                }
+                (false, Some(active)) => {
+                    active.recovered_at = Some(now);
+                    if alert.notify_recovery {
+                        let message = format!("{}: {} processes matching '{}', back within {}-{}", alert.name, count, pattern, min, max);
+                        push_event(&mut self.history, AlertEvent {
+                            at: now,
+                            message: message.clone(),
+                            recovered: true,
+                        });
+                        self.pending_recoveries.push(message);
+                    }
+                }
+                (false, None) => {}
+            }
+        }
+
+        // Remember this snapshot's CPU for the next spike comparison
+        let current_cpu = processes.iter().map(|p| (p.pid, p.cpu_usage)).collect();
+        self.cpu_before = std::mem::replace(&mut self.last_cpu, current_cpu);
+
+        // Clean up old active alerts (older than 5 minutes) and recovered ones once they've been shown
         let five_minutes_ago = now - Duration::from_secs(300);
+        let recovered_shown = now - Duration::from_secs(RECOVERED_DISPLAY_SECS);
         self.active_alerts.retain(|a| {
-            a.triggered_at > five_minutes_ago
+            a.triggered_at > five_minutes_ago && a.recovered_at.is_none_or(|at| at > recovered_shown)
         });
     }
 
@@ -279,6 +732,88 @@ impl AlertManager {
         fs::write(&self.config_path, content)?;
         Ok(())
     }
+
+    fn load_baselines(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.baseline_path.exists() {
+            return Ok(());
+        }
+
+        let content = fs::read_to_string(&self.baseline_path)?;
+        let config: BaselineConfig = toml::from_str(&content)?;
+        self.baselines = config.baselines;
+        Ok(())
+    }
+
+    fn save_baselines(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(parent) = self.baseline_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let config = BaselineConfig {
+            baselines: self.baselines.clone(),
+        };
+
+        let content = toml::to_string_pretty(&config)?;
+        fs::write(&self.baseline_path, content)?;
+        Ok(())
+    }
+}
+
+// Append to the alert history, dropping the oldest events past the limit
+fn push_event(history: &mut VecDeque<AlertEvent>, event: AlertEvent) {
+    history.push_back(event);
+    while history.len() > ALERT_HISTORY_LIMIT {
+        history.pop_front();
+    }
+}
+
+// "*" matches every process; anything else is a substring of the name
+fn pattern_matches(pattern: &str, name: &str) -> bool {
+    pattern == "*" || name.contains(pattern)
+}
+
+// "workers: expected 4-4 processes matching 'nginx', found 2"
+fn count_message(alert_name: &str, pattern: &str, min: usize, max: usize, count: usize) -> String {
+    format!("{}: expected {}-{} processes matching '{}', found {}", alert_name, min, max, pattern, count)
+}
+
+// "CPU back below 90%" and friends
+fn recovery_text(condition: &AlertCondition) -> String {
+    match condition {
+        AlertCondition::CpuGreaterThan { threshold, .. } => format!("CPU back below {}%", threshold),
+        AlertCondition::MemoryGreaterThan { threshold_mb, .. } => format!("memory back below {} MiB", threshold_mb),
+        AlertCondition::StuckInDState { .. } => "left D state".to_string(),
+        AlertCondition::AnomalyVsBaseline { factor, .. } => format!("back within {}x its baseline", factor),
+        AlertCondition::CpuSpike { .. } => "CPU settled after the spike".to_string(),
+        _ => "recovered".to_string(),
+    }
+}
+
+// CPU rose by more than `delta_percent` since `previous` and ended above the floor
+// (a process without a previous sample never spikes)
+fn is_cpu_spike(previous: &HashMap<u32, f32>, process: &crate::process::ProcessInfo, delta_percent: f32) -> bool {
+    previous.get(&process.pid).is_some_and(|before| {
+        process.cpu_usage - before > delta_percent && process.cpu_usage >= CPU_SPIKE_MIN_PERCENT
+    })
+}
+
+// CPU or memory above `factor` times the baseline of the process's name (no baseline = never)
+fn exceeds_baseline(baselines: &HashMap<String, ProcessBaseline>, process: &crate::process::ProcessInfo, factor: f32) -> bool {
+    let Some(baseline) = baselines.get(&process.name) else {
+        return false;
+    };
+    let memory_mb = process.memory_usage as f64 / (1024.0 * 1024.0);
+    process.cpu_usage > factor * baseline.avg_cpu.max(BASELINE_MIN_CPU)
+        || memory_mb > factor as f64 * baseline.avg_memory_mb.max(BASELINE_MIN_MEMORY_MB)
+}
+
+// "12.0% CPU / 340 MiB (baseline 2.1% / 120 MiB)"
+fn baseline_comparison(baselines: &HashMap<String, ProcessBaseline>, process: &crate::process::ProcessInfo) -> String {
+    let current = format!("{:.1}% CPU / {} MiB", process.cpu_usage, process.memory_usage / (1024 * 1024));
+    match baselines.get(&process.name) {
+        Some(baseline) => format!("{} (baseline {:.1}% / {:.0} MiB)", current, baseline.avg_cpu, baseline.avg_memory_mb),
+        None => current,
+    }
 }
 
 impl Default for AlertManager {
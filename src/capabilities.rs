@@ -0,0 +1,121 @@
+//! Linux capabilities held by a process, decoded from CapEff/CapPrm in /proc/<pid>/status
+//!
+//! Names follow capabilities(7) in lowercase without the CAP_ prefix ("net_admin"),
+//! which is also how the `caps` filter field matches them.
+
+// Indexed by capability bit number
+const CAPABILITY_NAMES: &[&str] = &[
+    "chown",              // 0
+    "dac_override",       // 1
+    "dac_read_search",    // 2
+    "fowner",             // 3
+    "fsetid",             // 4
+    "kill",               // 5
+    "setgid",             // 6
+    "setuid",             // 7
+    "setpcap",            // 8
+    "linux_immutable",    // 9
+    "net_bind_service",   // 10
+    "net_broadcast",      // 11
+    "net_admin",          // 12
+    "net_raw",            // 13
+    "ipc_lock",           // 14
+    "ipc_owner",          // 15
+    "sys_module",         // 16
+    "sys_rawio",          // 17
+    "sys_chroot",         // 18
+    "sys_ptrace",         // 19
+    "sys_pacct",          // 20
+    "sys_admin",          // 21
+    "sys_boot",           // 22
+    "sys_nice",           // 23
+    "sys_resource",       // 24
+    "sys_time",           // 25
+    "sys_tty_config",     // 26
+    "mknod",              // 27
+    "lease",              // 28
+    "audit_write",        // 29
+    "audit_control",      // 30
+    "setfcap",            // 31
+    "mac_override",       // 32
+    "mac_admin",          // 33
+    "syslog",             // 34
+    "wake_alarm",         // 35
+    "block_suspend",      // 36
+    "audit_read",         // 37
+    "perfmon",            // 38
+    "bpf",                // 39
+    "checkpoint_restore", // 40
+];
+
+// Capabilities that amount to (or easily escalate to) full root
+const DANGEROUS: &[&str] = &[
+    "sys_admin",
+    "sys_module",
+    "sys_ptrace",
+    "sys_rawio",
+    "sys_boot",
+    "dac_override",
+    "dac_read_search",
+    "setuid",
+    "setgid",
+    "setpcap",
+    "setfcap",
+    "fowner",
+    "chown",
+    "net_admin",
+    "net_raw",
+    "mac_admin",
+    "mac_override",
+    "bpf",
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    pub effective: u64, // CapEff - what the process can use right now
+    pub permitted: u64, // CapPrm - what it may raise into the effective set
+}
+
+impl Capabilities {
+    /// Effective set as names, lowest bit first
+    pub fn effective_names(&self) -> Vec<String> {
+        decode(self.effective)
+    }
+
+    /// Permitted but not currently effective
+    pub fn dormant_names(&self) -> Vec<String> {
+        decode(self.permitted & !self.effective)
+    }
+
+    /// Every known capability is effective, as for an unconfined root process
+    pub fn is_full(&self) -> bool {
+        let known = (1u64 << CAPABILITY_NAMES.len()) - 1;
+        self.effective & known == known
+    }
+}
+
+/// Name of a capability bit; bits newer than the table come out as "cap_<bit>"
+pub fn name(bit: u32) -> String {
+    CAPABILITY_NAMES.get(bit as usize).map_or_else(|| format!("cap_{}", bit), |name| name.to_string())
+}
+
+/// Names of every bit set in `mask`
+pub fn decode(mask: u64) -> Vec<String> {
+    (0..64).filter(|bit| mask & (1u64 << bit) != 0).map(name).collect()
+}
+
+/// Whether a capability (with or without the cap_ prefix, any case) is in the dangerous set
+pub fn is_dangerous(capability: &str) -> bool {
+    let capability = capability.to_lowercase();
+    DANGEROUS.contains(&capability.strip_prefix("cap_").unwrap_or(&capability))
+}
+
+/// CapEff/CapPrm from the text of a /proc/<pid>/status file
+pub fn parse_status(status: &str) -> Option<Capabilities> {
+    let field = |key: &str| {
+        status.lines()
+            .find_map(|line| line.strip_prefix(key))
+            .and_then(|hex| u64::from_str_radix(hex.trim(), 16).ok())
+    };
+    Some(Capabilities { effective: field("CapEff:")?, permitted: field("CapPrm:")? })
+}
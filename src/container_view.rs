@@ -1,6 +1,7 @@
 //! Container view module for detailed container information and drill-down
 
 use crate::process::ProcessInfo;
+use crate::process_group::{CgroupAccounting, UsageSource};
 
 #[derive(Clone)]
 pub struct ContainerInfo {
@@ -10,6 +11,7 @@ pub struct ContainerInfo {
     pub cpu_usage: f32,
     pub memory_usage: u64,
     pub start_time: Option<String>, // Container start time if available
+    pub source: UsageSource, // How cpu_usage/memory_usage were obtained
 }
 
 impl ContainerInfo {
@@ -21,6 +23,18 @@ impl ContainerInfo {
             cpu_usage: 0.0,
             memory_usage: 0,
             start_time: None,
+            source: UsageSource::Summed,
+        }
+    }
+
+    /// Use the container cgroup's own CPU/memory accounting instead of the process sums, when readable
+    pub fn use_cgroup_accounting(&mut self, accounting: &mut CgroupAccounting, cpu_divisor: f32) {
+        let usage = crate::process_group::common_cgroup(&self.processes)
+            .and_then(|cgroup| accounting.usage(&cgroup, cpu_divisor));
+        if let Some((cpu, memory)) = usage {
+            self.cpu_usage = cpu;
+            self.memory_usage = memory;
+            self.source = UsageSource::Cgroup;
         }
     }
 
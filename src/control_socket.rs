@@ -0,0 +1,165 @@
+//! Control socket - scripting lpm from outside the TUI
+//!
+//! Listens on a UNIX domain socket (--control-socket) for line-delimited JSON
+//! commands and answers each with one JSON line, e.g.
+//!
+//!   {"command": "list"}
+//!   {"command": "kill", "pid": 1234}
+//!   {"command": "renice", "pid": 1234, "nice": 10}
+//!   {"command": "signal", "pid": 1234, "signal": "HUP"}
+
+use serde::{Deserialize, Serialize};
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::RwLock;
+
+use crate::agent::AgentProcessInfo;
+use crate::process::{self, ProcessManager};
+use crate::signals::parse_signal;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "command", rename_all = "lowercase")]
+pub enum ControlCommand {
+    List,
+    Kill { pid: u32 },
+    Renice { pid: u32, nice: i32 },
+    Signal { pid: u32, signal: String }, // Number ("15"), name ("SIGTERM") or short name ("term")
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ControlResponse {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processes: Option<Vec<AgentProcessInfo>>, // Same shape the agent serves over HTTP
+}
+
+impl ControlResponse {
+    fn ok() -> Self {
+        Self { ok: true, error: None, processes: None }
+    }
+
+    fn error(message: impl Into<String>) -> Self {
+        Self { ok: false, error: Some(message.into()), processes: None }
+    }
+}
+
+/// Bind the socket, replacing a stale one, and restrict it to the owner (0600)
+pub fn bind(path: &Path) -> std::io::Result<UnixListener> {
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => {
+            // Only a socket nobody answers on is stale; a live one belongs to another lpm
+            if std::os::unix::net::UnixStream::connect(path).is_ok() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::AddrInUse,
+                    format!("{} is already being served", path.display()),
+                ));
+            }
+            std::fs::remove_file(path)?;
+        }
+        Ok(_) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("{} exists and is not a socket", path.display()),
+            ));
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    // Create the socket owner-only, so there is no window in which others can connect
+    let previous_umask = unsafe { libc::umask(0o077) };
+    let listener = UnixListener::bind(path);
+    unsafe { libc::umask(previous_umask) };
+    let listener = listener?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    Ok(listener)
+}
+
+/// Accept connections until the runtime shuts down, one task per client
+pub async fn serve(listener: UnixListener) {
+    let process_manager = Arc::new(RwLock::new(ProcessManager::new()));
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(handle_client(stream, process_manager.clone()));
+    }
+}
+
+/// Remove the socket file (called when the TUI exits)
+pub fn cleanup(path: &Path) {
+    let _ = std::fs::remove_file(path);
+}
+
+async fn handle_client(stream: UnixStream, process_manager: Arc<RwLock<ProcessManager>>) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<ControlCommand>(&line) {
+            Ok(command) => execute(command, &process_manager).await,
+            Err(e) => ControlResponse::error(format!("Invalid command: {}", e)),
+        };
+        let mut out = serde_json::to_string(&response).unwrap_or_else(|_| "{\"ok\":false}".to_string());
+        out.push('\n');
+        if writer.write_all(out.as_bytes()).await.is_err() {
+            break;
+        }
+    }
+}
+
+async fn execute(command: ControlCommand, process_manager: &Arc<RwLock<ProcessManager>>) -> ControlResponse {
+    match command {
+        ControlCommand::List => {
+            let mut pm = process_manager.write().await;
+            pm.refresh();
+            let processes = pm.get_processes()
+                .iter()
+                .map(|p| AgentProcessInfo::from(p.clone()))
+                .collect();
+            ControlResponse { processes: Some(processes), ..ControlResponse::ok() }
+        }
+        ControlCommand::Kill { pid } => {
+            let mut pm = process_manager.write().await;
+            if let Some(refusal) = lock_refusal(&mut pm, pid) {
+                return refusal;
+            }
+            result_response(pm.kill_process(pid))
+        }
+        ControlCommand::Renice { pid, nice } => {
+            let pm = process_manager.read().await;
+            result_response(pm.set_niceness(pid, nice))
+        }
+        ControlCommand::Signal { pid, signal } => {
+            let Some(number) = parse_signal(&signal) else {
+                return ControlResponse::error(format!("Unknown signal '{}'", signal));
+            };
+            let mut pm = process_manager.write().await;
+            // Continuing a stopped process is always allowed, as in the TUI
+            let refusal = if number == libc::SIGCONT { None } else { lock_refusal(&mut pm, pid) };
+            if let Some(refusal) = refusal {
+                return refusal;
+            }
+            result_response(pm.send_signal(pid, number))
+        }
+    }
+}
+
+// Error response if the process was locked against kill/stop in the TUI
+fn lock_refusal(pm: &mut ProcessManager, pid: u32) -> Option<ControlResponse> {
+    pm.refresh();
+    let process = pm.get_processes().iter().find(|p| p.pid == pid)?;
+    process::is_locked(process)
+        .then(|| ControlResponse::error(format!("Process {} ({}) is locked - unlock it in lpm first", process.name, pid)))
+}
+
+fn result_response(result: std::io::Result<()>) -> ControlResponse {
+    match result {
+        Ok(()) => ControlResponse::ok(),
+        Err(e) => ControlResponse::error(e.to_string()),
+    }
+}
@@ -2,16 +2,29 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::net::{Ipv6Addr, SocketAddr};
 use std::time::Duration;
 use tokio::time::timeout;
 use crate::process::ProcessInfo;
 
+/// Port used when a host is given without one (the agent's default)
+pub const DEFAULT_AGENT_PORT: u16 = 3000;
+
+// A dead or unresolvable host must not hold up the others for long
+const RESOLVE_TIMEOUT: Duration = Duration::from_secs(2);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RemoteHost {
-    pub address: String,  // IP:port or hostname:port
+    pub address: String,  // IPv4:port, [IPv6]:port or hostname:port
     pub name: String,
     pub connected: bool,
     pub last_update: Option<std::time::SystemTime>,
+    #[serde(default)]
+    pub resolved: Option<String>, // Socket address the host resolved to at the last attempt
+    #[serde(default)]
+    pub last_error: Option<String>, // Why the last attempt failed (resolution, connection, HTTP)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,11 +52,26 @@ impl From<RemoteProcessInfo> for ProcessInfo {
             parent_pid: rp.parent_pid,
             status: rp.status,
             user: rp.user,
+            tty: "?".to_string(),
             nice: rp.nice,
+            sched_policy: None, // Not reported by agents
             start_time_str: rp.start_time_str,
             start_timestamp: rp.start_timestamp, // Use remote process start timestamp
             cgroup: None,
             container_id: None,
+            unit: None,
+            peak_memory: rp.memory_usage,
+            vm_hwm: None,
+            tracer_pid: None,
+            thread_count: 0,
+            cmdline: String::new(),
+            capabilities: None,
+            security_label: None,
+            io_read_bytes: 0,
+            io_write_bytes: 0,
+            io_read_rate: 0.0,
+            io_write_rate: 0.0,
+            cpu_time_secs: 0.0, // Not reported by agents
             namespace_ids: std::collections::HashMap::new(),
             host: Some(rp.host),
         }
@@ -63,16 +91,21 @@ impl Coordinator {
         }
     }
 
-    pub fn add_host(&mut self, address: String, name: String) {
+    /// Add a host by address, returning it in canonical form; invalid addresses are rejected
+    pub fn add_host(&mut self, address: String, name: String) -> Result<String, String> {
+        let address = normalize_address(&address)?;
         // Check if host already exists
         if !self.hosts.iter().any(|h| h.address == address) {
             self.hosts.push(RemoteHost {
-                address,
+                address: address.clone(),
                 name,
                 connected: false,
                 last_update: None,
+                resolved: None,
+                last_error: None,
             });
         }
+        Ok(address)
     }
 
     pub fn remove_host(&mut self, address: &str) {
@@ -91,36 +124,199 @@ impl Coordinator {
             .collect()
     }
 
+    /// Last process list fetched from one host (empty until it has answered)
+    pub fn get_host_processes(&self, host_address: &str) -> &[RemoteProcessInfo] {
+        self.remote_processes.get(host_address).map_or(&[], |processes| processes.as_slice())
+    }
+
+    /// Compare the process sets of two hosts by process name
+    pub fn diff_hosts(&self, host_a: &str, host_b: &str) -> HostDiff {
+        diff_usage(
+            host_a,
+            host_b,
+            &usage_by_name(self.get_host_processes(host_a)),
+            &usage_by_name(self.get_host_processes(host_b)),
+        )
+    }
+
     pub fn update_host_data(&mut self, host_address: &str, processes: Vec<RemoteProcessInfo>) {
         // Update host connection status
         if let Some(host) = self.hosts.iter_mut().find(|h| h.address == host_address) {
             host.connected = true;
             host.last_update = Some(std::time::SystemTime::now());
+            host.last_error = None;
         }
         
         self.remote_processes.insert(host_address.to_string(), processes);
     }
 
-    pub fn mark_host_disconnected(&mut self, host_address: &str) {
+    pub fn mark_host_disconnected(&mut self, host_address: &str, error: String) {
         if let Some(host) = self.hosts.iter_mut().find(|h| h.address == host_address) {
             host.connected = false;
+            host.last_error = Some(error);
+        }
+    }
+
+    pub fn set_resolved(&mut self, host_address: &str, resolved: SocketAddr) {
+        if let Some(host) = self.hosts.iter_mut().find(|h| h.address == host_address) {
+            host.resolved = Some(resolved.to_string());
         }
     }
 }
 
-// Standalone async function to fetch data
-pub async fn fetch_host_data(host_address: String, host_name: String) -> Result<Vec<RemoteProcessInfo>, String> {
-    let url = format!("http://{}/api/processes", host_address);
-    
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(5))
+/// Split "host:port", "[IPv6]:port" or a bare host/IPv6 literal (default agent port) into host and port
+pub fn parse_host_address(input: &str) -> Result<(String, u16), String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Empty host address".to_string());
+    }
+    let parse_port = |port: &str| port.parse::<u16>().map_err(|_| format!("Invalid port '{}' in {}", port, input));
+    if let Some(rest) = input.strip_prefix('[') {
+        let (host, after) = rest.split_once(']').ok_or_else(|| format!("Missing ']' in {}", input))?;
+        host.parse::<Ipv6Addr>().map_err(|_| format!("Invalid IPv6 address '{}'", host))?;
+        let port = match after.strip_prefix(':') {
+            Some(port) => parse_port(port)?,
+            None if after.is_empty() => DEFAULT_AGENT_PORT,
+            None => return Err(format!("Unexpected '{}' after the IPv6 address in {}", after, input)),
+        };
+        return Ok((host.to_string(), port));
+    }
+    if input.parse::<Ipv6Addr>().is_ok() {
+        return Ok((input.to_string(), DEFAULT_AGENT_PORT));
+    }
+    match input.rsplit_once(':') {
+        Some((host, port)) if !host.is_empty() && !host.contains(':') => Ok((host.to_string(), parse_port(port)?)),
+        Some(_) => Err(format!("Put IPv6 addresses in brackets when giving a port, e.g. [::1]:{} (got {})", DEFAULT_AGENT_PORT, input)),
+        None => Ok((input.to_string(), DEFAULT_AGENT_PORT)),
+    }
+}
+
+/// Canonical "host:port" form of an address, with IPv6 literals in brackets
+pub fn normalize_address(input: &str) -> Result<String, String> {
+    let (host, port) = parse_host_address(input)?;
+    Ok(if host.contains(':') { format!("[{}]:{}", host, port) } else { format!("{}:{}", host, port) })
+}
+
+/// Resolve a host address to a socket address (DNS for hostnames), with a timeout
+pub async fn resolve_host(address: &str) -> Result<SocketAddr, String> {
+    let (host, port) = parse_host_address(address)?;
+    let mut addrs = timeout(RESOLVE_TIMEOUT, tokio::net::lookup_host((host.as_str(), port)))
+        .await
+        .map_err(|_| format!("Resolving {} timed out", host))?
+        .map_err(|e| format!("Cannot resolve {}: {}", host, e))?;
+    addrs.next().ok_or_else(|| format!("{} resolved to no addresses", host))
+}
+
+/// `resolve_host` for callers without an async context
+///
+/// The lookup runs on a worker thread, so a hung resolver costs the caller at most
+/// RESOLVE_TIMEOUT; the thread finishes (and is dropped) on its own afterwards.
+pub fn resolve_host_blocking(address: &str) -> Result<SocketAddr, String> {
+    use std::net::ToSocketAddrs;
+    let (host, port) = parse_host_address(address)?;
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let lookup_host = host.clone();
+    std::thread::spawn(move || {
+        let result = (lookup_host.as_str(), port).to_socket_addrs().map(|mut addrs| addrs.next());
+        let _ = sender.send(result);
+    });
+    match receiver.recv_timeout(RESOLVE_TIMEOUT) {
+        Ok(Ok(Some(addr))) => Ok(addr),
+        Ok(Ok(None)) => Err(format!("{} resolved to no addresses", host)),
+        Ok(Err(e)) => Err(format!("Cannot resolve {}: {}", host, e)),
+        Err(_) => Err(format!("Resolving {} timed out", host)),
+    }
+}
+
+fn http_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
         .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
+
+// Readable reason for a failed request to an agent
+fn request_error(address: SocketAddr, e: reqwest::Error) -> String {
+    if e.is_connect() {
+        format!("Cannot connect to {}: {}", address, e)
+    } else if e.is_timeout() {
+        format!("{} did not answer within {}s", address, REQUEST_TIMEOUT.as_secs())
+    } else {
+        format!("HTTP request to {} failed: {}", address, e)
+    }
+}
+
+/// A process name's instances and combined usage on one host
+#[derive(Clone, Debug, Default)]
+pub struct NameUsage {
+    pub count: usize,
+    pub cpu: f32,
+    pub memory: u64,
+}
+
+/// Process names running on only one of two hosts, and names whose usage differs a lot
+#[derive(Clone, Debug)]
+pub struct HostDiff {
+    pub host_a: String,
+    pub host_b: String,
+    pub only_a: Vec<(String, NameUsage)>,
+    pub only_b: Vec<(String, NameUsage)>,
+    pub changed: Vec<(String, NameUsage, NameUsage)>, // (name, on A, on B)
+}
+
+// A name's usage differs "a lot" past these: CPU points apart, or memory at least
+// this factor apart once the larger side is above the floor
+const DIFF_CPU_POINTS: f32 = 20.0;
+const DIFF_MEMORY_FACTOR: f64 = 2.0;
+const DIFF_MEMORY_FLOOR_BYTES: u64 = 50 * 1024 * 1024;
+
+fn usage_by_name(processes: &[RemoteProcessInfo]) -> HashMap<String, NameUsage> {
+    let mut usage: HashMap<String, NameUsage> = HashMap::new();
+    for process in processes {
+        let entry = usage.entry(process.name.clone()).or_default();
+        entry.count += 1;
+        entry.cpu += process.cpu_usage;
+        entry.memory += process.memory_usage;
+    }
+    usage
+}
+
+fn differs(a: &NameUsage, b: &NameUsage) -> bool {
+    let (low, high) = (a.memory.min(b.memory), a.memory.max(b.memory));
+    (a.cpu - b.cpu).abs() >= DIFF_CPU_POINTS
+        || a.count != b.count
+        || (high >= DIFF_MEMORY_FLOOR_BYTES && high as f64 >= low as f64 * DIFF_MEMORY_FACTOR)
+}
+
+/// Diff two per-name usage maps; each list is sorted by name
+pub fn diff_usage(host_a: &str, host_b: &str, a: &HashMap<String, NameUsage>, b: &HashMap<String, NameUsage>) -> HostDiff {
+    let mut only_a: Vec<_> = a.iter().filter(|(name, _)| !b.contains_key(*name)).map(|(n, u)| (n.clone(), u.clone())).collect();
+    let mut only_b: Vec<_> = b.iter().filter(|(name, _)| !a.contains_key(*name)).map(|(n, u)| (n.clone(), u.clone())).collect();
+    let mut changed: Vec<_> = a
+        .iter()
+        .filter_map(|(name, usage_a)| {
+            let usage_b = b.get(name)?;
+            differs(usage_a, usage_b).then(|| (name.clone(), usage_a.clone(), usage_b.clone()))
+        })
+        .collect();
+    only_a.sort_by(|x, y| x.0.cmp(&y.0));
+    only_b.sort_by(|x, y| x.0.cmp(&y.0));
+    changed.sort_by(|x, y| x.0.cmp(&y.0));
+    HostDiff { host_a: host_a.to_string(), host_b: host_b.to_string(), only_a, only_b, changed }
+}
+
+// Standalone async function to fetch data from an already resolved agent
+pub async fn fetch_host_data(address: SocketAddr, host_name: String) -> Result<Vec<RemoteProcessInfo>, String> {
+    // SocketAddr formats IPv6 as [addr]:port, as URLs need
+    let url = format!("http://{}/api/processes", address);
+    
+    let client = http_client()?;
     
-    let response = timeout(Duration::from_secs(5), client.get(&url).send())
+    let response = timeout(REQUEST_TIMEOUT, client.get(&url).send())
         .await
-        .map_err(|_| "Request timeout".to_string())?
-        .map_err(|e| format!("HTTP request failed: {}", e))?;
+        .map_err(|_| format!("{} did not answer within {}s", address, REQUEST_TIMEOUT.as_secs()))?
+        .map_err(|e| request_error(address, e))?;
     
     if !response.status().is_success() {
         return Err(format!("HTTP error: {}", response.status()));
@@ -167,17 +363,15 @@ pub async fn fetch_host_data(host_address: String, host_name: String) -> Result<
 impl Coordinator {
 
     pub async fn test_connection(&self, host_address: &str) -> bool {
-        let url = format!("http://{}/api/health", host_address);
+        let Ok(address) = resolve_host(host_address).await else {
+            return false;
+        };
+        let url = format!("http://{}/api/health", address);
         
-        if let Ok(client) = reqwest::Client::builder()
-            .timeout(Duration::from_secs(2))
-            .build()
+        if let Ok(client) = http_client()
+            && let Ok(Ok(resp)) = timeout(CONNECT_TIMEOUT, client.get(&url).send()).await
         {
-            if let Ok(response) = timeout(Duration::from_secs(2), client.get(&url).send()).await {
-                if let Ok(resp) = response {
-                    return resp.status().is_success();
-                }
-            }
+            return resp.status().is_success();
         }
         false
     }
@@ -0,0 +1,275 @@
+//! User-composed dashboard: a grid of the graphs the user picked from GraphData's histories
+//!
+//! The layout is saved to ~/.lpm/dashboard.toml whenever it changes.
+
+use crate::graph::GraphData;
+use crate::process::ProcessInfo;
+use crate::units::scale_for;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Paragraph},
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+pub const MAX_COLUMNS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Metric {
+    SystemCpu,
+    SystemMemory,
+    LoadAverage,
+    Network,
+    DiskIo, // Busiest block device
+    PsiCpu,
+    PsiMemory,
+    PsiIo,
+    ProcessCpu, // Of the widget's process name
+    ProcessMemory,
+}
+
+/// Metrics that need no process, in the order the picker offers them
+pub const SYSTEM_METRICS: [Metric; 8] = [
+    Metric::SystemCpu,
+    Metric::SystemMemory,
+    Metric::LoadAverage,
+    Metric::Network,
+    Metric::DiskIo,
+    Metric::PsiCpu,
+    Metric::PsiMemory,
+    Metric::PsiIo,
+];
+
+impl Metric {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Metric::SystemCpu => "System CPU",
+            Metric::SystemMemory => "System Memory",
+            Metric::LoadAverage => "Load Average (1m)",
+            Metric::Network => "Network (rx + tx)",
+            Metric::DiskIo => "Disk I/O (busiest device)",
+            Metric::PsiCpu => "CPU Pressure",
+            Metric::PsiMemory => "Memory Pressure",
+            Metric::PsiIo => "I/O Pressure",
+            Metric::ProcessCpu => "Process CPU",
+            Metric::ProcessMemory => "Process Memory",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DashboardWidget {
+    pub metric: Metric,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub process: Option<String>, // Process name for ProcessCpu/ProcessMemory
+}
+
+impl DashboardWidget {
+    pub fn system(metric: Metric) -> Self {
+        Self { metric, process: None }
+    }
+
+    pub fn title(&self) -> String {
+        match &self.process {
+            Some(name) => format!("{} - {}", self.metric.label(), name),
+            None => self.metric.label().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardLayout {
+    pub columns: usize,
+    pub widgets: Vec<DashboardWidget>, // Row-major
+    #[serde(skip)]
+    config_path: PathBuf,
+}
+
+impl DashboardLayout {
+    /// Load the saved layout, or a CPU/memory/load/network starter grid
+    pub fn load() -> Self {
+        let config_path = dirs::home_dir()
+            .map(|home| home.join(".lpm"))
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("dashboard.toml");
+        let mut layout = fs::read_to_string(&config_path)
+            .ok()
+            .and_then(|content| toml::from_str::<DashboardLayout>(&content).ok())
+            .unwrap_or_else(|| DashboardLayout {
+                columns: 2,
+                widgets: [Metric::SystemCpu, Metric::SystemMemory, Metric::LoadAverage, Metric::Network]
+                    .into_iter()
+                    .map(DashboardWidget::system)
+                    .collect(),
+                config_path: PathBuf::new(),
+            });
+        layout.columns = layout.columns.clamp(1, MAX_COLUMNS);
+        layout.config_path = config_path;
+        layout
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(parent) = self.config_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.config_path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn add(&mut self, widget: DashboardWidget) {
+        self.widgets.push(widget);
+        let _ = self.save();
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        if index < self.widgets.len() {
+            self.widgets.remove(index);
+            let _ = self.save();
+        }
+    }
+
+    /// Move a widget one slot earlier or later; returns its new index
+    pub fn shift(&mut self, index: usize, forward: bool) -> usize {
+        let target = if forward { index + 1 } else { index.wrapping_sub(1) };
+        if index < self.widgets.len() && target < self.widgets.len() {
+            self.widgets.swap(index, target);
+            let _ = self.save();
+            return target;
+        }
+        index
+    }
+
+    pub fn set_columns(&mut self, columns: usize) {
+        self.columns = columns.clamp(1, MAX_COLUMNS);
+        let _ = self.save();
+    }
+}
+
+// A widget's history, scaled for display: (points, unit, y-axis ceiling)
+fn series(widget: &DashboardWidget, graph_data: &GraphData, processes: &[ProcessInfo]) -> (Vec<f64>, String, f64) {
+    let percent = |values: Vec<f64>| {
+        let ceiling = values.iter().copied().fold(100.0, f64::max);
+        (values, "%".to_string(), ceiling)
+    };
+    // Byte histories are rescaled into whichever unit suits the peak
+    let bytes = |values: Vec<f64>, floor: f64, suffix: &str| {
+        let peak = values.iter().copied().fold(floor, f64::max);
+        let (divisor, unit) = scale_for(peak);
+        (values.iter().map(|v| v / divisor).collect(), format!("{}{}", unit, suffix), peak / divisor)
+    };
+    let process_history = || {
+        let name = widget.process.as_deref()?;
+        let pid = processes.iter().find(|p| p.name == name)?.pid;
+        graph_data.get_process_history(pid)
+    };
+    match widget.metric {
+        Metric::SystemCpu => percent(graph_data.get_cpu_history().iter().map(|&v| v as f64).collect()),
+        Metric::SystemMemory => bytes(
+            graph_data.get_memory_history().iter().map(|&mib| mib as f64 * 1024.0 * 1024.0).collect(),
+            100.0 * 1024.0 * 1024.0,
+            "",
+        ),
+        Metric::LoadAverage => {
+            let values: Vec<f64> = graph_data.get_load_history().iter().map(|&v| v as f64).collect();
+            let ceiling = values.iter().copied().fold(1.0, f64::max);
+            (values, "load".to_string(), ceiling)
+        }
+        Metric::Network => bytes(graph_data.get_network_history().iter().map(|&kb| kb as f64 * 1024.0).collect(), 1024.0, "/s"),
+        Metric::DiskIo => bytes(
+            graph_data.get_busiest_device_history().1.iter().map(|&kb| kb as f64 * 1024.0).collect(),
+            1024.0,
+            "/s",
+        ),
+        // PSI histories hold avg10 x 100
+        Metric::PsiCpu => percent(graph_data.get_psi_cpu().history.iter().map(|&v| v as f64 / 100.0).collect()),
+        Metric::PsiMemory => percent(graph_data.get_psi_memory().history.iter().map(|&v| v as f64 / 100.0).collect()),
+        Metric::PsiIo => percent(graph_data.get_psi_io().history.iter().map(|&v| v as f64 / 100.0).collect()),
+        Metric::ProcessCpu => percent(process_history().map_or_else(Vec::new, |(cpu, _)| cpu.iter().map(|&v| v as f64).collect())),
+        Metric::ProcessMemory => bytes(
+            process_history().map_or_else(Vec::new, |(_, memory)| memory.iter().map(|&v| v as f64).collect()),
+            1024.0 * 1024.0,
+            "",
+        ),
+    }
+}
+
+/// Draw the widgets in a grid of `layout.columns` columns; `selected` gets a highlighted border
+pub fn render_dashboard(
+    frame: &mut ratatui::Frame,
+    area: Rect,
+    layout: &DashboardLayout,
+    selected: usize,
+    graph_data: &GraphData,
+    processes: &[ProcessInfo],
+) {
+    if layout.widgets.is_empty() {
+        let empty = Paragraph::new("No graphs yet - press [a] to add one")
+            .style(Style::default().fg(Color::Black))
+            .block(Block::default().borders(Borders::ALL).title("Dashboard"));
+        frame.render_widget(empty, area);
+        return;
+    }
+    let columns = layout.columns.clamp(1, MAX_COLUMNS);
+    let rows = layout.widgets.len().div_ceil(columns);
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+        .split(area);
+    for (row, row_area) in row_areas.iter().enumerate() {
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+            .split(*row_area);
+        for (column, cell) in cells.iter().enumerate() {
+            let index = row * columns + column;
+            if let Some(widget) = layout.widgets.get(index) {
+                render_widget(frame, *cell, widget, index == selected, graph_data, processes);
+            }
+        }
+    }
+}
+
+fn render_widget(
+    frame: &mut ratatui::Frame,
+    area: Rect,
+    widget: &DashboardWidget,
+    selected: bool,
+    graph_data: &GraphData,
+    processes: &[ProcessInfo],
+) {
+    let (values, unit, ceiling) = series(widget, graph_data, processes);
+    let latest = values.last().map_or("-".to_string(), |v| format!("{:.1} {}", v, unit));
+    let border_style = if selected {
+        Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Black)
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("{} ({})", widget.title(), latest))
+        .style(Style::default().fg(Color::Black))
+        .border_style(border_style);
+    if values.is_empty() {
+        let note = if widget.process.is_some() { "Process not running" } else { "No samples yet" };
+        frame.render_widget(Paragraph::new(note).block(block), area);
+        return;
+    }
+    let points: Vec<(f64, f64)> = values.iter().enumerate().map(|(i, &v)| (i as f64, v)).collect();
+    let dataset = Dataset::default()
+        .marker(ratatui::symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Cyan))
+        .data(&points);
+    let chart = Chart::new(vec![dataset])
+        .block(block)
+        .x_axis(Axis::default().bounds([0.0, graph_data.max_points() as f64]).labels(vec![]))
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, ceiling])
+                .labels(vec![Span::from("0"), Span::from(format!("{:.1} {}", ceiling, unit))]),
+        );
+    frame.render_widget(chart, area);
+}
@@ -0,0 +1,213 @@
+//! "What is this process?" - descriptions of well-known processes plus heuristics
+//!
+//! Known names come from a bundled table (kernel threads and common daemons); anything
+//! else is classified from its parent, cgroup/unit and terminal, with the executable
+//! path and the package that owns it.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::process::{is_kernel_thread, ProcessInfo};
+
+// Exact process names
+const KNOWN_NAMES: &[(&str, &str)] = &[
+    ("systemd", "Init system and service manager (PID 1), or a per-user service manager"),
+    ("init", "Init process (PID 1) - starts and adopts every other process"),
+    ("kthreadd", "Kernel thread daemon - parent of all kernel threads"),
+    ("khugepaged", "Kernel thread collapsing pages into transparent huge pages"),
+    ("ksmd", "Kernel same-page merging - deduplicates identical memory pages"),
+    ("oom_reaper", "Kernel thread freeing the memory of OOM-killed processes"),
+    ("kauditd", "Kernel audit subsystem - forwards audit events to auditd"),
+    ("khungtaskd", "Kernel watchdog reporting tasks stuck in D state"),
+    ("kdevtmpfs", "Kernel thread populating /dev"),
+    ("watchdogd", "Kernel hard-lockup watchdog"),
+    ("systemd-journald", "Collects and stores logs from services, the kernel and syslog"),
+    ("systemd-logind", "Tracks user logins, sessions and seats; handles power keys"),
+    ("systemd-udevd", "Device manager - handles hotplug events and /dev permissions"),
+    ("systemd-resolved", "DNS resolver and cache (127.0.0.53)"),
+    ("systemd-networkd", "Network configuration daemon"),
+    ("systemd-timesyncd", "NTP client keeping the clock in sync"),
+    ("systemd-oomd", "Userspace OOM killer acting on memory pressure (PSI)"),
+    ("dbus-daemon", "D-Bus message bus - IPC between system services and desktop apps"),
+    ("dbus-broker", "D-Bus message bus (broker implementation)"),
+    ("NetworkManager", "Manages network connections (Wi-Fi, Ethernet, VPN)"),
+    ("wpa_supplicant", "Wi-Fi authentication (WPA/WPA2/WPA3)"),
+    ("sshd", "OpenSSH server - one process per listening server and per connection"),
+    ("cron", "Runs scheduled jobs from crontabs"),
+    ("crond", "Runs scheduled jobs from crontabs"),
+    ("atd", "Runs jobs queued with at(1)"),
+    ("rsyslogd", "Syslog daemon writing logs to /var/log"),
+    ("auditd", "Writes kernel audit events to disk"),
+    ("polkitd", "PolicyKit - authorizes privileged actions for unprivileged clients"),
+    ("udisksd", "Disk and removable media management service"),
+    ("upowerd", "Power and battery information service"),
+    ("accounts-daemon", "Provides user account information to desktops"),
+    ("chronyd", "NTP daemon keeping the clock in sync"),
+    ("ntpd", "NTP daemon keeping the clock in sync"),
+    ("containerd", "Container runtime managing container lifecycles"),
+    ("dockerd", "Docker daemon - API and image/container management"),
+    ("cupsd", "CUPS print server"),
+    ("avahi-daemon", "mDNS/DNS-SD - zero-configuration service discovery"),
+    ("bluetoothd", "Bluetooth stack daemon"),
+    ("pipewire", "Audio/video server for the desktop session"),
+    ("pipewire-pulse", "PulseAudio compatibility for PipeWire"),
+    ("wireplumber", "PipeWire session manager (routing and policy)"),
+    ("pulseaudio", "Sound server for the desktop session"),
+    ("Xorg", "X11 display server"),
+    ("Xwayland", "X11 compatibility server running under Wayland"),
+    ("gnome-shell", "GNOME desktop shell and Wayland compositor"),
+    ("kwin_wayland", "KDE Plasma Wayland compositor"),
+    ("plasmashell", "KDE Plasma desktop shell"),
+    ("gvfsd", "GNOME virtual filesystem daemon"),
+    ("tracker-miner-fs-3", "GNOME file indexer"),
+    ("snapd", "Snap package daemon"),
+    ("packagekitd", "PackageKit - package management service used by software centers"),
+    ("irqbalance", "Spreads hardware interrupts across CPUs"),
+    ("thermald", "Thermal daemon - throttles before the CPU overheats"),
+    ("agetty", "Login prompt on a text console"),
+    ("login", "Text console login session"),
+    ("bash", "Bash shell"),
+    ("zsh", "Z shell"),
+    ("fish", "fish shell"),
+    ("tmux: server", "tmux server holding terminal sessions"),
+    ("screen", "GNU screen terminal multiplexer"),
+    ("lpm", "This process manager"),
+];
+
+// Name prefixes, for per-CPU/per-device kernel threads ("kworker/3:1H", "irq/42-nvme0q1", ...)
+const KNOWN_PREFIXES: &[(&str, &str)] = &[
+    ("kworker/", "Kernel worker thread running deferred work (workqueues); the suffix is CPU:id, H = high priority, u = unbound"),
+    ("ksoftirqd/", "Per-CPU kernel thread handling softirqs (network, timers, block) under load"),
+    ("migration/", "Per-CPU kernel thread moving tasks between CPUs"),
+    ("rcu_", "Kernel RCU (read-copy-update) grace-period thread"),
+    ("rcuo", "Kernel RCU callback offload thread"),
+    ("cpuhp/", "Per-CPU kernel thread for CPU hotplug"),
+    ("idle_inject/", "Per-CPU kernel thread forcing idle time for thermal control"),
+    ("irq/", "Threaded interrupt handler (irq/<number>-<device>)"),
+    ("jbd2/", "ext4 journal commit thread for one filesystem"),
+    ("xfsaild/", "XFS metadata writeback thread for one filesystem"),
+    ("btrfs-", "Btrfs filesystem worker thread"),
+    ("kblockd", "Kernel block layer work queue"),
+    ("scsi_eh_", "SCSI error handler thread for one host adapter"),
+    ("nvme-", "NVMe driver work queue"),
+    ("writeback", "Kernel thread flushing dirty pages to disk"),
+    ("kswapd", "Kernel memory reclaim for one NUMA node - swaps/evicts pages when RAM runs low"),
+    ("kcompactd", "Kernel memory compaction (defragments free memory) for one NUMA node"),
+    ("systemd-", "systemd helper service"),
+    ("containerd-shim", "Per-container shim keeping a container running under containerd"),
+    ("php-fpm", "PHP FastCGI process manager (master or pool worker)"),
+    ("postgres", "PostgreSQL server or one of its backends/background workers"),
+    ("nginx", "nginx web server (master or worker)"),
+    ("apache2", "Apache HTTP server"),
+    ("httpd", "Apache HTTP server"),
+    ("mysqld", "MySQL/MariaDB database server"),
+    ("mariadbd", "MariaDB database server"),
+    ("redis-server", "Redis in-memory data store"),
+    ("gsd-", "GNOME settings daemon plugin"),
+    ("xdg-", "Desktop portal/helper service for the user session"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessKind {
+    KernelThread,
+    Container,
+    Daemon,
+    UserSession,
+    Other,
+}
+
+impl ProcessKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProcessKind::KernelThread => "Kernel thread",
+            ProcessKind::Container => "Container process",
+            ProcessKind::Daemon => "System daemon",
+            ProcessKind::UserSession => "User session",
+            ProcessKind::Other => "Other",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProcessExplanation {
+    pub description: Option<&'static str>, // From the bundled table
+    pub kind: ProcessKind,
+    pub reason: String, // Why the heuristic chose `kind`
+    pub exe: Option<String>,
+    pub package: Option<String>, // Only looked up for processes the table doesn't know
+}
+
+/// Description of a well-known process name (exact names first, then prefixes)
+pub fn describe(name: &str) -> Option<&'static str> {
+    KNOWN_NAMES
+        .iter()
+        .find(|(known, _)| *known == name)
+        .or_else(|| KNOWN_PREFIXES.iter().find(|(prefix, _)| name.starts_with(prefix)))
+        .map(|(_, description)| *description)
+}
+
+/// Classify a process from its parent, cgroup/unit and terminal
+pub fn classify(process: &ProcessInfo) -> (ProcessKind, String) {
+    if is_kernel_thread(process) {
+        return (ProcessKind::KernelThread, "child of kthreadd (PID 2), no executable".to_string());
+    }
+    if let Some(container) = &process.container_id {
+        let short: String = container.chars().take(12).collect();
+        return (ProcessKind::Container, format!("in container {}", short));
+    }
+    let has_tty = !matches!(process.tty.as_str(), "?" | "-" | "");
+    let unit = process.unit.as_deref().unwrap_or("");
+    if has_tty {
+        return (ProcessKind::UserSession, format!("attached to terminal {}", process.tty));
+    }
+    if unit.starts_with("session-") {
+        return (ProcessKind::UserSession, format!("in login session {}", unit));
+    }
+    if unit.ends_with(".service") && !process.cgroup.as_deref().unwrap_or("").contains("/user.slice/") {
+        return (ProcessKind::Daemon, format!("system service {}", unit));
+    }
+    if process.pid == 1 || process.parent_pid == Some(1) {
+        return (ProcessKind::Daemon, "no terminal, parent is init".to_string());
+    }
+    if !unit.is_empty() {
+        return (ProcessKind::UserSession, format!("user unit {}", unit));
+    }
+    (ProcessKind::Other, "no terminal, unit or container".to_string())
+}
+
+/// Executable behind a process (<proc_root>/<pid>/exe), None for kernel threads or without permission
+pub fn exe_path(proc_root: &Path, pid: u32) -> Option<String> {
+    let target = std::fs::read_link(proc_root.join(pid.to_string()).join("exe")).ok()?;
+    Some(target.to_string_lossy().into_owned())
+}
+
+/// Package that installed a file, asking dpkg or rpm (whichever is present)
+pub fn package_owner(path: &str) -> Option<String> {
+    // A replaced binary shows up as "/usr/bin/foo (deleted)"
+    let path = path.strip_suffix(" (deleted)").unwrap_or(path);
+    if let Ok(output) = Command::new("dpkg-query").args(["-S", path]).output()
+        && output.status.success()
+    {
+        // "coreutils: /usr/bin/sleep" (possibly several packages, comma-separated)
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        return stdout.lines().next().and_then(|line| line.split_once(": ")).map(|(pkg, _)| pkg.to_string());
+    }
+    if let Ok(output) = Command::new("rpm").args(["-qf", path]).output()
+        && output.status.success()
+    {
+        return String::from_utf8_lossy(&output.stdout).lines().next().map(|s| s.trim().to_string());
+    }
+    None
+}
+
+/// Everything the explain panel shows; the package lookup spawns a process, so callers cache this
+pub fn explain(proc_root: &Path, process: &ProcessInfo) -> ProcessExplanation {
+    let description = describe(&process.name);
+    let (kind, reason) = classify(process);
+    let exe = if kind == ProcessKind::KernelThread { None } else { exe_path(proc_root, process.pid) };
+    let package = match (&exe, description) {
+        (Some(exe), None) => package_owner(exe),
+        _ => None,
+    };
+    ProcessExplanation { description, kind, reason, exe, package }
+}
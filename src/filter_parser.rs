@@ -20,6 +20,92 @@ pub enum FilterExpression {
     Not(Box<FilterExpression>),
 }
 
+/// Value type of a filterable field, which decides the operators it accepts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldKind {
+    Text,
+    Numeric,
+}
+
+impl FieldKind {
+    pub fn operators(&self) -> &'static [&'static str] {
+        match self {
+            FieldKind::Text => &["==", "!=", "~="],
+            FieldKind::Numeric => &[">", ">=", "<", "<=", "==", "!="],
+        }
+    }
+}
+
+pub struct FilterField {
+    pub name: &'static str,
+    pub kind: FieldKind,
+    pub description: &'static str,
+}
+
+/// Every field the parser understands, in the order the guided builder lists them
+pub const FILTER_FIELDS: &[FilterField] = &[
+    FilterField { name: "name", kind: FieldKind::Text, description: "Process name" },
+    FilterField { name: "user", kind: FieldKind::Text, description: "Owning user" },
+    FilterField { name: "status", kind: FieldKind::Text, description: "State, e.g. running, sleeping, zombie" },
+    FilterField { name: "tty", kind: FieldKind::Text, description: "Controlling terminal, ? for none" },
+    FilterField { name: "label", kind: FieldKind::Text, description: "SELinux context or AppArmor profile, e.g. label ~= \"unconfined\"" },
+    FilterField { name: "caps", kind: FieldKind::Text, description: "Effective capabilities, e.g. caps ~= \"net_admin\"" },
+    FilterField { name: "pid", kind: FieldKind::Numeric, description: "Process ID" },
+    FilterField { name: "ppid", kind: FieldKind::Numeric, description: "Parent process ID" },
+    FilterField { name: "cpu", kind: FieldKind::Numeric, description: "CPU usage in percent" },
+    FilterField { name: "cpu_time", kind: FieldKind::Numeric, description: "Total CPU time in seconds (TIME+)" },
+    FilterField { name: "memory", kind: FieldKind::Numeric, description: "Resident memory in MiB" },
+    FilterField { name: "peak_memory", kind: FieldKind::Numeric, description: "Peak resident memory in MiB" },
+    FilterField { name: "nice", kind: FieldKind::Numeric, description: "Nice value (-20 to 19)" },
+    FilterField { name: "tracer", kind: FieldKind::Numeric, description: "PID of the attached debugger/tracer, 0 if none (tracer > 0: traced)" },
+    FilterField { name: "threads", kind: FieldKind::Numeric, description: "Number of threads" },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Join {
+    And,
+    Or,
+}
+
+/// One `field op value` clause assembled by the guided builder
+#[derive(Debug, Clone)]
+pub struct FilterClause {
+    pub join: Join, // How the clause attaches to the one before it (ignored for the first)
+    pub field: &'static str,
+    pub operator: &'static str,
+    pub value: String,
+}
+
+impl FilterClause {
+    /// Render as filter syntax, quoting values of text fields
+    pub fn to_expression(&self) -> String {
+        let is_text = FILTER_FIELDS
+            .iter()
+            .find(|f| f.name == self.field)
+            .is_none_or(|f| f.kind == FieldKind::Text);
+        if is_text {
+            format!("{} {} \"{}\"", self.field, self.operator, self.value)
+        } else {
+            format!("{} {} {}", self.field, self.operator, self.value)
+        }
+    }
+}
+
+/// Join clauses into an expression `parse` accepts; AND binds tighter than OR
+pub fn build_expression(clauses: &[FilterClause]) -> String {
+    let mut expression = String::new();
+    for (i, clause) in clauses.iter().enumerate() {
+        if i > 0 {
+            expression.push_str(match clause.join {
+                Join::And => " AND ",
+                Join::Or => " OR ",
+            });
+        }
+        expression.push_str(&clause.to_expression());
+    }
+    expression
+}
+
 pub struct FilterParser {
     regex_cache: HashMap<String, Regex>,
 }
@@ -196,10 +282,10 @@ impl FilterParser {
     pub fn evaluate(&mut self, process: &ProcessInfo, expr: &FilterExpression) -> bool {
         match expr {
             FilterExpression::FieldEquals { field, value } => {
-                self.get_field_value(process, field) == *value
+                self.field_equals(process, field, value)
             }
             FilterExpression::FieldNotEquals { field, value } => {
-                self.get_field_value(process, field) != *value
+                !self.field_equals(process, field, value)
             }
             FilterExpression::FieldRegex { field, pattern } => {
                 let field_value = self.get_field_value(process, field);
@@ -232,17 +318,35 @@ impl FilterParser {
         }
     }
 
+    fn field_equals(&self, process: &ProcessInfo, field: &str, value: &str) -> bool {
+        let field_value = self.get_field_value(process, field);
+        // Status names are matched case-insensitively (e.g. status == zombie)
+        if field == "status" {
+            field_value.trim().eq_ignore_ascii_case(value.trim())
+        } else {
+            field_value == value
+        }
+    }
+
     fn get_field_value(&self, process: &ProcessInfo, field: &str) -> String {
         match field {
             "name" => process.name.clone(),
             "user" => process.user.clone().unwrap_or_default(),
             "status" => process.status.clone(),
+            "tty" => process.tty.clone(),
+            "label" => process.security_label.clone().unwrap_or_default(),
+            // Comma-separated, so a regex can look for one capability
+            "caps" => process.capabilities.map(|c| c.effective_names().join(",")).unwrap_or_default(),
             // Handle numeric fields as strings for equality checks
             "pid" => process.pid.to_string(),
             "ppid" => process.parent_pid.unwrap_or(0).to_string(),
             "nice" => process.nice.to_string(),
+            "tracer" => process.tracer_pid.unwrap_or(0).to_string(),
+            "threads" => process.thread_count.to_string(),
             "cpu" => format!("{:.1}", process.cpu_usage),
+            "cpu_time" => format!("{:.0}", process.cpu_time_secs),
             "memory" => format!("{}", process.memory_usage / (1024 * 1024)),
+            "peak_memory" => format!("{}", effective_peak_memory(process) / (1024 * 1024)),
             _ => String::new(),
         }
     }
@@ -252,8 +356,12 @@ impl FilterParser {
             "pid" => process.pid as f64,
             "ppid" => process.parent_pid.unwrap_or(0) as f64,
             "cpu" => process.cpu_usage as f64,
-            "memory" => (process.memory_usage / (1024 * 1024)) as f64, // MB
+            "cpu_time" => process.cpu_time_secs, // Seconds
+            "memory" => (process.memory_usage / (1024 * 1024)) as f64, // MiB
+            "peak_memory" => (effective_peak_memory(process) / (1024 * 1024)) as f64, // MiB
             "nice" => process.nice as f64,
+            "tracer" => process.tracer_pid.unwrap_or(0) as f64,
+            "threads" => process.thread_count as f64,
             _ => 0.0,
         }
     }
@@ -265,3 +373,7 @@ impl Default for FilterParser {
     }
 }
 
+// Peak RSS of a process: the larger of the kernel's VmHWM and the peak seen this session
+fn effective_peak_memory(process: &ProcessInfo) -> u64 {
+    process.vm_hwm.unwrap_or(0).max(process.peak_memory)
+}
@@ -5,7 +5,7 @@ use std::time::{Duration, Instant};
 
 // Import Ratatui components
 use ratatui::{
-    widgets::{Block, Borders, Dataset, GraphType, Chart, Paragraph},
+    widgets::{Block, Borders, Dataset, GraphType, Chart, Paragraph, Table, Row, Cell, Sparkline},
     layout::{Layout, Constraint, Direction, Alignment, Rect},
     text::{Span, Line},
 };
@@ -22,6 +22,9 @@ use ratatui::style::{Style, Modifier, Color as RatatuiColor};
 
 use crate::ui::StatisticsTab;  // Add this at the top with other imports
 use crate::process::ProcessInfo;
+use crate::process_group::GroupType;
+use crate::units::{format_bytes, format_kib, scale_for};
+use crate::limits::{read_system_limits, LimitUsage, SystemLimits};
 
 // Add this struct at the top with other structs
 pub struct CpuInfo {
@@ -40,15 +43,78 @@ impl CpuInfo {
     }
 }
 
+// Throughput and IOPS of one block device since the previous sample
+pub struct DeviceStats {
+    pub name: String,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+}
+
+// Pressure stall averages from one /proc/pressure file, as percentages of wall time
+#[derive(Clone, Copy)]
+pub struct PsiStats {
+    pub some_avg10: f32,
+    pub some_avg60: f32,
+    pub full: Option<(f32, f32)>, // (avg10, avg60); absent for CPU on older kernels
+}
+
+// Latest pressure reading of one resource and a history of its "some" avg10 (hundredths of a percent)
+pub struct PsiTracker {
+    pub latest: Option<PsiStats>,
+    pub history: VecDeque<u64>,
+}
+
+impl PsiTracker {
+    fn new(max_points: usize) -> Self {
+        Self {
+            latest: None,
+            history: VecDeque::with_capacity(max_points),
+        }
+    }
+
+    fn update(&mut self, resource: &str, max_points: usize) {
+        self.latest = read_psi(resource);
+        if let Some(stats) = self.latest {
+            self.history.push_back((stats.some_avg10 * 100.0) as u64);
+            while self.history.len() > max_points {
+                self.history.pop_front();
+            }
+        }
+    }
+}
+
 // Modify GraphData struct
 pub struct GraphData {
     cpu_history: VecDeque<f32>,
     memory_history: VecDeque<u64>,
+    memory_total_mib: u64, // MemTotal at the latest sample
+    load_history: VecDeque<f32>, // 1-minute load average at each sample
+    network_history: VecDeque<u64>, // KB/s received + sent over all non-loopback interfaces
+    last_network_bytes: Option<(u64, Instant)>,
     max_points: usize,
     last_update: Instant,
     update_interval: Duration,
+    sample_interval: Duration, // Spacing of history points, for axis labels
     cpu_infos: Vec<CpuInfo>,  // Keep this for per-core display
     per_process_history: std::collections::HashMap<u32, (VecDeque<f32>, VecDeque<u64>)>,
+    tracked_group: Option<(GroupType, String)>, // Cgroup/container whose history is being recorded
+    group_history: (VecDeque<f32>, VecDeque<u64>),
+    device_counters: std::collections::HashMap<String, [u64; 4]>, // reads, sectors read, writes, sectors written
+    device_sample_time: Instant,
+    device_stats: Vec<DeviceStats>,
+    busiest_device_history: VecDeque<u64>, // KB/s of the busiest device at each sample
+    busiest_device: Option<String>,
+    show_virtual_devices: bool, // Include loop and ram devices
+    iowait_percent: f32,
+    last_iowait: (u64, u64), // (iowait ticks, total ticks)
+    psi_cpu: PsiTracker,
+    psi_memory: PsiTracker,
+    psi_io: PsiTracker,
+    last_swap_pages: Option<(u64, u64, Instant)>, // (pswpin, pswpout, when read)
+    swap_rates: (f64, f64), // Pages swapped in/out per second
+    limits: Option<SystemLimits>, // pid/thread/file/nproc usage vs ceilings, read each sample
 }
 
 impl GraphData {
@@ -56,12 +122,160 @@ impl GraphData {
         GraphData {
             cpu_history: VecDeque::with_capacity(max_points),
             memory_history: VecDeque::with_capacity(max_points),
+            memory_total_mib: 0,
+            load_history: VecDeque::with_capacity(max_points),
+            network_history: VecDeque::with_capacity(max_points),
+            last_network_bytes: None,
             max_points,
             last_update: Instant::now(),
             update_interval: Duration::from_millis(update_interval_ms),
+            sample_interval: Duration::from_secs(1),
             cpu_infos: (0..get_cpu_count()).map(|_| CpuInfo::new()).collect(),
             per_process_history: std::collections::HashMap::new(),
+            tracked_group: None,
+            group_history: (VecDeque::with_capacity(max_points), VecDeque::with_capacity(max_points)),
+            device_counters: std::collections::HashMap::new(),
+            device_sample_time: Instant::now(),
+            device_stats: Vec::new(),
+            busiest_device_history: VecDeque::with_capacity(max_points),
+            busiest_device: None,
+            show_virtual_devices: false,
+            iowait_percent: 0.0,
+            last_iowait: (0, 0),
+            psi_cpu: PsiTracker::new(max_points),
+            psi_memory: PsiTracker::new(max_points),
+            psi_io: PsiTracker::new(max_points),
+            last_swap_pages: None,
+            swap_rates: (0.0, 0.0),
+            limits: None,
+        }
+    }
+
+    // Diff /proc/diskstats against the previous sample to get per-device rates
+    fn update_device_stats(&mut self) {
+        let elapsed = self.device_sample_time.elapsed().as_secs_f64().max(0.1);
+        let mut counters = std::collections::HashMap::new();
+        let mut stats = Vec::new();
+        if let Ok(content) = std::fs::read_to_string("/proc/diskstats") {
+            for line in content.lines() {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                if parts.len() < 10 {
+                    continue;
+                }
+                let name = parts[2].to_string();
+                if !self.show_virtual_devices && (name.starts_with("loop") || name.starts_with("ram")) {
+                    continue;
+                }
+                let parse = |i: usize| parts[i].parse::<u64>().unwrap_or(0);
+                let current = [parse(3), parse(5), parse(7), parse(9)];
+                if let Some(prev) = self.device_counters.get(&name) {
+                    let delta = |i: usize| current[i].saturating_sub(prev[i]) as f64 / elapsed;
+                    stats.push(DeviceStats {
+                        name: name.clone(),
+                        read_iops: delta(0),
+                        read_bytes_per_sec: delta(1) * 512.0, // Sectors are always 512 bytes here
+                        write_iops: delta(2),
+                        write_bytes_per_sec: delta(3) * 512.0,
+                    });
+                }
+                counters.insert(name, current);
+            }
+        }
+        stats.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Record the busiest device's combined throughput for the sparkline
+        if let Some(busiest) = stats.iter().max_by(|a, b| {
+            (a.read_bytes_per_sec + a.write_bytes_per_sec)
+                .partial_cmp(&(b.read_bytes_per_sec + b.write_bytes_per_sec))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        }) {
+            self.busiest_device = Some(busiest.name.clone());
+            self.busiest_device_history.push_back(((busiest.read_bytes_per_sec + busiest.write_bytes_per_sec) / 1024.0) as u64);
+            while self.busiest_device_history.len() > self.max_points {
+                self.busiest_device_history.pop_front();
+            }
         }
+        self.device_counters = counters;
+        self.device_stats = stats;
+        self.device_sample_time = Instant::now();
+    }
+
+    // Share of CPU time spent waiting on I/O since the previous sample (aggregate cpu line of /proc/stat)
+    fn update_iowait(&mut self) {
+        let Ok(stat) = std::fs::read_to_string("/proc/stat") else {
+            return;
+        };
+        let Some(line) = stat.lines().next() else {
+            return;
+        };
+        let values: Vec<u64> = line.split_whitespace()
+            .skip(1)
+            .filter_map(|val| val.parse().ok())
+            .collect();
+        if values.len() < 5 {
+            return;
+        }
+        let iowait = values[4];
+        let total: u64 = values.iter().sum();
+        let total_delta = total.saturating_sub(self.last_iowait.1);
+        if self.last_iowait.1 > 0 && total_delta > 0 {
+            self.iowait_percent = iowait.saturating_sub(self.last_iowait.0) as f32 / total_delta as f32 * 100.0;
+        }
+        self.last_iowait = (iowait, total);
+    }
+
+    // Pages swapped in/out per second since the previous sample (from /proc/vmstat)
+    fn update_swap_rates(&mut self) {
+        let (_pgfault, pswpin, pswpout, _iowait) = get_vm_stats();
+        let now = Instant::now();
+        if let Some((last_in, last_out, last_time)) = self.last_swap_pages {
+            let elapsed = now.duration_since(last_time).as_secs_f64().max(0.1);
+            self.swap_rates = (
+                pswpin.saturating_sub(last_in) as f64 / elapsed,
+                pswpout.saturating_sub(last_out) as f64 / elapsed,
+            );
+        }
+        self.last_swap_pages = Some((pswpin, pswpout, now));
+    }
+
+    pub fn get_swap_rates(&self) -> (f64, f64) {
+        self.swap_rates
+    }
+
+    pub fn get_system_limits(&self) -> Option<&SystemLimits> {
+        self.limits.as_ref()
+    }
+
+    pub fn get_psi_cpu(&self) -> &PsiTracker {
+        &self.psi_cpu
+    }
+
+    pub fn get_psi_memory(&self) -> &PsiTracker {
+        &self.psi_memory
+    }
+
+    pub fn get_psi_io(&self) -> &PsiTracker {
+        &self.psi_io
+    }
+
+    pub fn get_device_stats(&self) -> &[DeviceStats] {
+        &self.device_stats
+    }
+
+    pub fn get_busiest_device_history(&self) -> (Option<&str>, &VecDeque<u64>) {
+        (self.busiest_device.as_deref(), &self.busiest_device_history)
+    }
+
+    pub fn get_iowait_percent(&self) -> f32 {
+        self.iowait_percent
+    }
+
+    pub fn toggle_virtual_devices(&mut self) {
+        self.show_virtual_devices = !self.show_virtual_devices;
+    }
+
+    pub fn shows_virtual_devices(&self) -> bool {
+        self.show_virtual_devices
     }
 
     fn update_cpu_info(&mut self) {
@@ -97,6 +311,26 @@ impl GraphData {
         }
     }
 
+    // Sample the 1-minute load average and the network rate since the previous sample
+    fn update_load_and_network(&mut self) {
+        self.load_history.push_back(get_load_average().0 as f32);
+        while self.load_history.len() > self.max_points {
+            self.load_history.pop_front();
+        }
+        let Some(bytes) = read_network_bytes() else {
+            return;
+        };
+        let now = Instant::now();
+        if let Some((last_bytes, last_time)) = self.last_network_bytes {
+            let elapsed = now.duration_since(last_time).as_secs_f64().max(0.1);
+            self.network_history.push_back((bytes.saturating_sub(last_bytes) as f64 / 1024.0 / elapsed) as u64);
+            while self.network_history.len() > self.max_points {
+                self.network_history.pop_front();
+            }
+        }
+        self.last_network_bytes = Some((bytes, now));
+    }
+
     pub fn update(&mut self, process_manager: &ProcessManager) {
         let now = Instant::now();
         if now.duration_since(self.last_update) < self.update_interval {
@@ -105,6 +339,14 @@ impl GraphData {
 
         // Update CPU info for the per-core display
         self.update_cpu_info();
+        self.update_iowait();
+        self.update_device_stats();
+        self.update_swap_rates();
+        self.psi_cpu.update("cpu", self.max_points);
+        self.psi_memory.update("memory", self.max_points);
+        self.psi_io.update("io", self.max_points);
+        self.limits = Some(read_system_limits());
+        self.update_load_and_network();
         
         // Get total CPU usage from all processes
         let total_cpu: f32 = process_manager.get_processes()
@@ -119,8 +361,9 @@ impl GraphData {
         }
         
         // Use system memory usage from /proc/meminfo
-        let (_mem_total, mem_used, _mem_free, _mem_cached, _mem_available) = get_memory_info();
-        let total_memory = mem_used / 1024; // Convert to MB
+        let (mem_total, mem_used, _mem_free, _mem_cached, _mem_available) = get_memory_info();
+        self.memory_total_mib = mem_total / 1024;
+        let total_memory = mem_used / 1024; // Convert KiB to MiB
         self.memory_history.push_back(total_memory);
         while self.memory_history.len() > self.max_points {
             self.memory_history.pop_front();
@@ -145,6 +388,24 @@ impl GraphData {
                 entry.1.pop_front();
             }
         }
+
+        // Sum the members of the tracked cgroup/container
+        if let Some((group_type, group_id)) = &self.tracked_group {
+            let members = process_manager.get_processes().iter().filter(|p| match group_type {
+                GroupType::Cgroup => p.cgroup.as_deref() == Some(group_id.as_str()),
+                GroupType::Container => p.container_id.as_deref() == Some(group_id.as_str()),
+                _ => false,
+            });
+            let (group_cpu, group_mem) = members.fold((0.0f32, 0u64), |(cpu, mem), p| (cpu + p.cpu_usage, mem + p.memory_usage));
+            self.group_history.0.push_back(group_cpu);
+            self.group_history.1.push_back(group_mem);
+            while self.group_history.0.len() > self.max_points {
+                self.group_history.0.pop_front();
+            }
+            while self.group_history.1.len() > self.max_points {
+                self.group_history.1.pop_front();
+            }
+        }
         self.last_update = now;
     }
 
@@ -160,9 +421,65 @@ impl GraphData {
         &self.memory_history
     }
 
+    pub fn get_load_history(&self) -> &VecDeque<f32> {
+        &self.load_history
+    }
+
+    pub fn get_network_history(&self) -> &VecDeque<u64> {
+        &self.network_history
+    }
+
+    /// Sample once per process refresh of `interval`. The gate is half of it so a refresh
+    /// that lands a little early is not dropped.
+    pub fn set_sample_interval(&mut self, interval: Duration) {
+        self.sample_interval = interval;
+        self.update_interval = interval / 2;
+    }
+
+    /// Number of samples each history keeps
+    pub fn max_points(&self) -> usize {
+        self.max_points
+    }
+
+    /// Latest system-wide (CPU %, memory used MiB, memory total MiB); CPU averages all cores
+    pub fn latest_system_usage(&self) -> (f32, u64, u64) {
+        let cpu = if self.cpu_infos.is_empty() {
+            0.0
+        } else {
+            self.cpu_infos.iter().map(|c| c.usage).sum::<f32>() / self.cpu_infos.len() as f32
+        };
+        let memory_used = self.memory_history.back().copied().unwrap_or(0);
+        (cpu, memory_used, self.memory_total_mib)
+    }
+
     pub fn get_process_history(&self, pid: u32) -> Option<(&VecDeque<f32>, &VecDeque<u64>)> {
         self.per_process_history.get(&pid).map(|(cpu, mem)| (cpu, mem))
     }
+
+    /// Start recording history for a cgroup or container (None stops tracking)
+    pub fn track_group(&mut self, group: Option<(GroupType, String)>) {
+        if self.tracked_group != group {
+            self.tracked_group = group;
+            self.group_history.0.clear();
+            self.group_history.1.clear();
+        }
+    }
+
+    pub fn get_tracked_group(&self) -> Option<&(GroupType, String)> {
+        self.tracked_group.as_ref()
+    }
+
+    pub fn get_group_history(&self) -> (&VecDeque<f32>, &VecDeque<u64>) {
+        (&self.group_history.0, &self.group_history.1)
+    }
+
+    /// Drop recorded process and group CPU history, e.g. after the CPU% normalization changes
+    pub fn clear_process_cpu_history(&mut self) {
+        for (cpu, _) in self.per_process_history.values_mut() {
+            cpu.clear();
+        }
+        self.group_history.0.clear();
+    }
 }
 
 pub fn render_graph_dashboard(
@@ -185,8 +502,8 @@ pub fn render_graph_dashboard(
         StatisticsTab::Graphs => render_graphs_tab(frame, main_chunks[1], graph_data),
         StatisticsTab::Overview => render_overview_tab(frame, main_chunks[1], graph_data, process_list),
         StatisticsTab::CPU => render_cpu_tab(frame, main_chunks[1], graph_data),
-        StatisticsTab::Memory => render_memory_tab(frame, main_chunks[1]),
-        StatisticsTab::Disk => render_disk_tab(frame, main_chunks[1]),
+        StatisticsTab::Memory => render_memory_tab(frame, main_chunks[1], graph_data),
+        StatisticsTab::Disk => render_disk_tab(frame, main_chunks[1], graph_data, process_list),
         StatisticsTab::Processes => {
             render_processes_tab(frame, main_chunks[1], process_list);
         },
@@ -219,7 +536,8 @@ pub fn render_tabs(frame: &mut ratatui::Frame, area: Rect, current_tab: &Statist
                 .fg(RatatuiColor::Black)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)),
         Span::raw(" "),
-        Span::styled("[1] Graphs  [2] Overview  [3] CPU  [4] Memory  [5] Disk  [6] Processes  [7] Advanced ", Style::default().fg(RatatuiColor::Black)),
+        Span::styled("[1] Graphs  [2] Overview  [3] CPU  [4] Memory  [5] Disk  [6] Processes  [7] Advanced  [d] Dashboard ", Style::default().fg(RatatuiColor::Black)),
+        Span::styled("[e] Export SVG  ", Style::default().fg(RatatuiColor::Black)),
         Span::styled("[S/Esc] Return", Style::default().fg(RatatuiColor::Black))
     ]);
 
@@ -332,7 +650,7 @@ fn render_memory_bars(
     let memory_gauge = ratatui::widgets::Gauge::default()
         .gauge_style(Style::default().fg(get_usage_color(memory_percentage as f32)))
         .percent(memory_percentage)
-        .label(format!("Mem [{:>4}M/{:>4}M]", mem_used / 1024, mem_total / 1024));
+        .label(format!("Mem [{}/{}]", format_kib(mem_used), format_kib(mem_total)));
 
     // Swap bar (reading from /proc/swaps)
 
@@ -357,12 +675,37 @@ fn render_memory_bars(
     let swap_gauge = ratatui::widgets::Gauge::default()
         .gauge_style(Style::default().fg(get_usage_color(swap_percentage as f32)))
         .percent(swap_percentage)
-        .label(format!("Swp [{:>4}M/{:>4}M]", swap_used, swap_total));
+        .label(format!("Swp [{}/{}]", format_kib(swap_used), format_kib(swap_total)));
 
     frame.render_widget(memory_gauge, mem_area);
     frame.render_widget(swap_gauge, swap_area);
 }
 
+/// One-line CPU and memory gauges shown above every view
+pub fn render_gauge_header(frame: &mut ratatui::Frame, area: Rect, graph_data: &GraphData) {
+    let (cpu, memory_used, memory_total) = graph_data.latest_system_usage();
+    let cpu_percent = cpu.clamp(0.0, 100.0);
+    let memory_percent = if memory_total > 0 {
+        (memory_used as f64 / memory_total as f64 * 100.0).clamp(0.0, 100.0) as f32
+    } else {
+        0.0
+    };
+    let halves = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let cpu_gauge = ratatui::widgets::Gauge::default()
+        .gauge_style(Style::default().fg(get_usage_color(cpu_percent)).bg(RatatuiColor::DarkGray))
+        .percent(cpu_percent.round() as u16)
+        .label(format!("CPU {:.1}%", cpu_percent));
+    let memory_gauge = ratatui::widgets::Gauge::default()
+        .gauge_style(Style::default().fg(get_usage_color(memory_percent)).bg(RatatuiColor::DarkGray))
+        .percent(memory_percent.round() as u16)
+        .label(format!("Mem {}/{} ({:.0}%)", format_kib(memory_used * 1024), format_kib(memory_total * 1024), memory_percent));
+    frame.render_widget(cpu_gauge, halves[0]);
+    frame.render_widget(memory_gauge, halves[1]);
+}
+
 fn get_usage_color(usage: f32) -> RatatuiColor {
     match usage as u16 {
         0..=50 => RatatuiColor::Green,
@@ -372,6 +715,28 @@ fn get_usage_color(usage: f32) -> RatatuiColor {
     }
 }
 
+// Parse /proc/pressure/<resource>; None when the kernel has no PSI support
+fn read_psi(resource: &str) -> Option<PsiStats> {
+    let content = std::fs::read_to_string(format!("/proc/pressure/{}", resource)).ok()?;
+    // Lines look like: some avg10=0.00 avg60=0.00 avg300=0.00 total=0
+    let parse_line = |kind: &str| -> Option<(f32, f32)> {
+        let line = content.lines().find(|l| l.starts_with(kind))?;
+        let field = |name: &str| -> Option<f32> {
+            line.split_whitespace()
+                .find_map(|part| part.strip_prefix(name))
+                .and_then(|value| value.parse().ok())
+        };
+        Some((field("avg10=")?, field("avg60=")?))
+    };
+    let (some_avg10, some_avg60) = parse_line("some")?;
+    Some(PsiStats {
+        some_avg10,
+        some_avg60,
+        full: parse_line("full"),
+    })
+}
+
+// (used, total) swap in KiB
 fn get_swap_info() -> (u64, u64) {
     if let Ok(swaps) = std::fs::read_to_string("/proc/swaps") {
         if let Some(swap_line) = swaps.lines().nth(1) {
@@ -381,7 +746,7 @@ fn get_swap_info() -> (u64, u64) {
                     parts[2].parse::<u64>(),
                     parts[3].parse::<u64>(),
                 ) {
-                    return (used / 1024, total / 1024);  // Convert KB to MB
+                    return (used, total);
                 }
             }
         }
@@ -398,6 +763,7 @@ pub fn render_overview_tab(frame: &mut ratatui::Frame, area: Rect, graph_data: &
             ratatui::layout::Constraint::Length(5),   // Memory Summary
             ratatui::layout::Constraint::Length(6),   // Disk Summary (increased from 4 to 6)
             ratatui::layout::Constraint::Length(4),   // Process States
+            ratatui::layout::Constraint::Length(6),   // System Limits
             ratatui::layout::Constraint::Min(1),      // Spacer
         ])
         .split(area);
@@ -438,10 +804,10 @@ pub fn render_overview_tab(frame: &mut ratatui::Frame, area: Rect, graph_data: &
     let (mem_total, mem_used, mem_free, mem_cached, _mem_available) = get_memory_info();
     let mem_summary = vec![
         Line::from(vec![Span::styled("Memory Summary", Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD))]),
-        Line::from(vec![Span::styled("Total: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_total / 1024), Style::default().fg(RatatuiColor::Black))]),
-        Line::from(vec![Span::styled("Used: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_used / 1024), get_usage_style((mem_used as f64 / mem_total as f64) * 100.0))]),
-        Line::from(vec![Span::styled("Free: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_free / 1024), Style::default().fg(RatatuiColor::Black))]),
-        Line::from(vec![Span::styled("Cached+Buffers: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_cached / 1024), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Total: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(mem_total), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Used: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(mem_used), get_usage_style((mem_used as f64 / mem_total as f64) * 100.0))]),
+        Line::from(vec![Span::styled("Free: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(mem_free), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Cached+Buffers: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(mem_cached), Style::default().fg(RatatuiColor::Black))]),
     ];
     let mem_summary_widget = Paragraph::new(mem_summary).block(Block::default().borders(Borders::ALL)).style(Style::default());
     frame.render_widget(mem_summary_widget, chunks[2]);
@@ -482,6 +848,44 @@ pub fn render_overview_tab(frame: &mut ratatui::Frame, area: Rect, graph_data: &
     ];
     let process_states_widget = Paragraph::new(process_states).block(Block::default().borders(Borders::ALL)).style(Style::default());
     frame.render_widget(process_states_widget, chunks[4]);
+
+    // System Limits (fork/thread/FD ceilings)
+    let limits = graph_data.get_system_limits().cloned().unwrap_or_else(read_system_limits);
+    render_limits_block(frame, chunks[5], &limits);
+}
+
+// One gauge per ceiling; the title turns red and names the limits at >= 80%
+fn render_limits_block(frame: &mut ratatui::Frame, area: Rect, limits: &SystemLimits) {
+    let near = limits.near_limits();
+    let nr_open = limits.nr_open.map(|n| format!(" (per-process FD max {})", n)).unwrap_or_default();
+    let (title, title_style) = if near.is_empty() {
+        (format!("System Limits{}", nr_open), Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD))
+    } else {
+        (format!("⚠ Near limit: {}{}", near.join(", "), nr_open), Style::default().fg(RatatuiColor::Red).add_modifier(Modifier::BOLD))
+    };
+    let block = Block::default().borders(Borders::ALL).title(Span::styled(title, title_style));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1); 4])
+        .split(inner);
+    for (usage, row) in limits.all().into_iter().zip(rows.iter()) {
+        frame.render_widget(limit_gauge(usage), *row);
+    }
+}
+
+fn limit_gauge(usage: &LimitUsage) -> ratatui::widgets::Gauge<'static> {
+    let percent = usage.percent().unwrap_or(0.0).clamp(0.0, 100.0);
+    let label = match usage.max {
+        Some(max) => format!("{}: {}/{} ({:.1}%)", usage.label, usage.used, max, percent),
+        None => format!("{}: {} (unlimited)", usage.label, usage.used),
+    };
+    ratatui::widgets::Gauge::default()
+        .gauge_style(Style::default().fg(get_usage_color(percent as f32)))
+        .percent(percent.round() as u16)
+        .label(label)
 }
 
 pub fn render_cpu_tab(frame: &mut ratatui::Frame, area: Rect, graph_data: &GraphData) {
@@ -524,11 +928,18 @@ pub fn render_cpu_tab(frame: &mut ratatui::Frame, area: Rect, graph_data: &Graph
             Span::styled(freq_str, Style::default().fg(RatatuiColor::Black)),
         ]));
     }
+    lines.push(Line::from(vec![Span::styled("", Style::default())]));
+    lines.extend(psi_lines("CPU", graph_data.get_psi_cpu()));
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(5)])
+        .split(area);
     let widget = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("CPU Info").style(Style::default().fg(RatatuiColor::Black))).wrap(ratatui::widgets::Wrap { trim: false });
-    frame.render_widget(widget, area);
+    frame.render_widget(widget, chunks[0]);
+    render_psi_sparkline(frame, chunks[1], "CPU", graph_data.get_psi_cpu());
 }
 
-pub fn render_memory_tab(frame: &mut ratatui::Frame, area: Rect) {
+pub fn render_memory_tab(frame: &mut ratatui::Frame, area: Rect, graph_data: &GraphData) {
     let (mem_total, mem_used, mem_free, mem_cached, _mem_available) = get_memory_info();
     let (swap_used, swap_total) = get_swap_info();
     // Read more details from /proc/meminfo
@@ -543,36 +954,116 @@ pub fn render_memory_tab(frame: &mut ratatui::Frame, area: Rect) {
             }
         }
     }
-    let mem_total_mb = mem_total / 1024;
-    let mem_used_mb = mem_used / 1024;
-    let mem_free_mb = mem_free / 1024;
-    let mem_cached_mb = mem_cached / 1024;
-    let mem_available_mb = available / 1024;
-    let mem_buffers_mb = buffers / 1024;
     let swap_free = swap_total.saturating_sub(swap_used);
     let mem_usage_percent = if mem_total > 0 { (mem_used as f64 / mem_total as f64) * 100.0 } else { 0.0 };
     let swap_usage_percent = if swap_total > 0 { (swap_used as f64 / swap_total as f64) * 100.0 } else { 0.0 };
-    let lines = vec![
+    let (swap_in_rate, swap_out_rate) = graph_data.get_swap_rates();
+    let mut lines = vec![
         Line::from(vec![Span::styled("Memory Information", Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD))]),
         Line::from(vec![Span::styled("", Style::default())]),
         Line::from(vec![Span::styled("-- RAM --", Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD))]),
-        Line::from(vec![Span::styled("Total: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_total_mb), Style::default().fg(RatatuiColor::Black))]),
-        Line::from(vec![Span::styled("Used: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB ({:.1}%)", mem_used_mb, mem_usage_percent), get_usage_style(mem_usage_percent))]),
-        Line::from(vec![Span::styled("Free: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_free_mb), Style::default().fg(RatatuiColor::Black))]),
-        Line::from(vec![Span::styled("Available: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_available_mb), Style::default().fg(RatatuiColor::Black))]),
-        Line::from(vec![Span::styled("Cached: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_cached_mb), Style::default().fg(RatatuiColor::Black))]),
-        Line::from(vec![Span::styled("Buffers: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", mem_buffers_mb), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Total: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(mem_total), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Used: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} ({:.1}%)", format_kib(mem_used), mem_usage_percent), get_usage_style(mem_usage_percent))]),
+        Line::from(vec![Span::styled("Free: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(mem_free), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Available: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(available), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Cached: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(mem_cached), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Buffers: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(buffers), Style::default().fg(RatatuiColor::Black))]),
         Line::from(vec![Span::styled("", Style::default())]),
         Line::from(vec![Span::styled("-- SWAP --", Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD))]),
-        Line::from(vec![Span::styled("Total: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", swap_total), Style::default().fg(RatatuiColor::Black))]),
-        Line::from(vec![Span::styled("Used: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB ({:.1}%)", swap_used, swap_usage_percent), get_usage_style(swap_usage_percent))]),
-        Line::from(vec![Span::styled("Free: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", swap_free), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Total: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(swap_total), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("Used: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} ({:.1}%)", format_kib(swap_used), swap_usage_percent), get_usage_style(swap_usage_percent))]),
+        Line::from(vec![Span::styled("Free: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format_kib(swap_free), Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![
+            Span::styled("Activity: ", Style::default().fg(RatatuiColor::Black)),
+            Span::styled(
+                format!("{:.0} pages/s in, {:.0} pages/s out", swap_in_rate, swap_out_rate),
+                if swap_in_rate + swap_out_rate > 0.0 { Style::default().fg(RatatuiColor::Red) } else { Style::default().fg(RatatuiColor::Black) },
+            ),
+        ]),
+        Line::from(vec![Span::styled("", Style::default())]),
     ];
+    lines.extend(psi_lines("Memory", graph_data.get_psi_memory()));
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(5)])
+        .split(area);
     let widget = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Memory Info").style(Style::default().fg(RatatuiColor::Black)));
-    frame.render_widget(widget, area);
+    frame.render_widget(widget, chunks[0]);
+    render_psi_sparkline(frame, chunks[1], "Memory", graph_data.get_psi_memory());
+}
+
+// Pressure stall lines for a Statistics tab, or a note when the kernel has no PSI
+fn psi_lines(resource: &str, tracker: &PsiTracker) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(vec![Span::styled(
+        format!("-- {} PRESSURE (PSI) --", resource.to_uppercase()),
+        Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD),
+    )])];
+    let Some(stats) = tracker.latest else {
+        lines.push(Line::from(vec![Span::styled("PSI unavailable", Style::default().fg(RatatuiColor::Gray))]));
+        return lines;
+    };
+    lines.push(Line::from(vec![
+        Span::styled("Some: ", Style::default().fg(RatatuiColor::Black)),
+        Span::styled(
+            format!("{:.2}% (10s), {:.2}% (60s)", stats.some_avg10, stats.some_avg60),
+            get_pressure_style(stats.some_avg10),
+        ),
+    ]));
+    if let Some((full_avg10, full_avg60)) = stats.full {
+        lines.push(Line::from(vec![
+            Span::styled("Full: ", Style::default().fg(RatatuiColor::Black)),
+            Span::styled(
+                format!("{:.2}% (10s), {:.2}% (60s)", full_avg10, full_avg60),
+                get_pressure_style(full_avg10),
+            ),
+        ]));
+    }
+    lines
+}
+
+// History of "some" avg10 for one resource
+fn render_psi_sparkline(frame: &mut ratatui::Frame, area: Rect, resource: &str, tracker: &PsiTracker) {
+    let data: Vec<u64> = tracker.history.iter().copied().collect();
+    let title = if tracker.latest.is_some() {
+        format!("{} Pressure (some, avg10)", resource)
+    } else {
+        format!("{} Pressure - PSI unavailable", resource)
+    };
+    // Scale to at least 10% so near-zero noise does not fill the graph
+    let max = data.iter().copied().max().unwrap_or(0).max(1000);
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(title).style(Style::default().fg(RatatuiColor::Black)))
+        .data(&data)
+        .max(max)
+        .style(Style::default().fg(RatatuiColor::Magenta));
+    frame.render_widget(sparkline, area);
+}
+
+// Stall time above a few percent is already noticeable; above 10% things feel stuck
+fn get_pressure_style(avg10: f32) -> Style {
+    if avg10 >= 10.0 {
+        Style::default().fg(RatatuiColor::Red).add_modifier(Modifier::BOLD)
+    } else if avg10 >= 1.0 {
+        Style::default().fg(RatatuiColor::Yellow)
+    } else {
+        Style::default().fg(RatatuiColor::Green)
+    }
 }
 
-pub fn render_disk_tab(frame: &mut ratatui::Frame, area: Rect) {
+// Number of processes shown in the Disk tab's top I/O table
+const TOP_IO_PROCESSES: usize = 15;
+
+pub fn render_disk_tab(frame: &mut ratatui::Frame, area: Rect, graph_data: &GraphData, process_list: &[ProcessInfo]) {
+    let devices = graph_data.get_device_stats();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(14), // Disk info and I/O pressure
+            Constraint::Length((devices.len() as u16 + 3).min(12)), // Devices
+            Constraint::Length(5),  // Busiest device and I/O pressure sparklines
+            Constraint::Min(5),     // Top I/O processes
+        ])
+        .split(area);
     let (disk_total, disk_used) = get_disk_stats();
     let disk_free = disk_total.saturating_sub(disk_used);
     // Try to get disk read/write speeds and storage type
@@ -580,7 +1071,7 @@ pub fn render_disk_tab(frame: &mut ratatui::Frame, area: Rect) {
     let storage_type = get_storage_type();
     let read_speed_str = if read_speed > 0.0 { format!("{:.1} MB/s", read_speed) } else { "Unavailable".to_string() };
     let write_speed_str = if write_speed > 0.0 { format!("{:.1} MB/s", write_speed) } else { "Unavailable".to_string() };
-    let lines = vec![
+    let mut lines = vec![
         Line::from(vec![Span::styled("Disk Information", Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD))]),
         Line::from(vec![Span::styled("Total: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", disk_total), Style::default().fg(RatatuiColor::Black))]),
         Line::from(vec![Span::styled("Used: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{} MB", disk_used), get_usage_style((disk_used as f64 / disk_total.max(1) as f64) * 100.0))]),
@@ -588,9 +1079,91 @@ pub fn render_disk_tab(frame: &mut ratatui::Frame, area: Rect) {
         Line::from(vec![Span::styled("Read Speed: ", Style::default().fg(RatatuiColor::Black)), Span::styled(read_speed_str, Style::default().fg(RatatuiColor::Black))]),
         Line::from(vec![Span::styled("Write Speed: ", Style::default().fg(RatatuiColor::Black)), Span::styled(write_speed_str, Style::default().fg(RatatuiColor::Black))]),
         Line::from(vec![Span::styled("Storage Type: ", Style::default().fg(RatatuiColor::Black)), Span::styled(storage_type, Style::default().fg(RatatuiColor::Black))]),
+        Line::from(vec![Span::styled("I/O Wait: ", Style::default().fg(RatatuiColor::Black)), Span::styled(format!("{:.1}%", graph_data.get_iowait_percent()), get_usage_style(graph_data.get_iowait_percent() as f64 * 5.0))]),
+        Line::from(vec![Span::styled("", Style::default())]),
     ];
+    lines.extend(psi_lines("I/O", graph_data.get_psi_io()));
     let widget = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Disk Info").style(Style::default().fg(RatatuiColor::Black)));
-    frame.render_widget(widget, area);
+    frame.render_widget(widget, chunks[0]);
+
+    // Per-device activity, iostat style
+    let device_rows: Vec<Row> = devices.iter()
+        .map(|d| Row::new(vec![
+            Cell::from(d.name.clone()),
+            Cell::from(format!("{:.1} KB/s", d.read_bytes_per_sec / 1024.0)),
+            Cell::from(format!("{:.1} KB/s", d.write_bytes_per_sec / 1024.0)),
+            Cell::from(format!("{:.1}", d.read_iops)),
+            Cell::from(format!("{:.1}", d.write_iops)),
+        ]).style(Style::default().fg(RatatuiColor::Black)))
+        .collect();
+    let device_title = if graph_data.shows_virtual_devices() {
+        "Block Devices ([v] hide loop/ram)"
+    } else {
+        "Block Devices ([v] show loop/ram)"
+    };
+    let device_table = Table::new(device_rows)
+        .header(Row::new(vec!["DEVICE", "READ/s", "WRITE/s", "R IOPS", "W IOPS"])
+            .style(Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD)))
+        .block(Block::default().borders(Borders::ALL).title(device_title).style(Style::default().fg(RatatuiColor::Black)))
+        .widths(&[
+            Constraint::Length(12),
+            Constraint::Length(14),
+            Constraint::Length(14),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ]);
+    frame.render_widget(device_table, chunks[1]);
+
+    let sparkline_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[2]);
+
+    // Throughput history of the busiest device
+    let (busiest, history) = graph_data.get_busiest_device_history();
+    let data: Vec<u64> = history.iter().copied().collect();
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL)
+            .title(format!("Busiest Device: {} (KB/s)", busiest.unwrap_or("-")))
+            .style(Style::default().fg(RatatuiColor::Black)))
+        .data(&data)
+        .style(Style::default().fg(RatatuiColor::Blue));
+    frame.render_widget(sparkline, sparkline_chunks[0]);
+    render_psi_sparkline(frame, sparkline_chunks[1], "I/O", graph_data.get_psi_io());
+
+    // Top disk consumers by combined read+write rate (ties broken by PID)
+    let mut top: Vec<&ProcessInfo> = process_list.iter().collect();
+    top.sort_by(|a, b| {
+        (b.io_read_rate + b.io_write_rate)
+            .partial_cmp(&(a.io_read_rate + a.io_write_rate))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.pid.cmp(&b.pid))
+    });
+    let rows: Vec<Row> = top.iter()
+        .take(TOP_IO_PROCESSES)
+        .map(|p| Row::new(vec![
+            Cell::from(p.pid.to_string()),
+            Cell::from(p.name.clone()),
+            Cell::from(format!("{:.1} KB/s", p.io_read_rate / 1024.0)),
+            Cell::from(format!("{:.1} KB/s", p.io_write_rate / 1024.0)),
+            Cell::from(format_bytes(p.io_read_bytes)),
+            Cell::from(format_bytes(p.io_write_bytes)),
+        ]).style(Style::default().fg(RatatuiColor::Black)))
+        .collect();
+    let header = Row::new(vec!["PID", "NAME", "READ/s", "WRITE/s", "TOTAL READ", "TOTAL WRITTEN"])
+        .style(Style::default().fg(RatatuiColor::Black).add_modifier(Modifier::BOLD));
+    let table = Table::new(rows)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title("Top Disk I/O Processes").style(Style::default().fg(RatatuiColor::Black)))
+        .widths(&[
+            Constraint::Length(8),
+            Constraint::Length(20),
+            Constraint::Length(14),
+            Constraint::Length(14),
+            Constraint::Length(14),
+            Constraint::Length(14),
+        ]);
+    frame.render_widget(table, chunks[3]);
 }
 
 pub fn render_processes_tab(frame: &mut ratatui::Frame, area: Rect, process_list: &[ProcessInfo]) {
@@ -622,7 +1195,7 @@ pub fn render_processes_tab(frame: &mut ratatui::Frame, area: Rect, process_list
     sorted_by_mem.sort_by(|a, b| b.1.memory_usage.partial_cmp(&a.1.memory_usage).unwrap_or(std::cmp::Ordering::Equal));
     for &(i, proc) in &sorted_by_mem.iter().take(5).collect::<Vec<_>>() {
         lines.push(Line::from(vec![Span::styled(
-            format!("{}. {} (PID {}) - MEM: {:.2} MB", i + 1, proc.name, proc.pid, proc.memory_usage as f64 / 1024.0 / 1024.0),
+            format!("{}. {} (PID {}) - MEM: {}", i + 1, proc.name, proc.pid, format_bytes(proc.memory_usage)),
             Style::default().fg(RatatuiColor::Blue)
         )]));
     }
@@ -661,6 +1234,113 @@ pub fn render_advanced_tab(frame: &mut ratatui::Frame, area: Rect, _graph_data:
     frame.render_widget(widget, area);
 }
 
+/// Export the graphs behind the current Statistics tab to an SVG file and return its path
+///
+/// The Disk tab exports the busiest device's throughput; every other tab exports CPU and memory history.
+pub fn export_snapshot_svg(graph_data: &GraphData, current_tab: &StatisticsTab) -> std::io::Result<std::path::PathBuf> {
+    use crate::svg_export::{render_svg, write_snapshot, LineChart, Series};
+
+    let x_label = format!("Samples ago (1 per {:.1}s)", graph_data.sample_interval.as_secs_f64());
+    let charts = match current_tab {
+        StatisticsTab::Disk => {
+            let (device, history) = graph_data.get_busiest_device_history();
+            let values: Vec<f64> = history.iter().map(|&v| v as f64).collect();
+            let y_max = values.iter().fold(1.0_f64, |a, &b| a.max(b)) * 1.1;
+            vec![LineChart {
+                title: format!("Disk Throughput ({})", device.unwrap_or("no device")),
+                y_label: "KB/s".to_string(),
+                x_label,
+                y_max,
+                series: vec![Series { label: "Read + Write".to_string(), color: "#1f77b4", values }],
+            }]
+        }
+        _ => {
+            let cpu: Vec<f64> = graph_data.get_cpu_history().iter().map(|&v| v as f64).collect();
+            let memory: Vec<f64> = graph_data.get_memory_history().iter().map(|&v| v as f64).collect();
+            let total_memory_mb = get_memory_info().0 as f64 / 1024.0;
+            let memory_max = memory.iter().fold(total_memory_mb, |a, &b| a.max(b));
+            vec![
+                LineChart {
+                    title: "CPU Usage".to_string(),
+                    y_label: "CPU %".to_string(),
+                    x_label: x_label.clone(),
+                    y_max: 100.0,
+                    series: vec![Series { label: "Total CPU".to_string(), color: "#17becf", values: cpu }],
+                },
+                LineChart {
+                    title: "Memory Usage".to_string(),
+                    y_label: "MiB".to_string(),
+                    x_label,
+                    y_max: memory_max,
+                    series: vec![Series { label: "Used memory".to_string(), color: "#2ca02c", values: memory }],
+                },
+            ]
+        }
+    };
+    let title = format!("lpm snapshot - {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+    write_snapshot("graph", &render_svg(&title, &charts))
+}
+
+/// Render CPU and memory history of the tracked cgroup/container side by side
+pub fn render_group_graph(frame: &mut ratatui::Frame, area: Rect, graph_data: &GraphData, title: &str) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let (cpu_history, mem_history) = graph_data.get_group_history();
+
+    let cpu_data: Vec<(f64, f64)> = cpu_history
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| (i as f64, value as f64))
+        .collect();
+    let max_cpu = cpu_data.iter().map(|&(_, y)| y).fold(100.0_f64, |a, b| a.max(b));
+    let cpu_dataset = Dataset::default()
+        .name("CPU %")
+        .marker(ratatui::symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(RatatuiColor::Cyan))
+        .data(&cpu_data);
+    let cpu_chart = Chart::new(vec![cpu_dataset])
+        .block(Block::default()
+            .title(format!("{} CPU (%)", title)).style(Style::default().fg(RatatuiColor::Black))
+            .borders(Borders::ALL))
+        .x_axis(ratatui::widgets::Axis::default()
+            .bounds([0.0, graph_data.max_points as f64])
+            .labels(vec![]))
+        .y_axis(ratatui::widgets::Axis::default()
+            .bounds([0.0, max_cpu])
+            .labels(vec![Span::from("0%"), Span::from(format!("{:.0}%", max_cpu))]));
+    frame.render_widget(cpu_chart, chunks[0]);
+
+    let mem_data: Vec<(f64, f64)> = mem_history
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| (i as f64, value as f64))
+        .collect();
+    let max_bytes = mem_data.iter().map(|&(_, y)| y).fold(100.0 * 1024.0 * 1024.0, f64::max);
+    let (divisor, unit) = scale_for(max_bytes);
+    let mem_data: Vec<(f64, f64)> = mem_data.into_iter().map(|(x, y)| (x, y / divisor)).collect();
+    let max_mem = max_bytes / divisor;
+    let mem_dataset = Dataset::default()
+        .name(format!("Memory {}", unit))
+        .marker(ratatui::symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(RatatuiColor::Green))
+        .data(&mem_data);
+    let mem_chart = Chart::new(vec![mem_dataset])
+        .block(Block::default()
+            .title(format!("{} Memory ({})", title, unit)).style(Style::default().fg(RatatuiColor::Black))
+            .borders(Borders::ALL))
+        .x_axis(ratatui::widgets::Axis::default()
+            .bounds([0.0, graph_data.max_points as f64])
+            .labels(vec![]))
+        .y_axis(ratatui::widgets::Axis::default()
+            .bounds([0.0, max_mem])
+            .labels(vec![Span::from(format!("0 {}", unit)), Span::from(format!("{:.1} {}", max_mem, unit))]));
+    frame.render_widget(mem_chart, chunks[1]);
+}
+
 fn render_cpu_graph(
     frame: &mut ratatui::Frame,
     area: Rect,
@@ -715,32 +1395,37 @@ fn render_memory_graph(
         .get_memory_history()
         .iter()
         .enumerate()
-        .map(|(i, &value)| (i as f64, value as f64)) // value is already in MB
+        .map(|(i, &value)| (i as f64, value as f64)) // value is in MiB
         .collect();
 
-    let max_memory = memory_data
+    let max_mib = memory_data
         .iter()
         .map(|&(_, y)| y)
         .fold(100.0_f64, |a, b| a.max(b));
+    // Rescale the MiB history into whichever unit suits the peak
+    let (divisor, unit) = scale_for(max_mib * 1024.0 * 1024.0);
+    let factor = 1024.0 * 1024.0 / divisor;
+    let memory_data: Vec<(f64, f64)> = memory_data.into_iter().map(|(x, y)| (x, y * factor)).collect();
+    let max_memory = max_mib * factor;
 
     let y_labels = if area.height > 15 {
         vec![
-            format!("0 MB"),
-            format!("{:.0} MB", max_memory / 4.0),
-            format!("{:.0} MB", max_memory / 2.0),
-            format!("{:.0} MB", max_memory * 3.0 / 4.0),
-            format!("{:.0} MB", max_memory),
+            format!("0 {}", unit),
+            format!("{:.1} {}", max_memory / 4.0, unit),
+            format!("{:.1} {}", max_memory / 2.0, unit),
+            format!("{:.1} {}", max_memory * 3.0 / 4.0, unit),
+            format!("{:.1} {}", max_memory, unit),
         ]
     } else if area.height > 10 {
         vec![
-            format!("0 MB"),
-            format!("{:.0} MB", max_memory / 2.0),
-            format!("{:.0} MB", max_memory),
+            format!("0 {}", unit),
+            format!("{:.1} {}", max_memory / 2.0, unit),
+            format!("{:.1} {}", max_memory, unit),
         ]
     } else {
         vec![
-            format!("0 MB"),
-            format!("{:.0} MB", max_memory),
+            format!("0 {}", unit),
+            format!("{:.1} {}", max_memory, unit),
         ]
     };
 
@@ -753,7 +1438,7 @@ fn render_memory_graph(
 
     let chart = Chart::new(vec![dataset])
         .block(Block::default()
-            .title("Memory Usage Over Time (MB)").style(Style::default().fg(RatatuiColor::Black))
+            .title(format!("Memory Usage Over Time ({})", unit)).style(Style::default().fg(RatatuiColor::Black))
             .borders(Borders::ALL))
         .x_axis(ratatui::widgets::Axis::default()
             .bounds([0.0, graph_data.max_points as f64])
@@ -1021,6 +1706,22 @@ fn get_cpu_details() -> (String, String, String) {
     }
     (model, freq, cache)
 }
+// Bytes received + sent by every interface except loopback, from /proc/net/dev
+fn read_network_bytes() -> Option<u64> {
+    let content = std::fs::read_to_string("/proc/net/dev").ok()?;
+    let total = content
+        .lines()
+        .skip(2) // Two header lines
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(interface, _)| interface.trim() != "lo")
+        .map(|(_, counters)| {
+            let fields: Vec<u64> = counters.split_whitespace().filter_map(|f| f.parse().ok()).collect();
+            fields.first().copied().unwrap_or(0) + fields.get(8).copied().unwrap_or(0)
+        })
+        .sum();
+    Some(total)
+}
+
 fn get_load_average() -> (f64, f64, f64) {
     if let Ok(loadavg) = std::fs::read_to_string("/proc/loadavg") {
         let values: Vec<f64> = loadavg.split_whitespace().take(3).filter_map(|s| s.parse().ok()).collect();
@@ -10,6 +10,8 @@ use crate::criu_manager::CriuManager;
 use crate::scheduler::{Scheduler, ScheduledTask, ScheduleType, ScheduleAction};
 use crate::scripting_rules::RuleEngine;
 use crate::process_log::ProcessExitLogEntry;
+use crate::process_group::ProcessGroupManager;
+use crate::treemap::{squarify, Tile};
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use std::collections::{HashSet, HashMap};
@@ -81,17 +83,40 @@ pub struct GuiApp {
     // Alert Dialog
     show_alert_dialog: bool,
     alert_name_input: String,
-    alert_condition_index: usize, // 0: CPU, 1: Memory, 2: ProcessDied
+    alert_condition_index: usize, // 0: CPU, 1: Memory, 2: ProcessDied, 3: StuckInDState, 4: AnomalyVsBaseline, 5: CpuSpike, 6: ProcessCountOutsideRange
     alert_threshold_input: String,
     alert_duration_input: String,
     alert_target_index: usize, // 0: All, 1: Pattern
     alert_target_pattern_input: String,
     
+    // Memory map
+    treemap_by_cgroup: bool, // Group tiles by cgroup instead of user
+    treemap_tiles: Vec<TreemapTile>,
+    treemap_groups: Vec<(egui::Rect, String, u64)>, // (area, group name, total RSS)
+    treemap_bounds: egui::Rect, // Area the cached layout was computed for
+    treemap_dirty: bool, // Set on refresh; the layout is recomputed before the next paint
+
     // Error feedback
     nice_error_message: Option<String>,
     last_error: Option<String>, // General error message for operations
 }
 
+// One process rectangle of the memory map
+struct TreemapTile {
+    rect: egui::Rect,
+    pid: u32,
+    name: String,
+    user: String,
+    group: String,
+    group_index: usize, // Picks the tile colour
+    memory: u64,
+    cpu: f32,
+}
+
+// Gap around each group so its processes read as one block, plus room for its label
+const TREEMAP_GROUP_MARGIN: f32 = 2.0;
+const TREEMAP_GROUP_LABEL_HEIGHT: f32 = 14.0;
+
 #[derive(Clone)]
 enum PendingAction {
     Kill(u32),
@@ -113,6 +138,7 @@ enum Tab {
     Logs,
     Schedule,
     Rules,
+    MemoryMap,
 }
 
 impl Default for GuiApp {
@@ -182,6 +208,12 @@ impl Default for GuiApp {
             alert_duration_input: String::new(),
             alert_target_index: 0,
             alert_target_pattern_input: String::new(),
+
+            treemap_by_cgroup: false,
+            treemap_tiles: Vec::new(),
+            treemap_groups: Vec::new(),
+            treemap_bounds: egui::Rect::NOTHING,
+            treemap_dirty: true,
         }
     }
 }
@@ -237,6 +269,9 @@ impl eframe::App for GuiApp {
                     if ui.selectable_label(self.selected_tab == Tab::Rules, "Rules").clicked() {
                         self.selected_tab = Tab::Rules;
                     }
+                    if ui.selectable_label(self.selected_tab == Tab::MemoryMap, "Memory Map").clicked() {
+                        self.selected_tab = Tab::MemoryMap;
+                    }
                 });
                 
                 ui.menu_button("Help", |ui| {
@@ -260,6 +295,7 @@ impl eframe::App for GuiApp {
                 Tab::Logs => self.draw_logs(ui),
                 Tab::Schedule => self.draw_schedule(ui),
                 Tab::Rules => self.draw_rules(ui),
+                Tab::MemoryMap => self.draw_memory_map(ui),
             }
         });
 
@@ -278,6 +314,7 @@ impl GuiApp {
         if let Ok(mut pm) = self.process_manager.lock() {
             pm.refresh();
         }
+        self.treemap_dirty = true;
         if let Ok(pm) = self.process_manager.lock() {
             if let Ok(mut gd) = self.graph_data.lock() {
                 gd.update(&pm);
@@ -299,15 +336,24 @@ impl GuiApp {
                 // Spawn async task to fetch data
                 tokio::spawn(async move {
                     for (address, name) in hosts_to_fetch {
-                        match crate::coordinator::fetch_host_data(address.clone(), name).await {
+                        let result = match crate::coordinator::resolve_host(&address).await {
+                            Ok(resolved) => {
+                                if let Ok(mut coord) = coordinator.lock() {
+                                    coord.set_resolved(&address, resolved);
+                                }
+                                crate::coordinator::fetch_host_data(resolved, name).await
+                            }
+                            Err(e) => Err(e),
+                        };
+                        match result {
                             Ok(processes) => {
                                 if let Ok(mut coord) = coordinator.lock() {
                                     coord.update_host_data(&address, processes);
                                 }
                             },
-                            Err(_) => {
+                            Err(e) => {
                                 if let Ok(mut coord) = coordinator.lock() {
-                                    coord.mark_host_disconnected(&address);
+                                    coord.mark_host_disconnected(&address, e);
                                 }
                             }
                         }
@@ -366,11 +412,13 @@ impl GuiApp {
                 if let Some(name) = self.known_pids.get(&pid) {
                     self.process_exit_log.push(ProcessExitLogEntry {
                         pid,
+                        parent_pid: None,
                         name: name.clone(),
                         user: None,
                         start_time: "Unknown".to_string(),
                         exit_time: chrono::Local::now(),
                         uptime_secs: 0,
+                        exit_reason: None,
                     });
                 }
             }
@@ -656,7 +704,7 @@ impl GuiApp {
                             // Memory
                             ui.with_layout(egui::Layout::left_to_right(egui::Align::LEFT), |ui| {
                                 ui.set_width(100.0);
-                                ui.label(format!("{}", process.memory_usage / (1024 * 1024)));
+                                ui.label(crate::units::format_bytes(process.memory_usage));
                             });
                             
                             // PPID
@@ -964,7 +1012,7 @@ impl GuiApp {
                         format!("CPU > {}% for {}s", threshold, duration_secs)
                     }
                     crate::alert::AlertCondition::MemoryGreaterThan { threshold_mb, duration_secs } => {
-                        format!("Memory > {}MB for {}s", threshold_mb, duration_secs)
+                        format!("Memory > {} MiB for {}s", threshold_mb, duration_secs)
                     }
                     crate::alert::AlertCondition::IoGreaterThan { threshold_mb_per_sec, duration_secs } => {
                         format!("I/O > {}MB/s for {}s", threshold_mb_per_sec, duration_secs)
@@ -972,6 +1020,18 @@ impl GuiApp {
                     crate::alert::AlertCondition::ProcessDied { pattern } => {
                         format!("Process died: {}", pattern)
                     }
+                    crate::alert::AlertCondition::StuckInDState { duration_secs } => {
+                        format!("Stuck in D state for {}s", duration_secs)
+                    }
+                    crate::alert::AlertCondition::AnomalyVsBaseline { factor, duration_secs } => {
+                        format!("{}x baseline CPU/Mem for {}s", factor, duration_secs)
+                    }
+                    crate::alert::AlertCondition::CpuSpike { delta_percent } => {
+                        format!("CPU jumps > +{}% between refreshes", delta_percent)
+                    }
+                    crate::alert::AlertCondition::ProcessCountOutsideRange { pattern, min, max } => {
+                        format!("Count of '{}' outside {}-{}", pattern, min, max)
+                    }
                 };
                 ui.label(condition_str);
                 
@@ -1037,8 +1097,13 @@ impl GuiApp {
             egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
                 for alert in active_alerts_data.iter().rev().take(20) {
                     ui.horizontal(|ui| {
-                        ui.colored_label(egui::Color32::RED, "⚠");
-                        ui.label(format!("{}: {}", alert.alert_name, alert.message));
+                        if alert.is_firing() {
+                            ui.colored_label(egui::Color32::RED, "⚠");
+                            ui.label(format!("{}: {}", alert.alert_name, alert.message));
+                        } else {
+                            ui.colored_label(egui::Color32::GREEN, "✅");
+                            ui.label(format!("{}: recovered - {}", alert.alert_name, alert.message));
+                        }
                     });
                 }
             });
@@ -1115,6 +1180,12 @@ impl GuiApp {
                 };
                 ui.colored_label(status_color, &host.name);
                 ui.label(&host.address);
+                if let Some(resolved) = host.resolved.as_ref().filter(|r| **r != host.address) {
+                    ui.label(format!("→ {}", resolved));
+                }
+                if let Some(error) = &host.last_error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
                 let address_to_remove = host.address.clone();
                 if ui.button("Remove").clicked() {
                     if let Ok(mut coord) = self.coordinator.lock() {
@@ -1131,8 +1202,10 @@ impl GuiApp {
             if ui.button("Add").clicked() && !self.host_input.trim().is_empty() {
                 let address = self.host_input.trim().to_string();
                 if let Ok(mut coord) = self.coordinator.lock() {
-                    coord.add_host(address.clone(), address.clone());
-                    self.host_input.clear();
+                    match coord.add_host(address.clone(), address.clone()) {
+                        Ok(_) => self.host_input.clear(),
+                        Err(e) => eprintln!("Invalid host address: {}", e),
+                    }
                 } else {
                     eprintln!("Failed to lock coordinator");
                 }
@@ -1209,7 +1282,7 @@ impl GuiApp {
                     ui.add_space(10.0);
                     
                     // Memory Graph
-                    ui.label("Memory Usage (MB)");
+                    ui.label("Memory Usage (MiB)");
                     egui_plot::Plot::new("proc_mem_plot")
                         .height(200.0)
                         .show(ui, |plot_ui| {
@@ -1301,6 +1374,15 @@ impl GuiApp {
                                 Err(e) => format!("Failed: {}", e),
                             }
                         },
+                        ScheduleAction::SendSignal { pid, signal } => {
+                            match crate::signals::parse_signal(&signal) {
+                                Some(number) => match pm.send_signal(pid, number) {
+                                    Ok(_) => "Success".to_string(),
+                                    Err(e) => format!("Failed: {}", e),
+                                },
+                                None => format!("Failed: unknown signal '{}'", signal),
+                            }
+                        },
                         ScheduleAction::ApplyRule { rule } => {
                             if let Ok(mut re) = self.rule_engine.lock() {
                                 re.set_rule(rule);
@@ -1361,6 +1443,171 @@ impl GuiApp {
         }
     }
 
+    // Lay out groups (by user or cgroup) by total RSS, then each group's processes inside it
+    fn layout_memory_map(&mut self, bounds: egui::Rect) {
+        let processes = match self.process_manager.lock() {
+            Ok(pm) => pm.get_processes().clone(),
+            Err(_) => return,
+        };
+        let mut groups = if self.treemap_by_cgroup {
+            ProcessGroupManager::group_by_cgroup(&processes)
+        } else {
+            ProcessGroupManager::group_by_username(&processes)
+        };
+        groups.retain(|g| g.total_memory > 0);
+        groups.sort_by_key(|g| std::cmp::Reverse(g.total_memory));
+
+        let to_rect = |t: Tile| egui::Rect::from_min_size(egui::pos2(t.x, t.y), egui::vec2(t.w, t.h));
+        let weights: Vec<f64> = groups.iter().map(|g| g.total_memory as f64).collect();
+        let bounds_tile = Tile { x: bounds.min.x, y: bounds.min.y, w: bounds.width(), h: bounds.height() };
+        self.treemap_tiles.clear();
+        self.treemap_groups.clear();
+        for (group_index, (group, tile)) in groups.iter_mut().zip(squarify(&weights, bounds_tile)).enumerate() {
+            let tile = tile.inset(TREEMAP_GROUP_MARGIN);
+            self.treemap_groups.push((to_rect(tile), group.group_id.clone(), group.total_memory));
+            // Processes go below the group label when there is room for it
+            let inner = if tile.h > TREEMAP_GROUP_LABEL_HEIGHT * 3.0 {
+                Tile { y: tile.y + TREEMAP_GROUP_LABEL_HEIGHT, h: tile.h - TREEMAP_GROUP_LABEL_HEIGHT, ..tile }
+            } else {
+                tile
+            };
+            group.processes.sort_by_key(|p| std::cmp::Reverse(p.memory_usage));
+            let weights: Vec<f64> = group.processes.iter().map(|p| p.memory_usage as f64).collect();
+            for (process, tile) in group.processes.iter().zip(squarify(&weights, inner)) {
+                if tile.w < 1.0 || tile.h < 1.0 {
+                    continue;
+                }
+                self.treemap_tiles.push(TreemapTile {
+                    rect: to_rect(tile),
+                    pid: process.pid,
+                    name: process.name.clone(),
+                    user: process.user.clone().unwrap_or_default(),
+                    group: group.group_id.clone(),
+                    group_index,
+                    memory: process.memory_usage,
+                    cpu: process.cpu_usage,
+                });
+            }
+        }
+        self.treemap_bounds = bounds;
+        self.treemap_dirty = false;
+    }
+
+    fn draw_memory_map(&mut self, ui: &mut egui::Ui) {
+        const PALETTE: [egui::Color32; 8] = [
+            egui::Color32::from_rgb(70, 130, 180),
+            egui::Color32::from_rgb(60, 160, 110),
+            egui::Color32::from_rgb(200, 140, 60),
+            egui::Color32::from_rgb(150, 90, 170),
+            egui::Color32::from_rgb(190, 80, 80),
+            egui::Color32::from_rgb(80, 160, 170),
+            egui::Color32::from_rgb(160, 150, 70),
+            egui::Color32::from_rgb(120, 120, 120),
+        ];
+
+        ui.heading("Memory Map");
+        ui.horizontal(|ui| {
+            ui.label("Group by:");
+            if ui.selectable_label(!self.treemap_by_cgroup, "User").clicked() {
+                self.treemap_by_cgroup = false;
+                self.treemap_dirty = true;
+            }
+            if ui.selectable_label(self.treemap_by_cgroup, "Cgroup").clicked() {
+                self.treemap_by_cgroup = true;
+                self.treemap_dirty = true;
+            }
+            ui.separator();
+            ui.label("Tile area is proportional to resident memory. Click a tile to select the process.");
+        });
+
+        // Actions for the selected process, the same ones the process list offers
+        let selected = self.selected_process_pid
+            .and_then(|pid| self.treemap_tiles.iter().find(|t| t.pid == pid))
+            .map(|tile| (tile.pid, tile.name.clone(), tile.memory));
+        if let Some((pid, name, memory)) = selected {
+            ui.horizontal(|ui| {
+                ui.label(format!("Selected: {} (PID: {}, {})", name, pid, crate::units::format_bytes(memory)));
+                if ui.button("Kill").clicked() {
+                    self.confirmation_message = format!("Are you sure you want to kill process {} (PID: {})?", name, pid);
+                    self.pending_action = Some(PendingAction::Kill(pid));
+                    self.show_kill_tree_option = false;
+                    self.show_confirmation_dialog = true;
+                }
+                if ui.button("Change Nice").clicked() {
+                    let nice = self.process_manager.lock().ok()
+                        .and_then(|pm| pm.get_processes().iter().find(|p| p.pid == pid).map(|p| p.nice));
+                    self.nice_input = nice.map(|n| n.to_string()).unwrap_or_default();
+                    self.show_nice_dialog = true;
+                }
+                if ui.button("Graph").clicked() {
+                    self.selected_tab = Tab::PerProcessGraph;
+                }
+            });
+        }
+        ui.separator();
+
+        let (response, painter) = ui.allocate_painter(ui.available_size(), egui::Sense::click());
+        let bounds = response.rect;
+        if self.treemap_dirty || bounds != self.treemap_bounds {
+            self.layout_memory_map(bounds);
+        }
+
+        let font = egui::FontId::proportional(11.0);
+        for (rect, name, memory) in &self.treemap_groups {
+            painter.rect_stroke(*rect, 0.0, egui::Stroke::new(1.0, egui::Color32::DARK_GRAY));
+            if rect.height() > TREEMAP_GROUP_LABEL_HEIGHT * 3.0 {
+                painter.text(
+                    rect.min + egui::vec2(3.0, 1.0),
+                    egui::Align2::LEFT_TOP,
+                    format!("{} ({})", name, crate::units::format_bytes(*memory)),
+                    font.clone(),
+                    egui::Color32::WHITE,
+                );
+            }
+        }
+        let hover = response.hover_pos();
+        let mut hovered = None;
+        for tile in &self.treemap_tiles {
+            let is_hovered = hover.is_some_and(|pos| tile.rect.contains(pos));
+            let base = PALETTE[tile.group_index % PALETTE.len()];
+            let fill = if is_hovered { base.gamma_multiply(1.3) } else { base };
+            painter.rect_filled(tile.rect.shrink(0.5), 0.0, fill);
+            if Some(tile.pid) == self.selected_process_pid {
+                painter.rect_stroke(tile.rect.shrink(1.0), 0.0, egui::Stroke::new(2.0, egui::Color32::YELLOW));
+            }
+            // Label only tiles big enough to hold it
+            if tile.rect.width() > 50.0 && tile.rect.height() > 14.0 {
+                painter.with_clip_rect(tile.rect).text(
+                    tile.rect.min + egui::vec2(3.0, 2.0),
+                    egui::Align2::LEFT_TOP,
+                    &tile.name,
+                    font.clone(),
+                    egui::Color32::WHITE,
+                );
+            }
+            if is_hovered {
+                hovered = Some(tile);
+            }
+        }
+
+        if let Some(tile) = hovered {
+            egui::show_tooltip_at_pointer(ui.ctx(), egui::Id::new("memory_map_tooltip"), |ui| {
+                ui.label(egui::RichText::new(&tile.name).strong());
+                ui.label(format!("PID: {}", tile.pid));
+                ui.label(format!("User: {}", tile.user));
+                ui.label(format!("{}: {}", if self.treemap_by_cgroup { "Cgroup" } else { "Group" }, tile.group));
+                ui.label(format!("Memory: {}", crate::units::format_bytes(tile.memory)));
+                ui.label(format!("CPU: {:.1}%", tile.cpu));
+            });
+        }
+        if response.clicked()
+            && let Some(pos) = response.interact_pointer_pos()
+            && let Some(tile) = self.treemap_tiles.iter().find(|t| t.rect.contains(pos))
+        {
+            self.selected_process_pid = Some(tile.pid);
+        }
+    }
+
     fn draw_rules(&mut self, ui: &mut egui::Ui) {
         ui.heading("Automation Rules");
         
@@ -1733,29 +1980,44 @@ impl GuiApp {
                             0 => "CPU Greater Than",
                             1 => "Memory Greater Than",
                             2 => "Process Died",
+                            3 => "Stuck in D State",
+                            4 => "Exceeds Baseline",
+                            5 => "CPU Spike",
+                            6 => "Process Count",
                             _ => "Unknown",
                         })
                         .show_ui(ui, |ui| {
                             ui.selectable_value(&mut self.alert_condition_index, 0, "CPU Greater Than");
                             ui.selectable_value(&mut self.alert_condition_index, 1, "Memory Greater Than");
                             ui.selectable_value(&mut self.alert_condition_index, 2, "Process Died");
+                            ui.selectable_value(&mut self.alert_condition_index, 3, "Stuck in D State");
+                            ui.selectable_value(&mut self.alert_condition_index, 4, "Exceeds Baseline");
+                            ui.selectable_value(&mut self.alert_condition_index, 5, "CPU Spike");
+                            ui.selectable_value(&mut self.alert_condition_index, 6, "Process Count");
                         });
                     ui.add_space(5.0);
                     
                     if self.alert_condition_index != 2 {
-                        ui.label("Threshold:");
-                        ui.text_edit_singleline(&mut self.alert_threshold_input);
-                        ui.label(match self.alert_condition_index {
-                            0 => "CPU percentage (e.g., 80.0)",
-                            1 => "Memory in MB (e.g., 1024)",
-                            _ => ""
-                        });
-                        ui.add_space(5.0);
+                        if self.alert_condition_index != 3 {
+                            ui.label("Threshold:");
+                            ui.text_edit_singleline(&mut self.alert_threshold_input);
+                            ui.label(match self.alert_condition_index {
+                                0 => "CPU percentage (e.g., 80.0)",
+                                1 => "Memory in MiB (e.g., 1024)",
+                                4 => "Multiple of the learned baseline (e.g., 3.0)",
+                                5 => "CPU percentage-point jump between refreshes (e.g., 50.0)",
+                                6 => "Expected number of matching processes, min-max (e.g., 4-4)",
+                                _ => ""
+                            });
+                            ui.add_space(5.0);
+                        }
                         
-                        ui.label("Duration (seconds):");
-                        ui.text_edit_singleline(&mut self.alert_duration_input);
-                        ui.label("How long the condition must persist");
-                        ui.add_space(5.0);
+                        if self.alert_condition_index != 5 && self.alert_condition_index != 6 {
+                            ui.label("Duration (seconds):");
+                            ui.text_edit_singleline(&mut self.alert_duration_input);
+                            ui.label("How long the condition must persist");
+                            ui.add_space(5.0);
+                        }
                     }
                     
                     ui.label("Target:");
@@ -1809,6 +2071,40 @@ impl GuiApp {
                                                 pattern,
                                             }
                                         }
+                                        6 => {
+                                            let (min, max) = match self.alert_threshold_input.split_once('-') {
+                                                Some((low, high)) => (low.trim().parse::<usize>().unwrap_or(1), high.trim().parse::<usize>().ok()),
+                                                None => (self.alert_threshold_input.trim().parse::<usize>().unwrap_or(1), None),
+                                            };
+                                            let pattern = if self.alert_target_index == 0 {
+                                                "*".to_string()
+                                            } else {
+                                                self.alert_target_pattern_input.clone()
+                                            };
+                                            crate::alert::AlertCondition::ProcessCountOutsideRange {
+                                                pattern,
+                                                min,
+                                                max: max.unwrap_or(min).max(min),
+                                            }
+                                        }
+                                        5 => {
+                                            let delta_percent = self.alert_threshold_input.parse::<f32>().unwrap_or(50.0);
+                                            crate::alert::AlertCondition::CpuSpike { delta_percent }
+                                        }
+                                        4 => {
+                                            let factor = self.alert_threshold_input.parse::<f32>().unwrap_or(3.0);
+                                            let duration = self.alert_duration_input.parse::<u64>().unwrap_or(10);
+                                            crate::alert::AlertCondition::AnomalyVsBaseline {
+                                                factor,
+                                                duration_secs: duration,
+                                            }
+                                        }
+                                        3 => {
+                                            let duration = self.alert_duration_input.parse::<u64>().unwrap_or(30);
+                                            crate::alert::AlertCondition::StuckInDState {
+                                                duration_secs: duration,
+                                            }
+                                        }
                                         _ => {
                                             crate::alert::AlertCondition::CpuGreaterThan {
                                                 threshold: 80.0,
@@ -1828,6 +2124,7 @@ impl GuiApp {
                                         condition,
                                         target,
                                         enabled: true,
+                                        notify_recovery: true,
                                     };
                                     
                                     am.add_alert(alert);
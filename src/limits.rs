@@ -0,0 +1,113 @@
+//! Kernel and per-user ceilings that make fork()/open() fail once reached
+//!
+//! Read from /proc/sys and /proc/loadavg plus lpm's own RLIMIT_NPROC, so a
+//! "can't fork" situation shows up before it bites.
+
+/// Share of a limit in use at which it is flagged
+pub const NEAR_LIMIT_PERCENT: f64 = 80.0;
+
+#[derive(Clone, Debug)]
+pub struct LimitUsage {
+    pub label: &'static str,
+    pub used: u64,
+    pub max: Option<u64>, // None = unlimited or unreadable
+}
+
+impl LimitUsage {
+    pub fn percent(&self) -> Option<f64> {
+        self.max.filter(|max| *max > 0).map(|max| self.used as f64 / max as f64 * 100.0)
+    }
+
+    pub fn is_near(&self) -> bool {
+        self.percent().is_some_and(|percent| percent >= NEAR_LIMIT_PERCENT)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SystemLimits {
+    pub pids: LimitUsage,       // Tasks (processes + threads) vs kernel.pid_max
+    pub threads: LimitUsage,    // Tasks vs kernel.threads-max
+    pub files: LimitUsage,      // Allocated file handles vs fs.file-max (from fs/file-nr)
+    pub user_tasks: LimitUsage, // This user's tasks vs RLIMIT_NPROC
+    pub nr_open: Option<u64>,   // Per-process FD ceiling (fs.nr_open)
+}
+
+impl SystemLimits {
+    pub fn all(&self) -> [&LimitUsage; 4] {
+        [&self.pids, &self.threads, &self.files, &self.user_tasks]
+    }
+
+    /// Labels of the limits at or above NEAR_LIMIT_PERCENT
+    pub fn near_limits(&self) -> Vec<&'static str> {
+        self.all().iter().filter(|l| l.is_near()).map(|l| l.label).collect()
+    }
+}
+
+fn read_u64(path: &str) -> Option<u64> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+// Total scheduling entities, the 4th field of /proc/loadavg ("running/total")
+fn task_count() -> u64 {
+    std::fs::read_to_string("/proc/loadavg")
+        .ok()
+        .and_then(|s| s.split_whitespace().nth(3)?.split('/').nth(1)?.parse().ok())
+        .unwrap_or(0)
+}
+
+// "allocated unused max" from /proc/sys/fs/file-nr
+fn file_handles() -> (u64, Option<u64>) {
+    let Ok(content) = std::fs::read_to_string("/proc/sys/fs/file-nr") else {
+        return (0, None);
+    };
+    let fields: Vec<u64> = content.split_whitespace().filter_map(|f| f.parse().ok()).collect();
+    match fields.as_slice() {
+        [allocated, unused, max, ..] => (allocated.saturating_sub(*unused), Some(*max)),
+        _ => (0, None),
+    }
+}
+
+// Threads owned by lpm's real user, which is what RLIMIT_NPROC counts
+#[cfg(target_os = "linux")]
+fn user_task_count() -> u64 {
+    let uid = unsafe { libc::getuid() };
+    let Ok(processes) = procfs::process::all_processes() else {
+        return 0;
+    };
+    processes
+        .flatten()
+        .filter(|p| p.uid().is_ok_and(|owner| owner == uid))
+        .filter_map(|p| p.stat().ok())
+        .map(|stat| stat.num_threads.max(1) as u64)
+        .sum()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn user_task_count() -> u64 {
+    0
+}
+
+// Soft RLIMIT_NPROC of lpm itself; None when unlimited
+fn nproc_limit() -> Option<u64> {
+    let mut limit = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+    // SAFETY: getrlimit only writes into the struct we pass
+    let result = unsafe { libc::getrlimit(libc::RLIMIT_NPROC, &mut limit) };
+    if result != 0 || limit.rlim_cur == libc::RLIM_INFINITY {
+        None
+    } else {
+        Some(limit.rlim_cur)
+    }
+}
+
+/// Read every limit and its current usage
+pub fn read_system_limits() -> SystemLimits {
+    let tasks = task_count();
+    let (files_used, files_max) = file_handles();
+    SystemLimits {
+        pids: LimitUsage { label: "pid_max", used: tasks, max: read_u64("/proc/sys/kernel/pid_max") },
+        threads: LimitUsage { label: "threads-max", used: tasks, max: read_u64("/proc/sys/kernel/threads-max") },
+        files: LimitUsage { label: "file-max", used: files_used, max: files_max },
+        user_tasks: LimitUsage { label: "user nproc", used: user_task_count(), max: nproc_limit() },
+        nr_open: read_u64("/proc/sys/fs/nr_open"),
+    }
+}
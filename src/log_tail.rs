@@ -0,0 +1,75 @@
+//! Incremental, auto-following tail of a log file
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+// Lines kept in memory; older lines are dropped as new ones arrive
+const MAX_TAIL_LINES: usize = 5000;
+// How much of an existing file to load when a tail is first opened
+const INITIAL_TAIL_BYTES: u64 = 64 * 1024;
+
+pub struct LogTail {
+    pub path: PathBuf,
+    pub lines: VecDeque<String>,
+    pub follow: bool,       // Keep the view pinned to the newest line
+    pub scroll_offset: usize,
+    offset: u64,            // Bytes of the file consumed so far
+    partial: String,        // Trailing text without a newline yet
+}
+
+impl LogTail {
+    /// Open a tail starting near the end of the file
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let len = std::fs::metadata(path)?.len();
+        let mut tail = Self {
+            path: path.to_path_buf(),
+            lines: VecDeque::new(),
+            follow: true,
+            scroll_offset: 0,
+            offset: len.saturating_sub(INITIAL_TAIL_BYTES),
+            partial: String::new(),
+        };
+        let skipped_start = tail.offset > 0;
+        tail.poll()?;
+        if skipped_start && !tail.lines.is_empty() {
+            // The first line is probably cut in half
+            tail.lines.pop_front();
+        }
+        Ok(tail)
+    }
+
+    /// Read whatever was appended since the last call
+    pub fn poll(&mut self) -> std::io::Result<()> {
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // Truncated or rotated - start over from the beginning
+            self.offset = 0;
+            self.partial.clear();
+            self.lines.push_back("--- file truncated ---".to_string());
+        }
+        if len == self.offset {
+            return Ok(());
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut buf = Vec::new();
+        file.take(len - self.offset).read_to_end(&mut buf)?;
+        self.offset += buf.len() as u64;
+
+        self.partial.push_str(&String::from_utf8_lossy(&buf));
+        if let Some(last_newline) = self.partial.rfind('\n') {
+            let rest = self.partial.split_off(last_newline + 1);
+            for line in self.partial.lines() {
+                self.lines.push_back(line.to_string());
+            }
+            self.partial = rest;
+        }
+        while self.lines.len() > MAX_TAIL_LINES {
+            self.lines.pop_front();
+            self.scroll_offset = self.scroll_offset.saturating_sub(1);
+        }
+        Ok(())
+    }
+}
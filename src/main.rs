@@ -15,6 +15,24 @@ mod criu_manager;
 mod coordinator;
 mod agent;
 mod gui;
+mod settings;
+mod signals;
+mod log_tail;
+mod svg_export;
+mod ordered_restart;
+mod units;
+mod self_stats;
+mod control_socket;
+mod ports;
+mod start_templates;
+mod limits;
+mod explain;
+mod power;
+mod capabilities;
+mod security;
+mod treemap;
+mod oom_guard;
+mod dashboard;
 
 use clap::Parser;
 
@@ -33,6 +51,18 @@ struct Args {
     /// Port for the agent to listen on (default: 3000)
     #[arg(short, long, default_value_t = 3000)]
     port: u16,
+
+    /// Read processes from an alternate proc filesystem (e.g. a container's mounted /proc)
+    #[arg(long)]
+    proc_root: Option<std::path::PathBuf>,
+
+    /// Listen for line-delimited JSON commands (list, kill, renice, signal) on this UNIX socket
+    #[arg(long)]
+    control_socket: Option<std::path::PathBuf>,
+
+    /// Refresh interval in milliseconds for this run (default: the saved setting, 1000)
+    #[arg(long, value_name = "MS")]
+    interval: Option<u64>,
 }
 
 //main to start the application
@@ -40,14 +70,25 @@ struct Args {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
     
+    if let Some(proc_root) = args.proc_root {
+        process::set_default_proc_root(proc_root);
+    }
+    
     if args.agent {
         let agent = agent::Agent::new(args.port);
         agent.start().await?;
         Ok(())
     } else if args.gui {
         gui::run_gui()
+    } else if let Some(socket_path) = args.control_socket {
+        // Bind before the TUI takes over the terminal so errors are still visible
+        let listener = control_socket::bind(&socket_path)?;
+        tokio::spawn(control_socket::serve(listener));
+        let result = ui::ui_renderer(args.interval);
+        control_socket::cleanup(&socket_path);
+        result
     } else {
-        ui::ui_renderer()
+        ui::ui_renderer(args.interval)
     }
 }
 
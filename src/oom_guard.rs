@@ -0,0 +1,64 @@
+//! Early OOM guard: kill the largest eligible process before the kernel OOM killer has to
+//!
+//! Trips once MemAvailable has stayed below a floor for a sustained period. Kills (and
+//! cancelled kills) are appended to ~/.lpm/audit.log.
+
+use crate::process::ProcessInfo;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Tracks how long available memory has been below the floor
+#[derive(Default)]
+pub struct OomGuard {
+    low_since: Option<Instant>,
+}
+
+impl OomGuard {
+    /// Record the latest MemAvailable (KiB); true once it has been below `floor_kib` for `sustain`
+    pub fn observe(&mut self, available_kib: u64, floor_kib: u64, sustain: Duration) -> bool {
+        if available_kib >= floor_kib {
+            self.low_since = None;
+            return false;
+        }
+        self.low_since.get_or_insert_with(Instant::now).elapsed() >= sustain
+    }
+
+    /// Start timing from scratch after acting, so the kernel gets time to reclaim the victim's memory
+    pub fn rearm(&mut self) {
+        self.low_since = None;
+    }
+}
+
+/// Which processes the guard may kill
+pub struct OomPolicy<'a> {
+    pub only_names: &'a [String], // Empty: any process
+    pub never_names: &'a [String],
+    pub spare_root: bool, // Never kill processes owned by root
+}
+
+/// MemAvailable from <proc_root>/meminfo, in KiB
+pub fn read_mem_available(proc_root: &Path) -> Option<u64> {
+    std::fs::read_to_string(proc_root.join("meminfo"))
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("MemAvailable:"))
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|kib| kib.parse().ok())
+}
+
+/// Largest-RSS process the policy allows killing; never lpm itself, init or a kernel thread
+pub fn pick_victim<'a>(processes: &'a [ProcessInfo], policy: &OomPolicy) -> Option<&'a ProcessInfo> {
+    let own_pid = std::process::id();
+    processes
+        .iter()
+        .filter(|p| p.pid != own_pid && p.pid != 1 && !crate::process::is_kernel_thread(p))
+        .filter(|p| policy.only_names.is_empty() || policy.only_names.contains(&p.name))
+        .filter(|p| !policy.never_names.contains(&p.name))
+        .filter(|p| !(policy.spare_root && p.user.as_deref() == Some("root")))
+        .max_by_key(|p| p.memory_usage)
+}
+
+/// Append a timestamped line to ~/.lpm/audit.log
+pub fn audit(message: &str) {
+    crate::settings::append_log("audit.log", message);
+}
@@ -0,0 +1,202 @@
+//! Sequenced restart of a group of related processes
+//!
+//! Processes are stopped last-to-first and started first-to-last, and each
+//! step waits for the previous one to finish (exit, or stay alive) before
+//! moving on. `tick` advances the sequence without blocking the UI.
+
+use crate::process::{LaunchSpec, ProcessManager};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// How long a process gets to exit after SIGTERM before it is sent SIGKILL
+const TERM_TIMEOUT: Duration = Duration::from_secs(5);
+// How long to wait for exit after SIGKILL before giving up on the sequence
+const KILL_TIMEOUT: Duration = Duration::from_secs(3);
+// A started process must stay alive this long before the next one starts
+const START_SETTLE: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StepState {
+    Pending,
+    Stopping,
+    Stopped,
+    Starting(u32),
+    Running(u32),
+    Failed(String),
+}
+
+/// One process in the sequence, with the command captured before it was stopped
+pub struct RestartStep {
+    pub pid: u32,
+    pub name: String,
+    pub spec: LaunchSpec,
+    pub state: StepState,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Phase {
+    Planning,
+    Stopping { index: usize, since: Instant, killed: bool },
+    Starting { index: usize, pid: u32, since: Instant },
+    Finished,
+    Aborted,
+}
+
+pub struct OrderedRestart {
+    pub steps: Vec<RestartStep>,
+    pub log: Vec<String>,
+    phase: Phase,
+}
+
+impl OrderedRestart {
+    pub fn new(steps: Vec<RestartStep>) -> Self {
+        Self {
+            steps,
+            log: Vec::new(),
+            phase: Phase::Planning,
+        }
+    }
+
+    /// Swap a step with the one before it; returns the step's new index
+    pub fn move_up(&mut self, index: usize) -> usize {
+        if self.is_planning() && index > 0 && index < self.steps.len() {
+            self.steps.swap(index, index - 1);
+            return index - 1;
+        }
+        index
+    }
+
+    /// Swap a step with the one after it; returns the step's new index
+    pub fn move_down(&mut self, index: usize) -> usize {
+        if self.is_planning() && index + 1 < self.steps.len() {
+            self.steps.swap(index, index + 1);
+            return index + 1;
+        }
+        index
+    }
+
+    pub fn is_planning(&self) -> bool {
+        self.phase == Phase::Planning
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(self.phase, Phase::Stopping { .. } | Phase::Starting { .. })
+    }
+
+    /// Short description of where the sequence is, for titles
+    pub fn status(&self) -> String {
+        let total = self.steps.len();
+        match self.phase {
+            Phase::Planning => format!("Planning {} process(es)", total),
+            Phase::Stopping { index, .. } => format!("Stopping {}/{}", total - index, total),
+            Phase::Starting { index, .. } => format!("Starting {}/{}", index + 1, total),
+            Phase::Finished => "Finished".to_string(),
+            Phase::Aborted => "Aborted".to_string(),
+        }
+    }
+
+    /// Begin stopping from the last step
+    pub fn start(&mut self, pm: &ProcessManager) {
+        if !self.is_planning() || self.steps.is_empty() {
+            return;
+        }
+        self.log.push(format!("Restarting {} process(es)", self.steps.len()));
+        self.begin_stop(pm, self.steps.len() - 1);
+    }
+
+    /// Stop the sequence where it is; processes already stopped stay stopped
+    pub fn abort(&mut self) {
+        if self.is_running() {
+            self.log.push("Aborted by user".to_string());
+            self.phase = Phase::Aborted;
+        }
+    }
+
+    /// Advance the sequence if the current step has finished
+    pub fn tick(&mut self, pm: &mut ProcessManager) {
+        match self.phase {
+            Phase::Stopping { index, since, killed } => {
+                let pid = self.steps[index].pid;
+                if !is_alive(pm.get_proc_root(), pid) {
+                    self.steps[index].state = StepState::Stopped;
+                    self.log.push(format!("Stopped {} (PID {})", self.steps[index].name, pid));
+                    if index == 0 {
+                        self.begin_start(pm, 0);
+                    } else {
+                        self.begin_stop(pm, index - 1);
+                    }
+                } else if !killed && since.elapsed() >= TERM_TIMEOUT {
+                    self.log.push(format!("{} (PID {}) ignored SIGTERM, sending SIGKILL", self.steps[index].name, pid));
+                    if let Err(e) = pm.kill_process(pid) {
+                        self.fail(index, format!("SIGKILL failed: {}", e));
+                        return;
+                    }
+                    self.phase = Phase::Stopping { index, since: Instant::now(), killed: true };
+                } else if killed && since.elapsed() >= KILL_TIMEOUT {
+                    self.fail(index, "did not exit after SIGKILL".to_string());
+                }
+            }
+            Phase::Starting { index, pid, since } => {
+                if !is_alive(pm.get_proc_root(), pid) {
+                    self.fail(index, format!("new process {} exited during startup", pid));
+                } else if since.elapsed() >= START_SETTLE {
+                    self.steps[index].state = StepState::Running(pid);
+                    self.log.push(format!("{} is running as PID {}", self.steps[index].name, pid));
+                    if index + 1 < self.steps.len() {
+                        self.begin_start(pm, index + 1);
+                    } else {
+                        self.log.push("All processes restarted".to_string());
+                        self.phase = Phase::Finished;
+                    }
+                }
+            }
+            Phase::Planning | Phase::Finished | Phase::Aborted => {}
+        }
+    }
+
+    fn begin_stop(&mut self, pm: &ProcessManager, index: usize) {
+        let step = &mut self.steps[index];
+        step.state = StepState::Stopping;
+        self.log.push(format!("Stopping {} (PID {})", step.name, step.pid));
+        if let Err(e) = pm.terminate_process(step.pid) {
+            // Already gone is fine; the next tick will notice
+            if e.raw_os_error() != Some(libc::ESRCH) {
+                self.fail(index, format!("SIGTERM failed: {}", e));
+                return;
+            }
+        }
+        self.phase = Phase::Stopping { index, since: Instant::now(), killed: false };
+    }
+
+    fn begin_start(&mut self, pm: &mut ProcessManager, index: usize) {
+        let step = &mut self.steps[index];
+        // The captured spec reuses the original environment as-is, as restart-with-args does
+        let result = pm.launch(&step.spec);
+        match result {
+            Ok(pid) => {
+                step.state = StepState::Starting(pid);
+                self.log.push(format!("Started {} as PID {}, waiting for it to settle", step.name, pid));
+                self.phase = Phase::Starting { index, pid, since: Instant::now() };
+            }
+            Err(e) => self.fail(index, format!("start failed: {}", e)),
+        }
+    }
+
+    fn fail(&mut self, index: usize, reason: String) {
+        self.log.push(format!("{} failed: {} - remaining steps skipped", self.steps[index].name, reason));
+        self.steps[index].state = StepState::Failed(reason);
+        self.phase = Phase::Aborted;
+    }
+}
+
+// A process counts as alive while it exists and is not a zombie
+fn is_alive(proc_root: &Path, pid: u32) -> bool {
+    match std::fs::read_to_string(proc_root.join(pid.to_string()).join("stat")) {
+        // The state field follows the parenthesised command name, which may itself contain ')'
+        Ok(stat) => stat
+            .rsplit_once(')')
+            .and_then(|(_, rest)| rest.trim_start().chars().next())
+            .is_some_and(|state| state != 'Z' && state != 'X'),
+        Err(_) => false,
+    }
+}
@@ -75,13 +75,16 @@ pub fn render_per_process_graph_tab(
                 // Memory Graph
                 let memory_data: Vec<(f64, f64)> = mem_history.iter()
                     .enumerate()
-                    .map(|(i, &usage)| (i as f64, usage as f64 / (1024.0 * 1024.0)))
+                    .map(|(i, &usage)| (i as f64, usage as f64))
                     .collect();
 
-                let max_memory = memory_data.iter()
+                let max_bytes = memory_data.iter()
                     .map(|&(_, y)| y)
                     .fold(0.0, f64::max)
-                    .max(1.0);  // Ensure we have at least 1MB range
+                    .max(1024.0 * 1024.0);  // Ensure we have at least 1 MiB range
+                let (divisor, unit) = crate::units::scale_for(max_bytes);
+                let memory_data: Vec<(f64, f64)> = memory_data.into_iter().map(|(x, y)| (x, y / divisor)).collect();
+                let max_memory = max_bytes / divisor;
 
                 let memory_dataset = Dataset::default()
                     .name("Memory Usage")
@@ -100,9 +103,9 @@ pub fn render_per_process_graph_tab(
                     .y_axis(ratatui::widgets::Axis::default()
                         .bounds([0.0, max_memory * 1.2])
                         .labels(vec![
-                            "0 MB".into(),
-                            format!("{:.1} MB", max_memory / 2.0).into(),
-                            format!("{:.1} MB", max_memory).into(),
+                            format!("0 {}", unit).into(),
+                            format!("{:.1} {}", max_memory / 2.0, unit).into(),
+                            format!("{:.1} {}", max_memory, unit).into(),
                         ]));
 
                 frame.render_widget(memory_chart, graph_chunks[1]);
@@ -111,7 +114,7 @@ pub fn render_per_process_graph_tab(
     } else {
         // Show process selection list
         let processes = process_manager.get_processes();
-        let headers = ["PID", "NAME", "CPU%", "MEM(MB)", "USER"];
+        let headers = ["PID", "NAME", "CPU%", "MEM", "USER"];
         
         let header_cells = headers
             .iter()
@@ -137,13 +140,11 @@ pub fn render_per_process_graph_tab(
                     Style::default().fg(Color::Blue)
                 };
 
-                let memory_mb = process.memory_usage / (1024 * 1024);
-
                 Row::new(vec![
                     Cell::from(process.pid.to_string()).style(style),
                     Cell::from(process.name.clone()).style(Style::default().fg(Color::Green)),
                     Cell::from(format!("{:.1}%", process.cpu_usage)).style(style),
-                    Cell::from(format!("{}", memory_mb)).style(style),
+                    Cell::from(crate::units::format_bytes(process.memory_usage)).style(style),
                     Cell::from(process.user.clone().unwrap_or_default()).style(Style::default().fg(Color::Magenta)),
                 ])
             })
@@ -156,7 +157,7 @@ pub fn render_per_process_graph_tab(
                 Constraint::Length(8),   // PID
                 Constraint::Length(20),  // NAME
                 Constraint::Length(8),   // CPU%
-                Constraint::Length(10),  // MEM(MB)
+                Constraint::Length(10),  // MEM
                 Constraint::Length(12),  // USER
             ]);
 
@@ -0,0 +1,179 @@
+//! Port → process lookup from <proc_root>/net/tcp{,6} and <proc_root>/<pid>/fd
+//!
+//! The kernel lists every TCP socket with its inode; a process owns the socket
+//! when one of its fds links to "socket:[<inode>]".
+
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SocketState {
+    Listen,
+    Established,
+    Other,
+}
+
+impl SocketState {
+    // State codes from include/net/tcp_states.h
+    fn from_hex(code: &str) -> Self {
+        match code {
+            "0A" => SocketState::Listen,
+            "01" => SocketState::Established,
+            _ => SocketState::Other,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SocketState::Listen => "LISTEN",
+            SocketState::Established => "ESTABLISHED",
+            SocketState::Other => "OTHER",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SocketEntry {
+    pub protocol: &'static str, // "tcp" or "tcp6"
+    pub local_addr: String,
+    pub local_port: u16,
+    pub remote_addr: String,
+    pub remote_port: u16,
+    pub state: SocketState,
+    pub inode: u64,
+}
+
+/// A socket on the looked-up port and the process holding it
+#[derive(Clone, Debug)]
+pub struct PortOwner {
+    pub pid: u32,
+    pub socket: SocketEntry,
+}
+
+// Decode "0100007F:1F90" (IPv4) or 32 hex digits + port (IPv6); addresses are
+// written as host-order 32-bit words
+fn parse_address(field: &str) -> Option<(String, u16)> {
+    let (addr_hex, port_hex) = field.split_once(':')?;
+    let port = u16::from_str_radix(port_hex, 16).ok()?;
+    let addr = match addr_hex.len() {
+        8 => Ipv4Addr::from(u32::from_str_radix(addr_hex, 16).ok()?.swap_bytes()).to_string(),
+        32 => {
+            let mut bytes = [0u8; 16];
+            for (i, chunk) in bytes.chunks_mut(4).enumerate() {
+                let word = u32::from_str_radix(&addr_hex[i * 8..i * 8 + 8], 16).ok()?;
+                chunk.copy_from_slice(&word.swap_bytes().to_be_bytes());
+            }
+            Ipv6Addr::from(bytes).to_string()
+        }
+        _ => return None,
+    };
+    Some((addr, port))
+}
+
+// Parse one of the /proc/net/tcp tables (missing file = no sockets of that family)
+fn read_table(path: &Path, protocol: &'static str) -> Vec<SocketEntry> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .skip(1) // Header
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                return None;
+            }
+            let (local_addr, local_port) = parse_address(fields[1])?;
+            let (remote_addr, remote_port) = parse_address(fields[2])?;
+            Some(SocketEntry {
+                protocol,
+                local_addr,
+                local_port,
+                remote_addr,
+                remote_port,
+                state: SocketState::from_hex(fields[3]),
+                inode: fields[9].parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// All IPv4 and IPv6 TCP sockets
+pub fn tcp_sockets(proc_root: &Path) -> Vec<SocketEntry> {
+    let mut sockets = read_table(&proc_root.join("net/tcp"), "tcp");
+    sockets.extend(read_table(&proc_root.join("net/tcp6"), "tcp6"));
+    sockets
+}
+
+// Socket inodes referenced by one process's fds (unreadable fds are skipped)
+fn socket_inodes_of(proc_root: &Path, pid: u32) -> Vec<u64> {
+    let Ok(entries) = std::fs::read_dir(proc_root.join(pid.to_string()).join("fd")) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| std::fs::read_link(entry.path()).ok())
+        .filter_map(|target| {
+            let target = target.to_string_lossy().into_owned();
+            target.strip_prefix("socket:[")?.strip_suffix(']')?.parse().ok()
+        })
+        .collect()
+}
+
+/// Map socket inode → owning PIDs, walking every process's fds once
+pub fn socket_owner_index(proc_root: &Path) -> HashMap<u64, Vec<u32>> {
+    let mut index: HashMap<u64, Vec<u32>> = HashMap::new();
+    let Ok(entries) = std::fs::read_dir(proc_root) else {
+        return index;
+    };
+    for entry in entries.flatten() {
+        let Some(pid) = entry.file_name().to_str().and_then(|s| s.parse::<u32>().ok()) else {
+            continue;
+        };
+        for inode in socket_inodes_of(proc_root, pid) {
+            let pids = index.entry(inode).or_default();
+            if !pids.contains(&pid) {
+                pids.push(pid);
+            }
+        }
+    }
+    index
+}
+
+/// Processes holding a TCP socket on `port` (local side), LISTEN first
+pub fn find_port_owners(proc_root: &Path, port: u16) -> Vec<PortOwner> {
+    let sockets: Vec<SocketEntry> = tcp_sockets(proc_root)
+        .into_iter()
+        .filter(|s| s.local_port == port && s.inode != 0)
+        .collect();
+    if sockets.is_empty() {
+        return Vec::new();
+    }
+    let index = socket_owner_index(proc_root);
+    let mut owners: Vec<PortOwner> = sockets
+        .into_iter()
+        .flat_map(|socket| {
+            index
+                .get(&socket.inode)
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |pid| PortOwner { pid, socket: socket.clone() })
+        })
+        .collect();
+    owners.sort_by_key(|o| (o.socket.state != SocketState::Listen, o.pid));
+    owners
+}
+
+/// TCP sockets held by one process (only its own fds are read)
+pub fn sockets_of_pid(proc_root: &Path, pid: u32) -> Vec<SocketEntry> {
+    let inodes = socket_inodes_of(proc_root, pid);
+    if inodes.is_empty() {
+        return Vec::new();
+    }
+    tcp_sockets(proc_root)
+        .into_iter()
+        .filter(|s| inodes.contains(&s.inode))
+        .collect()
+}
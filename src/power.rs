@@ -0,0 +1,179 @@
+//! Battery and AC state from /sys/class/power_supply, for the status bar
+//!
+//! Also reads the CPU frequency scaling state, since laptops usually clock down
+//! (or switch governor) on battery. Reads are cached for POWER_REFRESH_SECS.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// sysfs is only re-read this often; battery charge changes slowly
+pub const POWER_REFRESH_SECS: u64 = 10;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChargeStatus {
+    Charging,
+    Discharging,
+    Full,
+    NotCharging, // Plugged in but held below full (charge thresholds)
+    Unknown,
+}
+
+impl ChargeStatus {
+    fn parse(status: &str) -> Self {
+        match status.trim() {
+            "Charging" => ChargeStatus::Charging,
+            "Discharging" => ChargeStatus::Discharging,
+            "Full" => ChargeStatus::Full,
+            "Not charging" => ChargeStatus::NotCharging,
+            _ => ChargeStatus::Unknown,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChargeStatus::Charging => "charging",
+            ChargeStatus::Discharging => "on battery",
+            ChargeStatus::Full => "full",
+            ChargeStatus::NotCharging => "plugged in",
+            ChargeStatus::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PowerState {
+    pub battery_percent: f64, // Across all batteries, weighted by capacity when sysfs reports energy/charge
+    pub status: ChargeStatus,
+    pub ac_online: Option<bool>, // None when there is no Mains supply to ask
+    pub cpu_freq_mhz: Option<(f64, f64)>, // (average current, highest max) over all cores
+    pub governor: Option<String>, // cpufreq governor of cpu0
+}
+
+impl PowerState {
+    /// Running on battery, either from the battery status or the AC adapter being offline
+    pub fn on_battery(&self) -> bool {
+        self.status == ChargeStatus::Discharging || self.ac_online == Some(false)
+    }
+
+    /// Average CPU clock as a percentage of the maximum, i.e. how far frequency scaling has throttled
+    pub fn cpu_freq_percent(&self) -> Option<f64> {
+        self.cpu_freq_mhz.filter(|(_, max)| *max > 0.0).map(|(cur, max)| cur / max * 100.0)
+    }
+}
+
+/// Caches the power state between refreshes; None on machines without a battery
+#[derive(Default)]
+pub struct PowerMonitor {
+    state: Option<PowerState>,
+    last_read: Option<Instant>,
+}
+
+impl PowerMonitor {
+    pub fn update(&mut self) {
+        if self.last_read.is_some_and(|at| at.elapsed() < Duration::from_secs(POWER_REFRESH_SECS)) {
+            return;
+        }
+        self.last_read = Some(Instant::now());
+        self.state = read_power_state(Path::new("/sys/class/power_supply"));
+    }
+
+    /// Latest reading, or None when no battery is present (desktops)
+    pub fn state(&self) -> Option<&PowerState> {
+        self.state.as_ref()
+    }
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+fn read_f64(path: &Path) -> Option<f64> {
+    read_trimmed(path)?.parse().ok()
+}
+
+// (now, full) in the battery's own unit: energy_* (µWh) or charge_* (µAh)
+fn battery_capacity(dir: &Path) -> Option<(f64, f64)> {
+    ["energy", "charge"].iter().find_map(|kind| {
+        let now = read_f64(&dir.join(format!("{}_now", kind)))?;
+        let full = read_f64(&dir.join(format!("{}_full", kind)))?;
+        (full > 0.0).then_some((now, full))
+    })
+}
+
+/// Read every supply under `root`; None when none of them is a system battery
+pub fn read_power_state(root: &Path) -> Option<PowerState> {
+    let mut capacities = Vec::new(); // (now, full) per battery
+    let mut percents = Vec::new(); // Fallback for batteries reporting only `capacity`
+    let mut statuses = Vec::new();
+    let mut ac_online = None;
+
+    for entry in fs::read_dir(root).ok()?.flatten() {
+        let dir = entry.path();
+        match read_trimmed(&dir.join("type")).as_deref() {
+            Some("Battery") => {
+                // Peripherals (mice, keyboards) report scope=Device; only count the system battery
+                if read_trimmed(&dir.join("scope")).as_deref() == Some("Device") {
+                    continue;
+                }
+                match battery_capacity(&dir) {
+                    Some(capacity) => capacities.push(capacity),
+                    None => percents.extend(read_f64(&dir.join("capacity"))),
+                }
+                statuses.push(ChargeStatus::parse(&read_trimmed(&dir.join("status")).unwrap_or_default()));
+            }
+            Some("Mains") => {
+                let online = read_trimmed(&dir.join("online")).as_deref() == Some("1");
+                ac_online = Some(ac_online.unwrap_or(false) || online);
+            }
+            _ => {}
+        }
+    }
+    if statuses.is_empty() {
+        return None;
+    }
+
+    let battery_percent = if !capacities.is_empty() {
+        let (now, full) = capacities.iter().fold((0.0, 0.0), |(n, f), (now, full)| (n + now, f + full));
+        now / full * 100.0
+    } else if !percents.is_empty() {
+        percents.iter().sum::<f64>() / percents.len() as f64
+    } else {
+        0.0
+    };
+    // One discharging battery means the machine is running on battery
+    let status = [ChargeStatus::Discharging, ChargeStatus::Charging, ChargeStatus::NotCharging, ChargeStatus::Full]
+        .into_iter()
+        .find(|s| statuses.contains(s))
+        .unwrap_or(ChargeStatus::Unknown);
+
+    let (cpu_freq_mhz, governor) = read_cpu_scaling();
+    Some(PowerState { battery_percent: battery_percent.clamp(0.0, 100.0), status, ac_online, cpu_freq_mhz, governor })
+}
+
+// Average current and highest max frequency (MHz) over all cores, plus cpu0's governor
+fn read_cpu_scaling() -> (Option<(f64, f64)>, Option<String>) {
+    let Ok(entries) = fs::read_dir("/sys/devices/system/cpu") else {
+        return (None, None);
+    };
+    let mut current = Vec::new();
+    let mut max: f64 = 0.0;
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if !name.strip_prefix("cpu").is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit())) {
+            continue;
+        }
+        let cpufreq = entry.path().join("cpufreq");
+        // sysfs reports kHz
+        if let Some(khz) = read_f64(&cpufreq.join("scaling_cur_freq")) {
+            current.push(khz / 1000.0);
+        }
+        if let Some(khz) = read_f64(&cpufreq.join("cpuinfo_max_freq")) {
+            max = max.max(khz / 1000.0);
+        }
+    }
+    let freq = (!current.is_empty()).then(|| (current.iter().sum::<f64>() / current.len() as f64, max));
+    let governor = read_trimmed(Path::new("/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor"));
+    (freq, governor)
+}
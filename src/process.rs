@@ -1,12 +1,59 @@
 use crate::scripting_rules::RuleEngine;
 use crate::filter_parser::{FilterParser, FilterExpression};
+use crate::process_group::systemd_unit_from_cgroup;
 use sysinfo::{ProcessExt, System, SystemExt, PidExt, UserExt};
 #[cfg(target_os = "linux")]
 use procfs::process::Process as ProcfsProcess; // Import procfs for nice value
-use std::convert::TryInto; // Import the try_into function
 use chrono::{Local, TimeZone};
 use libc::{self, c_int};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex, OnceLock};
+
+const DEFAULT_PROC_ROOT: &str = "/proc";
+
+// Proc root chosen on the command line (--proc-root), used by every new ProcessManager
+static PROC_ROOT_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Set the proc root used by process managers created after this call
+pub fn set_default_proc_root(path: PathBuf) {
+    let _ = PROC_ROOT_OVERRIDE.set(path);
+}
+
+/// Proc root chosen with --proc-root, or /proc
+pub fn default_proc_root() -> PathBuf {
+    PROC_ROOT_OVERRIDE.get().cloned().unwrap_or_else(|| PathBuf::from(DEFAULT_PROC_ROOT))
+}
+
+// (name, start timestamp) of processes protected from kill/stop, shared by the TUI and the control socket
+static LOCKED_PROCESSES: LazyLock<Mutex<HashSet<(String, u64)>>> = LazyLock::new(Default::default);
+
+fn locked_processes() -> std::sync::MutexGuard<'static, HashSet<(String, u64)>> {
+    LOCKED_PROCESSES.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Whether a process is protected from kill/stop by a lock
+pub fn is_locked(process: &ProcessInfo) -> bool {
+    locked_processes().contains(&(process.name.clone(), process.start_timestamp))
+}
+
+/// Lock or unlock a process against kill/stop; returns whether it is now locked
+pub fn toggle_lock(process: &ProcessInfo) -> bool {
+    let key = (process.name.clone(), process.start_timestamp);
+    let mut locked = locked_processes();
+    if locked.remove(&key) {
+        false
+    } else {
+        locked.insert(key);
+        true
+    }
+}
+
+/// Drop the locks of processes that exited; a restarted process has a new start time
+pub fn release_exited_locks(running: &[ProcessInfo]) {
+    let running: HashSet<(String, u64)> = running.iter().map(|p| (p.name.clone(), p.start_timestamp)).collect();
+    locked_processes().retain(|key| running.contains(key));
+}
 
 #[derive(Clone)] 
 pub struct ProcessInfo {
@@ -17,15 +64,229 @@ pub struct ProcessInfo {
     pub parent_pid: Option<u32>,
     pub status: String,
     pub user: Option<String>,
+    pub tty: String, // Controlling terminal (e.g. "pts/3"), "?" when there is none
     pub nice: i32, 
+    pub sched_policy: Option<u32>, // SCHED_* policy from stat (0 other, 1 FIFO, 2 RR, 3 batch, 5 idle, 6 deadline)
     pub start_time_str: String,
     pub start_timestamp: u64, // Store actual start timestamp (seconds since boot) for uptime calculation
     pub cgroup: Option<String>,
     pub container_id: Option<String>,
+    pub unit: Option<String>, // Systemd unit from the cgroup path (e.g. "nginx.service"), None without systemd
+    pub peak_memory: u64, // Highest RSS observed this session (bytes)
+    pub vm_hwm: Option<u64>, // Kernel's RSS high-water mark (VmHWM) in bytes
+    pub tracer_pid: Option<u32>, // TracerPid from status: the debugger/strace attached with ptrace, None when untraced
+    pub thread_count: u32, // Threads from status, 0 when unreadable
+    pub cmdline: String, // Arguments from cmdline joined by spaces; empty for kernel threads
+    pub capabilities: Option<crate::capabilities::Capabilities>, // CapEff/CapPrm, None when unreadable or remote
+    pub security_label: Option<String>, // SELinux context or AppArmor profile, None without a MAC system
+    pub io_read_bytes: u64,  // Cumulative bytes read from storage
+    pub io_write_bytes: u64, // Cumulative bytes written to storage
+    pub io_read_rate: f64,   // Bytes/s read since the previous refresh
+    pub io_write_rate: f64,  // Bytes/s written since the previous refresh
+    pub cpu_time_secs: f64,  // Cumulative user+system CPU time (top's TIME+)
     pub namespace_ids: std::collections::HashMap<String, u64>,
     pub host: Option<String>, // Host identifier for multi-host mode (None = local)
 }
 
+/// A single thread (task) of a process, read from <proc_root>/<pid>/task/<tid>
+#[derive(Clone)]
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub name: String,
+    pub cpu_usage: f32,
+    pub status: String,
+}
+
+/// Everything needed to launch a process, or relaunch a running one
+pub struct LaunchSpec {
+    pub program: String,
+    pub args: Vec<String>,
+    pub working_dir: Option<String>,
+    pub env_vars: Vec<(String, String)>,
+    pub inherit_env: bool,           // Layer env_vars on our environment instead of starting empty
+    pub output_path: Option<String>, // Stdout/stderr log; None for ~/.local/share/lpm/logs/<name>-<pid>.log
+    pub run_as: Option<RunAs>,       // Identity the child switches to before exec
+}
+
+// Variables that differ between shells for reasons unrelated to how a process was launched
+const UNINTERESTING_ENV_VARS: &[&str] = &["_", "SHLVL", "PWD", "OLDPWD", "LS_COLORS"];
+
+impl LaunchSpec {
+    /// Shell command that relaunches the process: `cd <cwd> && [VAR=value ...] program args...`.
+    /// Only variables missing from or different in `base_env` are included.
+    pub fn to_shell_command(&self, base_env: &HashMap<String, String>) -> String {
+        let mut parts = Vec::new();
+        for (key, value) in &self.env_vars {
+            if UNINTERESTING_ENV_VARS.contains(&key.as_str()) || base_env.get(key) == Some(value) {
+                continue;
+            }
+            parts.push(format!("{}={}", key, shell_quote(value)));
+        }
+        parts.push(shell_quote(&self.program));
+        parts.extend(self.args.iter().map(|arg| shell_quote(arg)));
+        let command = parts.join(" ");
+        match &self.working_dir {
+            Some(dir) => format!("cd {} && {}", shell_quote(dir), command),
+            None => command,
+        }
+    }
+}
+
+// Quote a word for a POSIX shell, leaving plain words readable
+fn shell_quote(word: &str) -> String {
+    let plain = !word.is_empty()
+        && word.chars().all(|c| c.is_ascii_alphanumeric() || "_@%+=:,./-".contains(c));
+    if plain {
+        word.to_string()
+    } else {
+        format!("'{}'", word.replace('\'', "'\\''"))
+    }
+}
+
+/// Identity a started process switches to before exec
+#[derive(Clone, Debug)]
+pub struct RunAs {
+    pub user: Option<String>, // Passwd name, if the uid has an entry
+    pub uid: u32,
+    pub gid: u32,
+    pub groups: Vec<u32>, // Supplementary groups
+    pub home: Option<String>,
+}
+
+/// Resolve "user", "uid", "user:group" or "uid:gid" to the identity to run a process as.
+/// Without a group the user's primary group is used; supplementary groups come from /etc/group.
+pub fn resolve_run_as(spec: &str) -> Result<RunAs, String> {
+    let (user_part, group_part) = match spec.trim().split_once(':') {
+        Some((user, group)) => (user.trim(), Some(group.trim())),
+        None => (spec.trim(), None),
+    };
+    if user_part.is_empty() {
+        return Err("User is empty".to_string());
+    }
+    let entry = lookup_user(user_part);
+    let uid = match (&entry, user_part.parse::<u32>()) {
+        (Some((_, uid, _, _)), _) => *uid,
+        (None, Ok(uid)) => uid,
+        (None, Err(_)) => return Err(format!("Unknown user '{}'", user_part)),
+    };
+    let gid = match group_part.filter(|g| !g.is_empty()) {
+        Some(group) => match group.parse::<u32>() {
+            Ok(gid) => gid,
+            Err(_) => lookup_group(group).ok_or_else(|| format!("Unknown group '{}'", group))?,
+        },
+        None => match &entry {
+            Some((_, _, gid, _)) => *gid,
+            None => return Err(format!("UID {} has no passwd entry - give a group as {}:<gid>", uid, uid)),
+        },
+    };
+    let groups = match &entry {
+        Some((name, _, _, _)) => supplementary_groups(name, gid),
+        None => vec![gid],
+    };
+    Ok(RunAs {
+        user: entry.as_ref().map(|(name, _, _, _)| name.clone()),
+        uid,
+        gid,
+        groups,
+        home: entry.map(|(_, _, _, home)| home),
+    })
+}
+
+// Look up a passwd entry by name or numeric uid: (name, uid, primary gid, home)
+fn lookup_user(user: &str) -> Option<(String, u32, u32, String)> {
+    // SAFETY: passwd is plain data filled in by getpw*_r from our buffer
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    let mut buf = vec![0 as libc::c_char; 16 * 1024];
+    let rc = match user.parse::<u32>() {
+        // SAFETY: all pointers are valid for the duration of the call
+        Ok(uid) => unsafe { libc::getpwuid_r(uid, &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) },
+        Err(_) => {
+            let name = std::ffi::CString::new(user).ok()?;
+            // SAFETY: all pointers are valid for the duration of the call
+            unsafe { libc::getpwnam_r(name.as_ptr(), &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) }
+        }
+    };
+    if rc != 0 || result.is_null() {
+        return None;
+    }
+    // SAFETY: on success the fields point into `buf`, which is still alive
+    let text = |p: *const libc::c_char| unsafe { std::ffi::CStr::from_ptr(p) }.to_string_lossy().into_owned();
+    Some((text(pwd.pw_name), pwd.pw_uid, pwd.pw_gid, text(pwd.pw_dir)))
+}
+
+// Look up a group id by name
+fn lookup_group(group: &str) -> Option<u32> {
+    let name = std::ffi::CString::new(group).ok()?;
+    // SAFETY: group is plain data filled in by getgrnam_r from our buffer
+    let mut grp: libc::group = unsafe { std::mem::zeroed() };
+    let mut result: *mut libc::group = std::ptr::null_mut();
+    let mut buf = vec![0 as libc::c_char; 16 * 1024];
+    // SAFETY: all pointers are valid for the duration of the call
+    let rc = unsafe { libc::getgrnam_r(name.as_ptr(), &mut grp, buf.as_mut_ptr(), buf.len(), &mut result) };
+    if rc != 0 || result.is_null() {
+        return None;
+    }
+    Some(grp.gr_gid)
+}
+
+// All groups `user` belongs to, including `gid`. Resolved before fork: getgrouplist is not async-signal-safe.
+fn supplementary_groups(user: &str, gid: u32) -> Vec<u32> {
+    let Ok(name) = std::ffi::CString::new(user) else {
+        return vec![gid];
+    };
+    let mut count: c_int = 32;
+    loop {
+        let mut groups = vec![0 as libc::gid_t; count as usize];
+        // SAFETY: `groups` holds `count` entries; getgrouplist updates `count` to the real size
+        let rc = unsafe { libc::getgrouplist(name.as_ptr(), gid, groups.as_mut_ptr(), &mut count) };
+        if rc >= 0 {
+            groups.truncate(count as usize);
+            return groups;
+        }
+        if count as usize <= groups.len() {
+            // Should not happen, but never loop forever
+            return vec![gid];
+        }
+    }
+}
+
+// Whether lpm holds a capability in its effective set (CapEff in /proc/self/status)
+fn has_capability(bit: u32) -> bool {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| crate::capabilities::parse_status(&status))
+        .is_some_and(|caps| caps.effective & (1 << bit) != 0)
+}
+
+// Switching to another identity needs CAP_SETUID/CAP_SETGID (normally: running as root)
+fn check_can_run_as(run_as: &RunAs) -> std::io::Result<()> {
+    const CAP_SETGID: u32 = 6;
+    const CAP_SETUID: u32 = 7;
+    // SAFETY: these calls cannot fail
+    let (uid, gid) = unsafe { (libc::geteuid(), libc::getegid()) };
+    let needs_setuid = run_as.uid != uid;
+    let needs_setgid = run_as.gid != gid || run_as.groups.iter().any(|g| *g != gid);
+    if (needs_setuid && uid != 0 && !has_capability(CAP_SETUID))
+        || (needs_setgid && uid != 0 && !has_capability(CAP_SETGID))
+    {
+        return Err(run_as_permission_error(run_as));
+    }
+    Ok(())
+}
+
+fn run_as_permission_error(run_as: &RunAs) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::PermissionDenied,
+        format!(
+            "EPERM: starting as {} (uid {}, gid {}) requires root or CAP_SETUID/CAP_SETGID",
+            run_as.user.as_deref().unwrap_or("?"),
+            run_as.uid,
+            run_as.gid
+        ),
+    )
+}
+
 pub struct ProcessManager {
     system: System,
     filtered_processes: Vec<ProcessInfo>,// for the scripting
@@ -36,11 +297,35 @@ pub struct ProcessManager {
     filter_value: Option<String>,
     advanced_filter: Option<FilterExpression>,
     filter_parser: FilterParser,
+    secondary_sort_mode: Option<String>,
+    secondary_sort_ascending: bool,
     spawned_children: Vec<std::process::Child>,
+    child_exits: HashMap<u32, crate::process_log::ExitReason>, // Reaped children not yet picked up by the exit log
+    output_logs: HashMap<u32, std::path::PathBuf>, // Output log files of processes we launched
+    proc_root: PathBuf, // Root of the proc filesystem to read (default /proc)
+    root_cpu_ticks: HashMap<u32, u64>, // utime+stime per PID at the last alternate-root refresh
+    root_cpu_sample_time: std::time::Instant,
+    peak_memory: HashMap<u32, (u64, u64)>, // PID -> (start timestamp, peak RSS) observed this session
+    hide_kernel_threads: bool,
+    started_after: Option<u64>, // Only list processes started after this Unix timestamp
+    pid_namespace: Option<u64>, // Only list processes in this PID namespace (inode of /proc/<pid>/ns/pid)
+    cpu_per_core: bool, // CPU% relative to one core instead of all cores
+    io_totals: HashMap<u32, (u64, u64, u64)>, // PID -> (start timestamp, read bytes, write bytes) at the last refresh
+    io_sample_time: std::time::Instant,
+    thread_ticks: HashMap<u32, (u64, std::time::Instant)>, // TID -> (utime+stime, sample time) at the last thread read
+    pinned: std::collections::HashSet<(String, u64)>, // (name, start timestamp) of processes kept above the sorted list
+    idle_thresholds: Vec<f32>, // CPU thresholds of scheduled cleanups, tracked on every collect
+    idle_since: HashMap<(u32, u32), (u64, std::time::Instant)>, // (PID, threshold bits) -> (start timestamp, continuously below since)
+    d_state_since: HashMap<u32, (u64, std::time::Instant)>, // PID -> (start timestamp, continuously in D state since)
 }
 
 impl ProcessManager {
     pub fn new() -> Self {
+        Self::with_proc_root(default_proc_root())
+    }
+
+    /// Create a process manager that reads processes from the given proc root
+    pub fn with_proc_root(proc_root: PathBuf) -> Self {
         let mut system = System::new_all(); 
         system.refresh_all(); 
         ProcessManager { 
@@ -49,22 +334,51 @@ impl ProcessManager {
             filtered_processes: Vec::new(),
             sort_mode: None,
             sort_ascending: true,
+            secondary_sort_mode: None,
+            secondary_sort_ascending: true,
             filter_mode: None,
             filter_value: None,
             advanced_filter: None,
             filter_parser: FilterParser::new(),
             spawned_children: Vec::new(),
+            child_exits: HashMap::new(),
+            output_logs: HashMap::new(),
+            proc_root,
+            root_cpu_ticks: HashMap::new(),
+            root_cpu_sample_time: std::time::Instant::now(),
+            peak_memory: HashMap::new(),
+            hide_kernel_threads: false,
+            started_after: None,
+            pid_namespace: None,
+            cpu_per_core: false,
+            io_totals: HashMap::new(),
+            io_sample_time: std::time::Instant::now(),
+            thread_ticks: HashMap::new(),
+            pinned: std::collections::HashSet::new(),
+            idle_thresholds: Vec::new(),
+            idle_since: HashMap::new(),
+            d_state_since: HashMap::new(),
         }
     }
 
-    pub fn refresh(&mut self) {
+    /// Reap finished children and re-read every process, without sorting
+    pub fn collect(&mut self) {
         // Reap zombie processes
         let mut i = 0;
         while i < self.spawned_children.len() {
             if let Some(child) = self.spawned_children.get_mut(i) {
                 match child.try_wait() {
-                    Ok(Some(_)) => {
-                        // Process finished, remove it
+                    Ok(Some(status)) => {
+                        // Process finished; remember how, then remove it
+                        use std::os::unix::process::ExitStatusExt;
+                        let reason = match (status.code(), status.signal()) {
+                            (Some(code), _) => Some(crate::process_log::ExitReason::Code(code)),
+                            (None, Some(signal)) => Some(crate::process_log::ExitReason::Signal(signal)),
+                            (None, None) => None,
+                        };
+                        if let Some(reason) = reason {
+                            self.child_exits.insert(child.id(), reason);
+                        }
                         self.spawned_children.remove(i);
                     }
                     Ok(None) => {
@@ -80,15 +394,101 @@ impl ProcessManager {
                 break;
             }
         }
+        // Only exits of listed processes can be picked up (when their row disappears); drop the
+        // rest, e.g. children that exited before a refresh ever saw them
+        let listed: HashSet<u32> = self.processes.iter().map(|p| p.pid).collect();
+        self.child_exits.retain(|pid, _| listed.contains(pid));
 
         self.system.refresh_all();
         self.update_processes();
-        // Re-apply sort if there is an active sort mode
+        self.track_idle();
+        self.track_d_state();
+    }
+
+    /// How a process lpm started ended, once it has been reaped; each exit is handed out once
+    pub fn take_child_exit(&mut self, pid: u32) -> Option<crate::process_log::ExitReason> {
+        self.child_exits.remove(&pid)
+    }
+
+    /// CPU thresholds whose idle time should be tracked (from the scheduled cleanup tasks)
+    pub fn set_idle_thresholds(&mut self, thresholds: Vec<f32>) {
+        self.idle_thresholds = thresholds;
+    }
+
+    // Start or reset each process's "below threshold since" timer; any sample at or above
+    // the threshold resets it, so a brief spike restarts the idle period
+    fn track_idle(&mut self) {
+        let now = std::time::Instant::now();
+        let mut seen = std::collections::HashSet::new();
+        for process in &self.processes {
+            for threshold in &self.idle_thresholds {
+                let key = (process.pid, threshold.to_bits());
+                if process.cpu_usage < *threshold {
+                    let entry = self.idle_since.entry(key).or_insert((process.start_timestamp, now));
+                    // A reused PID starts a fresh timer
+                    if entry.0 != process.start_timestamp {
+                        *entry = (process.start_timestamp, now);
+                    }
+                    seen.insert(key);
+                }
+            }
+        }
+        self.idle_since.retain(|key, _| seen.contains(key));
+    }
+
+    /// How long a process has been continuously below `cpu_threshold` (zero if not tracked)
+    pub fn idle_duration(&self, pid: u32, cpu_threshold: f32) -> std::time::Duration {
+        self.idle_since
+            .get(&(pid, cpu_threshold.to_bits()))
+            .map_or(std::time::Duration::ZERO, |(_, since)| since.elapsed())
+    }
+
+    // Start a process's D-state timer when it enters uninterruptible sleep; leaving D
+    // (or exiting) drops it, so the duration is always one continuous stretch
+    fn track_d_state(&mut self) {
+        let now = std::time::Instant::now();
+        let mut in_d_state = HashMap::new();
+        for process in self.processes.iter().filter(|p| is_disk_sleep(p)) {
+            let since = match self.d_state_since.get(&process.pid) {
+                Some((start, since)) if *start == process.start_timestamp => *since,
+                _ => now, // New, or a reused PID
+            };
+            in_d_state.insert(process.pid, (process.start_timestamp, since));
+        }
+        self.d_state_since = in_d_state;
+    }
+
+    /// How long a process has been continuously in D state (None if it is not in D now)
+    pub fn d_state_duration(&self, pid: u32) -> Option<std::time::Duration> {
+        self.d_state_since.get(&pid).map(|(_, since)| since.elapsed())
+    }
+
+    /// Kernel function a process is blocked in (<proc_root>/<pid>/wchan), None when running
+    pub fn wchan(&self, pid: u32) -> Option<String> {
+        let wchan = std::fs::read_to_string(self.proc_root.join(pid.to_string()).join("wchan")).ok()?;
+        let wchan = wchan.trim();
+        (!wchan.is_empty() && wchan != "0").then(|| wchan.to_string())
+    }
+
+    /// Collect fresh process data and re-apply the active sort
+    pub fn refresh(&mut self) {
+        self.collect();
+        self.resort();
+    }
+
+    /// Re-apply the active sort mode, if any
+    pub fn resort(&mut self) {
         if let Some(mode) = self.sort_mode.clone() {
             self.sort_processes(&mode);
         }
     }
 
+    /// Keep the fresh readings in the row order of a previous (already sorted) snapshot
+    pub fn keep_order_of(&mut self, previous: &[ProcessInfo]) {
+        let position: HashMap<u32, usize> = previous.iter().enumerate().map(|(i, p)| (p.pid, i)).collect();
+        self.processes.sort_by_key(|p| position.get(&p.pid).copied().unwrap_or(usize::MAX));
+    }
+
     pub fn set_filter(&mut self, mode: Option<String>, value: Option<String>) {
         self.filter_mode = mode;
         self.filter_value = value;
@@ -116,6 +516,11 @@ impl ProcessManager {
         Ok(())
     }
 
+    /// Whether a simple or advanced filter is narrowing the process list
+    pub fn has_filter(&self) -> bool {
+        self.advanced_filter.is_some() || (self.filter_mode.is_some() && self.filter_value.is_some())
+    }
+
     pub fn get_advanced_filter_string(&self) -> Option<String> {
         // For now, we don't serialize back to string
         // This could be enhanced later
@@ -123,19 +528,116 @@ impl ProcessManager {
     }
 
     fn update_processes(&mut self) {
+        // sysinfo only reads the host /proc, so an alternate root is read directly through procfs
+        let mut all_processes = if self.proc_root == Path::new(DEFAULT_PROC_ROOT) {
+            self.collect_system_processes()
+        } else {
+            self.collect_proc_root_processes()
+        };
+        self.update_peak_memory(&mut all_processes);
+        self.update_io_rates(&mut all_processes);
+        // Forget thread samples that are no longer being read
+        self.thread_ticks.retain(|_, (_, sampled)| sampled.elapsed() < std::time::Duration::from_secs(10));
+        let mut processes = Vec::new();
+        
+        for proc_info in all_processes {
+            // Hide kernel threads if requested (applies before every other filter)
+            if self.hide_kernel_threads && is_kernel_thread(&proc_info) {
+                continue;
+            }
+            if self.started_after.is_some_and(|mark| proc_info.start_timestamp < mark) {
+                continue;
+            }
+            if self.pid_namespace.is_some_and(|ns| proc_info.namespace_ids.get("pid") != Some(&ns)) {
+                continue;
+            }
+            // Apply advanced filter if set
+            if let Some(ref filter_expr) = self.advanced_filter {
+                if !self.filter_parser.evaluate(&proc_info, filter_expr) {
+                    continue;
+                }
+            }
+            // Apply simple filter if set (and no advanced filter)
+            else if let (Some(mode), Some(value)) = (&self.filter_mode, &self.filter_value) {
+                let should_include = match mode.as_str() {
+                    "user" => proc_info.user.as_ref().is_some_and(|u| u.contains(value)),
+                    "name" => proc_info.name.to_lowercase().contains(&value.to_lowercase()),
+                    "pid" => proc_info.pid.to_string().contains(value),
+                    "ppid" => proc_info.parent_pid.is_some_and(|p| p.to_string().contains(value)),
+                    "status" => proc_info.status.to_lowercase().contains(&value.trim().to_lowercase()),
+                    "traced" => proc_info.tracer_pid.is_some(),
+                    _ => true,
+                };
+                if !should_include {
+                    continue;
+                }
+            }
+
+            processes.push(proc_info);
+        }
+        
+        self.processes = processes;
+
+        // Re-apply sort if there is an active sort mode
+        if let Some(mode) = self.sort_mode.clone() {
+            self.sort_processes(&mode);
+        } else {
+            self.pin_to_top();
+        }
+    }
+
+    // Track the highest RSS seen per PID; a reused PID (different start time) starts over
+    fn update_peak_memory(&mut self, processes: &mut [ProcessInfo]) {
+        let current: std::collections::HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+        self.peak_memory.retain(|pid, _| current.contains(pid));
+        for proc_info in processes.iter_mut() {
+            let entry = self.peak_memory
+                .entry(proc_info.pid)
+                .or_insert((proc_info.start_timestamp, 0));
+            if entry.0 != proc_info.start_timestamp {
+                *entry = (proc_info.start_timestamp, 0);
+            }
+            entry.1 = entry.1.max(proc_info.memory_usage);
+            proc_info.peak_memory = entry.1;
+        }
+    }
+
+    // Derive per-process I/O rates from the change in cumulative totals since the last refresh
+    fn update_io_rates(&mut self, processes: &mut [ProcessInfo]) {
+        let elapsed = self.io_sample_time.elapsed().as_secs_f64();
+        let mut totals = HashMap::new();
+        for proc_info in processes.iter_mut() {
+            if let Some(&(start, read, write)) = self.io_totals.get(&proc_info.pid)
+                && start == proc_info.start_timestamp
+                && elapsed > 0.0
+            {
+                proc_info.io_read_rate = proc_info.io_read_bytes.saturating_sub(read) as f64 / elapsed;
+                proc_info.io_write_rate = proc_info.io_write_bytes.saturating_sub(write) as f64 / elapsed;
+            }
+            totals.insert(proc_info.pid, (proc_info.start_timestamp, proc_info.io_read_bytes, proc_info.io_write_bytes));
+        }
+        self.io_totals = totals;
+        self.io_sample_time = std::time::Instant::now();
+    }
+
+    // Build process info for every process known to sysinfo
+    fn collect_system_processes(&self) -> Vec<ProcessInfo> {
         let mut processes = Vec::new();
         
         for (pid, process) in self.system.processes() {
-            // Retrieve nice value using procfs (Linux only)
+            // Nice value, state, tty, policy and CPU time from one read of stat (Linux only)
             #[cfg(target_os = "linux")]
-            let nice_value = {
-                let pid_i32: i32 = pid.as_u32().try_into().unwrap_or(0);
-                ProcfsProcess::new(pid_i32)
-                    .and_then(|p| p.stat().map(|stat| stat.nice))
-                    .unwrap_or(0)
+            let stat = match read_stat_fields(&self.proc_root, pid.as_u32()) {
+                Ok(stat) => stat,
+                // Exited after sysinfo listed it; skip rather than show a half-read entry
+                Err(e) if process_vanished(&e) => continue,
+                Err(e) => {
+                    debug_log(&format!("PID {}: could not read stat: {}", pid.as_u32(), e));
+                    StatFields::default()
+                }
             };
             #[cfg(not(target_os = "linux"))]
-            let nice_value = {
+            let stat = {
                 // Use libc to get nice value on macOS
                 unsafe {
                     // Clear errno before call
@@ -143,11 +645,12 @@ impl ProcessManager {
                     let prio = libc::getpriority(libc::PRIO_PROCESS, pid.as_u32());
                     // getpriority returns -1 on error AND on valid priority -1.
                     // Must check errno.
-                    if prio == -1 && *libc::__error() != 0 {
+                    let nice = if prio == -1 && *libc::__error() != 0 {
                         0 // Error, default to 0
                     } else {
                         prio
-                    }
+                    };
+                    StatFields { nice, ..StatFields::default() }
                 }
             };
             // Format the start time
@@ -155,33 +658,14 @@ impl ProcessManager {
             let pid_u32 = pid.as_u32();
             
             // Get cgroup, container, and namespace info
-            let cgroup = get_cgroup(pid_u32);
+            let cgroup = get_cgroup(&self.proc_root, pid_u32);
             let container_id = cgroup.as_ref().and_then(|cg| get_container_id(cg));
-            let namespace_ids = get_namespace_ids(pid_u32);
+            let unit = cgroup.as_deref().and_then(systemd_unit_from_cgroup);
+            let namespace_ids = get_namespace_ids(&self.proc_root, pid_u32);
+            let (vm_hwm, capabilities, tracer_pid, thread_count) = read_status_fields(&self.proc_root, pid_u32);
             
             // Determine status - prefer procfs on Linux for accuracy
-            #[cfg(target_os = "linux")]
-            let raw_status = {
-                let pid_i32: i32 = pid.as_u32().try_into().unwrap_or(0);
-                ProcfsProcess::new(pid_i32)
-                    .and_then(|p| p.stat().map(|stat| match stat.state {
-                        'R' => "Running".to_string(),
-                        'S' => "Sleeping".to_string(),
-                        'D' => "Disk Sleep".to_string(),
-                        'Z' => "Zombie".to_string(),
-                        'T' => "Stopped".to_string(),
-                        't' => "Tracing Stop".to_string(),
-                        'X' | 'x' => "Dead".to_string(),
-                        'K' => "Wakekill".to_string(),
-                        'W' => "Waking".to_string(),
-                        'P' => "Parked".to_string(),
-                        'I' => "Idle".to_string(),
-                        _ => format!("Unknown({})", stat.state),
-                    }))
-                    .unwrap_or_else(|_| process.status().to_string())
-            };
-            #[cfg(not(target_os = "linux"))]
-            let raw_status = process.status().to_string();
+            let raw_status = stat.status.clone().unwrap_or_else(|| process.status().to_string());
 
             // Check for both "Sleep" and "Sleeping" as sysinfo output varies
             // If CPU usage > 0, consider it Running regardless of reported state (often transient)
@@ -191,53 +675,227 @@ impl ProcessManager {
                 raw_status
             };
 
-            let proc_info = ProcessInfo {
+            processes.push(ProcessInfo {
                 pid: pid_u32,
                 name: process.name().to_string(),
-                cpu_usage: process.cpu_usage() / self.system.cpus().len() as f32,
+                cpu_usage: process.cpu_usage() / self.cpu_divisor() as f32,
                 memory_usage: process.memory(),
                 parent_pid: process.parent().map(|p| p.as_u32()),
                 status,
                 user: process.user_id()
                     .and_then(|id| self.system.get_user_by_id(id)
                     .map(|user| user.name().to_string())),
-                nice: nice_value as i32,
+                tty: tty_name(stat.tty_nr),
+                nice: stat.nice,
+                sched_policy: stat.sched_policy,
                 start_time_str: formatted_time,
                 start_timestamp: process.start_time(), // Store actual start timestamp (seconds since boot)
                 cgroup,
                 container_id,
+                unit,
+                peak_memory: process.memory(),
+                vm_hwm,
+                tracer_pid,
+                thread_count,
+                cmdline: read_cmdline_string(&self.proc_root, pid_u32),
+                capabilities,
+                security_label: crate::security::read_label(&self.proc_root, pid_u32),
+                io_read_bytes: process.disk_usage().total_read_bytes,
+                io_write_bytes: process.disk_usage().total_written_bytes,
+                io_read_rate: 0.0,
+                io_write_rate: 0.0,
+                cpu_time_secs: stat.cpu_time_secs,
                 namespace_ids,
                 host: None, // Local processes have no host
-            };
+            });
 
-            // Apply advanced filter if set
-            if let Some(ref filter_expr) = self.advanced_filter {
-                if !self.filter_parser.evaluate(&proc_info, filter_expr) {
+        }
+        
+        processes
+    }
+
+    // Build process info by reading an alternate proc root (e.g. a container's mounted /proc)
+    #[cfg(target_os = "linux")]
+    fn collect_proc_root_processes(&mut self) -> Vec<ProcessInfo> {
+        let mut processes = Vec::new();
+        let all = match procfs::process::all_processes_with_root(&self.proc_root) {
+            Ok(all) => all,
+            Err(_) => return processes,
+        };
+        let ticks_per_second = procfs::ticks_per_second().max(1);
+        let page_size = procfs::page_size();
+        let boot_time = read_boot_time(&self.proc_root);
+        let cpu_divisor = self.cpu_divisor();
+        let elapsed = self.root_cpu_sample_time.elapsed().as_secs_f64();
+        let mut cpu_ticks = HashMap::new();
+        
+        for proc in all.flatten() {
+            let stat = match proc.stat() {
+                Ok(stat) => stat,
+                Err(e) if process_vanished(&e) => continue,
+                Err(e) => {
+                    debug_log(&format!("PID {}: could not read stat: {}", proc.pid, e));
                     continue;
                 }
-            }
-            // Apply simple filter if set (and no advanced filter)
-            else if let (Some(mode), Some(value)) = (&self.filter_mode, &self.filter_value) {
-                let should_include = match mode.as_str() {
-                    "user" => proc_info.user.as_ref().map_or(false, |u| u.contains(value)),
-                    "name" => proc_info.name.to_lowercase().contains(&value.to_lowercase()),
-                    "pid" => proc_info.pid.to_string().contains(value),
-                    "ppid" => proc_info.parent_pid.map_or(false, |p| p.to_string().contains(value)),
-                    _ => true,
-                };
-                if !should_include {
-                    continue;
+            };
+            let pid = stat.pid as u32;
+            
+            // CPU usage from the utime+stime delta since the previous refresh
+            let total_ticks = stat.utime + stat.stime;
+            let cpu_usage = match self.root_cpu_ticks.get(&pid) {
+                Some(prev) if elapsed > 0.0 => {
+                    (total_ticks.saturating_sub(*prev) as f64 / ticks_per_second as f64 / elapsed * 100.0 / cpu_divisor) as f32
                 }
-            }
-
-            processes.push(proc_info);
+                _ => 0.0,
+            };
+            cpu_ticks.insert(pid, total_ticks);
+            
+            let start_timestamp = boot_time + stat.starttime / ticks_per_second;
+            let user = proc.uid().ok().and_then(|uid| {
+                self.system.users().iter()
+                    .find(|u| **u.id() == uid)
+                    .map(|u| u.name().to_string())
+            });
+            let cgroup = get_cgroup(&self.proc_root, pid);
+            let container_id = cgroup.as_ref().and_then(|cg| get_container_id(cg));
+            let unit = cgroup.as_deref().and_then(systemd_unit_from_cgroup);
+            let (vm_hwm, capabilities, tracer_pid, thread_count) = read_status_fields(&self.proc_root, pid);
+            let (io_read_bytes, io_write_bytes) = proc.io()
+                .map(|io| (io.read_bytes, io.write_bytes))
+                .unwrap_or((0, 0));
+            let raw_status = state_to_status(stat.state);
+            let status = if cpu_usage > 0.0 && (raw_status == "Sleeping" || raw_status == "Idle") {
+                "Run".to_string()
+            } else {
+                raw_status
+            };
+            
+            processes.push(ProcessInfo {
+                pid,
+                name: stat.comm.clone(),
+                cpu_usage,
+                memory_usage: stat.rss * page_size,
+                parent_pid: if stat.ppid > 0 { Some(stat.ppid as u32) } else { None },
+                status,
+                user,
+                tty: tty_name(stat.tty_nr),
+                nice: stat.nice as i32,
+                sched_policy: stat.policy,
+                start_time_str: format_timestamp(start_timestamp),
+                start_timestamp,
+                cgroup,
+                container_id,
+                unit,
+                peak_memory: stat.rss * page_size,
+                vm_hwm,
+                tracer_pid,
+                thread_count,
+                cmdline: read_cmdline_string(&self.proc_root, pid),
+                capabilities,
+                security_label: crate::security::read_label(&self.proc_root, pid),
+                io_read_bytes,
+                io_write_bytes,
+                io_read_rate: 0.0,
+                io_write_rate: 0.0,
+                cpu_time_secs: total_ticks as f64 / ticks_per_second as f64,
+                namespace_ids: get_namespace_ids(&self.proc_root, pid),
+                host: None,
+            });
         }
         
-        self.processes = processes;
+        self.root_cpu_ticks = cpu_ticks;
+        self.root_cpu_sample_time = std::time::Instant::now();
+        processes
+    }
 
-        // Re-apply sort if there is an active sort mode
-        if let Some(mode) = self.sort_mode.clone() {
-            self.sort_processes(&mode);
+    #[cfg(not(target_os = "linux"))]
+    fn collect_proc_root_processes(&mut self) -> Vec<ProcessInfo> {
+        Vec::new() // Alternate proc roots are only supported on Linux
+    }
+
+    /// Unix time the system booted: now minus the uptime in <proc_root>/uptime
+    pub fn boot_time(&self) -> Option<u64> {
+        let uptime: f64 = std::fs::read_to_string(self.proc_root.join("uptime"))
+            .ok()?
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()?;
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).ok()?.as_secs_f64();
+        Some((now - uptime).max(0.0) as u64)
+    }
+
+    /// Total physical memory in bytes
+    pub fn get_total_memory(&self) -> u64 {
+        self.system.total_memory()
+    }
+
+    pub fn get_proc_root(&self) -> &Path {
+        &self.proc_root
+    }
+
+    pub fn set_hide_kernel_threads(&mut self, hide: bool) {
+        self.hide_kernel_threads = hide;
+        self.update_processes();
+    }
+
+    pub fn is_hiding_kernel_threads(&self) -> bool {
+        self.hide_kernel_threads
+    }
+
+    /// Show only processes started at or after a Unix timestamp (None shows all)
+    pub fn set_started_after(&mut self, mark: Option<u64>) {
+        self.started_after = mark;
+        self.update_processes();
+    }
+
+    pub fn get_started_after(&self) -> Option<u64> {
+        self.started_after
+    }
+
+    /// Show only processes in one PID namespace, e.g. a single container's (None shows all)
+    pub fn set_pid_namespace(&mut self, namespace: Option<u64>) {
+        self.pid_namespace = namespace;
+        self.update_processes();
+    }
+
+    pub fn get_pid_namespace(&self) -> Option<u64> {
+        self.pid_namespace
+    }
+
+    /// Command name of any process, including ones the current filters hide
+    pub fn process_name(&self, pid: u32) -> Option<String> {
+        std::fs::read_to_string(self.proc_root.join(pid.to_string()).join("comm"))
+            .ok()
+            .map(|comm| comm.trim_end().to_string())
+    }
+
+    /// Inode of the PID namespace a process is in
+    pub fn pid_namespace_of(&self, pid: u32) -> Option<u64> {
+        get_namespace_ids(&self.proc_root, pid).get("pid").copied()
+    }
+
+    /// Report process CPU% per core (a busy single thread is 100%) or as a share of the whole system
+    pub fn set_cpu_per_core(&mut self, per_core: bool) {
+        self.cpu_per_core = per_core;
+        self.update_processes();
+    }
+
+    pub fn is_cpu_per_core(&self) -> bool {
+        self.cpu_per_core
+    }
+
+    /// CPU% that means "every core fully busy" in the current normalization mode
+    pub fn cpu_full_scale(&self) -> f32 {
+        100.0 * (self.system.cpus().len().max(1) as f64 / self.cpu_divisor()) as f32
+    }
+
+    /// Raw per-core CPU% is divided by this to get the reported value
+    pub fn cpu_divisor(&self) -> f64 {
+        if self.cpu_per_core {
+            1.0
+        } else {
+            self.system.cpus().len().max(1) as f64
         }
     }
 
@@ -255,81 +913,53 @@ impl ProcessManager {
         self.sort_processes(mode);
     }
 
+    /// Set a secondary sort key used to break ties in the primary key (None clears it)
+    pub fn set_secondary_sort(&mut self, mode: Option<&str>, ascending: bool) {
+        self.secondary_sort_mode = mode.map(|m| m.to_string());
+        self.secondary_sort_ascending = ascending;
+        if let Some(primary) = self.sort_mode.clone() {
+            self.sort_processes(&primary);
+        }
+    }
+
+    pub fn get_secondary_sort(&self) -> Option<(&str, bool)> {
+        self.secondary_sort_mode.as_deref().map(|m| (m, self.secondary_sort_ascending))
+    }
+
     fn sort_processes(&mut self, mode: &str) {
-        match mode {
-            "pid" => {
-                if self.sort_ascending {
-                    self.processes.sort_by_key(|p| p.pid);
-                } else {
-                    self.processes.sort_by_key(|p| std::cmp::Reverse(p.pid));
-                }
-            }
-            "mem" => {
-                if self.sort_ascending {
-                    self.processes.sort_by_key(|p| p.memory_usage);
-                } else {
-                    self.processes.sort_by_key(|p| std::cmp::Reverse(p.memory_usage));
-                }
-            }
-            "ppid" => {
-                if self.sort_ascending {
-                    self.processes.sort_by_key(|p| p.parent_pid.unwrap_or(0));
-                } else {
-                    self.processes.sort_by_key(|p| std::cmp::Reverse(p.parent_pid.unwrap_or(0)));
-                }
-            }
-            "start" => {
-                if self.sort_ascending {
-                    self.processes.sort_by(|a, b| a.start_time_str.cmp(&b.start_time_str));
-                } else {
-                    self.processes.sort_by(|a, b| b.start_time_str.cmp(&a.start_time_str));
-                }
-            }
-            "nice" => {
-                if self.sort_ascending {
-                    self.processes.sort_by_key(|p| p.nice);
-                } else {
-                    self.processes.sort_by_key(|p| std::cmp::Reverse(p.nice));
-                }
-            }
-            "cpu" => {
-                if self.sort_ascending {
-                    self.processes.sort_by(|a, b| a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
-                } else {
-                    self.processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
-                }
-            }
-            "name" => {
-                if self.sort_ascending {
-                    self.processes.sort_by(|a, b| a.name.cmp(&b.name));
-                } else {
-                    self.processes.sort_by(|a, b| b.name.cmp(&a.name));
-                }
-            }
-            "user" => {
-                if self.sort_ascending {
-                    self.processes.sort_by(|a, b| {
-                        let a_user = a.user.as_ref().map(|s| s.as_str()).unwrap_or("");
-                        let b_user = b.user.as_ref().map(|s| s.as_str()).unwrap_or("");
-                        a_user.cmp(b_user)
-                    });
-                } else {
-                    self.processes.sort_by(|a, b| {
-                        let a_user = a.user.as_ref().map(|s| s.as_str()).unwrap_or("");
-                        let b_user = b.user.as_ref().map(|s| s.as_str()).unwrap_or("");
-                        b_user.cmp(a_user)
-                    });
-                }
-            }
-            "status" => {
-                if self.sort_ascending {
-                    self.processes.sort_by(|a, b| a.status.cmp(&b.status));
-                } else {
-                    self.processes.sort_by(|a, b| b.status.cmp(&a.status));
-                }
-            }
-            _ => {}
+        // Primary key, then optional secondary key, then PID so equal rows never swap between refreshes
+        let primary_ascending = self.sort_ascending;
+        let secondary = self.secondary_sort_mode.clone();
+        let secondary_ascending = self.secondary_sort_ascending;
+        self.processes.sort_by(|a, b| {
+            let primary = compare_processes(mode, a, b);
+            let primary = if primary_ascending { primary } else { primary.reverse() };
+            primary
+                .then_with(|| match &secondary {
+                    Some(secondary_mode) => {
+                        let ord = compare_processes(secondary_mode, a, b);
+                        if secondary_ascending { ord } else { ord.reverse() }
+                    }
+                    None => std::cmp::Ordering::Equal,
+                })
+                .then_with(|| a.pid.cmp(&b.pid))
+        });
+        self.pin_to_top();
+    }
+
+    /// Keep these processes (by name and start timestamp) at the top, in sort order, above everything else
+    pub fn set_pinned(&mut self, pinned: std::collections::HashSet<(String, u64)>) {
+        self.pinned = pinned;
+        self.pin_to_top();
+    }
+
+    // Stable partition, so pinned processes and the remainder each keep their sort order
+    fn pin_to_top(&mut self) {
+        if self.pinned.is_empty() {
+            return;
         }
+        let pinned = &self.pinned;
+        self.processes.sort_by_key(|p| !pinned.contains(&(p.name.clone(), p.start_timestamp)));
     }
 
     /// Apply profile-based prioritization to move prioritized processes to the top
@@ -341,39 +971,162 @@ impl ProcessManager {
         // Stable partition: prioritized first, others second
         // This maintains the relative order within each group (preserving the sort)
         self.processes.sort_by_key(|p| !is_prioritized(&p.name));
+        // Pinned processes stay above prioritized ones
+        self.pin_to_top();
+    }
+
+
+    pub fn set_niceness(&self, pid: u32, nice: i32) -> std::io::Result<()> {
+        // Validate niceness range
+        if nice < -20 || nice > 19 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Nice value must be between -20 and 19"
+            ));
+        }
+
+        // Check privileges if setting negative nice
+        if nice < 0 && unsafe { libc::geteuid() } != 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "Root privileges required for negative nice values (use sudo)"
+            ));
+        }
+        let temp_pid: libc::id_t = pid;
+
+        // SAFETY: This is safe because we're passing valid arguments
+        let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, temp_pid, nice as c_int) };
+        
+        // Callers report failures (the TUI owns the terminal, so nothing is printed here)
+        if result != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// All processes owned by `user` as (pid, name), regardless of the active filter.
+    /// lpm itself and the shell that launched it are left out so a bulk renice can't starve them.
+    pub fn processes_of_user(&self, user: &str) -> Vec<(u32, String)> {
+        let own_pid = std::process::id();
+        let parent_pid = unsafe { libc::getppid() } as u32;
+        let mut result: Vec<(u32, String)> = self.system.processes()
+            .iter()
+            .filter(|(_, process)| {
+                process.user_id()
+                    .and_then(|id| self.system.get_user_by_id(id))
+                    .is_some_and(|u| u.name() == user)
+            })
+            .map(|(pid, process)| (pid.as_u32(), process.name().to_string()))
+            .filter(|(pid, _)| *pid != own_pid && *pid != parent_pid)
+            .collect();
+        result.sort_by_key(|(pid, _)| *pid);
+        result
+    }
+
+    /// Set the nice value of each PID, returning (succeeded, failed)
+    pub fn renice_pids(&self, pids: &[u32], nice: i32) -> (usize, usize) {
+        let mut success_count = 0;
+        let mut fail_count = 0;
+        for pid in pids {
+            match self.set_niceness(*pid, nice) {
+                Ok(_) => success_count += 1,
+                Err(_) => fail_count += 1,
+            }
+        }
+        (success_count, fail_count)
     }
 
+    /// CPUs a process may run on (sched_getaffinity); empty if it could not be read
+    pub fn get_cpu_affinity(&self, pid: u32) -> Vec<usize> {
+        // SAFETY: cpu_set_t is plain data that sched_getaffinity fills in
+        unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            if libc::sched_getaffinity(pid as libc::pid_t, std::mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+                return Vec::new();
+            }
+            (0..libc::CPU_SETSIZE as usize).filter(|cpu| libc::CPU_ISSET(*cpu, &set)).collect()
+        }
+    }
 
-    pub fn set_niceness(&self, pid: u32, nice: i32) -> std::io::Result<()> {
-        // Validate niceness range
-        if nice < -20 || nice > 19 {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Nice value must be between -20 and 19"
-            ));
+    /// I/O scheduling class and priority (ioprio_get)
+    pub fn get_ionice(&self, pid: u32) -> std::io::Result<(IoClass, u8)> {
+        // SAFETY: ioprio_get only reads its integer arguments
+        let value = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, pid as libc::c_long) };
+        if value < 0 {
+            return Err(std::io::Error::last_os_error());
         }
+        let value = value as u32;
+        let class = match value >> IOPRIO_CLASS_SHIFT {
+            1 => IoClass::Realtime,
+            2 => IoClass::BestEffort,
+            3 => IoClass::Idle,
+            _ => IoClass::None,
+        };
+        Ok((class, (value & 0x7) as u8))
+    }
 
-        // Check privileges if setting negative nice
-        if nice < 0 && unsafe { libc::geteuid() } != 0 {
+    /// Set the I/O scheduling class and priority (0 = highest, 7 = lowest; ignored for idle)
+    pub fn set_ionice(&self, pid: u32, class: IoClass, priority: u8) -> std::io::Result<()> {
+        if priority > 7 {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "I/O priority must be between 0 and 7"));
+        }
+        let (class_bits, priority) = match class {
+            IoClass::None => (0, 0),
+            IoClass::Realtime => (1, priority),
+            IoClass::BestEffort => (2, priority),
+            IoClass::Idle => (3, 0),
+        };
+        let value = (class_bits << IOPRIO_CLASS_SHIFT) | priority as u32;
+        // SAFETY: ioprio_set only reads its integer arguments
+        let result = unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, pid as libc::c_long, value as libc::c_long) };
+        if result != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Restrict a process to the given CPUs; CPUs that are not online are refused
+    pub fn set_cpu_affinity(&self, pid: u32, cpus: &[usize]) -> std::io::Result<()> {
+        let online = online_cpus();
+        if let Some(cpu) = cpus.iter().find(|cpu| !online.contains(cpu)) {
             return Err(std::io::Error::new(
-                std::io::ErrorKind::PermissionDenied,
-                "Root privileges required for negative nice values (use sudo)"
+                std::io::ErrorKind::InvalidInput,
+                format!("CPU {} is not online - online CPUs are {}", cpu, format_cpu_list(&online)),
             ));
         }
-        let temp_pid: libc::id_t = pid;
-
-        // SAFETY: This is safe because we're passing valid arguments
-        let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, temp_pid, nice as c_int) };
-        
+        // SAFETY: cpu_set_t is plain data, and CPU_SET ignores indices past its capacity
+        let result = unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            libc::CPU_ZERO(&mut set);
+            for cpu in cpus {
+                libc::CPU_SET(*cpu, &mut set);
+            }
+            libc::sched_setaffinity(pid as libc::pid_t, std::mem::size_of::<libc::cpu_set_t>(), &set)
+        };
         if result != 0 {
-            let err = std::io::Error::last_os_error();
-            eprintln!("Failed to set nice for PID {}: {}", pid, err);
-            return Err(err);
+            return Err(std::io::Error::last_os_error());
         }
-
         Ok(())
     }
 
+    /// Apply a nice value and/or CPU affinity to each PID, returning (succeeded, failed);
+    /// a process counts as failed if either change was refused
+    pub fn tune_pids(&self, pids: &[u32], nice: Option<i32>, cpus: Option<&[usize]>) -> (usize, usize) {
+        let mut success_count = 0;
+        let mut fail_count = 0;
+        for pid in pids {
+            let niced = nice.map_or(Ok(()), |nice| self.set_niceness(*pid, nice));
+            let pinned = cpus.map_or(Ok(()), |cpus| self.set_cpu_affinity(*pid, cpus));
+            if niced.is_ok() && pinned.is_ok() {
+                success_count += 1;
+            } else {
+                fail_count += 1;
+            }
+        }
+        (success_count, fail_count)
+    }
+
     pub fn apply_nice_adjustments<F>(&self, get_nice_adjustment: F) -> (usize, usize)
     where
         F: Fn(&str) -> Option<i32>
@@ -397,6 +1150,22 @@ impl ProcessManager {
     }
 
 
+    /// Send an arbitrary signal (see `crate::signals::SIGNALS`) to a process
+    pub fn send_signal(&self, pid: u32, signal: i32) -> std::io::Result<()> {
+        use libc::{kill, pid_t};
+        
+        let temp_pid: pid_t = pid as pid_t;
+        
+        // SAFETY: This is safe because we're passing valid arguments
+        let result = unsafe { kill(temp_pid, signal) };
+        
+        if result != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        
+        Ok(())
+    }
+
     pub fn stop_process(&self, pid: u32) -> std::io::Result<()> {
         use libc::{kill, pid_t, SIGSTOP};
         
@@ -476,7 +1245,7 @@ impl ProcessManager {
         for process in &self.processes {
             if process.name.contains(pattern) {
                 // Try to read the command line before killing
-                if let Some((program, args)) = read_process_cmdline(process.pid) {
+                if let Some((program, args)) = read_process_cmdline(&self.proc_root, process.pid) {
                     processes_to_restart.push((process.pid, program, args));
                 } else {
                     // If we can't read cmdline, just kill it (fallback behavior)
@@ -519,15 +1288,23 @@ impl ProcessManager {
     }
 
     /// Cleanup idle processes based on criteria
+    ///
+    /// Only processes that have stayed below `cpu_threshold` for `duration_seconds` count as idle;
+    /// the threshold must be registered with `set_idle_thresholds` for its timers to run.
     pub fn cleanup_idle_processes(
         &self,
         cpu_threshold: f32,
         memory_threshold: u64,
+        duration_seconds: u64,
         action: &str,
     ) -> std::io::Result<Vec<u32>> {
         let mut cleaned_pids = Vec::new();
+        let required = std::time::Duration::from_secs(duration_seconds);
         for process in &self.processes {
-            if process.cpu_usage < cpu_threshold && process.memory_usage > memory_threshold {
+            if process.cpu_usage < cpu_threshold
+                && process.memory_usage > memory_threshold
+                && self.idle_duration(process.pid, cpu_threshold) >= required
+            {
                 match action {
                     "kill" => {
                         if let Err(e) = self.kill_process(process.pid) {
@@ -562,32 +1339,68 @@ impl ProcessManager {
             .collect()
     }
 
-    /// Kill a process and all its children recursively
-    pub fn kill_process_and_children(&self, pid: u32) -> std::io::Result<Vec<u32>> {
-        let mut killed_pids = Vec::new();
-        let children = self.get_child_processes(pid);
-        
-        // First kill all children recursively
-        for child in &children {
-            let child_children = self.get_child_processes(child.pid);
-            if !child_children.is_empty() {
-                // Recursively kill children of children
-                let _ = self.kill_process_and_children(child.pid);
-            }
-            // Kill the child
-            if let Err(e) = self.kill_process(child.pid) {
-                return Err(e);
+    /// Every descendant of a process (children, grandchildren, ...), each listed before its parent
+    pub fn descendants(&self, pid: u32) -> Vec<u32> {
+        let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+        for process in &self.processes {
+            if let Some(parent_pid) = process.parent_pid {
+                children.entry(parent_pid).or_default().push(process.pid);
             }
-            killed_pids.push(child.pid);
         }
-        
-        // Then kill the parent
-        if let Err(e) = self.kill_process(pid) {
-            return Err(e);
+        // Breadth-first from pid; reversed, the deepest processes come first
+        let mut order = Vec::new();
+        let mut seen = HashSet::from([pid]);
+        let mut queue = VecDeque::from([pid]);
+        while let Some(current) = queue.pop_front() {
+            for &child in children.get(&current).into_iter().flatten() {
+                if seen.insert(child) {
+                    order.push(child);
+                    queue.push_back(child);
+                }
+            }
         }
-        killed_pids.push(pid);
-        
-        Ok(killed_pids)
+        order.reverse();
+        order
+    }
+
+    /// Send a signal to a process and its whole descendant tree, returning every PID signaled
+    ///
+    /// The tree is computed up front and frozen top-down with SIGSTOP, so no parent can reap a
+    /// killed child or spawn a new one meanwhile; it is then signaled leaves first and thawed.
+    /// Descendants that already exited are skipped.
+    pub fn kill_tree(&self, pid: u32, signal: i32) -> std::io::Result<Vec<u32>> {
+        let descendants = self.descendants(pid);
+        // Sends to a tree member, treating an already exited one as skipped
+        let send = |target: u32, signal: i32| match self.send_signal(target, signal) {
+            Ok(()) => Ok(true),
+            Err(e) if e.raw_os_error() == Some(libc::ESRCH) && target != pid => Ok(false),
+            Err(e) => Err(e),
+        };
+        let freeze = signal != libc::SIGSTOP && signal != libc::SIGCONT;
+        if freeze {
+            send(pid, libc::SIGSTOP)?;
+            for &descendant in descendants.iter().rev() {
+                send(descendant, libc::SIGSTOP)?;
+            }
+        }
+        let mut signaled = Vec::new();
+        for &target in descendants.iter().chain(std::iter::once(&pid)) {
+            if send(target, signal)? {
+                signaled.push(target);
+            }
+        }
+        if freeze {
+            // Stopped processes only act on a pending SIGTERM/SIGINT/... once continued
+            for &target in &signaled {
+                let _ = self.send_signal(target, libc::SIGCONT);
+            }
+        }
+        Ok(signaled)
+    }
+
+    /// Kill a process and all its descendants
+    pub fn kill_process_and_children(&self, pid: u32) -> std::io::Result<Vec<u32>> {
+        self.kill_tree(pid, libc::SIGKILL)
     }
 
     /// Start a new process with the given parameters
@@ -598,8 +1411,29 @@ impl ProcessManager {
         working_dir: Option<&str>,
         env_vars: &[(String, String)],
     ) -> std::io::Result<u32> {
+        self.launch(&LaunchSpec {
+            program: program.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            working_dir: working_dir.map(str::to_string),
+            env_vars: env_vars.to_vec(),
+            inherit_env: true,
+            output_path: None,
+            run_as: None,
+        })
+    }
+
+    /// Start a process, optionally with a clean environment containing only `env_vars`.
+    /// Stdout/stderr go to `output_path`, or to `~/.local/share/lpm/logs/<name>-<pid>.log` if None.
+    /// With `run_as`, the child drops to that user and its groups between fork and exec.
+    pub fn launch(&mut self, spec: &LaunchSpec) -> std::io::Result<u32> {
         use std::process::Command;
         
+        let LaunchSpec { program, args, working_dir, env_vars, inherit_env, output_path, run_as } = spec;
+        let (inherit_env, output_path, run_as) = (*inherit_env, output_path.as_deref(), run_as.as_ref());
+        if let Some(run_as) = run_as {
+            check_can_run_as(run_as)?;
+        }
+        
         let mut command = Command::new(program);
         
         // Set arguments
@@ -618,47 +1452,496 @@ impl ProcessManager {
             command.current_dir(dir);
         }
         
-        // Set environment variables
+        // Set environment variables (on top of an empty environment if not inheriting)
+        if !inherit_env {
+            command.env_clear();
+        }
+        // Point the inherited login variables at the target user (explicit variables below still win)
+        if let Some(RunAs { user: Some(user), home, .. }) = run_as
+            && inherit_env
+        {
+            command.env("USER", user).env("LOGNAME", user);
+            if let Some(home) = home {
+                command.env("HOME", home);
+            }
+        }
         for (key, value) in env_vars {
             command.env(key, value);
         }
         
-        // Redirect child process stdout/stderr to /dev/null to prevent output from interfering with TUI
+        // Redirect child process stdout/stderr to a log file to prevent output from interfering with TUI.
+        // The default log name contains the PID, so it is opened under a temporary name and renamed after spawn.
+        let program_name = std::path::Path::new(program)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| program.to_string());
+        let log_path = match output_path {
+            Some(path) => std::path::PathBuf::from(path),
+            None => default_log_dir().join(format!("{}-starting-{}.log", program_name, std::process::id())),
+        };
+        if let Some(parent) = log_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let log_file = std::fs::OpenOptions::new().create(true).append(true).open(&log_path)?;
+        if let Some(run_as) = run_as {
+            use std::os::unix::process::CommandExt;
+            let (uid, gid, groups) = (run_as.uid, run_as.gid, run_as.groups.clone());
+            // SAFETY: runs in the forked child before exec and only makes async-signal-safe calls.
+            // Groups go first: setgroups/setgid are no longer permitted once the uid is dropped.
+            unsafe {
+                command.pre_exec(move || {
+                    if libc::setgroups(groups.len(), groups.as_ptr()) != 0
+                        || libc::setgid(gid) != 0
+                        || libc::setuid(uid) != 0
+                    {
+                        return Err(std::io::Error::last_os_error());
+                    }
+                    Ok(())
+                });
+            }
+        }
         let child = command
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
+            .stdout(log_file.try_clone()?)
+            .stderr(log_file)
             .stdin(std::process::Stdio::null())
-            .spawn()?;
+            .spawn();
+        let child = match child {
+            Ok(child) => child,
+            Err(e) => {
+                if output_path.is_none() {
+                    let _ = std::fs::remove_file(&log_path);
+                }
+                if let Some(run_as) = run_as
+                    && e.raw_os_error() == Some(libc::EPERM)
+                {
+                    return Err(run_as_permission_error(run_as));
+                }
+                return Err(e);
+            }
+        };
         let pid = child.id();
+
+        let log_path = if output_path.is_none() {
+            let final_path = default_log_dir().join(format!("{}-{}.log", program_name, pid));
+            match std::fs::rename(&log_path, &final_path) {
+                Ok(()) => final_path,
+                Err(_) => log_path,
+            }
+        } else {
+            log_path
+        };
+        self.output_logs.insert(pid, log_path);
         
         // Store child handle to prevent zombies
         self.spawned_children.push(child);
         
         Ok(pid)
     }
+
+    /// Read the command line, working directory and environment of a running process
+    pub fn get_launch_spec(&self, pid: u32) -> Option<LaunchSpec> {
+        let (program, args) = read_process_cmdline(&self.proc_root, pid)?;
+        let env_vars = read_process_environ(&self.proc_root, pid);
+        Some(LaunchSpec {
+            program,
+            args,
+            working_dir: read_process_cwd(&self.proc_root, pid),
+            // Reuse the captured environment as-is; fall back to ours if it was unreadable
+            inherit_env: env_vars.is_empty(),
+            env_vars,
+            output_path: None,
+            run_as: None,
+        })
+    }
+
+    /// Current working directory and executable of a process (None when exited or not permitted)
+    pub fn process_paths(&self, pid: u32) -> (Option<String>, Option<String>) {
+        let exe = std::fs::read_link(self.proc_root.join(pid.to_string()).join("exe"))
+            .ok()
+            .map(|path| path.to_string_lossy().to_string());
+        (read_process_cwd(&self.proc_root, pid), exe)
+    }
+
+    /// Kill a process and start the given (possibly modified) command in its place
+    pub fn restart_with(&mut self, pid: u32, spec: &LaunchSpec) -> std::io::Result<u32> {
+        // Refuse before killing anything if the new process could not be started as requested
+        if let Some(run_as) = &spec.run_as {
+            check_can_run_as(run_as)?;
+        }
+        self.kill_process(pid)?;
+        // Wait a brief moment for the process to fully terminate
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        self.launch(spec)
+    }
+
+    /// Enumerate the threads of a process with per-thread CPU since the previous read
+    #[cfg(target_os = "linux")]
+    pub fn get_threads(&mut self, pid: u32) -> Vec<ThreadInfo> {
+        let tasks = match ProcfsProcess::new_with_root(self.proc_root.join(pid.to_string())).and_then(|p| p.tasks()) {
+            Ok(tasks) => tasks,
+            Err(_) => return Vec::new(),
+        };
+        let ticks_per_second = procfs::ticks_per_second().max(1) as f64;
+        let cpu_divisor = self.cpu_divisor();
+        let now = std::time::Instant::now();
+        let mut threads = Vec::new();
+
+        for task in tasks.flatten() {
+            let stat = match task.stat() {
+                Ok(stat) => stat,
+                Err(e) if process_vanished(&e) => continue,
+                Err(e) => {
+                    debug_log(&format!("TID {} of PID {}: could not read stat: {}", task.tid, pid, e));
+                    continue;
+                }
+            };
+            let tid = task.tid as u32;
+            let total_ticks = stat.utime + stat.stime;
+            let cpu_usage = match self.thread_ticks.get(&tid) {
+                Some(&(prev, sampled)) => {
+                    let elapsed = now.duration_since(sampled).as_secs_f64();
+                    if elapsed > 0.0 {
+                        (total_ticks.saturating_sub(prev) as f64 / ticks_per_second / elapsed * 100.0 / cpu_divisor) as f32
+                    } else {
+                        0.0
+                    }
+                }
+                None => 0.0,
+            };
+            self.thread_ticks.insert(tid, (total_ticks, now));
+            threads.push(ThreadInfo {
+                tid,
+                name: stat.comm.clone(),
+                cpu_usage,
+                status: state_to_status(stat.state),
+            });
+        }
+
+        threads.sort_by_key(|t| t.tid);
+        threads
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn get_threads(&mut self, _pid: u32) -> Vec<ThreadInfo> {
+        Vec::new() // Per-thread information is only available on Linux
+    }
+
+    /// Get the output log path of a process launched by this manager
+    pub fn get_output_log(&self, pid: u32) -> Option<&std::path::Path> {
+        self.output_logs.get(&pid).map(|p| p.as_path())
+    }
+
+    /// Log files a process writes to: its lpm output log plus log-like files it has open for writing
+    pub fn find_log_files(&self, pid: u32) -> Vec<PathBuf> {
+        let mut logs: Vec<PathBuf> = self.get_output_log(pid).map(|p| p.to_path_buf()).into_iter().collect();
+        for path in find_open_log_files(&self.proc_root, pid) {
+            if !logs.contains(&path) {
+                logs.push(path);
+            }
+        }
+        logs
+    }
+}
+
+/// Uninterruptible sleep (D state), usually waiting on disk or NFS
+pub fn is_disk_sleep(process: &ProcessInfo) -> bool {
+    // "Disk Sleep" from /proc, "UninterruptibleDiskSleep" from sysinfo elsewhere
+    let status = process.status.trim();
+    status == "Disk Sleep" || status.contains("Uninterruptible")
+}
+
+/// Kernel threads are kthreadd (PID 2) and its children
+pub fn is_kernel_thread(process: &ProcessInfo) -> bool {
+    process.pid == 2 || process.parent_pid == Some(2)
+}
+
+/// Compare two processes by a sort mode in ascending order
+pub fn compare_processes(mode: &str, a: &ProcessInfo, b: &ProcessInfo) -> std::cmp::Ordering {
+    match mode {
+        "pid" => a.pid.cmp(&b.pid),
+        "mem" => a.memory_usage.cmp(&b.memory_usage),
+        "ppid" => a.parent_pid.unwrap_or(0).cmp(&b.parent_pid.unwrap_or(0)),
+        "start" => a.start_timestamp.cmp(&b.start_timestamp), // The displayed HH:MM:SS wraps at midnight
+        "nice" => a.nice.cmp(&b.nice),
+        "cpu" => a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal),
+        "time" => a.cpu_time_secs.partial_cmp(&b.cpu_time_secs).unwrap_or(std::cmp::Ordering::Equal),
+        "threads" => a.thread_count.cmp(&b.thread_count),
+        "io" => (a.io_read_rate + a.io_write_rate)
+            .partial_cmp(&(b.io_read_rate + b.io_write_rate))
+            .unwrap_or(std::cmp::Ordering::Equal),
+        "name" => a.name.cmp(&b.name),
+        "user" => {
+            let a_user = a.user.as_deref().unwrap_or("");
+            let b_user = b.user.as_deref().unwrap_or("");
+            a_user.cmp(b_user)
+        }
+        "status" => a.status.cmp(&b.status),
+        _ => std::cmp::Ordering::Equal,
+    }
+}
+
+// Directory for output logs of launched processes
+fn default_log_dir() -> std::path::PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("lpm")
+        .join("logs")
+}
+
+// Map a /proc/<pid>/stat state character to a readable status
+#[cfg(target_os = "linux")]
+fn state_to_status(state: char) -> String {
+    match state {
+        'R' => "Running".to_string(),
+        'S' => "Sleeping".to_string(),
+        'D' => "Disk Sleep".to_string(),
+        'Z' => "Zombie".to_string(),
+        'T' => "Stopped".to_string(),
+        't' => "Tracing Stop".to_string(),
+        'X' | 'x' => "Dead".to_string(),
+        'K' => "Wakekill".to_string(),
+        'W' => "Waking".to_string(),
+        'P' => "Parked".to_string(),
+        'I' => "Idle".to_string(),
+        _ => format!("Unknown({})", state),
+    }
+}
+
+// Helper function to read the boot time (seconds since epoch) from <proc_root>/stat
+#[cfg(target_os = "linux")]
+fn read_boot_time(proc_root: &Path) -> u64 {
+    std::fs::read_to_string(proc_root.join("stat"))
+        .ok()
+        .and_then(|stat| {
+            stat.lines()
+                .find(|line| line.starts_with("btime"))
+                .and_then(|line| line.split_whitespace().nth(1))
+                .and_then(|value| value.parse().ok())
+        })
+        .unwrap_or(0)
+}
+
+// Whether a per-PID read failed because the process exited between listing and reading
+#[cfg(target_os = "linux")]
+fn process_vanished(err: &procfs::ProcError) -> bool {
+    match err {
+        // An exiting process can leave a truncated stat behind
+        procfs::ProcError::NotFound(_) | procfs::ProcError::Incomplete(_) => true,
+        procfs::ProcError::Io(e, _) => matches!(e.raw_os_error(), Some(libc::ENOENT) | Some(libc::ESRCH)),
+        _ => false,
+    }
+}
+
+// Append a diagnostic line to ~/.lpm/debug.log when LPM_DEBUG is set (the TUI owns stderr)
+#[cfg(target_os = "linux")]
+fn debug_log(message: &str) {
+    if std::env::var_os("LPM_DEBUG").is_some() {
+        crate::settings::append_log("debug.log", message);
+    }
+}
+
+// Helper function to read VmHWM (peak RSS, in bytes), the capability sets, TracerPid and the
+// thread count from <proc_root>/<pid>/status in one read (Linux only)
+#[cfg(target_os = "linux")]
+fn read_status_fields(proc_root: &Path, pid: u32) -> (Option<u64>, Option<crate::capabilities::Capabilities>, Option<u32>, u32) {
+    let Ok(status) = std::fs::read_to_string(proc_root.join(pid.to_string()).join("status")) else {
+        return (None, None, None, 0);
+    };
+    let field = |name: &str| status.lines()
+        .find_map(|line| line.strip_prefix(name))
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|value| value.parse::<u64>().ok());
+    let vm_hwm = field("VmHWM:").map(|kb| kb * 1024);
+    // TracerPid is 0 when nothing is attached
+    let tracer_pid = field("TracerPid:").filter(|&tracer| tracer != 0).map(|tracer| tracer as u32);
+    let thread_count = field("Threads:").unwrap_or(0) as u32;
+    (vm_hwm, crate::capabilities::parse_status(&status), tracer_pid, thread_count)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_status_fields(_proc_root: &Path, _pid: u32) -> (Option<u64>, Option<crate::capabilities::Capabilities>, Option<u32>, u32) {
+    (None, None, None, 0) // Not supported on non-Linux systems
 }
-// Helper function to read cgroup from /proc/<pid>/cgroup (Linux only)
+
+// The fields of <proc_root>/<pid>/stat a refresh needs, so the file is read and parsed once per process
+#[derive(Default)]
+struct StatFields {
+    nice: i32,
+    status: Option<String>, // None: fall back to sysinfo's state
+    tty_nr: i32,
+    sched_policy: Option<u32>,
+    cpu_time_secs: f64, // utime + stime
+}
+
+// Helper function to read the stat fields of a process (Linux only)
 #[cfg(target_os = "linux")]
-fn get_cgroup(pid: u32) -> Option<String> {
-    let cgroup_path = format!("/proc/{}/cgroup", pid);
-    if let Ok(content) = std::fs::read_to_string(&cgroup_path) {
-        // Parse cgroup file format: hierarchy:controller:path
-        // We're interested in the path part
-        for line in content.lines() {
-            let parts: Vec<&str> = line.split(':').collect();
-            if parts.len() >= 3 {
-                let path = parts[2].trim();
-                if !path.is_empty() && path != "/" {
-                    return Some(path.to_string());
+fn read_stat_fields(proc_root: &Path, pid: u32) -> Result<StatFields, procfs::ProcError> {
+    let stat = ProcfsProcess::new_with_root(proc_root.join(pid.to_string())).and_then(|p| p.stat())?;
+    Ok(StatFields {
+        nice: stat.nice as i32,
+        status: Some(state_to_status(stat.state)),
+        tty_nr: stat.tty_nr,
+        sched_policy: stat.policy,
+        cpu_time_secs: (stat.utime + stat.stime) as f64 / procfs::ticks_per_second().max(1) as f64,
+    })
+}
+
+/// How much high CPU use in a scheduling class should worry the user
+#[derive(Clone, Copy, PartialEq)]
+pub enum SchedClass {
+    Normal,     // SCHED_OTHER, or unknown
+    Background, // SCHED_BATCH / SCHED_IDLE: soaking up spare CPU is their job
+    Realtime,   // SCHED_FIFO / SCHED_RR / SCHED_DEADLINE: can starve everything else
+}
+
+pub fn sched_class(policy: Option<u32>) -> SchedClass {
+    match policy {
+        Some(1) | Some(2) | Some(6) => SchedClass::Realtime,
+        Some(3) | Some(5) => SchedClass::Background,
+        _ => SchedClass::Normal,
+    }
+}
+
+// ioprio_get/ioprio_set arguments (linux/ioprio.h)
+const IOPRIO_WHO_PROCESS: libc::c_long = 1;
+const IOPRIO_CLASS_SHIFT: u32 = 13;
+
+/// I/O scheduling class, as set by ionice
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IoClass {
+    None,       // Never set: best-effort with a priority derived from the nice value
+    Realtime,   // Always served first; can starve other processes' I/O
+    BestEffort,
+    Idle,       // Only served when no one else wants the disk
+}
+
+impl IoClass {
+    pub fn label(&self) -> &'static str {
+        match self {
+            IoClass::None => "none",
+            IoClass::Realtime => "realtime",
+            IoClass::BestEffort => "best-effort",
+            IoClass::Idle => "idle",
+        }
+    }
+}
+
+/// Format an I/O class and priority like ionice prints them: "best-effort: prio 4", "idle"
+pub fn format_ionice(class: IoClass, priority: u8) -> String {
+    match class {
+        IoClass::Realtime | IoClass::BestEffort => format!("{}: prio {}", class.label(), priority),
+        IoClass::None | IoClass::Idle => class.label().to_string(),
+    }
+}
+
+/// Parse "idle", "be 4", "best-effort 7", "rt 0" or "realtime 2" (priority defaults to 4)
+pub fn parse_ionice(input: &str) -> Result<(IoClass, u8), String> {
+    let mut parts = input.split_whitespace();
+    let class = match parts.next().map(str::to_lowercase).as_deref() {
+        Some("idle" | "3") => IoClass::Idle,
+        Some("be" | "best-effort" | "2") => IoClass::BestEffort,
+        Some("rt" | "realtime" | "1") => IoClass::Realtime,
+        Some(other) => return Err(format!("Unknown I/O class '{}' - use idle, be or rt", other)),
+        None => return Err("No I/O class given".to_string()),
+    };
+    let priority = match parts.next() {
+        Some(p) => p.parse::<u8>().ok().filter(|p| *p <= 7).ok_or_else(|| format!("Invalid I/O priority '{}' - must be 0-7", p))?,
+        None => 4,
+    };
+    Ok((class, priority))
+}
+
+/// Parse a CPU list like taskset's: "0-3,6" -> [0, 1, 2, 3, 6]
+pub fn parse_cpu_list(list: &str) -> Result<Vec<usize>, String> {
+    let mut cpus = Vec::new();
+    for part in list.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+        let parse = |s: &str| s.trim().parse::<usize>().map_err(|_| format!("Invalid CPU '{}'", s.trim()));
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse(first)?, parse(last)?);
+                if first > last {
+                    return Err(format!("Invalid CPU range '{}'", part));
                 }
+                cpus.extend(first..=last);
             }
+            None => cpus.push(parse(part)?),
         }
     }
-    None
+    if cpus.is_empty() {
+        return Err("No CPUs given".to_string());
+    }
+    cpus.sort_unstable();
+    cpus.dedup();
+    Ok(cpus)
+}
+
+/// Collapse a sorted CPU list back into taskset form: [0, 1, 2, 3, 6] -> "0-3,6"
+pub fn format_cpu_list(cpus: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &cpu in cpus {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == cpu => *last = cpu,
+            _ => ranges.push((cpu, cpu)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|&(first, last)| if first == last { first.to_string() } else { format!("{}-{}", first, last) })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Online CPUs from /sys/devices/system/cpu/online, which may have holes (e.g. "0-3,6");
+/// falls back to 0..N from sysconf when that file is unreadable
+pub fn online_cpus() -> Vec<usize> {
+    std::fs::read_to_string("/sys/devices/system/cpu/online")
+        .ok()
+        .and_then(|list| parse_cpu_list(&list).ok())
+        .unwrap_or_else(|| {
+            // SAFETY: sysconf has no preconditions
+            let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
+            (0..count.max(1) as usize).collect()
+        })
+}
+
+/// Format cumulative CPU seconds like top's TIME+ (minutes:seconds.hundredths)
+pub fn format_cpu_time(secs: f64) -> String {
+    let hundredths = (secs * 100.0).round() as u64;
+    format!("{}:{:02}.{:02}", hundredths / 6000, (hundredths / 100) % 60, hundredths % 100)
+}
+
+// Resolve a tty_nr device number to a device name such as "pts/3" or "tty1"
+fn tty_name(tty_nr: i32) -> String {
+    if tty_nr == 0 {
+        return "?".to_string();
+    }
+    let tty_nr = tty_nr as u32;
+    let major = (tty_nr >> 8) & 0xfff;
+    let minor = (tty_nr & 0xff) | ((tty_nr >> 12) & 0xfff00);
+    match major {
+        // Unix98 pseudo-terminals span majors 136-143, 256 minors each
+        136..=143 => format!("pts/{}", (major - 136) * 256 + minor),
+        4 if minor < 64 => format!("tty{}", minor),
+        4 => format!("ttyS{}", minor - 64),
+        5 if minor == 0 => "tty".to_string(),
+        5 if minor == 1 => "console".to_string(),
+        5 if minor == 2 => "ptmx".to_string(),
+        188 => format!("ttyUSB{}", minor),
+        204 if minor >= 64 => format!("ttyAMA{}", minor - 64),
+        _ => format!("{}:{}", major, minor),
+    }
+}
+
+// Helper function to read cgroup from <proc_root>/<pid>/cgroup (Linux only)
+#[cfg(target_os = "linux")]
+fn get_cgroup(proc_root: &Path, pid: u32) -> Option<String> {
+    let cgroup_path = proc_root.join(pid.to_string()).join("cgroup");
+    let content = std::fs::read_to_string(&cgroup_path).ok()?;
+    crate::process_group::parse_cgroup_file(&content, crate::process_group::host_cgroup_version())
 }
 
 #[cfg(not(target_os = "linux"))]
-fn get_cgroup(_pid: u32) -> Option<String> {
+fn get_cgroup(_proc_root: &Path, _pid: u32) -> Option<String> {
     None // Not supported on non-Linux systems
 }
 
@@ -751,14 +2034,14 @@ fn get_container_id(_cgroup: &str) -> Option<String> {
     None // Not supported on non-Linux systems
 }
 
-/// Read the command line of a process from /proc/<pid>/cmdline
+/// Read the command line of a process from <proc_root>/<pid>/cmdline
 /// Returns (program, args) if successful, None otherwise
 #[cfg(target_os = "linux")]
-fn read_process_cmdline(pid: u32) -> Option<(String, Vec<String>)> {
+fn read_process_cmdline(proc_root: &Path, pid: u32) -> Option<(String, Vec<String>)> {
     use std::fs;
     use std::io::Read;
     
-    let cmdline_path = format!("/proc/{}/cmdline", pid);
+    let cmdline_path = proc_root.join(pid.to_string()).join("cmdline");
     
     // Try to read the cmdline file
     if let Ok(mut file) = fs::File::open(&cmdline_path) {
@@ -788,11 +2071,107 @@ fn read_process_cmdline(pid: u32) -> Option<(String, Vec<String>)> {
 /// Read the command line of a process (non-Linux fallback)
 /// On non-Linux systems, we can't easily read cmdline, so return None
 #[cfg(not(target_os = "linux"))]
-fn read_process_cmdline(_pid: u32) -> Option<(String, Vec<String>)> {
+fn read_process_cmdline(_proc_root: &Path, _pid: u32) -> Option<(String, Vec<String>)> {
     None // Not supported on non-Linux systems
 }
 
-// Helper function to read namespace IDs from /proc/<pid>/ns/* (Linux only)
+// Full command line as one string (arguments joined by spaces), empty when unreadable or a kernel thread
+fn read_cmdline_string(proc_root: &Path, pid: u32) -> String {
+    read_process_cmdline(proc_root, pid)
+        .map(|(program, args)| std::iter::once(program).chain(args).collect::<Vec<_>>().join(" "))
+        .unwrap_or_default()
+}
+
+/// Command line for display: kernel threads (empty cmdline) show their name in brackets like ps
+pub fn display_cmdline(process: &ProcessInfo) -> String {
+    if process.cmdline.is_empty() {
+        format!("[{}]", process.name)
+    } else {
+        process.cmdline.clone()
+    }
+}
+
+/// Regular files open for writing in <proc_root>/<pid>/fd that look like logs
+/// (named *.log or under a log directory)
+#[cfg(target_os = "linux")]
+fn find_open_log_files(proc_root: &Path, pid: u32) -> Vec<PathBuf> {
+    let proc_dir = proc_root.join(pid.to_string());
+    let entries = match std::fs::read_dir(proc_dir.join("fd")) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    let mut logs = Vec::new();
+    for entry in entries.flatten() {
+        let target = match std::fs::read_link(entry.path()) {
+            Ok(target) => target,
+            Err(_) => continue,
+        };
+        let path_str = target.to_string_lossy();
+        let looks_like_log = path_str.ends_with(".log")
+            || path_str.starts_with("/var/log/")
+            || path_str.contains("/log/")
+            || path_str.contains("/logs/");
+        if !looks_like_log || !target.is_file() || logs.contains(&target) {
+            continue;
+        }
+        // Access mode is in the low bits of the octal "flags:" line (O_WRONLY = 1, O_RDWR = 2)
+        let fd = entry.file_name().to_string_lossy().to_string();
+        let writable = std::fs::read_to_string(proc_dir.join("fdinfo").join(&fd))
+            .ok()
+            .and_then(|info| {
+                info.lines()
+                    .find_map(|line| line.strip_prefix("flags:"))
+                    .and_then(|flags| u32::from_str_radix(flags.trim(), 8).ok())
+            })
+            .is_some_and(|flags| flags & 0o3 != 0);
+        if writable {
+            logs.push(target);
+        }
+    }
+    logs
+}
+
+#[cfg(not(target_os = "linux"))]
+fn find_open_log_files(_proc_root: &Path, _pid: u32) -> Vec<PathBuf> {
+    Vec::new()
+}
+
+/// Read the working directory of a process from the <proc_root>/<pid>/cwd link
+#[cfg(target_os = "linux")]
+fn read_process_cwd(proc_root: &Path, pid: u32) -> Option<String> {
+    std::fs::read_link(proc_root.join(pid.to_string()).join("cwd"))
+        .ok()
+        .map(|path| path.to_string_lossy().to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_process_cwd(_proc_root: &Path, _pid: u32) -> Option<String> {
+    None
+}
+
+/// Read the environment of a process from <proc_root>/<pid>/environ (null-separated KEY=VALUE)
+#[cfg(target_os = "linux")]
+fn read_process_environ(proc_root: &Path, pid: u32) -> Vec<(String, String)> {
+    std::fs::read(proc_root.join(pid.to_string()).join("environ"))
+        .map(|contents| {
+            contents
+                .split(|&b| b == 0)
+                .filter_map(|entry| {
+                    let entry = String::from_utf8_lossy(entry);
+                    entry.split_once('=').map(|(k, v)| (k.to_string(), v.to_string()))
+                })
+                .filter(|(k, _)| !k.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_process_environ(_proc_root: &Path, _pid: u32) -> Vec<(String, String)> {
+    Vec::new()
+}
+
+// Helper function to read namespace IDs from <proc_root>/<pid>/ns/* (Linux only)
 // 
 // Returns a HashMap mapping namespace type names (e.g., "pid", "net", "mnt") to their inode IDs.
 // In Linux, every process should have namespace IDs for all namespace types.
@@ -801,9 +2180,9 @@ fn read_process_cmdline(_pid: u32) -> Option<(String, Vec<String>)> {
 // 2. Permission denied reading /proc/<pid>/ns/*
 // 3. The process is in a different mount namespace
 #[cfg(target_os = "linux")]
-fn get_namespace_ids(pid: u32) -> HashMap<String, u64> {
+fn get_namespace_ids(proc_root: &Path, pid: u32) -> HashMap<String, u64> {
     let mut namespace_ids = HashMap::new();
-    let ns_dir = format!("/proc/{}/ns", pid);
+    let ns_dir = proc_root.join(pid.to_string()).join("ns");
     
     // Try to read the namespace directory
     if let Ok(entries) = std::fs::read_dir(&ns_dir) {
@@ -839,7 +2218,7 @@ fn get_namespace_ids(pid: u32) -> HashMap<String, u64> {
 }
 
 #[cfg(not(target_os = "linux"))]
-fn get_namespace_ids(_pid: u32) -> HashMap<String, u64> {
+fn get_namespace_ids(_proc_root: &Path, _pid: u32) -> HashMap<String, u64> {
     HashMap::new() // Not supported on non-Linux systems
 }
 
@@ -852,3 +2231,193 @@ fn format_timestamp(timestamp: u64) -> String {
         _ => "00:00:00".to_string() // Fallback if conversion fails
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A manager whose process table is exactly `tree`: (pid, parent pid)
+    fn manager_with(tree: &[(u32, Option<u32>)]) -> ProcessManager {
+        let mut manager = ProcessManager::new();
+        manager.refresh();
+        let template = manager.processes.first().cloned().expect("at least one running process");
+        manager.processes = tree
+            .iter()
+            .map(|&(pid, parent_pid)| ProcessInfo { pid, parent_pid, ..template.clone() })
+            .collect();
+        manager
+    }
+
+    #[test]
+    fn descendants_covers_every_level_leaves_first() {
+        // 10 -> 11 -> 13 -> 14, 10 -> 12; 20 is unrelated
+        let manager = manager_with(&[
+            (10, Some(1)),
+            (11, Some(10)),
+            (12, Some(10)),
+            (13, Some(11)),
+            (14, Some(13)),
+            (20, Some(1)),
+        ]);
+        let descendants = manager.descendants(10);
+        let mut sorted = descendants.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![11, 12, 13, 14]);
+        let position = |pid| descendants.iter().position(|&p| p == pid).unwrap();
+        assert!(position(14) < position(13));
+        assert!(position(13) < position(11));
+        assert!(manager.descendants(14).is_empty());
+    }
+
+    #[test]
+    fn descendants_stops_on_a_ppid_cycle() {
+        let manager = manager_with(&[(30, Some(31)), (31, Some(30))]);
+        assert_eq!(manager.descendants(30), vec![31]);
+    }
+
+    #[test]
+    fn kill_tree_signals_a_real_multi_level_tree() {
+        // sh -> (sh -> sleep) + sleep
+        let mut child = std::process::Command::new("sh")
+            .args(["-c", "sh -c 'sleep 30 & wait' & sleep 30 & wait"])
+            .spawn()
+            .expect("spawn sh");
+        let root = child.id();
+        let mut manager = ProcessManager::new();
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        let mut tree = Vec::new();
+        while std::time::Instant::now() < deadline {
+            manager.refresh();
+            tree = manager.descendants(root);
+            if tree.len() >= 3 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        assert_eq!(tree.len(), 3, "expected an inner sh and two sleeps under {}", root);
+
+        let signaled = manager.kill_tree(root, libc::SIGKILL).expect("kill_tree");
+        assert_eq!(signaled.last(), Some(&root), "the root is signaled last");
+        // A descendant may only be missing if it had already exited on its own
+        for pid in &tree {
+            let exists = std::path::Path::new(&format!("/proc/{}", pid)).exists();
+            assert!(signaled.contains(pid) || !exists, "descendant {} was not signaled", pid);
+        }
+        let status = child.wait().expect("reap root");
+        assert!(!status.success());
+        // Grandchildren were re-parented or reaped; none may survive
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        for pid in tree {
+            let alive = std::fs::read_to_string(format!("/proc/{}/stat", pid))
+                .is_ok_and(|stat| !stat.contains(") Z "));
+            assert!(!alive, "descendant {} survived kill_tree", pid);
+        }
+    }
+
+    #[test]
+    fn collection_skips_a_process_whose_stat_vanished() {
+        // Fake proc root: 101 and 103 have a stat, 102 exited between listing and reading
+        let root = std::env::temp_dir().join(format!("lpm-proc-root-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let own_stat = std::fs::read_to_string("/proc/self/stat").expect("read own stat");
+        let (_, rest) = own_stat.split_once(' ').expect("pid field");
+        for pid in [101, 102, 103] {
+            let dir = root.join(pid.to_string());
+            std::fs::create_dir_all(&dir).unwrap();
+            if pid != 102 {
+                std::fs::write(dir.join("stat"), format!("{} {}", pid, rest)).unwrap();
+            }
+        }
+
+        let mut manager = ProcessManager::with_proc_root(root.clone());
+        let mut pids: Vec<u32> = manager.collect_proc_root_processes().iter().map(|p| p.pid).collect();
+        pids.sort_unstable();
+        let _ = std::fs::remove_dir_all(&root);
+        assert_eq!(pids, vec![101, 103]);
+    }
+
+    #[test]
+    fn missing_or_truncated_proc_files_count_as_vanished() {
+        assert!(process_vanished(&procfs::ProcError::NotFound(None)));
+        assert!(process_vanished(&procfs::ProcError::Incomplete(None)));
+        assert!(process_vanished(&procfs::ProcError::Io(std::io::Error::from_raw_os_error(libc::ESRCH), None)));
+        assert!(!process_vanished(&procfs::ProcError::PermissionDenied(None)));
+    }
+
+    #[test]
+    fn cpu_spike_resets_the_idle_timer() {
+        let pause = std::time::Duration::from_millis(30);
+        let mut manager = manager_with(&[(40, Some(1))]);
+        manager.set_idle_thresholds(vec![5.0]);
+        manager.processes[0].cpu_usage = 1.0;
+        manager.track_idle();
+        std::thread::sleep(pause);
+        manager.track_idle();
+        assert!(manager.idle_duration(40, 5.0) >= pause);
+        // Only configured thresholds are tracked
+        assert_eq!(manager.idle_duration(40, 10.0), std::time::Duration::ZERO);
+
+        // One sample at or above the threshold drops the timer...
+        manager.processes[0].cpu_usage = 5.0;
+        manager.track_idle();
+        assert_eq!(manager.idle_duration(40, 5.0), std::time::Duration::ZERO);
+
+        // ...so the next quiet sample starts a fresh idle period
+        manager.processes[0].cpu_usage = 1.0;
+        manager.track_idle();
+        assert!(manager.idle_duration(40, 5.0) < pause);
+
+        // A reused PID starts over as well
+        std::thread::sleep(pause);
+        manager.processes[0].start_timestamp += 1;
+        manager.track_idle();
+        assert!(manager.idle_duration(40, 5.0) < pause);
+    }
+
+    #[test]
+    fn affinity_is_checked_against_the_online_cpu_list() {
+        // parse_cpu_list reads the sysfs format, trailing newline included
+        assert_eq!(parse_cpu_list("0-3,6\n"), Ok(vec![0, 1, 2, 3, 6]));
+        let online = online_cpus();
+        assert!(!online.is_empty());
+        let manager = ProcessManager::new();
+        let offline = (0..).find(|cpu| !online.contains(cpu)).unwrap();
+        let err = manager.set_cpu_affinity(std::process::id(), &[offline]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    // Hand the manager a `true` child and wait until it has exited (not yet reaped); spawned
+    // directly so no launch log is written into the working tree or the home directory
+    fn finished_child(manager: &mut ProcessManager) -> u32 {
+        let child = std::process::Command::new("true").spawn().expect("spawn true");
+        let pid = child.id();
+        manager.spawned_children.push(child);
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while std::fs::read_to_string(format!("/proc/{}/stat", pid)).is_ok_and(|stat| !stat.contains(") Z "))
+            && std::time::Instant::now() < deadline
+        {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        pid
+    }
+
+    #[test]
+    fn child_exit_is_kept_while_the_child_is_listed() {
+        let mut manager = manager_with(&[]);
+        let pid = finished_child(&mut manager);
+        manager.processes = manager_with(&[(pid, Some(1))]).processes;
+        manager.collect();
+        assert!(manager.take_child_exit(pid).is_some());
+        assert!(manager.take_child_exit(pid).is_none(), "each exit is handed out once");
+    }
+
+    #[test]
+    fn child_exit_of_an_unlisted_child_is_dropped() {
+        // Reaped before any listing showed it, so no vanished row would ever take the exit
+        let mut manager = manager_with(&[]);
+        let pid = finished_child(&mut manager);
+        manager.collect();
+        assert!(manager.spawned_children.is_empty());
+        assert!(manager.take_child_exit(pid).is_none());
+    }
+}
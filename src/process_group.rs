@@ -2,6 +2,111 @@
 
 use crate::process::ProcessInfo;
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// Layout of the cgroup hierarchy mounted on this host
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CgroupVersion {
+    V1,     // Per-controller hierarchies only
+    V2,     // Single unified hierarchy
+    Hybrid, // v1 controllers plus a unified hierarchy at /sys/fs/cgroup/unified
+}
+
+/// Detect the cgroup layout under the given cgroup mount point (normally /sys/fs/cgroup)
+pub fn detect_cgroup_version(cgroup_root: &Path) -> CgroupVersion {
+    if cgroup_root.join("cgroup.controllers").exists() {
+        CgroupVersion::V2
+    } else if cgroup_root.join("unified").join("cgroup.controllers").exists() {
+        CgroupVersion::Hybrid
+    } else {
+        CgroupVersion::V1
+    }
+}
+
+/// Detect the cgroup layout from a mount table (/proc/<pid>/mounts); None if no cgroup is mounted
+pub fn cgroup_version_from_mounts(mounts: &str) -> Option<CgroupVersion> {
+    let fs_types: Vec<&str> = mounts.lines().filter_map(|line| line.split_whitespace().nth(2)).collect();
+    let unified = fs_types.contains(&"cgroup2");
+    let controllers = fs_types.contains(&"cgroup");
+    match (controllers, unified) {
+        (false, true) => Some(CgroupVersion::V2),
+        (true, true) => Some(CgroupVersion::Hybrid),
+        (true, false) => Some(CgroupVersion::V1),
+        (false, false) => None,
+    }
+}
+
+/// Cgroup layout of the monitored host, detected once
+///
+/// Read through --proc-root's mount table, so an alternate /proc describes its own host;
+/// falls back to our /sys/fs/cgroup when that table is unavailable.
+pub fn host_cgroup_version() -> CgroupVersion {
+    static VERSION: OnceLock<CgroupVersion> = OnceLock::new();
+    *VERSION.get_or_init(|| {
+        std::fs::read_to_string(crate::process::default_proc_root().join("1").join("mounts"))
+            .ok()
+            .and_then(|mounts| cgroup_version_from_mounts(&mounts))
+            .unwrap_or_else(|| detect_cgroup_version(Path::new("/sys/fs/cgroup")))
+    })
+}
+
+/// Extract a group id from the contents of /proc/<pid>/cgroup
+///
+/// v2 has a single `0::/path` line; v1 has one `id:controllers:/path` line per hierarchy.
+/// On v1 the name=systemd hierarchy is preferred since its paths match the v2 layout,
+/// so the same process gets the same group id on either kind of host.
+/// Returns None for processes in the root cgroup.
+pub fn parse_cgroup_file(content: &str, version: CgroupVersion) -> Option<String> {
+    let entries: Vec<(&str, &str)> = content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, ':');
+            let _hierarchy = parts.next()?;
+            let controllers = parts.next()?;
+            let path = parts.next()?;
+            Some((controllers, path.trim()))
+        })
+        .collect();
+
+    let unified = entries.iter().find(|(controllers, _)| controllers.is_empty()).map(|(_, path)| *path);
+    let systemd = entries.iter().find(|(controllers, _)| *controllers == "name=systemd").map(|(_, path)| *path);
+    let any_controller = entries
+        .iter()
+        .filter(|(controllers, _)| !controllers.is_empty())
+        .map(|(_, path)| *path)
+        .find(|path| normalize_cgroup_path(path).is_some());
+
+    let path = match version {
+        CgroupVersion::V2 => unified,
+        CgroupVersion::Hybrid => systemd.or(unified).or(any_controller),
+        CgroupVersion::V1 => systemd.or(any_controller),
+    }?;
+    normalize_cgroup_path(path)
+}
+
+// Strip trailing slashes; the root cgroup has no group id
+fn normalize_cgroup_path(path: &str) -> Option<String> {
+    let path = path.trim().trim_end_matches('/');
+    if path.is_empty() {
+        None
+    } else {
+        Some(path.to_string())
+    }
+}
+
+/// Systemd unit owning a cgroup path: the innermost `.service`/`.scope` component
+///
+/// e.g. `/user.slice/user-1000.slice/user@1000.service/app.slice/foo.service` -> `foo.service`.
+/// Returns None on hosts without systemd (SysV, runit, ...), whose cgroup paths have no unit names.
+pub fn systemd_unit_from_cgroup(cgroup: &str) -> Option<String> {
+    cgroup
+        .split('/')
+        .rev()
+        .find(|component| component.ends_with(".service") || component.ends_with(".scope"))
+        .map(|unit| unit.to_string())
+}
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum GroupType {
@@ -9,6 +114,8 @@ pub enum GroupType {
     Container,
     Namespace(String), // namespace type (e.g., "pid", "net", "mnt")
     Username, // Group by actual username (e.g., "mohab", "root")
+    Tty, // Group by controlling terminal (e.g., "pts/3"), "?" for daemons
+    SystemdUnit, // Group by systemd unit (e.g., "nginx.service"), as systemctl sees them
 }
 
 #[derive(Clone)]
@@ -18,6 +125,7 @@ pub struct ProcessGroup {
     pub processes: Vec<ProcessInfo>,
     pub total_cpu: f32,
     pub total_memory: u64,
+    pub source: UsageSource, // How total_cpu/total_memory were obtained
 }
 
 impl ProcessGroup {
@@ -28,6 +136,25 @@ impl ProcessGroup {
             processes: Vec::new(),
             total_cpu: 0.0,
             total_memory: 0,
+            source: UsageSource::Summed,
+        }
+    }
+
+    /// Replace the summed totals with the cgroup's own accounting, when the group maps to one
+    /// cgroup (cgroup, container and systemd unit groups) and its files can be read
+    pub fn use_cgroup_accounting(&mut self, accounting: &mut CgroupAccounting, cpu_divisor: f32) {
+        let cgroup = match self.group_type {
+            GroupType::Cgroup => Some(self.group_id.clone()).filter(|id| id.starts_with('/')), // Not "No cgroup"
+            GroupType::Container => common_cgroup(&self.processes),
+            GroupType::SystemdUnit => self.processes.first()
+                .and_then(|p| p.cgroup.as_deref())
+                .and_then(|cgroup| unit_cgroup(cgroup, &self.group_id)),
+            _ => None,
+        };
+        if let Some((cpu, memory)) = cgroup.and_then(|cgroup| accounting.usage(&cgroup, cpu_divisor)) {
+            self.total_cpu = cpu;
+            self.total_memory = memory;
+            self.source = UsageSource::Cgroup;
         }
     }
 
@@ -42,6 +169,122 @@ impl ProcessGroup {
     }
 }
 
+/// Where a group's CPU/memory totals came from
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum UsageSource {
+    Summed, // Sum over the member processes (misses exited children, double-counts shared memory)
+    Cgroup, // The cgroup's own accounting: cpu.stat usage_usec and memory.current
+}
+
+impl UsageSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            UsageSource::Summed => "summed over processes",
+            UsageSource::Cgroup => "cgroup accounting",
+        }
+    }
+}
+
+/// Cumulative CPU time (µs, from cpu.stat) and current memory (bytes) of a cgroup
+///
+/// Only the unified hierarchy has these files, so v1 hosts always return None.
+pub fn read_cgroup_usage(cgroup_root: &Path, version: CgroupVersion, cgroup: &str) -> Option<(u64, u64)> {
+    let unified = match version {
+        CgroupVersion::V2 => cgroup_root.to_path_buf(),
+        CgroupVersion::Hybrid => cgroup_root.join("unified"),
+        CgroupVersion::V1 => return None,
+    };
+    let dir = unified.join(cgroup.trim_start_matches('/'));
+    let usage_usec = std::fs::read_to_string(dir.join("cpu.stat"))
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("usage_usec "))?
+        .trim()
+        .parse()
+        .ok()?;
+    let memory = std::fs::read_to_string(dir.join("memory.current")).ok()?.trim().parse().ok()?;
+    Some((usage_usec, memory))
+}
+
+/// Deepest cgroup containing every one of the processes; None if one has no cgroup or only the root is shared
+pub fn common_cgroup(processes: &[ProcessInfo]) -> Option<String> {
+    let mut cgroups = processes.iter().map(|p| p.cgroup.as_deref());
+    let mut common: Vec<&str> = cgroups.next()??.split('/').collect();
+    for cgroup in cgroups {
+        let components: Vec<&str> = cgroup?.split('/').collect();
+        let shared = common.iter().zip(&components).take_while(|(a, b)| a == b).count();
+        common.truncate(shared);
+    }
+    normalize_cgroup_path(&common.join("/"))
+}
+
+// The cgroup of a systemd unit: a member's path cut after the (innermost) unit component
+fn unit_cgroup(cgroup: &str, unit: &str) -> Option<String> {
+    let components: Vec<&str> = cgroup.split('/').collect();
+    let end = components.iter().rposition(|component| *component == unit)?;
+    Some(components[..=end].join("/"))
+}
+
+// Readings closer together than this reuse the previous CPU rate
+const CGROUP_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+// Samples not read for this long belong to cgroups that went away (or views no longer shown)
+const CGROUP_SAMPLE_EXPIRY: Duration = Duration::from_secs(60);
+
+struct CgroupSample {
+    usage_usec: u64,
+    at: Instant,
+    cpu_percent: Option<f32>, // None until a second reading
+    memory: u64,
+}
+
+/// Turns successive cgroup readings into CPU rates, diffing cpu.stat between refreshes
+#[derive(Default)]
+pub struct CgroupAccounting {
+    samples: HashMap<String, CgroupSample>,
+}
+
+impl CgroupAccounting {
+    /// CPU% (per-core percent divided by `cpu_divisor`, like process CPU%) and memory of a cgroup;
+    /// None when its files cannot be read, or until two readings give a CPU rate
+    pub fn usage(&mut self, cgroup: &str, cpu_divisor: f32) -> Option<(f32, u64)> {
+        let now = Instant::now();
+        if let Some(sample) = self.samples.get(cgroup)
+            && now.duration_since(sample.at) < CGROUP_SAMPLE_INTERVAL
+        {
+            return sample.cpu_percent.map(|cpu| (cpu / cpu_divisor, sample.memory));
+        }
+        self.samples.retain(|_, sample| now.duration_since(sample.at) < CGROUP_SAMPLE_EXPIRY);
+        let Some((usage_usec, memory)) = read_cgroup_usage(Path::new("/sys/fs/cgroup"), host_cgroup_version(), cgroup) else {
+            self.samples.remove(cgroup);
+            return None;
+        };
+        let cpu_percent = self.samples.get(cgroup).map(|previous| {
+            let elapsed_usec = now.duration_since(previous.at).as_micros().max(1) as f64;
+            (usage_usec.saturating_sub(previous.usage_usec) as f64 / elapsed_usec * 100.0) as f32
+        });
+        self.samples.insert(cgroup.to_string(), CgroupSample { usage_usec, at: now, cpu_percent, memory });
+        cpu_percent.map(|cpu| (cpu / cpu_divisor, memory))
+    }
+}
+
+/// Column the by-user summary is ordered by
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum UserSortKey {
+    Cpu,
+    Memory,
+    Count,
+}
+
+impl UserSortKey {
+    pub fn label(&self) -> &'static str {
+        match self {
+            UserSortKey::Cpu => "CPU",
+            UserSortKey::Memory => "memory",
+            UserSortKey::Count => "process count",
+        }
+    }
+}
+
 pub struct ProcessGroupManager;
 
 impl ProcessGroupManager {
@@ -143,5 +386,143 @@ impl ProcessGroupManager {
 
         groups.into_values().collect()
     }
+
+    /// Per-user totals (the username groups), ordered by `key` descending; ties go by name
+    pub fn users_by(processes: &[ProcessInfo], key: UserSortKey) -> Vec<ProcessGroup> {
+        let mut users = Self::group_by_username(processes);
+        users.sort_by(|a, b| {
+            let order = match key {
+                UserSortKey::Cpu => b.total_cpu.partial_cmp(&a.total_cpu).unwrap_or(std::cmp::Ordering::Equal),
+                UserSortKey::Memory => b.total_memory.cmp(&a.total_memory),
+                UserSortKey::Count => b.process_count().cmp(&a.process_count()),
+            };
+            order.then_with(|| a.group_id.cmp(&b.group_id))
+        });
+        users
+    }
+
+    /// Group processes by controlling terminal; processes without one share the "?" group
+    pub fn group_by_tty(processes: &[ProcessInfo]) -> Vec<ProcessGroup> {
+        let mut groups: HashMap<String, ProcessGroup> = HashMap::new();
+
+        for process in processes {
+            let tty = process.tty.clone();
+            let group = groups.entry(tty.clone())
+                .or_insert_with(|| ProcessGroup::new(GroupType::Tty, tty));
+            group.add_process(process.clone());
+        }
+
+        groups.into_values().collect()
+    }
+
+    /// Group processes by systemd unit; processes outside any unit share the "No unit" group
+    pub fn group_by_systemd_unit(processes: &[ProcessInfo]) -> Vec<ProcessGroup> {
+        let mut groups: HashMap<String, ProcessGroup> = HashMap::new();
+
+        for process in processes {
+            let unit = process.unit.clone().unwrap_or_else(|| "No unit".to_string());
+            let group = groups.entry(unit.clone())
+                .or_insert_with(|| ProcessGroup::new(GroupType::SystemdUnit, unit));
+            group.add_process(process.clone());
+        }
+
+        groups.into_values().collect()
+    }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const V1_CGROUP: &str = "12:memory:/user.slice/user-1000.slice/session-2.scope
+11:cpu,cpuacct:/user.slice
+1:name=systemd:/user.slice/user-1000.slice/session-2.scope
+";
+    const V2_CGROUP: &str = "0::/system.slice/nginx.service\n";
+    const HYBRID_CGROUP: &str = "5:memory:/system.slice/nginx.service
+1:name=systemd:/system.slice/nginx.service
+0::/system.slice/nginx.service
+";
+
+    // Fresh, empty directory standing in for a cgroup mount point
+    fn cgroup_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("lpm-cgroup-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn detects_each_cgroup_layout() {
+        let v1 = cgroup_root("v1");
+        std::fs::create_dir_all(v1.join("memory")).unwrap();
+        assert_eq!(detect_cgroup_version(&v1), CgroupVersion::V1);
+
+        let v2 = cgroup_root("v2");
+        std::fs::write(v2.join("cgroup.controllers"), "cpu memory\n").unwrap();
+        assert_eq!(detect_cgroup_version(&v2), CgroupVersion::V2);
+
+        let hybrid = cgroup_root("hybrid");
+        std::fs::create_dir_all(hybrid.join("unified")).unwrap();
+        std::fs::write(hybrid.join("unified").join("cgroup.controllers"), "").unwrap();
+        assert_eq!(detect_cgroup_version(&hybrid), CgroupVersion::Hybrid);
+
+        for root in [v1, v2, hybrid] {
+            let _ = std::fs::remove_dir_all(root);
+        }
+    }
+
+    #[test]
+    fn evicts_samples_of_cgroups_no_longer_read() {
+        let Some(long_ago) = Instant::now().checked_sub(CGROUP_SAMPLE_EXPIRY * 2) else {
+            return;
+        };
+        let mut accounting = CgroupAccounting::default();
+        let stale = CgroupSample { usage_usec: 0, at: long_ago, cpu_percent: Some(1.0), memory: 0 };
+        accounting.samples.insert("/lpm-test/gone.scope".to_string(), stale);
+        assert_eq!(accounting.usage("/lpm-test/also-gone.scope", 1.0), None);
+        assert!(accounting.samples.is_empty());
+    }
+
+    #[test]
+    fn detects_cgroup_layout_from_mounts() {
+        let v1 = "cgroup /sys/fs/cgroup/memory cgroup rw,memory 0 0\n";
+        let v2 = "cgroup2 /sys/fs/cgroup cgroup2 rw,nsdelegate 0 0\n";
+        let hybrid = "cgroup2 /sys/fs/cgroup/unified cgroup2 rw 0 0\ncgroup /sys/fs/cgroup/memory cgroup rw,memory 0 0\n";
+        assert_eq!(cgroup_version_from_mounts(v1), Some(CgroupVersion::V1));
+        assert_eq!(cgroup_version_from_mounts(v2), Some(CgroupVersion::V2));
+        assert_eq!(cgroup_version_from_mounts(hybrid), Some(CgroupVersion::Hybrid));
+        assert_eq!(cgroup_version_from_mounts("proc /proc proc rw 0 0\n"), None);
+    }
+
+    #[test]
+    fn parses_v1_cgroup_file_preferring_systemd() {
+        assert_eq!(
+            parse_cgroup_file(V1_CGROUP, CgroupVersion::V1).as_deref(),
+            Some("/user.slice/user-1000.slice/session-2.scope")
+        );
+        // Without name=systemd any controller path is used
+        assert_eq!(parse_cgroup_file("11:cpu,cpuacct:/user.slice/\n", CgroupVersion::V1).as_deref(), Some("/user.slice"));
+    }
+
+    #[test]
+    fn parses_v2_cgroup_file() {
+        assert_eq!(parse_cgroup_file(V2_CGROUP, CgroupVersion::V2).as_deref(), Some("/system.slice/nginx.service"));
+        assert_eq!(parse_cgroup_file("0::/\n", CgroupVersion::V2), None);
+    }
+
+    #[test]
+    fn parses_hybrid_cgroup_file_to_the_same_group_as_v2() {
+        assert_eq!(
+            parse_cgroup_file(HYBRID_CGROUP, CgroupVersion::Hybrid),
+            parse_cgroup_file(V2_CGROUP, CgroupVersion::V2)
+        );
+        // Hybrid hosts without name=systemd fall back to the unified path
+        assert_eq!(
+            parse_cgroup_file("5:memory:/\n0::/init.scope\n", CgroupVersion::Hybrid).as_deref(),
+            Some("/init.scope")
+        );
+    }
+}
@@ -3,16 +3,91 @@
 
 use ratatui::{Frame, layout::Rect};
 use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+/// How a process ended: its exit code, or the signal that terminated it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExitReason {
+    Code(i32),
+    Signal(i32),
+}
 
 /// Struct to store exited process info for the log.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ProcessExitLogEntry {
     pub pid: u32,
+    #[serde(default)]
+    pub parent_pid: Option<u32>, // Parent at the last refresh before the exit
     pub name: String,
     pub user: Option<String>,
     pub start_time: String,
     pub exit_time: DateTime<Local>,
     pub uptime_secs: u64,
+    pub exit_reason: Option<ExitReason>, // None when lpm could not observe how the process ended
+}
+
+fn exit_log_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".lpm").join("exit_log.jsonl"))
+}
+
+/// Load the newest `limit` entries from ~/.lpm/exit_log.jsonl, oldest first; unreadable lines are skipped.
+pub fn load_exit_log(limit: usize) -> Vec<ProcessExitLogEntry> {
+    let Some(file) = exit_log_path().and_then(|path| std::fs::File::open(path).ok()) else {
+        return Vec::new();
+    };
+    let mut entries: Vec<ProcessExitLogEntry> = std::io::BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect();
+    let skip = entries.len().saturating_sub(limit);
+    entries.drain(..skip);
+    entries
+}
+
+/// Append one entry as a JSON line. Once the file has grown past `max_bytes` it is
+/// moved to exit_log.jsonl.1 (replacing the previous one) and a fresh file is started.
+pub fn append_exit_log(entry: &ProcessExitLogEntry, max_bytes: u64) {
+    let Some(path) = exit_log_path() else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if std::fs::metadata(&path).is_ok_and(|meta| meta.len() > max_bytes) {
+        let _ = std::fs::rename(&path, path.with_extension("jsonl.1"));
+    }
+    let Ok(line) = serde_json::to_string(entry) else {
+        return;
+    };
+    if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(&path) {
+        let _ = writeln!(file, "{}", line);
+    }
+}
+
+/// Bucket an exit falls into when the log is grouped by exit reason.
+pub fn exit_reason_bucket(reason: Option<ExitReason>) -> String {
+    match reason {
+        Some(ExitReason::Code(0)) => "Clean exit (0)".to_string(),
+        Some(ExitReason::Code(code)) => format!("Exit code {}", code),
+        Some(ExitReason::Signal(signal)) => match crate::signals::signal_name(signal) {
+            Some(name) => format!("Killed by {}", name),
+            None => format!("Killed by signal {}", signal),
+        },
+        None => "Unknown".to_string(),
+    }
+}
+
+/// Short form for the log table: "0", "1", "SIGSEGV", or "-" when unknown.
+fn exit_reason_text(reason: Option<ExitReason>) -> String {
+    match reason {
+        Some(ExitReason::Code(code)) => code.to_string(),
+        Some(ExitReason::Signal(signal)) => crate::signals::signal_name(signal)
+            .map_or_else(|| format!("signal {}", signal), str::to_string),
+        None => "-".to_string(),
+    }
 }
 
 /// Render the process log tab.
@@ -28,6 +103,7 @@ pub fn render_process_log_tab(frame: &mut Frame, area: Rect, log: &[ProcessExitL
         Cell::from("Start Time").style(Style::default().fg(Color::Black)),
         Cell::from("Exit Time").style(Style::default().fg(Color::Black)),
         Cell::from("Uptime").style(Style::default().fg(Color::Black)),
+        Cell::from("Exit").style(Style::default().fg(Color::Black)),
     ]);
     let rows: Vec<Row> = log.iter().rev().map(|entry| {
         Row::new(vec![
@@ -37,6 +113,7 @@ pub fn render_process_log_tab(frame: &mut Frame, area: Rect, log: &[ProcessExitL
             Cell::from(entry.start_time.clone()),
             Cell::from(entry.exit_time.format("%Y-%m-%d %H:%M:%S").to_string()),
             Cell::from(format!("{}s", entry.uptime_secs)),
+            Cell::from(exit_reason_text(entry.exit_reason)),
         ]).style(Style::default().fg(Color::Black))
     }).collect();
     let table = Table::new(rows)
@@ -49,6 +126,7 @@ pub fn render_process_log_tab(frame: &mut Frame, area: Rect, log: &[ProcessExitL
             Constraint::Length(19),
             Constraint::Length(19),
             Constraint::Length(8),
+            Constraint::Length(10),
         ]);
     frame.render_widget(table, area);
 } 
\ No newline at end of file
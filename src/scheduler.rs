@@ -29,6 +29,7 @@ pub enum ScheduleAction {
     StopProcess { pid: u32 },
     ContinueProcess { pid: u32 },
     ReniceProcess { pid: u32, nice: i32 },
+    SendSignal { pid: u32, signal: String }, // Signal name or number, e.g. "SIGHUP" or "1"
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -99,6 +100,29 @@ impl ScheduledTask {
     }
 }
 
+/// Outcome of importing a task file
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub imported: Vec<String>,
+    pub duplicates: Vec<String>, // Names that already exist (skipped)
+    pub rejected: Vec<(String, String)>, // (task name or "#index", reason)
+}
+
+impl ImportReport {
+    /// "Imported 3, skipped 1 duplicate, rejected 1: backup (bad cron field '61')"
+    pub fn summary(&self) -> String {
+        let mut summary = format!("Imported {}", self.imported.len());
+        if !self.duplicates.is_empty() {
+            summary.push_str(&format!(", skipped {} duplicate(s): {}", self.duplicates.len(), self.duplicates.join(", ")));
+        }
+        if !self.rejected.is_empty() {
+            let reasons: Vec<String> = self.rejected.iter().map(|(name, reason)| format!("{} ({})", name, reason)).collect();
+            summary.push_str(&format!(", rejected {}: {}", self.rejected.len(), reasons.join("; ")));
+        }
+        summary
+    }
+}
+
 pub struct Scheduler {
     tasks: Vec<ScheduledTask>,
     task_log: Vec<(String, SystemTime, String)>, // (task_name, time, result)
@@ -132,6 +156,18 @@ impl Scheduler {
         }
     }
 
+    /// Replace the task at `index`, keeping its enabled state and last run time
+    pub fn update_task(&mut self, index: usize, mut task: ScheduledTask) -> bool {
+        if let Some(existing) = self.tasks.get_mut(index) {
+            task.enabled = existing.enabled;
+            task.last_run = existing.last_run;
+            *existing = task;
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn toggle_task(&mut self, index: usize) -> bool {
         if let Some(task) = self.tasks.get_mut(index) {
             task.enabled = !task.enabled;
@@ -153,6 +189,44 @@ impl Scheduler {
         }
     }
 
+    /// Write every task (schedule, action, enabled) to a JSON file
+    pub fn export_tasks(&self, path: &std::path::Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.tasks)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, json)
+    }
+
+    /// Add the valid tasks from a JSON file exported by `export_tasks`; tasks whose name already
+    /// exists are skipped and broken ones (unknown action, bad cron, invalid parameters) rejected
+    pub fn import_tasks(&mut self, path: &std::path::Path) -> std::io::Result<ImportReport> {
+        let content = std::fs::read_to_string(path)?;
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&content)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, format!("not a task list: {}", e)))?;
+
+        let mut report = ImportReport::default();
+        for (index, entry) in entries.into_iter().enumerate() {
+            let label = entry.get("name").and_then(|n| n.as_str()).map_or_else(|| format!("#{}", index + 1), str::to_string);
+            let task = match serde_json::from_value::<ScheduledTask>(entry) {
+                Ok(task) => task,
+                Err(e) => {
+                    report.rejected.push((label, e.to_string()));
+                    continue;
+                }
+            };
+            if let Err(reason) = validate_task(&task) {
+                report.rejected.push((label, reason));
+                continue;
+            }
+            if self.tasks.iter().any(|t| t.name == task.name) {
+                report.duplicates.push(task.name);
+                continue;
+            }
+            report.imported.push(task.name.clone());
+            self.tasks.push(task);
+        }
+        Ok(report)
+    }
+
     /// Check which tasks should run now and return their indices
     pub fn check_due_tasks(&mut self) -> Vec<usize> {
         let now = SystemTime::now();
@@ -272,6 +346,64 @@ impl Scheduler {
     }
 }
 
+// Reject tasks that would fail or misbehave when run
+fn validate_task(task: &ScheduledTask) -> Result<(), String> {
+    if task.name.trim().is_empty() {
+        return Err("empty name".to_string());
+    }
+    match &task.schedule {
+        ScheduleType::Cron(expr) => validate_cron(expr)?,
+        ScheduleType::Interval(0) => return Err("interval must be at least 1s".to_string()),
+        ScheduleType::Interval(_) | ScheduleType::Once(_) => {}
+    }
+    match &task.action {
+        ScheduleAction::RestartProcess { pattern } if pattern.trim().is_empty() => Err("empty restart pattern".to_string()),
+        ScheduleAction::StartProcess { program, .. } if program.trim().is_empty() => Err("empty program".to_string()),
+        ScheduleAction::ApplyRule { rule } if rule.trim().is_empty() => Err("empty rule".to_string()),
+        ScheduleAction::CleanupIdle { action, .. } if !matches!(action.as_str(), "kill" | "stop" | "lower_priority") => {
+            Err(format!("unknown cleanup action '{}'", action))
+        }
+        ScheduleAction::ReniceProcess { nice, .. } if !(-20..=19).contains(nice) => Err(format!("nice {} out of range", nice)),
+        ScheduleAction::SendSignal { signal, .. } if crate::signals::parse_signal(signal).is_none() => {
+            Err(format!("unknown signal '{}'", signal))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check a 5-field cron expression (minute hour day month weekday); each field is "*",
+/// a number, a range "a-b", a list "a,b" or any of those with a "/step"
+pub fn validate_cron(expr: &str) -> Result<(), String> {
+    const FIELDS: [(&str, u32, u32); 5] = [("minute", 0, 59), ("hour", 0, 23), ("day", 1, 31), ("month", 1, 12), ("weekday", 0, 7)];
+    let parts: Vec<&str> = expr.split_whitespace().collect();
+    if parts.len() != FIELDS.len() {
+        return Err(format!("cron needs 5 fields, got {}", parts.len()));
+    }
+    for (part, (field, min, max)) in parts.iter().zip(FIELDS) {
+        for item in part.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, Some(step)),
+                None => (item, None),
+            };
+            if let Some(step) = step
+                && step.parse::<u32>().map_or(true, |s| s == 0)
+            {
+                return Err(format!("bad {} step '{}'", field, item));
+            }
+            if range == "*" {
+                continue;
+            }
+            let (start, end) = range.split_once('-').unwrap_or((range, range));
+            let in_bounds = |value: &str| value.parse::<u32>().ok().filter(|v| (min..=max).contains(v));
+            match (in_bounds(start), in_bounds(end)) {
+                (Some(start), Some(end)) if start <= end => {}
+                _ => return Err(format!("bad {} field '{}' ({}-{})", field, item, min, max)),
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Load scheduler tasks from config file
 pub fn load_tasks() -> Vec<ScheduledTask> {
     let config_path = std::path::Path::new(&std::env::var("HOME").unwrap_or_else(|_| ".".to_string()))
@@ -300,3 +432,62 @@ pub fn save_tasks(tasks: &[ScheduledTask]) -> std::io::Result<()> {
     std::fs::write(config_path, toml_string)
 }
 
+
+/// Parse a one-off run time: a unix timestamp, "in 5m" / "in 2h" (s, m, h, d),
+/// "today 18:00", "tomorrow 09:00", or "2024-06-01 14:30" (local time)
+pub fn parse_once_time(input: &str) -> Result<SystemTime, String> {
+    use chrono::{Duration as ChronoDuration, Local, NaiveDateTime, NaiveTime, TimeZone};
+
+    let input = input.trim().to_lowercase();
+    if let Ok(timestamp) = input.parse::<u64>() {
+        let time = UNIX_EPOCH
+            .checked_add(Duration::from_secs(timestamp))
+            .ok_or_else(|| format!("Timestamp {} is out of range", timestamp))?;
+        if time <= SystemTime::now() {
+            return Err(format!("Timestamp {} is in the past", timestamp));
+        }
+        return Ok(time);
+    }
+
+    if let Some(relative) = input.strip_prefix("in ") {
+        let relative = relative.replace(' ', "");
+        let split = relative.find(|c: char| !c.is_ascii_digit()).unwrap_or(relative.len());
+        let (amount, unit) = relative.split_at(split);
+        let amount: u64 = amount.parse().map_err(|_| format!("Invalid amount in '{}'", input))?;
+        let multiplier = match unit {
+            "s" | "sec" | "secs" => 1,
+            "m" | "min" | "mins" => 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+            "d" | "day" | "days" => 86400,
+            _ => return Err(format!("Unknown unit '{}' (use s, m, h or d)", unit)),
+        };
+        return amount
+            .checked_mul(multiplier)
+            .and_then(|seconds| SystemTime::now().checked_add(Duration::from_secs(seconds)))
+            .ok_or_else(|| format!("'{}' is too far in the future", input));
+    }
+
+    let now = Local::now();
+    let day_offset = if let Some(time) = input.strip_prefix("tomorrow") {
+        Some((1, time.trim().to_string()))
+    } else {
+        input.strip_prefix("today").map(|time| (0, time.trim().to_string()))
+    };
+    let naive = if let Some((days, time)) = day_offset {
+        let time = if time.is_empty() { "00:00".to_string() } else { time };
+        let time = NaiveTime::parse_from_str(&time, "%H:%M")
+            .map_err(|_| format!("Invalid time '{}' (use HH:MM)", time))?;
+        (now.date_naive() + ChronoDuration::days(days)).and_time(time)
+    } else {
+        NaiveDateTime::parse_from_str(&input, "%Y-%m-%d %H:%M")
+            .map_err(|_| "Use a timestamp, 'in 5m', 'tomorrow 09:00' or 'YYYY-MM-DD HH:MM'".to_string())?
+    };
+
+    let local = Local.from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| format!("'{}' does not exist in the local time zone", input))?;
+    if local < now {
+        return Err(format!("{} is in the past", local.format("%Y-%m-%d %H:%M")));
+    }
+    Ok(UNIX_EPOCH + Duration::from_secs(local.timestamp().max(0) as u64))
+}
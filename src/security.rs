@@ -0,0 +1,63 @@
+//! SELinux context / AppArmor profile of a process, from /proc/<pid>/attr
+//!
+//! The active MAC system is detected once; without one, labels are not read at all
+//! and the field stays hidden.
+
+use std::path::Path;
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MacSystem {
+    SELinux,
+    AppArmor,
+}
+
+impl MacSystem {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MacSystem::SELinux => "SELinux",
+            MacSystem::AppArmor => "AppArmor",
+        }
+    }
+}
+
+static MAC_SYSTEM: OnceLock<Option<MacSystem>> = OnceLock::new();
+
+/// The MAC system enforcing labels on this machine, if any (detected on first call)
+pub fn mac_system() -> Option<MacSystem> {
+    *MAC_SYSTEM.get_or_init(|| {
+        if Path::new("/sys/fs/selinux/enforce").exists() {
+            Some(MacSystem::SELinux)
+        } else if std::fs::read_to_string("/sys/module/apparmor/parameters/enabled").is_ok_and(|s| s.trim() == "Y") {
+            Some(MacSystem::AppArmor)
+        } else {
+            None
+        }
+    })
+}
+
+/// Security label of a process, None without a MAC system or when unreadable
+pub fn read_label(proc_root: &Path, pid: u32) -> Option<String> {
+    let attr = proc_root.join(pid.to_string()).join("attr");
+    // Kernels with stacked LSMs expose AppArmor's own file; attr/current belongs to the first LSM
+    let paths = match mac_system()? {
+        MacSystem::AppArmor => vec![attr.join("apparmor").join("current"), attr.join("current")],
+        MacSystem::SELinux => vec![attr.join("current")],
+    };
+    paths.iter().find_map(|path| {
+        let label = std::fs::read_to_string(path).ok()?;
+        let label = label.trim_end_matches(['\0', '\n']).trim();
+        (!label.is_empty()).then(|| label.to_string())
+    })
+}
+
+/// Whether a label means the process is not confined by policy
+pub fn is_unconfined(label: &str) -> bool {
+    // AppArmor: "unconfined"; SELinux: a type like unconfined_t in user:role:type:level
+    label == "unconfined" || label.split(':').nth(2).is_some_and(|ty| ty.starts_with("unconfined"))
+}
+
+/// AppArmor profiles carry their mode in parentheses: "/usr/sbin/cupsd (enforce)"
+pub fn apparmor_mode(label: &str) -> Option<&str> {
+    label.rsplit_once(" (").and_then(|(_, mode)| mode.strip_suffix(')'))
+}
@@ -0,0 +1,61 @@
+//! lpm's own resource usage, read from /proc/self
+//!
+//! Shown in the Help view so users can check the monitor is not itself the
+//! resource hog.
+
+use procfs::process::Process;
+use std::time::Instant;
+
+#[derive(Clone, Debug, Default)]
+pub struct SelfStats {
+    pub cpu_percent: f32, // Relative to one core, like top
+    pub rss_bytes: u64,
+    pub threads: i64,
+    pub open_fds: usize,
+}
+
+/// Samples /proc/self on each refresh; CPU% needs two samples
+#[derive(Default)]
+pub struct SelfMonitor {
+    stats: Option<SelfStats>,
+    last_sample: Option<(u64, Instant)>, // (utime + stime ticks, when)
+}
+
+impl SelfMonitor {
+    pub fn update(&mut self) {
+        let Ok(me) = Process::myself() else {
+            self.stats = None;
+            return;
+        };
+        let Ok(stat) = me.stat() else {
+            self.stats = None;
+            return;
+        };
+        let now = Instant::now();
+        let ticks = stat.utime + stat.stime;
+        let cpu_percent = match self.last_sample {
+            Some((prev_ticks, prev_time)) => {
+                let elapsed = now.duration_since(prev_time).as_secs_f64();
+                if elapsed > 0.0 {
+                    let ticks_per_second = procfs::ticks_per_second().max(1) as f64;
+                    (ticks.saturating_sub(prev_ticks) as f64 / ticks_per_second / elapsed * 100.0) as f32
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+        self.last_sample = Some((ticks, now));
+        self.stats = Some(SelfStats {
+            cpu_percent,
+            rss_bytes: stat.rss * procfs::page_size(),
+            threads: stat.num_threads,
+            open_fds: me.fd_count().unwrap_or(0),
+        });
+    }
+
+    /// Latest sample, or None when /proc/self could not be read
+    pub fn stats(&self) -> Option<&SelfStats> {
+        self.stats.as_ref()
+    }
+}
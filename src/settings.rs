@@ -0,0 +1,158 @@
+//! Persistent user settings (stored in ~/.lpm/settings.toml)
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Operator note on one process instance; matched by name and start time, so it does not
+/// carry over to a restarted process
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessNote {
+    pub pid: u32, // The note is dropped once this PID is gone
+    pub name: String,
+    pub start_timestamp: u64,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub hide_kernel_threads: bool,
+    pub auto_fit_columns: bool, // Size process list columns to their content instead of fixed widths
+    pub exit_log_min_lifetime_secs: u64, // Exits of processes that lived less than this are not logged
+    pub exit_log_include_short_lived: bool, // Log short-lived processes anyway
+    pub exit_log_persist: bool, // Append exits to ~/.lpm/exit_log.jsonl and reload them on startup
+    pub exit_log_capacity: usize, // Exits kept in the Process Log (and reloaded on startup)
+    pub exit_log_max_bytes: u64, // exit_log.jsonl is rotated to exit_log.jsonl.1 past this size
+    pub inline_bars: bool, // Draw proportional bars next to CPU% and MEM values
+    pub memory_bar_ceiling_mb: u64, // Memory that fills a MEM bar; 0 = total RAM
+    pub cpu_per_core: bool, // Process CPU% relative to one core (can exceed 100%) instead of the whole system
+    pub memory_units: crate::units::MemoryUnits, // Binary (MiB/GiB) or decimal (MB/GB) memory sizes
+    pub sort_directions: HashMap<String, bool>, // Sort column -> last chosen direction (true = ascending)
+    pub show_cpu_time: bool, // Show the cumulative CPU time (TIME+) column
+    pub pinned_names: Vec<String>, // Process names pinned to the top of the list
+    pub show_unit: bool, // Show the systemd UNIT column
+    pub show_io: bool, // Show the I/O column (disk read + write rate since the previous refresh)
+    pub show_thread_count: bool, // Show the THR (thread count) column
+    pub refresh_interval_ms: u64, // Process refresh and graph sampling interval (--interval overrides it for one run)
+    pub refresh_change_tolerance: f32, // CPU points / % of RAM a process may drift before the list is re-sorted and redrawn; 0 = always update
+    pub force_refresh_secs: u64, // Full update at least this often, even when nothing changed
+    pub d_state_warn_secs: u64, // Highlight processes continuously in D state (disk/NFS wait) for this long
+    pub baseline_learning_secs: u64, // Length of the window [b] in Alerts learns normal per-name usage over
+    pub multi_column: bool, // Continue the process list in side-by-side compact columns when the terminal is wide enough
+    pub armed_auto_actions: bool, // Count down (cancel with X) before blacklist kills, early-OOM kills and destructive scheduled tasks run
+    pub auto_action_countdown_secs: u64, // Length of that countdown
+    pub sched_aware_cpu_colors: bool, // Color CPU by scheduling class: busy batch/idle processes stay green, busy realtime ones turn red
+    pub change_highlight: bool, // Highlight the CPU/MEM/STATUS cells that moved since the previous refresh
+    pub change_cpu_points: f32, // CPU% points a process must move to count as changed
+    pub change_memory_percent: f32, // Percent of its own memory a process must grow or shrink by
+    pub change_dim_unchanged: bool, // Dim the rows that did not change while highlighting
+    pub oom_guard: bool, // Kill the largest eligible process when available memory stays low (early OOM)
+    pub oom_guard_available_mb: u64, // MemAvailable below this counts as low
+    pub oom_guard_sustain_secs: u64, // How long memory must stay low before the guard acts
+    pub oom_guard_only_names: Vec<String>, // Only these process names may be killed; empty = any
+    pub oom_guard_never_names: Vec<String>, // Process names the guard never kills
+    pub oom_guard_spare_root: bool, // Never kill processes owned by root
+    pub cgroup_accounting: bool, // Group/container totals from the cgroup's cpu.stat and memory.current instead of summing processes
+    pub boot_window_secs: u64, // Processes started this soon after boot count as auto-started in the boot report
+    pub show_gauge_header: bool, // CPU/memory gauges above every view ([F2] toggles)
+    pub process_notes: Vec<ProcessNote>, // Notes attached with [E] in the process list
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            hide_kernel_threads: true, // Cleaner first impression
+            auto_fit_columns: false,
+            exit_log_min_lifetime_secs: 2,
+            exit_log_include_short_lived: false,
+            exit_log_persist: true,
+            exit_log_capacity: 100,
+            exit_log_max_bytes: 1024 * 1024,
+            inline_bars: false,
+            memory_bar_ceiling_mb: 0,
+            cpu_per_core: false,
+            memory_units: crate::units::MemoryUnits::Binary,
+            sort_directions: HashMap::new(),
+            show_cpu_time: false,
+            pinned_names: Vec::new(),
+            show_unit: false,
+            show_io: true,
+            show_thread_count: false,
+            refresh_interval_ms: 1000,
+            refresh_change_tolerance: 0.5,
+            force_refresh_secs: 5,
+            d_state_warn_secs: 10,
+            baseline_learning_secs: 300,
+            multi_column: false,
+            armed_auto_actions: false,
+            auto_action_countdown_secs: 5,
+            sched_aware_cpu_colors: true,
+            change_highlight: false,
+            change_cpu_points: 2.0,
+            change_memory_percent: 5.0,
+            change_dim_unchanged: true,
+            oom_guard: false,
+            oom_guard_available_mb: 256,
+            oom_guard_sustain_secs: 10,
+            oom_guard_only_names: Vec::new(),
+            oom_guard_never_names: vec!["systemd".to_string(), "sshd".to_string(), "Xorg".to_string(), "Xwayland".to_string()],
+            oom_guard_spare_root: true,
+            cgroup_accounting: true,
+            boot_window_secs: 60,
+            show_gauge_header: true,
+            process_notes: Vec::new(),
+        }
+    }
+}
+
+impl Settings {
+    /// Load settings from disk, falling back to defaults if missing or invalid
+    pub fn load() -> Self {
+        fs::read_to_string(settings_path())
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Preferred direction for a sort column; usage columns default to highest first
+    pub fn sort_ascending(&self, mode: &str) -> bool {
+        self.sort_directions
+            .get(mode)
+            .copied()
+            .unwrap_or(!matches!(mode, "cpu" | "mem" | "time" | "io" | "threads"))
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = settings_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = toml::to_string_pretty(self)?;
+        fs::write(&path, content)?;
+        Ok(())
+    }
+}
+
+fn settings_path() -> PathBuf {
+    dirs::home_dir()
+        .map(|mut p| {
+            p.push(".lpm");
+            p
+        })
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("settings.toml")
+}
+
+/// Append a timestamped line to ~/.lpm/<file_name>, creating the directory if needed
+pub fn append_log(file_name: &str, message: &str) {
+    use std::io::Write;
+    let Some(dir) = dirs::home_dir().map(|home| home.join(".lpm")) else {
+        return;
+    };
+    let _ = fs::create_dir_all(&dir);
+    if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(dir.join(file_name)) {
+        let _ = writeln!(file, "{} {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), message);
+    }
+}
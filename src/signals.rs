@@ -0,0 +1,69 @@
+//! Signal table and helpers shared by the signal picker, scheduler and rules
+
+pub struct SignalInfo {
+    pub name: &'static str,
+    pub number: i32,
+    pub description: &'static str,
+}
+
+/// Standard signals, in numeric order
+pub const SIGNALS: &[SignalInfo] = &[
+    SignalInfo { name: "SIGHUP", number: libc::SIGHUP, description: "hangup / reload config" },
+    SignalInfo { name: "SIGINT", number: libc::SIGINT, description: "interrupt (Ctrl+C)" },
+    SignalInfo { name: "SIGQUIT", number: libc::SIGQUIT, description: "quit and dump core" },
+    SignalInfo { name: "SIGILL", number: libc::SIGILL, description: "illegal instruction" },
+    SignalInfo { name: "SIGTRAP", number: libc::SIGTRAP, description: "trace/breakpoint trap" },
+    SignalInfo { name: "SIGABRT", number: libc::SIGABRT, description: "abort" },
+    SignalInfo { name: "SIGBUS", number: libc::SIGBUS, description: "bus error" },
+    SignalInfo { name: "SIGFPE", number: libc::SIGFPE, description: "floating point exception" },
+    SignalInfo { name: "SIGKILL", number: libc::SIGKILL, description: "force kill (cannot be caught)" },
+    SignalInfo { name: "SIGUSR1", number: libc::SIGUSR1, description: "user-defined 1" },
+    SignalInfo { name: "SIGSEGV", number: libc::SIGSEGV, description: "segmentation fault" },
+    SignalInfo { name: "SIGUSR2", number: libc::SIGUSR2, description: "user-defined 2" },
+    SignalInfo { name: "SIGPIPE", number: libc::SIGPIPE, description: "broken pipe" },
+    SignalInfo { name: "SIGALRM", number: libc::SIGALRM, description: "timer alarm" },
+    SignalInfo { name: "SIGTERM", number: libc::SIGTERM, description: "graceful terminate" },
+    SignalInfo { name: "SIGCHLD", number: libc::SIGCHLD, description: "child stopped or exited" },
+    SignalInfo { name: "SIGCONT", number: libc::SIGCONT, description: "continue if stopped" },
+    SignalInfo { name: "SIGSTOP", number: libc::SIGSTOP, description: "stop (cannot be caught)" },
+    SignalInfo { name: "SIGTSTP", number: libc::SIGTSTP, description: "terminal stop (Ctrl+Z)" },
+    SignalInfo { name: "SIGTTIN", number: libc::SIGTTIN, description: "background read from tty" },
+    SignalInfo { name: "SIGTTOU", number: libc::SIGTTOU, description: "background write to tty" },
+    SignalInfo { name: "SIGURG", number: libc::SIGURG, description: "urgent socket data" },
+    SignalInfo { name: "SIGXCPU", number: libc::SIGXCPU, description: "CPU time limit exceeded" },
+    SignalInfo { name: "SIGXFSZ", number: libc::SIGXFSZ, description: "file size limit exceeded" },
+    SignalInfo { name: "SIGVTALRM", number: libc::SIGVTALRM, description: "virtual timer alarm" },
+    SignalInfo { name: "SIGPROF", number: libc::SIGPROF, description: "profiling timer alarm" },
+    SignalInfo { name: "SIGWINCH", number: libc::SIGWINCH, description: "window resized" },
+    SignalInfo { name: "SIGIO", number: libc::SIGIO, description: "I/O now possible" },
+    SignalInfo { name: "SIGSYS", number: libc::SIGSYS, description: "bad system call" },
+];
+
+/// Parse a signal given as a number ("15"), a name ("SIGTERM") or a short name ("term")
+pub fn parse_signal(input: &str) -> Option<i32> {
+    let input = input.trim();
+    if let Ok(number) = input.parse::<i32>() {
+        return SIGNALS.iter().find(|s| s.number == number).map(|s| s.number);
+    }
+    let upper = input.to_uppercase();
+    let name = if upper.starts_with("SIG") { upper } else { format!("SIG{}", upper) };
+    SIGNALS.iter().find(|s| s.name == name).map(|s| s.number)
+}
+
+/// Name of a signal number, e.g. 15 -> "SIGTERM"
+pub fn signal_name(number: i32) -> Option<&'static str> {
+    SIGNALS.iter().find(|s| s.number == number).map(|s| s.name)
+}
+
+/// Signals whose name or number matches a partially typed query, an exact match first
+pub fn filter_signals(query: &str) -> Vec<&'static SignalInfo> {
+    let exact = parse_signal(query);
+    let query = query.trim().to_uppercase();
+    let query = query.strip_prefix("SIG").unwrap_or(query.as_str());
+    let mut matches: Vec<&'static SignalInfo> = SIGNALS
+        .iter()
+        .filter(|s| query.is_empty() || s.name[3..].contains(query) || s.number.to_string().starts_with(query))
+        .collect();
+    matches.sort_by_key(|s| Some(s.number) != exact);
+    matches
+}
@@ -0,0 +1,135 @@
+//! Saved Start Process templates (name -> program, args, cwd, env)
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartTemplate {
+    pub name: String,
+    pub program: String,
+    pub arguments: String, // Space-separated, as typed in the form
+    pub working_dir: String, // Empty = lpm's working directory
+    pub env_vars: Vec<(String, String)>,
+    pub env_inherit: bool,
+}
+
+impl StartTemplate {
+    /// Whether the program can still be found (absolute/relative path, or a name on $PATH)
+    pub fn program_exists(&self) -> bool {
+        let program = Path::new(&self.program);
+        if self.program.contains('/') {
+            return program.exists();
+        }
+        std::env::var_os("PATH")
+            .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(program).exists()))
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TemplateConfig {
+    templates: Vec<StartTemplate>,
+}
+
+pub struct TemplateStore {
+    templates: Vec<StartTemplate>,
+    config_path: PathBuf,
+}
+
+impl TemplateStore {
+    pub fn new() -> Self {
+        let config_dir = dirs::home_dir()
+            .map(|mut p| {
+                p.push(".lpm");
+                p
+            })
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let mut store = Self {
+            templates: Vec::new(),
+            config_path: config_dir.join("templates.toml"),
+        };
+
+        // Seed a few examples until the user saves their own
+        if store.config_path.exists() {
+            let _ = store.load_templates();
+        } else {
+            store.templates = example_templates();
+        }
+
+        store
+    }
+
+    pub fn get_templates(&self) -> &[StartTemplate] {
+        &self.templates
+    }
+
+    /// Add a template, replacing one with the same name
+    pub fn add_template(&mut self, template: StartTemplate) {
+        self.templates.retain(|t| t.name != template.name);
+        self.templates.push(template);
+        self.templates.sort_by(|a, b| a.name.cmp(&b.name));
+        let _ = self.save_templates();
+    }
+
+    pub fn remove_template(&mut self, name: &str) -> bool {
+        let len_before = self.templates.len();
+        self.templates.retain(|t| t.name != name);
+        let removed = self.templates.len() < len_before;
+        if removed {
+            let _ = self.save_templates();
+        }
+        removed
+    }
+
+    fn load_templates(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.config_path.exists() {
+            return Ok(()); // No config file yet
+        }
+
+        let content = fs::read_to_string(&self.config_path)?;
+        let config: TemplateConfig = toml::from_str(&content)?;
+        self.templates = config.templates;
+        Ok(())
+    }
+
+    fn save_templates(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(parent) = self.config_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let config = TemplateConfig {
+            templates: self.templates.clone(),
+        };
+
+        let content = toml::to_string_pretty(&config)?;
+        fs::write(&self.config_path, content)?;
+        Ok(())
+    }
+}
+
+impl Default for TemplateStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn example_templates() -> Vec<StartTemplate> {
+    let template = |name: &str, program: &str, arguments: &str| StartTemplate {
+        name: name.to_string(),
+        program: program.to_string(),
+        arguments: arguments.to_string(),
+        working_dir: String::new(),
+        env_vars: Vec::new(),
+        env_inherit: true,
+    };
+    vec![
+        StartTemplate {
+            env_vars: vec![("PYTHONUNBUFFERED".to_string(), "1".to_string())],
+            ..template("http-server", "/usr/bin/python3", "-m http.server 8000")
+        },
+        template("ping-localhost", "/usr/bin/ping", "-c 100 127.0.0.1"),
+        template("sleep-5m", "/usr/bin/sleep", "300"),
+    ]
+}
@@ -0,0 +1,206 @@
+//! Minimal SVG line-chart writer for exporting graph snapshots
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+const CHART_WIDTH: f64 = 800.0;
+const CHART_HEIGHT: f64 = 300.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 50.0;
+const TITLE_HEIGHT: f64 = 40.0;
+const GRID_LINES: usize = 4;
+
+/// One line on a chart
+pub struct Series {
+    pub label: String,
+    pub color: &'static str,
+    pub values: Vec<f64>,
+}
+
+/// A titled chart with a fixed y range starting at zero
+pub struct LineChart {
+    pub title: String,
+    pub y_label: String,
+    pub x_label: String,
+    pub y_max: f64,
+    pub series: Vec<Series>,
+}
+
+/// Render charts stacked vertically into a standalone SVG document
+pub fn render_svg(title: &str, charts: &[LineChart]) -> String {
+    let height = TITLE_HEIGHT + CHART_HEIGHT * charts.len() as f64;
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif">"#,
+        w = CHART_WIDTH,
+        h = height
+    );
+    let _ = writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="26" font-size="18" font-weight="bold" text-anchor="middle">{}</text>"#,
+        CHART_WIDTH / 2.0,
+        escape(title)
+    );
+    for (i, chart) in charts.iter().enumerate() {
+        render_chart(&mut svg, chart, TITLE_HEIGHT + CHART_HEIGHT * i as f64);
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// Append one chart whose top edge is at `top`
+fn render_chart(svg: &mut String, chart: &LineChart, top: f64) {
+    let left = MARGIN_LEFT;
+    let right = CHART_WIDTH - MARGIN_RIGHT;
+    let plot_top = top + MARGIN_TOP;
+    let plot_bottom = top + CHART_HEIGHT - MARGIN_BOTTOM;
+    let plot_width = right - left;
+    let plot_height = plot_bottom - plot_top;
+    let y_max = if chart.y_max > 0.0 { chart.y_max } else { 1.0 };
+
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="{}" font-size="14" font-weight="bold" text-anchor="middle">{}</text>"#,
+        CHART_WIDTH / 2.0,
+        top + 24.0,
+        escape(&chart.title)
+    );
+
+    // Horizontal grid lines with y-axis values
+    for step in 0..=GRID_LINES {
+        let fraction = step as f64 / GRID_LINES as f64;
+        let y = plot_bottom - fraction * plot_height;
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{left}" y1="{y:.1}" x2="{right}" y2="{y:.1}" stroke="#dddddd"/>"##
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{:.1}" font-size="11" text-anchor="end">{}</text>"#,
+            left - 6.0,
+            y + 4.0,
+            format_axis_value(fraction * y_max)
+        );
+    }
+
+    // Axes and labels
+    let _ = writeln!(
+        svg,
+        r#"<line x1="{left}" y1="{plot_top}" x2="{left}" y2="{plot_bottom}" stroke="black"/>"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"<line x1="{left}" y1="{plot_bottom}" x2="{right}" y2="{plot_bottom}" stroke="black"/>"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}" font-size="12" text-anchor="middle" transform="rotate(-90 {:.1} {:.1})">{}</text>"#,
+        left - 52.0,
+        plot_top + plot_height / 2.0,
+        left - 52.0,
+        plot_top + plot_height / 2.0,
+        escape(&chart.y_label)
+    );
+    let _ = writeln!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}" font-size="12" text-anchor="middle">{}</text>"#,
+        left + plot_width / 2.0,
+        plot_bottom + 36.0,
+        escape(&chart.x_label)
+    );
+
+    // x-axis ticks count samples back from the newest point on the right
+    let samples = chart.series.iter().map(|s| s.values.len()).max().unwrap_or(0);
+    if samples > 1 {
+        for tick in [samples - 1, (samples - 1) / 2, 0] {
+            let x = left + plot_width * (samples - 1 - tick) as f64 / (samples - 1) as f64;
+            let _ = writeln!(
+                svg,
+                r#"<text x="{:.1}" y="{:.1}" font-size="11" text-anchor="middle">{}</text>"#,
+                x,
+                plot_bottom + 16.0,
+                tick
+            );
+        }
+    }
+
+    // Data lines, right-aligned so every series ends at the newest sample
+    for series in &chart.series {
+        if series.values.len() < 2 || samples < 2 {
+            continue;
+        }
+        let offset = samples - series.values.len();
+        let points: Vec<String> = series
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let x = left + plot_width * (offset + i) as f64 / (samples - 1) as f64;
+                let y = plot_bottom - (value / y_max).clamp(0.0, 1.0) * plot_height;
+                format!("{:.1},{:.1}", x, y)
+            })
+            .collect();
+        let _ = writeln!(
+            svg,
+            r#"<polyline fill="none" stroke="{}" stroke-width="2" points="{}"/>"#,
+            series.color,
+            points.join(" ")
+        );
+    }
+
+    // Legend in the top-right corner of the plot
+    for (i, series) in chart.series.iter().enumerate() {
+        let y = plot_top + 14.0 * i as f64;
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{:.1}" y="{:.1}" width="10" height="10" fill="{}"/>"#,
+            right - 150.0,
+            y,
+            series.color
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{:.1}" font-size="11">{}</text>"#,
+            right - 135.0,
+            y + 9.0,
+            escape(&series.label)
+        );
+    }
+}
+
+// Compact axis label: whole numbers stay whole, small values keep one decimal
+fn format_axis_value(value: f64) -> String {
+    if value >= 10.0 || value == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Write an SVG document to ~/.lpm/snapshots/<prefix>-<timestamp>.svg and return its path
+pub fn write_snapshot(prefix: &str, svg: &str) -> std::io::Result<PathBuf> {
+    let dir = dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".lpm")
+        .join("snapshots");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "{}-{}.svg",
+        prefix,
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::write(&path, svg)?;
+    Ok(path)
+}
@@ -0,0 +1,93 @@
+//! Squarified treemap layout (Bruls, Huizing & van Wijk)
+//!
+//! Splits a rectangle into tiles whose areas are proportional to a list of weights,
+//! keeping tiles as close to square as possible. Used by the GUI memory map.
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Tile {
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+impl Tile {
+    /// Shrink by `margin` on every side (never below zero size)
+    pub fn inset(&self, margin: f32) -> Tile {
+        Tile {
+            x: self.x + margin,
+            y: self.y + margin,
+            w: (self.w - 2.0 * margin).max(0.0),
+            h: (self.h - 2.0 * margin).max(0.0),
+        }
+    }
+}
+
+/// One tile per weight, in the same order. Weights should be sorted descending for
+/// the squarest result; zero weights get empty tiles.
+pub fn squarify(weights: &[f64], bounds: Tile) -> Vec<Tile> {
+    let total: f64 = weights.iter().filter(|w| **w > 0.0).sum();
+    if total <= 0.0 || bounds.w <= 0.0 || bounds.h <= 0.0 {
+        return vec![Tile { w: 0.0, h: 0.0, ..bounds }; weights.len()];
+    }
+    let scale = bounds.w as f64 * bounds.h as f64 / total;
+    let areas: Vec<f64> = weights.iter().map(|w| w.max(0.0) * scale).collect();
+
+    let mut tiles = Vec::with_capacity(weights.len());
+    let mut rest = bounds;
+    let mut start = 0;
+    while start < areas.len() {
+        if areas[start] <= 0.0 {
+            tiles.push(Tile { w: 0.0, h: 0.0, ..rest });
+            start += 1;
+            continue;
+        }
+        // Grow the row while that makes its worst aspect ratio better
+        let side = rest.w.min(rest.h) as f64;
+        let mut end = start + 1;
+        while end < areas.len()
+            && areas[end] > 0.0
+            && worst_ratio(&areas[start..=end], side) <= worst_ratio(&areas[start..end], side)
+        {
+            end += 1;
+        }
+        rest = lay_row(&areas[start..end], rest, &mut tiles);
+        start = end;
+    }
+    tiles
+}
+
+// Worst (largest) aspect ratio of a row of areas laid along a side of this length
+fn worst_ratio(row: &[f64], side: f64) -> f64 {
+    let sum: f64 = row.iter().sum();
+    let max = row.iter().copied().fold(0.0, f64::max);
+    let min = row.iter().copied().fold(f64::INFINITY, f64::min);
+    let (side2, sum2) = (side * side, sum * sum);
+    (side2 * max / sum2).max(sum2 / (side2 * min))
+}
+
+// Place a row along the shorter side of `rect`, returning the space left over
+fn lay_row(row: &[f64], rect: Tile, tiles: &mut Vec<Tile>) -> Tile {
+    let sum: f64 = row.iter().sum();
+    if rect.w >= rect.h {
+        // A column on the left
+        let width = (sum / rect.h as f64) as f32;
+        let mut y = rect.y;
+        for area in row {
+            let h = (area / width as f64) as f32;
+            tiles.push(Tile { x: rect.x, y, w: width, h });
+            y += h;
+        }
+        Tile { x: rect.x + width, w: (rect.w - width).max(0.0), ..rect }
+    } else {
+        // A row along the top
+        let height = (sum / rect.w as f64) as f32;
+        let mut x = rect.x;
+        for area in row {
+            let w = (area / height as f64) as f32;
+            tiles.push(Tile { x, y: rect.y, w, h: height });
+            x += w;
+        }
+        Tile { y: rect.y + height, h: (rect.h - height).max(0.0), ..rect }
+    }
+}
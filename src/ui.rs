@@ -1,4 +1,6 @@
 use crate::process;
+use crate::ports;
+use crate::explain;
 use crate::scripting_rules::RuleEngine;
 use crate::graph;
 use std::io::stdout;
@@ -29,7 +31,7 @@ use chrono::Local;
 use std::collections::{HashSet, VecDeque};
 
 // ViewMode enum to track current view
-#[derive(PartialEq)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 enum ViewMode {
     ProcessList,
     Statistics,  // Renamed from GraphView
@@ -57,6 +59,14 @@ enum ViewMode {
     MultiHost, // Multi-host view
     HostManagement, // Host management view
     TaskEditor, // Task editor view for creating/editing scheduled tasks
+    Suspended, // Processes stopped (SIGSTOP) by lpm
+    LogTail,   // Auto-following tail of a process's log file
+    OrderedRestart, // Sequenced stop/start of the multi-selected processes
+    UserSummary, // CPU/memory/process totals per user
+    HostDiff, // Process-set differences between two remote hosts
+    BootReport, // What started automatically at boot (from Help's diagnostics)
+    Dashboard, // User-composed grid of graphs (from Statistics)
+    ProcessTree, // Parent/child hierarchy built from parent_pid
 }
 
 // Input state for various operations
@@ -71,8 +81,13 @@ struct InputState {
     program_path: String,
     working_dir: String,
     arguments: String,
+    output_log: String, // Output log path (empty = default log location)
+    run_as_input: String, // User (and optional group) to start the process as (empty = lpm's own user)
     env_vars: Vec<(String, String)>, // (key, value)
-    current_start_input_field: usize, // 0=program, 1=working_dir, 2=arguments, 3=env_vars
+    env_input: String, // Pending KEY=VALUE entry
+    env_inherit: bool, // Inherit current environment (false = clean environment)
+    restart_target: Option<u32>, // PID to kill when the StartProcess form is confirmed (restart with modified args)
+    current_start_input_field: usize, // 0=program, 1=working_dir, 2=arguments, 3=env_vars, 4=output_log, 5=run_as
     // Advanced filter input
     advanced_filter_input: String,
     // Task editor input
@@ -81,6 +96,8 @@ struct InputState {
     task_schedule_value: String, // Cron expression, interval seconds, or timestamp
     task_action_type: String, // "restart", "cleanup", or "rule"
     task_action_value: String, // Process pattern, cleanup params, or rule expression
+    task_once_confirmed: Option<(String, std::time::SystemTime)>, // "once" schedule value the user confirmed, and its resolved time
+    editing_task_index: Option<usize>, // Scheduler task being edited in place (None = creating a new task)
     current_task_field: usize, // 0=name, 1=schedule_type, 2=schedule_value, 3=action_type, 4=action_value
 }
 
@@ -96,7 +113,12 @@ impl Default for InputState {
             program_path: String::new(),
             working_dir: String::new(),
             arguments: String::new(),
+            output_log: String::new(),
+            run_as_input: String::new(),
             env_vars: Vec::new(),
+            env_input: String::new(),
+            env_inherit: true,
+            restart_target: None,
             current_start_input_field: 0,
             advanced_filter_input: String::new(),
             task_name: String::new(),
@@ -104,6 +126,8 @@ impl Default for InputState {
             task_schedule_value: String::new(),
             task_action_type: String::new(),
             task_action_value: String::new(),
+            task_once_confirmed: None,
+            editing_task_index: None,
             current_task_field: 0,
         }
     }
@@ -114,7 +138,24 @@ impl Default for InputState {
 enum NiceInputState {
     SelectingPid,
     EnteringNice,
+    EnteringUserNice,   // Nice value for every process of `App::renice_user`
+    ConfirmingUserNice, // Batch confirmation before renicing `App::renice_targets`
+    EnteringFilterTune,   // Nice value and/or CPU list for every process matching the active filter
+    ConfirmingFilterTune, // Batch confirmation before applying `App::filter_tune` to `App::renice_targets`
+    EnteringAffinity,     // CPU list (e.g. 0,1,4-7) for the selected process
+    EnteringIonice,       // I/O class and priority (e.g. "be 7", "idle") for the selected process
+}
+// Kill/terminate of a process that has children, waiting for the parent/children/subtree choice
+#[derive(PartialEq, Clone)]
+struct DependencyWarningState {
+    pid: u32,
+    process_name: String,
+    action_type: String,
+    child_count: usize,
+    children: Vec<(u32, String)>, // (pid, name)
+    descendants: Vec<u32>, // Whole subtree below pid, leaves first
 }
+
 // KillStopInputState enum to track the state of kill/stop/continue input
 #[derive(PartialEq, Clone)]
 enum KillStopInputState {
@@ -125,18 +166,23 @@ enum KillStopInputState {
         process_name: String,
         action_type: String, // "kill", "stop", "terminate", "continue"
     },
-    DependencyWarning {
+    PickingSignal {
         pid: u32,
         process_name: String,
-        action_type: String,
-        child_count: usize,
-        children: Vec<(u32, String)>, // (pid, name)
+        query: String,        // Partially typed signal name or number
+        selected_index: usize, // Index into the filtered signal list
     },
+    DependencyWarning(DependencyWarningState),
     ConfirmingBatchAction {
         pids: Vec<u32>,
         process_names: Vec<String>,
         action_type: String,
     },
+    EnteringBlacklistDuration {
+        pid: u32,
+        process_name: String,
+        minutes: String, // Empty = blacklist until cleared
+    },
 }
 
 // StatisticsTab enum to track the current statistics tab
@@ -155,6 +201,112 @@ pub enum StatisticsTab {
     Help,            // New tab for help
 }
 
+// Previous value of a process setting changed by lpm, kept so the change can be undone
+struct NiceChange {
+    pid: u32,
+    name: String,
+    start_timestamp: u64, // Guards against restoring onto a reused PID
+    old_nice: i32,
+}
+
+// Most recent change that can be reverted by reapplying the old values
+enum ReversibleAction {
+    Renice { changes: Vec<NiceChange>, new_nice: i32 },
+    Affinity { pid: u32, name: String, start_timestamp: u64, previous: Vec<usize> }, // CPU mask before the edit
+}
+
+// Which way the scheduler's file prompt moves tasks
+#[derive(Clone, Copy, PartialEq)]
+enum TaskFileOp {
+    Export,
+    Import,
+}
+
+// Step of the guided advanced-filter builder
+#[derive(Clone, Copy, PartialEq)]
+enum BuilderStep {
+    Field,    // Choosing the field of the next clause
+    Operator, // Choosing an operator valid for that field
+    Value,    // Typing the value
+    Next,     // Clause added: AND/OR another, or apply
+}
+
+// One host's answer to a background fetch: its resolved address and processes, or why it failed
+type HostFetchResult = (String, Result<(std::net::SocketAddr, Vec<crate::coordinator::RemoteProcessInfo>), String>);
+
+// Clauses assembled so far plus the one being built
+struct FilterBuilder {
+    clauses: Vec<crate::filter_parser::FilterClause>,
+    step: BuilderStep,
+    join: crate::filter_parser::Join, // Join for the clause being built
+    field_index: usize,    // Into filter_parser::FILTER_FIELDS
+    operator_index: usize, // Into the field kind's operators
+    value: String,
+}
+
+impl FilterBuilder {
+    fn new() -> Self {
+        Self {
+            clauses: Vec::new(),
+            step: BuilderStep::Field,
+            join: crate::filter_parser::Join::And,
+            field_index: 0,
+            operator_index: 0,
+            value: String::new(),
+        }
+    }
+
+    fn field(&self) -> &'static crate::filter_parser::FilterField {
+        &crate::filter_parser::FILTER_FIELDS[self.field_index]
+    }
+
+    fn operator(&self) -> &'static str {
+        self.field().kind.operators()[self.operator_index]
+    }
+}
+
+// Process name that is killed on sight until the entry expires or is cleared
+struct BlacklistEntry {
+    name: String,
+    until: Option<std::time::Instant>, // None = until cleared
+    kills: u32, // Respawns killed so far
+}
+
+// What an armed auto-action will do when its countdown elapses
+enum AutoAction {
+    BlacklistKill { pid: u32, name: String },
+    OomKill { pid: u32, name: String, reason: String },
+    Scheduled { task_name: String, action: crate::scheduler::ScheduleAction },
+}
+
+// Destructive automatic action shown with a countdown before it runs
+struct PendingAction {
+    description: String, // e.g. "Killing runaway-proc (1234) - blacklisted"
+    fire_at: std::time::Instant,
+    action: AutoAction,
+}
+
+// Filter and sort remembered by one of the process-picking views (kill/stop, change nice, per-process graph)
+#[derive(Clone, Default)]
+struct ViewPrefs {
+    filter: String, // Case-insensitive substring of the name or user; empty shows everything
+    sort: Option<(&'static str, bool)>, // (sort mode, ascending); None keeps the main list's order
+}
+
+// Which cells of a process moved past the change-highlight thresholds since the previous refresh
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ProcessChange {
+    cpu: bool,
+    memory: bool,
+    status: bool,
+}
+
+// Sort modes cycled with [o] in those views; after the last one the view goes back to the main list's order
+const VIEW_SORT_MODES: [&str; 6] = ["cpu", "mem", "pid", "name", "user", "nice"];
+
+// Sort modes cycled with [o] in the active-alerts pane; None keeps the newest first
+const ALERT_SORT_MODES: [&str; 3] = ["time", "severity", "process"];
+
 // LogGroupMode enum to track process log grouping
 #[derive(PartialEq, Clone, Copy)]
 enum LogGroupMode {
@@ -162,6 +314,7 @@ enum LogGroupMode {
     Name,
     PPID,
     User,
+    ExitReason,
 }
 
 // App state
@@ -171,12 +324,15 @@ struct App {
     view_mode: ViewMode,
     scroll_offset: usize,
     display_limit: usize,
+    column_rows: usize, // Rows per column of the process list; equals display_limit unless split into columns
     input_state: InputState,
-    sort_ascending: bool,
-    sort_mode: Option<String>,
+    sort_mode: Option<String>, // Direction per column is remembered in settings.sort_directions
     filter_mode: Option<String>,
     stats_scroll_offset: usize,  // New field for statistics scrolling
     nice_input_state: NiceInputState,  // Track which input we're currently handling
+    renice_user: Option<String>,             // User whose processes are being reniced
+    filter_tune: (Option<i32>, Option<Vec<usize>>), // Nice value and CPU affinity pending for the filter's matches
+    renice_targets: Vec<(u32, String)>,      // (pid, name) pending user renice confirmation
     current_stats_tab: StatisticsTab,  // New field for tracking current statistics tab
     change_nice_scroll_offset: usize,
     selected_process_index: usize,
@@ -184,6 +340,7 @@ struct App {
     selected_process_for_graph: Option<u32>,  // Add this
     kill_stop_input_state: KillStopInputState,
     process_exit_log: VecDeque<ProcessExitLogEntry>, // Add this
+    exit_log_capacity: usize, // Most entries process_exit_log holds (settings.exit_log_capacity)
     prev_pids: std::collections::HashMap<u32, String>, // For tracking exited processes with names
     process_first_seen: std::collections::HashMap<u32, std::time::Instant>, // Track when we first saw each process
     log_filter_input: String, // For process log search/filter
@@ -199,6 +356,8 @@ struct App {
     current_namespace_type: Option<String>, // Current namespace type if grouping by namespace
     frozen_group_order: Vec<String>, // Frozen group order to prevent jumping when expanded
     group_view_frozen: bool, // Whether group order is frozen
+    group_filter_input: String, // Grouped view search (substring of group/container name)
+    group_filter_active: bool,  // True while typing the grouped view search
     selected_container_id: Option<String>, // Selected container for detail view
     selected_namespace: Option<(String, u64)>, // Selected namespace (type, id) for detail view
     detail_view_scroll_offset: usize, // Scroll offset for detail view
@@ -206,6 +365,8 @@ struct App {
     scheduler: crate::scheduler::Scheduler,
     selected_task_index: usize, // Selected task in scheduler view
     scheduler_scroll_offset: usize, // Scroll offset for scheduler view
+    task_file_prompt: Option<TaskFileOp>, // Export/import path prompt open in the scheduler view
+    task_file_input: String,
     scheduler_last_check: std::time::Instant, // Last time we checked for due tasks
     // Profile management
     profile_manager: crate::profile::ProfileManager,
@@ -217,6 +378,9 @@ struct App {
     profile_edit_hide: String,
     profile_edit_nice: String,
     profile_edit_current_field: usize, // 0=prioritize, 1=hide, 2=nice
+    // Secondary sort key configuration
+    editing_secondary_sort: bool, // Sort menu keys set the secondary key
+    secondary_sort_ascending: bool,
     // Multi-select state
     multi_select_mode: bool,
     selected_processes: HashSet<u32>,
@@ -228,7 +392,9 @@ struct App {
     alert_edit_name: String,
     alert_edit_threshold: String,
     alert_edit_duration: String,
-    alert_edit_current_field: usize, // 0=Name, 1=Threshold, 2=Duration
+    alert_edit_pattern: String, // Process name pattern for death and count alerts
+    alert_test_report: Option<crate::alert::AlertTestReport>, // Last "test" of the alert being edited
+    alert_edit_current_field: usize, // 0=Name, 1=Threshold, 2=Duration, 3=Pattern
     // CRIU checkpoint management
     criu_manager: crate::criu_manager::CriuManager,
     selected_checkpoint_index: usize,
@@ -239,31 +405,113 @@ struct App {
     selected_host_index: usize,
     host_scroll_offset: usize,
     host_input: String,
+    diff_base_host: Option<String>, // Address of the first host picked for a comparison
+    host_diff: Option<crate::coordinator::HostDiff>,
+    host_diff_scroll: usize,
+    host_results: Option<std::sync::mpsc::Receiver<HostFetchResult>>, // Fetch of every host's processes in flight
+    view_prefs: std::collections::HashMap<ViewMode, ViewPrefs>, // Own filter/sort of the kill/stop, change nice and per-process graph views
+    view_filter_editing: Option<ViewMode>, // View whose filter is being typed
+    process_changes: std::collections::HashMap<u32, ProcessChange>, // PID -> what changed at the last refresh (only with the change highlight on)
+    settings: crate::settings::Settings,
+    suspended_by_lpm: std::collections::HashMap<u32, (String, std::time::Instant)>, // PID -> (name, when lpm stopped it)
+    selected_suspended_index: usize,
+    user_summary_sort: crate::process_group::UserSortKey,
+    selected_user_index: usize,
+    tree_collapsed: HashSet<u32>, // Process tree nodes whose children are hidden
+    selected_tree_index: usize,
+    boot_report_group: crate::process_group::GroupType, // Username, Cgroup or SystemdUnit
+    boot_report_scroll: usize,
+    dashboard: crate::dashboard::DashboardLayout,
+    dashboard_selected: usize,
+    dashboard_picker: Option<usize>, // Highlighted entry of the open "add graph" picker
+    cgroup_accounting: crate::process_group::CgroupAccounting, // cpu.stat readings behind group CPU rates
+    log_tail: Option<crate::log_tail::LogTail>,
+    log_tail_candidates: Vec<std::path::PathBuf>, // Log files of the process being tailed
+    show_threads: bool, // Show thread sub-rows under every visible process
+    process_detail: Option<u32>, // PID whose detail popup is open in the process list
+    goto_pid_active: bool, // True while the "go to PID" prompt is open
+    goto_pid_input: String,
+    list_search_active: bool, // True while typing a "/" search in the process list
+    list_search_query: String, // Kept after Enter so matches stay highlighted and [n]/[N] cycle them
+    port_lookup_active: bool, // True while the "find by port" prompt is open
+    port_lookup_input: String,
+    process_notes: std::collections::HashMap<(String, u64), (u32, String)>, // (name, start timestamp) -> (PID, note)
+    note_editing: Option<(u32, String, u64)>, // (PID, name, start timestamp) of the process whose note is being typed
+    note_input: String,
+    process_sockets: Vec<ports::SocketEntry>, // TCP sockets of the process in the per-process view
+    process_explanation: Option<(u32, u64, explain::ProcessExplanation)>, // (PID, start timestamp, explanation) for the per-process view
+    thread_expanded: HashSet<u32>, // Processes whose threads were expanded individually
+    thread_cache: std::collections::HashMap<u32, Vec<process::ThreadInfo>>, // PID -> threads read at the last refresh
+    // Views the user navigated through to reach the current one (for the breadcrumb)
+    view_stack: Vec<ViewMode>,
+    // Toast notifications for newly fired alerts: (message, expires at)
+    alert_toasts: VecDeque<(String, std::time::Instant, bool)>, // (message, expires at, is a recovery)
     last_process_refresh: std::time::Instant,
+    refresh_interval: Duration, // Time between process refreshes and graph samples
+    paused: bool, // Space in the process list: keep showing the last snapshot
+    refresh_count: usize, // Number of completed data refreshes (drives the status spinner)
+    last_fingerprint: u64, // process_fingerprint of the list currently shown
+    last_full_refresh: std::time::Instant, // Last refresh that re-sorted and redrew the list
+    needs_redraw: bool, // Something changed since the last frame
+    last_draw: std::time::Instant,
+    last_reversible_action: Option<ReversibleAction>, // Undone with [u] in the process list
+    pinned_processes: HashSet<(String, u64)>, // (name, start timestamp) of processes shown above the sorted list
+    start_mark: Option<u64>, // Unix time marked with [z]; [Z] lists only processes started since
+    ordered_restart: Option<crate::ordered_restart::OrderedRestart>, // Planned or in-progress group restart
+    self_monitor: crate::self_stats::SelfMonitor, // lpm's own CPU/memory/thread/fd usage
+    power_monitor: crate::power::PowerMonitor, // Battery/AC state for the status box (cached)
+    oom_guard: crate::oom_guard::OomGuard, // How long available memory has been low
+    kill_blacklist: Vec<BlacklistEntry>, // Names killed again on every refresh while active
+    pending_actions: Vec<PendingAction>, // Armed auto-actions counting down (settings.armed_auto_actions)
+    vetoed_pids: HashSet<u32>, // Processes whose armed blacklist kill was cancelled
+    filter_builder: Option<FilterBuilder>, // Guided advanced-filter builder; None = raw text editing
+    template_store: crate::start_templates::TemplateStore,
+    template_picker: Option<usize>, // Selected template while the picker is open over the Start Process form
+    template_name_input: Option<String>, // Name being typed while saving the form as a template
+    selected_restart_step: usize,
+    command_popup: Option<String>, // Relaunch command shown over the per-process view
 }
 
 impl App {
     fn new() -> Self {
+        let settings = crate::settings::Settings::load();
+        let mut process_manager = ProcessManager::new();
+        process_manager.set_hide_kernel_threads(settings.hide_kernel_threads);
+        process_manager.set_cpu_per_core(settings.cpu_per_core);
+        crate::units::set_memory_units(settings.memory_units);
+        let process_notes = settings.process_notes
+            .iter()
+            .map(|note| ((note.name.clone(), note.start_timestamp), (note.pid, note.text.clone())))
+            .collect();
+        let exit_log_capacity = settings.exit_log_capacity.max(1);
+        let mut process_exit_log = VecDeque::with_capacity(exit_log_capacity);
+        if settings.exit_log_persist {
+            process_exit_log.extend(crate::process_log::load_exit_log(exit_log_capacity));
+        }
         Self {
-            process_manager: ProcessManager::new(),
+            process_manager,
             graph_data: graph::GraphData::new(60, 500),
             rule_engine: RuleEngine::new(),
             view_mode: ViewMode::ProcessList,
             scroll_offset: 0,
             display_limit: 20,
+            column_rows: 20,
             input_state: InputState::default(),
-            sort_ascending: true,
             sort_mode: Some("pid".to_string()),
             filter_mode: None,
             stats_scroll_offset: 0,  // Initialize stats scroll offset
             nice_input_state: NiceInputState::SelectingPid,
+            renice_user: None,
+            filter_tune: (None, None),
+            renice_targets: Vec::new(),
             current_stats_tab: StatisticsTab::Graphs,  // Default to Graphs tab
             change_nice_scroll_offset: 0,
             selected_process_index: 0,
             per_process_graph_scroll_offset: 0,  // Add this
             selected_process_for_graph: None,    // Add this
             kill_stop_input_state: KillStopInputState::SelectingPid,
-            process_exit_log: VecDeque::with_capacity(100), // Keep last 100 exits
+            process_exit_log,
+            exit_log_capacity,
             prev_pids: std::collections::HashMap::new(),
             process_first_seen: std::collections::HashMap::new(), // Track when processes were first seen
             log_filter_input: String::new(),
@@ -277,6 +525,8 @@ impl App {
             current_namespace_type: None,
             frozen_group_order: Vec::new(),
             group_view_frozen: false,
+            group_filter_input: String::new(),
+            group_filter_active: false,
             selected_container_id: None,
             selected_namespace: None,
             detail_view_scroll_offset: 0,
@@ -291,7 +541,11 @@ impl App {
             },
             selected_task_index: 0,
             scheduler_scroll_offset: 0,
+            task_file_prompt: None,
+            task_file_input: String::new(),
             scheduler_last_check: std::time::Instant::now(),
+            editing_secondary_sort: false,
+            secondary_sort_ascending: true,
             multi_select_mode: false,
             selected_processes: HashSet::new(),
             profile_manager: crate::profile::ProfileManager::new(),
@@ -310,6 +564,8 @@ impl App {
             alert_edit_name: String::new(),
             alert_edit_threshold: String::new(),
             alert_edit_duration: String::new(),
+            alert_edit_pattern: String::new(),
+            alert_test_report: None,
             alert_edit_current_field: 0,
             criu_manager: crate::criu_manager::CriuManager::new(),
             selected_checkpoint_index: 0,
@@ -319,20 +575,617 @@ impl App {
             selected_host_index: 0,
             host_scroll_offset: 0,
             host_input: String::new(),
+            diff_base_host: None,
+            host_diff: None,
+            host_diff_scroll: 0,
+            host_results: None,
+            view_prefs: std::collections::HashMap::new(),
+            view_filter_editing: None,
+            process_changes: std::collections::HashMap::new(),
+            settings,
+            suspended_by_lpm: std::collections::HashMap::new(),
+            selected_suspended_index: 0,
+            user_summary_sort: crate::process_group::UserSortKey::Cpu,
+            selected_user_index: 0,
+            tree_collapsed: HashSet::new(),
+            selected_tree_index: 0,
+            boot_report_group: crate::process_group::GroupType::Username,
+            boot_report_scroll: 0,
+            dashboard: crate::dashboard::DashboardLayout::load(),
+            dashboard_selected: 0,
+            dashboard_picker: None,
+            cgroup_accounting: crate::process_group::CgroupAccounting::default(),
+            log_tail: None,
+            log_tail_candidates: Vec::new(),
+            show_threads: false,
+            process_detail: None,
+            goto_pid_active: false,
+            goto_pid_input: String::new(),
+            list_search_active: false,
+            list_search_query: String::new(),
+            port_lookup_active: false,
+            port_lookup_input: String::new(),
+            process_notes,
+            note_editing: None,
+            note_input: String::new(),
+            process_sockets: Vec::new(),
+            process_explanation: None,
+            thread_expanded: HashSet::new(),
+            thread_cache: std::collections::HashMap::new(),
+            view_stack: Vec::new(),
+            alert_toasts: VecDeque::new(),
             last_process_refresh: std::time::Instant::now(),
+            refresh_interval: Duration::from_secs(1), // Set from settings.refresh_interval_ms below
+            paused: false,
+            refresh_count: 0,
+            last_fingerprint: 0,
+            last_full_refresh: std::time::Instant::now(),
+            needs_redraw: true,
+            last_draw: std::time::Instant::now(),
+            last_reversible_action: None,
+            pinned_processes: HashSet::new(),
+            start_mark: None,
+            ordered_restart: None,
+            self_monitor: crate::self_stats::SelfMonitor::default(),
+            power_monitor: crate::power::PowerMonitor::default(),
+            oom_guard: crate::oom_guard::OomGuard::default(),
+            kill_blacklist: Vec::new(),
+            pending_actions: Vec::new(),
+            vetoed_pids: HashSet::new(),
+            filter_builder: None,
+            template_store: crate::start_templates::TemplateStore::new(),
+            template_picker: None,
+            template_name_input: None,
+            selected_restart_step: 0,
+            command_popup: None,
+        }
+    }
+
+    /// Update the view stack after the view changed from `previous` to the current view
+    fn track_view_change(&mut self, previous: ViewMode) {
+        if self.view_mode == previous {
+            return;
+        }
+        if self.view_mode == ViewMode::ProcessList {
+            self.view_stack.clear();
+        } else if let Some(pos) = self.view_stack.iter().position(|v| *v == self.view_mode) {
+            // Went back to a view we came through - drop everything above it
+            self.view_stack.truncate(pos);
+        } else {
+            self.view_stack.push(previous);
+        }
+    }
+
+    /// Jump straight back to the process list from any view
+    fn return_to_process_list(&mut self) {
+        self.view_mode = ViewMode::ProcessList;
+        self.view_stack.clear();
+        self.editing_secondary_sort = false;
+        self.graph_data.track_group(None);
+    }
+
+    /// Breadcrumb label for a view
+    fn view_label(&self, view: ViewMode) -> String {
+        match view {
+            ViewMode::ProcessList => "Processes".to_string(),
+            ViewMode::Statistics => "Statistics".to_string(),
+            ViewMode::FilterSort => "Filter/Sort".to_string(),
+            ViewMode::Sort => "Sort".to_string(),
+            ViewMode::Filter | ViewMode::FilterInput => "Filter".to_string(),
+            ViewMode::AdvancedFilter => "Advanced Filter".to_string(),
+            ViewMode::KillStop => "Kill/Stop".to_string(),
+            ViewMode::ChangeNice => "Change Nice".to_string(),
+            ViewMode::PerProcessGraph => "Per-Process Graph".to_string(),
+            ViewMode::ProcessLog => "Process Log".to_string(),
+            ViewMode::Help => "Help".to_string(),
+            ViewMode::RuleInput => "Rule".to_string(),
+            ViewMode::GroupedView => "Grouped".to_string(),
+            ViewMode::ContainerDetail => format!("Container {}", self.selected_container_id.as_deref().unwrap_or("?")),
+            ViewMode::NamespaceDetail => match &self.selected_namespace {
+                Some((ns_type, ns_id)) => format!("Namespace {}:{}", ns_type, ns_id),
+                None => "Namespace".to_string(),
+            },
+            ViewMode::Scheduler => "Scheduler".to_string(),
+            ViewMode::TaskEditor => "Task Editor".to_string(),
+            ViewMode::StartProcess => "Start Process".to_string(),
+            ViewMode::ProfileManagement => "Profiles".to_string(),
+            ViewMode::ProfileEditor => "Profile Editor".to_string(),
+            ViewMode::AlertManagement => "Alerts".to_string(),
+            ViewMode::AlertEditor => "Alert Editor".to_string(),
+            ViewMode::CheckpointManagement => "Checkpoints".to_string(),
+            ViewMode::MultiHost => "Multi-Host".to_string(),
+            ViewMode::HostManagement => "Hosts".to_string(),
+            ViewMode::Suspended => "Suspended".to_string(),
+            ViewMode::LogTail => "Log Tail".to_string(),
+            ViewMode::OrderedRestart => "Ordered Restart".to_string(),
+            ViewMode::UserSummary => "By User".to_string(),
+            ViewMode::HostDiff => "Host Diff".to_string(),
+            ViewMode::BootReport => "Boot Report".to_string(),
+            ViewMode::Dashboard => "Dashboard".to_string(),
+            ViewMode::ProcessTree => "Tree".to_string(),
+        }
+    }
+
+    // Clamp and apply a refresh interval to both the process refresh and the graph sampling
+    fn set_refresh_interval(&mut self, interval_ms: u64) {
+        self.refresh_interval = Duration::from_millis(interval_ms.clamp(MIN_REFRESH_MS, MAX_REFRESH_MS));
+        self.graph_data.set_sample_interval(self.refresh_interval);
+    }
+
+    // Re-read threads only for expanded processes, or every visible one when thread rows are on
+    fn refresh_threads(&mut self) {
+        let mut pids: Vec<u32> = self.thread_expanded.iter().copied().collect();
+        if self.show_threads {
+            pids.extend(self.process_manager.get_processes()
+                .iter()
+                .skip(self.scroll_offset)
+                .take(self.display_limit)
+                .map(|p| p.pid));
+        }
+        self.thread_cache.clear();
+        for pid in pids {
+            if !self.thread_cache.contains_key(&pid) {
+                let threads = self.process_manager.get_threads(pid);
+                self.thread_cache.insert(pid, threads);
+            }
+        }
+    }
+
+    // Direction of the current primary sort column
+    fn sort_ascending(&self) -> bool {
+        self.sort_mode.as_deref().is_none_or(|mode| self.settings.sort_ascending(mode))
+    }
+
+    // Flip and remember the direction of the current primary sort column, then re-sort
+    fn toggle_sort_direction(&mut self) {
+        if let Some(mode) = self.sort_mode.clone() {
+            let ascending = !self.settings.sort_ascending(&mode);
+            self.settings.sort_directions.insert(mode.clone(), ascending);
+            let _ = self.settings.save();
+            self.process_manager.set_sort(&mode, ascending);
+        }
+    }
+
+    // Write the notes map back to settings.toml
+    fn save_notes(&mut self) {
+        self.settings.process_notes = self.process_notes
+            .iter()
+            .map(|((name, start_timestamp), (pid, text))| crate::settings::ProcessNote {
+                pid: *pid,
+                name: name.clone(),
+                start_timestamp: *start_timestamp,
+                text: text.clone(),
+            })
+            .collect();
+        let _ = self.settings.save();
+    }
+
+    // Pin every running instance of a pinned name (so restarts and new sessions stay pinned) and forget exited ones
+    fn sync_pinned(&mut self) {
+        let pinned_names = &self.settings.pinned_names;
+        self.pinned_processes = self.process_manager.get_processes()
+            .iter()
+            .filter(|p| pinned_names.contains(&p.name))
+            .map(|p| (p.name.clone(), p.start_timestamp))
+            .collect();
+        self.process_manager.set_pinned(self.pinned_processes.clone());
+    }
+
+    // Run one scheduler action now, returning the line for the task log
+    fn execute_schedule_action(&mut self, action: &crate::scheduler::ScheduleAction) -> String {
+        match action {
+            crate::scheduler::ScheduleAction::RestartProcess { pattern } => {
+                match self.process_manager.restart_process_by_pattern(pattern) {
+                    Ok(pids) => {
+                        if pids.is_empty() {
+                            format!("No processes found matching '{}' to restart", pattern)
+                        } else {
+                            format!("Restarted {} process(es) matching '{}'", pids.len(), pattern)
+                        }
+                    },
+                    Err(e) => format!("Error restarting processes matching '{}': {}", pattern, e),
+                }
+            }
+            crate::scheduler::ScheduleAction::StartProcess { program, args } => {
+                let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+                match self.process_manager.start_process(program, &args_str, None, &[]) {
+                    Ok(pid) => format!("Started process '{}' (PID: {})", program, pid),
+                    Err(e) => format!("Error starting '{}': {}", program, e),
+                }
+            }
+            crate::scheduler::ScheduleAction::CleanupIdle { cpu_threshold, memory_threshold, duration_seconds, action } => {
+                match self.process_manager.cleanup_idle_processes(*cpu_threshold, *memory_threshold, *duration_seconds, action) {
+                    Ok(pids) => format!("Cleaned up {} idle processes", pids.len()),
+                    Err(e) => format!("Error: {}", e),
+                }
+            }
+            crate::scheduler::ScheduleAction::ApplyRule { rule } => {
+                self.rule_engine.set_rule(rule.clone());
+                self.process_manager.apply_rules(&mut self.rule_engine);
+                "Rule applied".to_string()
+            }
+            crate::scheduler::ScheduleAction::KillProcess { pid } => {
+                match self.process_manager.kill_process(*pid) {
+                    Ok(_) => format!("Killed process PID {}", pid),
+                    Err(e) => format!("Error killing PID {}: {}", pid, e),
+                }
+            }
+            crate::scheduler::ScheduleAction::StopProcess { pid } => {
+                match self.process_manager.stop_process(*pid) {
+                    Ok(_) => {
+                        track_suspension(&mut self.suspended_by_lpm, self.process_manager.get_processes(), *pid, "stop");
+                        format!("Stopped process PID {}", pid)
+                    }
+                    Err(e) => format!("Error stopping PID {}: {}", pid, e),
+                }
+            }
+            crate::scheduler::ScheduleAction::ContinueProcess { pid } => {
+                match self.process_manager.continue_process(*pid) {
+                    Ok(_) => {
+                        track_suspension(&mut self.suspended_by_lpm, self.process_manager.get_processes(), *pid, "continue");
+                        format!("Continued process PID {}", pid)
+                    }
+                    Err(e) => format!("Error continuing PID {}: {}", pid, e),
+                }
+            }
+            crate::scheduler::ScheduleAction::ReniceProcess { pid, nice } => {
+                match self.process_manager.set_niceness(*pid, *nice) {
+                    Ok(_) => format!("Reniced PID {} to {}", pid, nice),
+                    Err(e) => format!("Error renicing PID {}: {}", pid, e),
+                }
+            }
+            crate::scheduler::ScheduleAction::SendSignal { pid, signal } => {
+                match crate::signals::parse_signal(signal) {
+                    Some(number) => match self.process_manager.send_signal(*pid, number) {
+                        Ok(_) => format!("Sent {} to PID {}", signal, pid),
+                        Err(e) => format!("Error sending {} to PID {}: {}", signal, pid, e),
+                    },
+                    None => format!("Error: unknown signal '{}'", signal),
+                }
+            }
+        }
+    }
+
+    // Queue a destructive automatic action; it runs once the countdown elapses unless cancelled with X
+    fn arm_action(&mut self, description: String, action: AutoAction) {
+        let fire_at = std::time::Instant::now() + Duration::from_secs(self.settings.auto_action_countdown_secs);
+        self.pending_actions.push(PendingAction { description, fire_at, action });
+        self.needs_redraw = true;
+    }
+
+    // Fetch every host's processes in the background, unless a fetch is still in flight
+    fn fetch_hosts(&mut self) {
+        if self.host_results.is_some() || self.coordinator.get_hosts().is_empty() {
+            return;
+        }
+        let hosts: Vec<(String, String)> = self.coordinator.get_hosts().iter().map(|h| (h.address.clone(), h.name.clone())).collect();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.host_results = Some(receiver);
+        tokio::spawn(async move {
+            for (address, name) in hosts {
+                let result = match crate::coordinator::resolve_host(&address).await {
+                    Ok(resolved) => crate::coordinator::fetch_host_data(resolved, name).await.map(|processes| (resolved, processes)),
+                    Err(e) => Err(e),
+                };
+                if sender.send((address, result)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    // Apply the host answers that arrived since the last frame, and recompare an open host diff
+    fn collect_host_results(&mut self) {
+        let Some(receiver) = &self.host_results else {
+            return;
+        };
+        let mut results = Vec::new();
+        loop {
+            match receiver.try_recv() {
+                Ok(result) => results.push(result),
+                Err(std::sync::mpsc::TryRecvError::Empty) => break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    self.host_results = None;
+                    break;
+                }
+            }
+        }
+        if results.is_empty() {
+            return;
+        }
+        for (address, result) in results {
+            match result {
+                Ok((resolved, processes)) => {
+                    self.coordinator.set_resolved(&address, resolved);
+                    self.coordinator.update_host_data(&address, processes);
+                }
+                Err(e) => self.coordinator.mark_host_disconnected(&address, e),
+            }
+        }
+        if let Some(diff) = &self.host_diff {
+            self.host_diff = Some(self.coordinator.diff_hosts(&diff.host_a, &diff.host_b));
+        }
+        self.needs_redraw = true;
+    }
+
+    // Execute armed actions whose countdown has elapsed
+    fn run_pending_actions(&mut self) {
+        if self.pending_actions.is_empty() {
+            return;
+        }
+        // Keep the countdown ticking on screen
+        self.needs_redraw = true;
+        let now = std::time::Instant::now();
+        let (due, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_actions)
+            .into_iter()
+            .partition(|pending| pending.fire_at <= now);
+        self.pending_actions = waiting;
+        for pending in due {
+            match pending.action {
+                AutoAction::BlacklistKill { pid, name } => {
+                    // The process may have exited (or the entry expired) during the countdown
+                    let still_running = self.process_manager.get_processes().iter().any(|p| p.pid == pid && p.name == name);
+                    if !still_running || !self.kill_blacklist.iter().any(|e| e.name == name) {
+                        continue;
+                    }
+                    if self.process_manager.kill_process(pid).is_ok() {
+                        if let Some(entry) = self.kill_blacklist.iter_mut().find(|e| e.name == name) {
+                            entry.kills += 1;
+                        }
+                        self.input_state.message = Some((format!("Blacklist killed {} ({})", name, pid), false));
+                        self.input_state.message_timeout = Some(now + Duration::from_secs(3));
+                    }
+                }
+                AutoAction::OomKill { pid, name, reason } => {
+                    if self.process_manager.get_processes().iter().any(|p| p.pid == pid && p.name == name) {
+                        self.oom_kill(pid, &name, &reason);
+                    }
+                }
+                AutoAction::Scheduled { task_name, action } => {
+                    let result = self.execute_schedule_action(&action);
+                    self.scheduler.add_log_entry(task_name, result);
+                }
+            }
+        }
+    }
+
+    // Drop every armed action; vetoed blacklist kills are not re-armed for the same process
+    fn cancel_pending_actions(&mut self) {
+        let cancelled = self.pending_actions.len();
+        for pending in self.pending_actions.drain(..) {
+            match pending.action {
+                AutoAction::BlacklistKill { pid, .. } => {
+                    self.vetoed_pids.insert(pid);
+                }
+                AutoAction::OomKill { pid, name, .. } => {
+                    // The guard moves on to the next-largest process if memory stays low
+                    self.vetoed_pids.insert(pid);
+                    crate::oom_guard::audit(&format!("early-oom: kill of {} ({}) cancelled by the user", name, pid));
+                }
+                AutoAction::Scheduled { .. } => {}
+            }
+        }
+        self.input_state.message = Some((format!("Cancelled {} automatic action(s)", cancelled), false));
+        self.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+    }
+
+    // Kill any process whose name is on the blacklist, dropping expired entries first
+    fn enforce_kill_blacklist(&mut self) {
+        let now = std::time::Instant::now();
+        self.kill_blacklist.retain(|entry| entry.until.is_none_or(|until| until > now));
+        if self.kill_blacklist.is_empty() {
+            return;
+        }
+        let own_pid = std::process::id();
+        let mut killed = Vec::new();
+        let mut armed = Vec::new();
+        for process in self.process_manager.get_processes() {
+            if process.pid == own_pid || process::is_locked(process) {
+                continue;
+            }
+            if self.settings.armed_auto_actions {
+                let already_armed = self.pending_actions.iter()
+                    .any(|p| matches!(&p.action, AutoAction::BlacklistKill { pid, .. } if *pid == process.pid));
+                if self.kill_blacklist.iter().any(|e| e.name == process.name)
+                    && !already_armed
+                    && !self.vetoed_pids.contains(&process.pid)
+                {
+                    armed.push((process.pid, process.name.clone()));
+                }
+                continue;
+            }
+            if let Some(entry) = self.kill_blacklist.iter_mut().find(|e| e.name == process.name)
+                && self.process_manager.kill_process(process.pid).is_ok()
+            {
+                entry.kills += 1;
+                killed.push(format!("{} ({})", process.name, process.pid));
+            }
+        }
+        if !killed.is_empty() {
+            self.input_state.message = Some((format!("Blacklist killed respawned: {}", killed.join(", ")), false));
+            self.input_state.message_timeout = Some(now + Duration::from_secs(3));
+        }
+        for (pid, name) in armed {
+            self.arm_action(format!("Killing {} ({}) - blacklisted", name, pid), AutoAction::BlacklistKill { pid, name });
+        }
+    }
+
+    // Kill (or arm a kill of) the largest eligible process once available memory has stayed low long enough
+    fn enforce_oom_guard(&mut self) {
+        if !self.settings.oom_guard {
+            return;
+        }
+        let Some(available) = crate::oom_guard::read_mem_available(self.process_manager.get_proc_root()) else {
+            return;
+        };
+        let floor = self.settings.oom_guard_available_mb * 1024;
+        let sustain = Duration::from_secs(self.settings.oom_guard_sustain_secs);
+        if !self.oom_guard.observe(available, floor, sustain) {
+            return;
+        }
+        // One kill at a time: an armed kill runs or is cancelled before the next is picked
+        if self.pending_actions.iter().any(|p| matches!(p.action, AutoAction::OomKill { .. })) {
+            return;
+        }
+        let candidates: Vec<process::ProcessInfo> = self.process_manager.get_processes()
+            .iter()
+            .filter(|p| !process::is_locked(p) && !self.vetoed_pids.contains(&p.pid))
+            .cloned()
+            .collect();
+        let policy = crate::oom_guard::OomPolicy {
+            only_names: &self.settings.oom_guard_only_names,
+            never_names: &self.settings.oom_guard_never_names,
+            spare_root: self.settings.oom_guard_spare_root,
+        };
+        let Some(victim) = crate::oom_guard::pick_victim(&candidates, &policy) else {
+            return;
+        };
+        let (pid, name, memory) = (victim.pid, victim.name.clone(), victim.memory_usage);
+        let reason = format!(
+            "MemAvailable {} below {} for {}s, RSS {}",
+            crate::units::format_kib(available),
+            crate::units::format_kib(floor),
+            sustain.as_secs(),
+            crate::units::format_bytes(memory),
+        );
+        if self.settings.armed_auto_actions {
+            self.arm_action(format!("Early OOM: killing {} ({}) - {}", name, pid, reason), AutoAction::OomKill { pid, name, reason });
+        } else {
+            self.oom_kill(pid, &name, &reason);
         }
+        self.oom_guard.rearm();
+    }
+
+    fn oom_kill(&mut self, pid: u32, name: &str, reason: &str) {
+        let message = match self.process_manager.kill_process(pid) {
+            Ok(_) => (format!("Early OOM guard killed {} ({})", name, pid), false),
+            Err(e) => (format!("Early OOM guard could not kill {} ({}): {}", name, pid, e), true),
+        };
+        crate::oom_guard::audit(&format!("early-oom: {} - {}", message.0, reason));
+        self.input_state.message = Some(message);
+        self.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(5));
     }
 
     fn refresh(&mut self) {
-        // Throttle process updates to once per second
-        if self.last_process_refresh.elapsed() < Duration::from_secs(1) {
+        // Pick up new log lines on every frame so the tail view stays live
+        if let Some(tail) = &mut self.log_tail {
+            let _ = tail.poll();
+            self.needs_redraw = true;
+        }
+        // Armed auto-actions fire on time regardless of the refresh throttle
+        self.run_pending_actions();
+        // Advance a group restart every frame so each step starts as soon as the previous finishes
+        if let Some(restart) = &mut self.ordered_restart {
+            restart.tick(&mut self.process_manager);
+            self.needs_redraw = true;
+        }
+
+        self.collect_host_results();
+
+        // Paused: input and armed actions keep working, the snapshot stays as it is
+        if self.paused {
+            return;
+        }
+        // Throttle process updates to the refresh interval
+        if self.last_process_refresh.elapsed() < self.refresh_interval {
             return;
         }
         self.last_process_refresh = std::time::Instant::now();
+        self.refresh_count = self.refresh_count.wrapping_add(1);
+        self.fetch_hosts();
+        self.self_monitor.update();
+        self.power_monitor.update();
 
-        let prev_map: std::collections::HashMap<u32, process::ProcessInfo> = self.process_manager.get_processes().iter().map(|p| (p.pid, p.clone())).collect();
+        let prev_processes = self.process_manager.get_processes().clone();
+        let prev_map: std::collections::HashMap<u32, process::ProcessInfo> = prev_processes.iter().map(|p| (p.pid, p.clone())).collect();
         let prev_pids = self.prev_pids.clone();
-        self.process_manager.refresh();
+        // Track idle time against every scheduled cleanup's CPU threshold
+        let idle_thresholds = self.scheduler.get_tasks()
+            .iter()
+            .filter_map(|task| match &task.action {
+                crate::scheduler::ScheduleAction::CleanupIdle { cpu_threshold, .. } => Some(*cpu_threshold),
+                _ => None,
+            })
+            .collect();
+        self.process_manager.set_idle_thresholds(idle_thresholds);
+        self.process_manager.collect();
+        self.sync_pinned();
+
+        // Keep the fresh readings, but skip the re-sort and redraw while no process moved more than
+        // the tolerance; any PID appearing or exiting changes the fingerprint, so the order below is total
+        let fingerprint = process_fingerprint(
+            self.process_manager.get_processes(),
+            self.settings.refresh_change_tolerance,
+            self.process_manager.get_total_memory(),
+        );
+        let changed = self.settings.refresh_change_tolerance <= 0.0
+            || fingerprint != self.last_fingerprint
+            || self.last_full_refresh.elapsed() >= Duration::from_secs(self.settings.force_refresh_secs);
+        if changed {
+            self.process_manager.resort();
+            self.last_fingerprint = fingerprint;
+            self.last_full_refresh = std::time::Instant::now();
+            self.needs_redraw = true;
+        } else {
+            self.process_manager.keep_order_of(&prev_processes);
+        }
+
+        // Mark what moved since the previous refresh for the change highlight
+        let process_changes = if self.settings.change_highlight && !prev_map.is_empty() {
+            self.process_manager.get_processes()
+                .iter()
+                .filter_map(|process| {
+                    let change = match prev_map.get(&process.pid) {
+                        Some(prev) if prev.start_timestamp == process.start_timestamp => process_change(prev, process, &self.settings),
+                        _ => ProcessChange { cpu: true, memory: true, status: true }, // Started since the previous refresh
+                    };
+                    (change.cpu || change.memory || change.status).then_some((process.pid, change))
+                })
+                .collect()
+        } else {
+            std::collections::HashMap::new()
+        };
+        if process_changes != self.process_changes {
+            self.process_changes = process_changes;
+            self.needs_redraw = true;
+        }
+
+        // Forget lpm-suspended processes that have since exited
+        let proc_root = self.process_manager.get_proc_root().to_path_buf();
+        self.suspended_by_lpm.retain(|pid, _| proc_root.join(pid.to_string()).exists());
+        // Notes expire with their process; a listed PID with another start time was reused
+        let note_count = self.process_notes.len();
+        let listed_starts: std::collections::HashMap<u32, u64> = self.process_manager.get_processes()
+            .iter()
+            .map(|p| (p.pid, p.start_timestamp))
+            .collect();
+        self.process_notes.retain(|(_, start_timestamp), (pid, _)| {
+            proc_root.join(pid.to_string()).exists() && listed_starts.get(pid).is_none_or(|start| start == start_timestamp)
+        });
+        if self.process_notes.len() != note_count {
+            self.save_notes();
+        }
+
+        // Sockets of the process shown in the per-process view (only its own fds are read)
+        self.process_sockets = match self.selected_process_for_graph {
+            Some(pid) if matches!(self.view_mode, ViewMode::PerProcessGraph) => ports::sockets_of_pid(&proc_root, pid),
+            _ => Vec::new(),
+        };
+
+        // Explain the process in the per-process view once per process (may ask the package manager)
+        let viewed = self.selected_process_for_graph
+            .filter(|_| matches!(self.view_mode, ViewMode::PerProcessGraph))
+            .and_then(|pid| self.process_manager.get_processes().iter().find(|p| p.pid == pid));
+        match viewed {
+            Some(process) => {
+                let key = (process.pid, process.start_timestamp);
+                if self.process_explanation.as_ref().map(|(pid, start, _)| (*pid, *start)) != Some(key) {
+                    let explanation = explain::explain(&proc_root, process);
+                    self.process_explanation = Some((key.0, key.1, explanation));
+                }
+            }
+            None => self.process_explanation = None,
+        }
         
         // Apply profile-based prioritization if active
         if let Some(_profile_name) = self.profile_manager.get_active_profile() {
@@ -347,6 +1200,9 @@ impl App {
             });
         }
         
+        self.enforce_kill_blacklist();
+        self.enforce_oom_guard();
+        
         self.graph_data.update(&self.process_manager);
         let current: Vec<_> = self.process_manager.get_processes().iter().map(|p| p.pid).collect();
         let current_set: HashSet<u32> = current.iter().copied().collect();
@@ -384,15 +1240,27 @@ impl App {
                         0
                     }
                 };
+                // Only known for processes lpm started and reaped; taken even when the entry is skipped
+                let exit_reason = self.process_manager.take_child_exit(*pid);
+                // Skip fork-exec-exit noise unless the user asked to see it
+                if uptime_secs < self.settings.exit_log_min_lifetime_secs && !self.settings.exit_log_include_short_lived {
+                    self.process_first_seen.remove(pid);
+                    continue;
+                }
                 let entry = ProcessExitLogEntry {
                     pid: proc.pid,
+                    parent_pid: proc.parent_pid,
                     name: proc.name.clone(),
                     user: proc.user.clone(),
                     start_time: proc.start_time_str.clone(),
                     exit_time,
                     uptime_secs,
+                    exit_reason,
                 };
-                if self.process_exit_log.len() >= 100 {
+                if self.settings.exit_log_persist {
+                    crate::process_log::append_exit_log(&entry, self.settings.exit_log_max_bytes);
+                }
+                while self.process_exit_log.len() >= self.exit_log_capacity {
                     self.process_exit_log.pop_front();
                 }
                 self.process_exit_log.push_back(entry);
@@ -406,10 +1274,37 @@ impl App {
             .iter()
             .map(|p| (p.pid, p.name.clone()))
             .collect();
+        self.thread_expanded.retain(|pid| current_set.contains(pid));
+        // A lock ends when its process exits
+        process::release_exited_locks(self.process_manager.get_processes());
+        self.refresh_threads();
         
         // Check alerts
+        let known_alerts: HashSet<(String, Option<u32>)> = self.alert_manager.get_active_alerts()
+            .iter()
+            .filter(|a| a.is_firing())
+            .map(|a| (a.alert_name.clone(), a.process_pid))
+            .collect();
         self.alert_manager.check_alerts(self.process_manager.get_processes(), &prev_pids);
         
+        // Queue a toast for every alert that just fired
+        for alert in self.alert_manager.get_active_alerts().iter().filter(|a| a.is_firing()) {
+            if !known_alerts.contains(&(alert.alert_name.clone(), alert.process_pid)) {
+                self.alert_toasts.push_back((
+                    format!("{}: {}", alert.alert_name, alert.message),
+                    std::time::Instant::now() + Duration::from_secs(5),
+                    false,
+                ));
+            }
+        }
+        // ...and for every one that recovered
+        for message in self.alert_manager.take_recoveries() {
+            self.alert_toasts.push_back((message, std::time::Instant::now() + Duration::from_secs(5), true));
+        }
+        while self.alert_toasts.len() > 5 {
+            self.alert_toasts.pop_front();
+        }
+        
         // Check for due scheduler tasks every 5 seconds
         if self.scheduler_last_check.elapsed().as_secs() >= 5 {
             let due_tasks = self.scheduler.check_due_tasks();
@@ -422,63 +1317,12 @@ impl App {
                 .collect();
             
             for (task_name, action) in tasks_to_execute {
-                let result = match &action {
-                    crate::scheduler::ScheduleAction::RestartProcess { pattern } => {
-                        match self.process_manager.restart_process_by_pattern(pattern) {
-                            Ok(pids) => {
-                                if pids.is_empty() {
-                                    format!("No processes found matching '{}' to restart", pattern)
-                                } else {
-                                    format!("Restarted {} process(es) matching '{}'", pids.len(), pattern)
-                                }
-                            },
-                            Err(e) => format!("Error restarting processes matching '{}': {}", pattern, e),
-                        }
-                    }
-                    crate::scheduler::ScheduleAction::StartProcess { program, args } => {
-                        let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-                        match self.process_manager.start_process(program, &args_str, None, &[]) {
-                            Ok(pid) => format!("Started process '{}' (PID: {})", program, pid),
-                            Err(e) => format!("Error starting '{}': {}", program, e),
-                        }
-                    }
-                    crate::scheduler::ScheduleAction::CleanupIdle { cpu_threshold, memory_threshold, action, .. } => {
-                        // Note: duration_seconds is not currently checked - would require historical tracking
-                        match self.process_manager.cleanup_idle_processes(*cpu_threshold, *memory_threshold, action) {
-                            Ok(pids) => format!("Cleaned up {} idle processes", pids.len()),
-                            Err(e) => format!("Error: {}", e),
-                        }
-                    }
-                    crate::scheduler::ScheduleAction::ApplyRule { rule } => {
-                        self.rule_engine.set_rule(rule.clone());
-                        self.process_manager.apply_rules(&mut self.rule_engine);
-                        "Rule applied".to_string()
-                    }
-                    crate::scheduler::ScheduleAction::KillProcess { pid } => {
-                        match self.process_manager.kill_process(*pid) {
-                            Ok(_) => format!("Killed process PID {}", pid),
-                            Err(e) => format!("Error killing PID {}: {}", pid, e),
-                        }
-                    }
-                    crate::scheduler::ScheduleAction::StopProcess { pid } => {
-                        match self.process_manager.stop_process(*pid) {
-                            Ok(_) => format!("Stopped process PID {}", pid),
-                            Err(e) => format!("Error stopping PID {}: {}", pid, e),
-                        }
-                    }
-                    crate::scheduler::ScheduleAction::ContinueProcess { pid } => {
-                        match self.process_manager.continue_process(*pid) {
-                            Ok(_) => format!("Continued process PID {}", pid),
-                            Err(e) => format!("Error continuing PID {}: {}", pid, e),
-                        }
-                    }
-                    crate::scheduler::ScheduleAction::ReniceProcess { pid, nice } => {
-                        match self.process_manager.set_niceness(*pid, *nice) {
-                            Ok(_) => format!("Reniced PID {} to {}", pid, nice),
-                            Err(e) => format!("Error renicing PID {}: {}", pid, e),
-                        }
-                    }
-                };
+                if self.settings.armed_auto_actions && is_destructive_schedule_action(&action) {
+                    let description = format!("Task '{}': {}", task_name, describe_schedule_action(&action, self.process_manager.get_processes()));
+                    self.arm_action(description, AutoAction::Scheduled { task_name, action });
+                    continue;
+                }
+                let result = self.execute_schedule_action(&action);
                 self.scheduler.add_log_entry(task_name, result);
             }
             self.scheduler_last_check = std::time::Instant::now();
@@ -504,17 +1348,18 @@ fn draw_sidebar(f: &mut Frame, app: &App, area: Rect) {
     ];
 
     let current_index = match app.view_mode {
-        ViewMode::ProcessList | ViewMode::FilterSort | ViewMode::Sort | ViewMode::Filter | ViewMode::FilterInput | ViewMode::KillStop | ViewMode::ChangeNice | ViewMode::StartProcess | ViewMode::AdvancedFilter | ViewMode::PerProcessGraph | ViewMode::ProcessLog | ViewMode::GroupedView | ViewMode::ContainerDetail | ViewMode::NamespaceDetail => 0,
-        ViewMode::Statistics => 1,
+        ViewMode::ProcessList | ViewMode::ProcessTree | ViewMode::Suspended | ViewMode::LogTail | ViewMode::OrderedRestart | ViewMode::UserSummary | ViewMode::FilterSort | ViewMode::Sort | ViewMode::Filter | ViewMode::FilterInput | ViewMode::KillStop | ViewMode::ChangeNice | ViewMode::StartProcess | ViewMode::AdvancedFilter | ViewMode::PerProcessGraph | ViewMode::ProcessLog | ViewMode::GroupedView | ViewMode::ContainerDetail | ViewMode::NamespaceDetail => 0,
+        ViewMode::Statistics | ViewMode::Dashboard => 1,
         ViewMode::ProfileManagement | ViewMode::ProfileEditor => 2,
         ViewMode::AlertManagement | ViewMode::AlertEditor => 3,
         ViewMode::CheckpointManagement => 4,
-        ViewMode::MultiHost | ViewMode::HostManagement => 5,
+        ViewMode::MultiHost | ViewMode::HostManagement | ViewMode::HostDiff => 5,
         ViewMode::Scheduler | ViewMode::TaskEditor => 6,
         ViewMode::RuleInput => 7,
-        ViewMode::Help => 8,
+        ViewMode::Help | ViewMode::BootReport => 8,
     };
 
+    let badges = sidebar_badges(app);
     let list_items: Vec<ListItem> = items
         .iter()
         .enumerate()
@@ -524,7 +1369,11 @@ fn draw_sidebar(f: &mut Frame, app: &App, area: Rect) {
             } else {
                 Style::default().fg(Color::White)
             };
-            ListItem::new(item).style(style)
+            let mut spans = vec![Span::raw(item)];
+            if let Some((_, badge, color)) = badges.iter().find(|(label, _, _)| *label == item) {
+                spans.push(Span::styled(format!(" {}", badge), Style::default().fg(*color).add_modifier(Modifier::BOLD)));
+            }
+            ListItem::new(Line::from(spans)).style(style)
         })
         .collect();
 
@@ -532,251 +1381,540 @@ fn draw_sidebar(f: &mut Frame, app: &App, area: Rect) {
         .block(Block::default().borders(Borders::ALL).border_type(BorderType::Rounded).title("Menu").style(Style::default().fg(Color::White).bg(Color::Rgb(20, 20, 20))))
         .highlight_style(Style::default().fg(Color::White).bg(Color::Black).add_modifier(Modifier::BOLD));
 
-    f.render_widget(list, area);
+    let status_height = 4 + power_lines(app).len() as u16;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(status_height)])
+        .split(area);
+    f.render_widget(list, chunks[0]);
+    draw_refresh_status(f, app, chunks[1]);
 }
 
-//ui_renderer
-pub fn ui_renderer() -> Result<(), Box<dyn Error>> {
-    // Terminal initialization
-    enable_raw_mode()?;
-    let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    let mut app = App::new();
+// Counts shown next to sidebar items as (item label, badge text, color by severity)
+fn sidebar_badges(app: &App) -> Vec<(&'static str, String, Color)> {
+    let mut badges = vec![("Processes", app.process_manager.get_processes().len().to_string(), Color::Gray)];
+
+    // A process that died is more urgent than a threshold being crossed
+    let active: Vec<_> = app.alert_manager.get_active_alerts().iter().filter(|a| a.is_firing()).collect();
+    if !active.is_empty() {
+        let has_death = active.iter().any(|active_alert| {
+            app.alert_manager.get_alerts().iter().any(|alert| {
+                alert.name == active_alert.alert_name
+                    && matches!(alert.condition, crate::alert::AlertCondition::ProcessDied { .. })
+            })
+        });
+        let color = if has_death { Color::Red } else { Color::Yellow };
+        badges.push(("Alerts", active.len().to_string(), color));
+    }
 
-    loop {
-        app.refresh();
+    let hosts = app.coordinator.get_hosts();
+    if !hosts.is_empty() {
+        let connected = hosts.iter().filter(|h| h.connected).count();
+        let color = if connected == hosts.len() {
+            Color::Green
+        } else if connected == 0 {
+            Color::Red
+        } else {
+            Color::Yellow
+        };
+        badges.push(("Multi-Host", format!("{}/{}", connected, hosts.len()), color));
+    }
 
-        terminal.draw(|f| {
-            let chunks = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([
-                    Constraint::Length(20), // Sidebar width
-                    Constraint::Min(0),     // Main content
-                ])
-                .split(f.size());
+    badges
+}
 
-            draw_sidebar(f, &app, chunks[0]);
-            let main_area = chunks[1];
-            
-            // Render background
-            let background = Block::default().style(Style::default().bg(Color::White));
-            f.render_widget(background, main_area);
+// Clock, refresh spinner and data age, so a stalled refresh is noticeable
+fn draw_refresh_status(f: &mut Frame, app: &App, area: Rect) {
+    const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+    let age = app.last_process_refresh.elapsed().as_secs();
+    // Several missed refresh intervals in a row means refresh is stuck
+    let stale_after = (app.refresh_interval * 5).as_secs().max(5);
+    let age_style = if age >= stale_after && !app.paused {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+    let mut status = vec![
+        Line::from(Span::styled(Local::now().format("%H:%M:%S").to_string(), Style::default().fg(Color::White).add_modifier(Modifier::BOLD))),
+        Line::from(vec![
+            Span::styled(format!("{} ", SPINNER[app.refresh_count % SPINNER.len()]), Style::default().fg(Color::Cyan)),
+            if app.paused {
+                Span::styled(format!("[PAUSED] {}s ago", age), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            } else {
+                Span::styled(format!("updated {}s ago", age), age_style)
+            },
+        ]),
+    ];
+    status.extend(power_lines(app));
+    let paragraph = Paragraph::new(status)
+        .block(Block::default().borders(Borders::ALL).border_type(BorderType::Rounded).style(Style::default().fg(Color::White).bg(Color::Rgb(20, 20, 20))));
+    f.render_widget(paragraph, area);
+}
 
-            match app.view_mode {
-                ViewMode::ProcessList => draw_process_list(f, &mut app, main_area),
-                ViewMode::Statistics => graph::render_graph_dashboard(
-                    f,
-                    &app.graph_data,
-                    &app.current_stats_tab,
-                    app.process_manager.get_processes(),
-                    main_area,
-                ),
-                ViewMode::FilterSort => draw_filter_sort_menu(f, &app, main_area),
-                ViewMode::Sort => draw_sort_menu(f, &app, main_area),
-                ViewMode::Filter => draw_filter_menu(f, main_area),
-                ViewMode::FilterInput => draw_filter_input_menu(f, &app, main_area),
-                ViewMode::AdvancedFilter => draw_advanced_filter_input(f, &mut app, main_area),
-                ViewMode::KillStop => draw_kill_stop_menu(f, &mut app, main_area),
-                ViewMode::ChangeNice => draw_change_nice_menu(f, &mut app, main_area),
-                ViewMode::PerProcessGraph => render_per_process_graph_tab(f, main_area, &app),
-                ViewMode::RuleInput => draw_rule_input(f, &app, main_area), //for scripting
-                ViewMode::GroupedView => draw_grouped_view(f, &mut app, main_area),
-                ViewMode::ContainerDetail => draw_container_detail_view(f, &mut app, main_area),
-                ViewMode::NamespaceDetail => draw_namespace_detail_view(f, &mut app, main_area),
-                ViewMode::Scheduler => draw_scheduler_view(f, &mut app, main_area),
-                ViewMode::StartProcess => draw_start_process_menu(f, &mut app, main_area),
-                ViewMode::ProfileManagement => draw_profile_management(f, &mut app, main_area),
-                ViewMode::ProfileEditor => draw_profile_editor(f, &mut app, main_area),
-                ViewMode::AlertManagement => draw_alert_management(f, &mut app, main_area),
-                ViewMode::AlertEditor => draw_alert_editor(f, &mut app, main_area),
-                ViewMode::CheckpointManagement => draw_checkpoint_management(f, &mut app, main_area),
-                ViewMode::MultiHost => draw_multi_host_view(f, &mut app, main_area),
-                ViewMode::HostManagement => draw_host_management(f, &mut app, main_area),
-                ViewMode::TaskEditor => draw_task_editor(f, &mut app, main_area),
-                ViewMode::ProcessLog => {
-                    let size = main_area;
-                    // Filter log if needed
-                    let log: Vec<_> = if app.log_filter_input.is_empty() {
-                        app.process_exit_log.make_contiguous().to_vec()
-                    } else {
-                        let query = app.log_filter_input.to_lowercase();
-                        app.process_exit_log
-                            .iter()
-                            .filter(|entry| {
-                                entry.name.to_lowercase().contains(&query)
-                                    || entry.user.as_ref().map(|u| u.to_lowercase().contains(&query)).unwrap_or(false)
-                                    || entry.pid.to_string().contains(&query)
-                            })
-                            .cloned()
-                            .collect()
-                    };
-                    // Draw filter input at top (make it 3 lines tall)
-                    let group_status = match app.log_group_mode {
-                        LogGroupMode::None => "Ungrouped (press 'g' to group)",
-                        LogGroupMode::Name => "Grouped by Name (press 'g' to group by PPID, 'u' to ungroup)",
-                        LogGroupMode::PPID => "Grouped by PPID (press 'g' to group by User, 'u' to ungroup)",
-                        LogGroupMode::User => "Grouped by User (press 'g' to ungroup, 'u' to ungroup)",
-                    };
-                    let filter_line = if app.log_filter_active {
-                        format!("/{}", app.log_filter_input)
-                    } else if !app.log_filter_input.is_empty() {
-                        format!("Filter: {} | {}", app.log_filter_input, group_status)
-                    } else {
-                        format!("{}\nPress / to search/filter, ↑/↓/PgUp/PgDn to scroll, g: group, u: ungroup, Esc/q: back", group_status)
-                    };
-                    let chunks = Layout::default()
+// Battery line plus CPU clock/governor for the status box; empty on machines without a battery
+fn power_lines(app: &App) -> Vec<Line<'static>> {
+    let Some(power) = app.power_monitor.state() else {
+        return Vec::new();
+    };
+    let battery_color = match power.battery_percent {
+        _ if !power.on_battery() => Color::Green,
+        p if p < 20.0 => Color::Red,
+        p if p < 50.0 => Color::Yellow,
+        _ => Color::White,
+    };
+    let icon = if power.on_battery() { "🔋" } else { "🔌" };
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{} {:.0}% {}", icon, power.battery_percent, power.status.label()),
+        Style::default().fg(battery_color),
+    ))];
+    // Clock relative to max shows whether frequency scaling is holding the CPU back
+    if let Some(percent) = power.cpu_freq_percent() {
+        let governor = power.governor.as_deref().unwrap_or("");
+        let clock_color = if percent < 50.0 { Color::Yellow } else { Color::Gray };
+        lines.push(Line::from(Span::styled(format!("clk {:.0}% {}", percent, governor), Style::default().fg(clock_color))));
+    }
+    lines
+}
+
+//ui_renderer
+pub fn ui_renderer(interval_ms: Option<u64>) -> Result<(), Box<dyn Error>> {
+    // Restore the terminal before the panic message is printed, so a crash in a
+    // draw function doesn't leave the shell in raw mode / alternate screen
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        default_hook(info);
+    }));
+
+    // Terminal initialization
+    enable_raw_mode()?;
+    let mut stdout = stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    // Run the main loop, then always clean up - even if it returned an error
+    let result = run_ui_loop(&mut terminal, interval_ms);
+
+    // Cleanup and restore terminal
+    restore_terminal();
+    terminal.show_cursor()?;
+    let _ = std::panic::take_hook();
+    
+    result
+}
+
+// Disable raw mode and leave the alternate screen (errors are ignored, this is best-effort)
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(stdout(), LeaveAlternateScreen, crossterm::cursor::Show);
+}
+
+// Main refresh/draw/input loop of the TUI
+fn run_ui_loop(terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>, interval_ms: Option<u64>) -> Result<(), Box<dyn Error>> {
+    let mut app = App::new();
+    app.set_refresh_interval(interval_ms.unwrap_or(app.settings.refresh_interval_ms));
+
+    loop {
+        app.refresh();
+        
+        // Drop expired alert toasts
+        let now = std::time::Instant::now();
+        let toast_count = app.alert_toasts.len();
+        app.alert_toasts.retain(|(_, expires, _)| *expires > now);
+        if app.alert_toasts.len() != toast_count {
+            app.needs_redraw = true;
+        }
+        // Expire timed status messages
+        if app.input_state.message_timeout.is_some_and(|timeout| timeout <= now) {
+            app.input_state.message = None;
+            app.input_state.message_timeout = None;
+            app.needs_redraw = true;
+        }
+
+        // Redraw when something changed, and at least once a second for clocks and countdowns
+        if app.needs_redraw || app.last_draw.elapsed() >= Duration::from_secs(1) {
+            terminal.draw(|f| {
+                let chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([
+                        Constraint::Length(20), // Sidebar width
+                        Constraint::Min(0),     // Main content
+                    ])
+                    .split(f.size());
+
+                draw_sidebar(f, &app, chunks[0]);
+                let mut main_area = chunks[1];
+
+                // System CPU/memory gauges, on every view
+                if app.settings.show_gauge_header {
+                    let header_chunks = Layout::default()
                         .direction(Direction::Vertical)
-                        .constraints([
-                            Constraint::Length(5), // Increase height to accommodate two lines
-                            Constraint::Min(0),
-                        ])
-                        .split(size);
-                    let filter_para = Paragraph::new(filter_line)
-                        .block(Block::default().borders(Borders::ALL).title("Search/Filter/Group").style(Style::default().fg(Color::Black)));
-                    f.render_widget(filter_para, chunks[0]);
-                    // Calculate visible log window
-                    let log_height = chunks[1].height as usize;
-                    let (visible, is_grouped) = match app.log_group_mode {
-                        LogGroupMode::None => {
-                            let total = log.len();
-                            let max_scroll = total.saturating_sub(log_height);
-                            let offset = app.log_scroll_offset.min(max_scroll);
-                            (&log[offset..(offset + log_height).min(total)], false)
-                        }
-                        LogGroupMode::Name | LogGroupMode::PPID | LogGroupMode::User => {
-                            use std::collections::BTreeMap;
-                            let mut grouped: BTreeMap<String, Vec<&ProcessExitLogEntry>> = BTreeMap::new();
-                            for entry in &log {
-                                let key = match app.log_group_mode {
-                                    LogGroupMode::Name => entry.name.clone(),
-                                    LogGroupMode::PPID => entry.user.clone().unwrap_or_else(|| "Unknown".to_string()), // Use user for now, will fix below
-                                    LogGroupMode::User => entry.user.clone().unwrap_or_else(|| "Unknown".to_string()),
-                                    LogGroupMode::None => unreachable!(),
-                                };
-                                grouped.entry(key).or_default().push(entry);
+                        .constraints([Constraint::Length(1), Constraint::Min(0)])
+                        .split(main_area);
+                    graph::render_gauge_header(f, header_chunks[0], &app.graph_data);
+                    main_area = header_chunks[1];
+                }
+                
+                // Breadcrumb for nested views
+                if !app.view_stack.is_empty() {
+                    let breadcrumb_chunks = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Length(1), Constraint::Min(0)])
+                        .split(main_area);
+                    let mut crumbs: Vec<String> = app.view_stack.iter().map(|v| app.view_label(*v)).collect();
+                    crumbs.push(app.view_label(app.view_mode));
+                    let breadcrumb = Paragraph::new(Line::from(vec![
+                        Span::styled(crumbs.join(" > "), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+                        Span::styled("   [Esc] Back  [Home] Process List", Style::default().fg(Color::Gray)),
+                    ]))
+                    .style(Style::default().bg(Color::DarkGray));
+                    f.render_widget(breadcrumb, breadcrumb_chunks[0]);
+                    main_area = breadcrumb_chunks[1];
+                }
+                
+                // Render background
+                let background = Block::default().style(Style::default().bg(Color::White));
+                f.render_widget(background, main_area);
+
+                match app.view_mode {
+                    ViewMode::ProcessList => draw_process_list(f, &mut app, main_area),
+                    ViewMode::Statistics => graph::render_graph_dashboard(
+                        f,
+                        &app.graph_data,
+                        &app.current_stats_tab,
+                        app.process_manager.get_processes(),
+                        main_area,
+                    ),
+                    ViewMode::FilterSort => draw_filter_sort_menu(f, &app, main_area),
+                    ViewMode::Sort => draw_sort_menu(f, &app, main_area),
+                    ViewMode::Filter => draw_filter_menu(f, main_area),
+                    ViewMode::FilterInput => draw_filter_input_menu(f, &app, main_area),
+                    ViewMode::AdvancedFilter => draw_advanced_filter_input(f, &mut app, main_area),
+                    ViewMode::KillStop => draw_kill_stop_menu(f, &mut app, main_area),
+                    ViewMode::ChangeNice => draw_change_nice_menu(f, &mut app, main_area),
+                    ViewMode::PerProcessGraph => render_per_process_graph_tab(f, main_area, &app),
+                    ViewMode::RuleInput => draw_rule_input(f, &app, main_area), //for scripting
+                    ViewMode::GroupedView => draw_grouped_view(f, &mut app, main_area),
+                    ViewMode::ContainerDetail => draw_container_detail_view(f, &mut app, main_area),
+                    ViewMode::NamespaceDetail => draw_namespace_detail_view(f, &mut app, main_area),
+                    ViewMode::Scheduler => draw_scheduler_view(f, &mut app, main_area),
+                    ViewMode::StartProcess => draw_start_process_menu(f, &mut app, main_area),
+                    ViewMode::ProfileManagement => draw_profile_management(f, &mut app, main_area),
+                    ViewMode::ProfileEditor => draw_profile_editor(f, &mut app, main_area),
+                    ViewMode::AlertManagement => draw_alert_management(f, &mut app, main_area),
+                    ViewMode::AlertEditor => draw_alert_editor(f, &mut app, main_area),
+                    ViewMode::CheckpointManagement => draw_checkpoint_management(f, &mut app, main_area),
+                    ViewMode::MultiHost => draw_multi_host_view(f, &mut app, main_area),
+                    ViewMode::HostManagement => draw_host_management(f, &mut app, main_area),
+                    ViewMode::TaskEditor => draw_task_editor(f, &mut app, main_area),
+                    ViewMode::Suspended => draw_suspended_view(f, &mut app, main_area),
+                    ViewMode::LogTail => draw_log_tail(f, &mut app, main_area),
+                    ViewMode::OrderedRestart => draw_ordered_restart(f, &mut app, main_area),
+                    ViewMode::UserSummary => draw_user_summary(f, &mut app, main_area),
+                    ViewMode::BootReport => draw_boot_report(f, &mut app, main_area),
+                    ViewMode::Dashboard => draw_dashboard(f, &mut app, main_area),
+                    ViewMode::ProcessTree => draw_process_tree(f, &mut app, main_area),
+                    ViewMode::HostDiff => draw_host_diff(f, &mut app, main_area),
+                    ViewMode::ProcessLog => {
+                        let size = main_area;
+                        // Filter log if needed
+                        let log: Vec<_> = if app.log_filter_input.is_empty() {
+                            app.process_exit_log.make_contiguous().to_vec()
+                        } else {
+                            let query = app.log_filter_input.to_lowercase();
+                            app.process_exit_log
+                                .iter()
+                                .filter(|entry| {
+                                    entry.name.to_lowercase().contains(&query)
+                                        || entry.user.as_ref().map(|u| u.to_lowercase().contains(&query)).unwrap_or(false)
+                                        || entry.pid.to_string().contains(&query)
+                                })
+                                .cloned()
+                                .collect()
+                        };
+                        // Draw filter input at top (make it 3 lines tall)
+                        let group_status = match app.log_group_mode {
+                            LogGroupMode::None => "Ungrouped (press 'g' to group)",
+                            LogGroupMode::Name => "Grouped by Name (press 'g' to group by PPID, 'u' to ungroup)",
+                            LogGroupMode::PPID => "Grouped by PPID (press 'g' to group by User, 'u' to ungroup)",
+                            LogGroupMode::User => "Grouped by User (press 'g' to group by Exit Reason, 'u' to ungroup)",
+                            LogGroupMode::ExitReason => "Grouped by Exit Reason (press 'g' to ungroup, 'u' to ungroup)",
+                        };
+                        let filter_line = if app.log_filter_active {
+                            format!("/{}", app.log_filter_input)
+                        } else if !app.log_filter_input.is_empty() {
+                            format!("Filter: {} | {}", app.log_filter_input, group_status)
+                        } else {
+                            format!("{}\nPress / to search/filter, ↑/↓/PgUp/PgDn to scroll, g: group, u: ungroup, Esc/q: back", group_status)
+                        };
+                        let lifetime_status = if app.settings.exit_log_include_short_lived {
+                            format!("Showing all exits ([i] hide processes that lived < {}s, [+/-] adjust)", app.settings.exit_log_min_lifetime_secs)
+                        } else {
+                            format!("Hiding processes that lived < {}s ([i] include them, [+/-] adjust)", app.settings.exit_log_min_lifetime_secs)
+                        };
+                        let filter_line = format!("{}\n{}", filter_line, lifetime_status);
+                        let chunks = Layout::default()
+                            .direction(Direction::Vertical)
+                            .constraints([
+                                Constraint::Length(5), // Increase height to accommodate two lines
+                                Constraint::Min(0),
+                            ])
+                            .split(size);
+                        let filter_para = Paragraph::new(filter_line)
+                            .block(Block::default().borders(Borders::ALL).title("Search/Filter/Group").style(Style::default().fg(Color::Black)));
+                        f.render_widget(filter_para, chunks[0]);
+                        // Calculate visible log window
+                        let log_height = chunks[1].height as usize;
+                        let (visible, is_grouped) = match app.log_group_mode {
+                            LogGroupMode::None => {
+                                let total = log.len();
+                                let max_scroll = total.saturating_sub(log_height);
+                                let offset = app.log_scroll_offset.min(max_scroll);
+                                (&log[offset..(offset + log_height).min(total)], false)
                             }
-                            // If grouping by PPID, fix key
-                            if app.log_group_mode == LogGroupMode::PPID {
-                                grouped.clear();
+                            LogGroupMode::Name | LogGroupMode::PPID | LogGroupMode::User | LogGroupMode::ExitReason => {
+                                use std::collections::BTreeMap;
+                                let mut grouped: BTreeMap<String, Vec<&ProcessExitLogEntry>> = BTreeMap::new();
                                 for entry in &log {
-                                    let key = format!("{}", entry.pid); // Actually, we want PPID, but ProcessExitLogEntry doesn't have it. For now, use PID.
+                                    let key = match app.log_group_mode {
+                                        LogGroupMode::Name => entry.name.clone(),
+                                        LogGroupMode::PPID => entry.parent_pid.map_or_else(|| "Unknown".to_string(), |ppid| ppid.to_string()),
+                                        LogGroupMode::User => entry.user.clone().unwrap_or_else(|| "Unknown".to_string()),
+                                        LogGroupMode::ExitReason => crate::process_log::exit_reason_bucket(entry.exit_reason),
+                                        LogGroupMode::None => unreachable!(),
+                                    };
                                     grouped.entry(key).or_default().push(entry);
                                 }
-                            }
-                            // Build summary rows
-                            let mut summary: Vec<(String, usize, u64, u64, u64, String)> = Vec::new();
-                            for (key, entries) in grouped.iter() {
-                                let count = entries.len();
-                                let min_uptime = entries.iter().map(|e| e.uptime_secs).min().unwrap_or(0);
-                                let max_uptime = entries.iter().map(|e| e.uptime_secs).max().unwrap_or(0);
-                                let avg_uptime = if count > 0 { entries.iter().map(|e| e.uptime_secs).sum::<u64>() / count as u64 } else { 0 };
-                                let most_recent = entries.iter().map(|e| e.exit_time).max().map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap_or_default();
-                                summary.push((key.clone(), count, min_uptime, max_uptime, avg_uptime, most_recent));
-                            }
-                            // Sort by count descending
-                            summary.sort_by(|a, b| b.1.cmp(&a.1));
-                            let total = summary.len();
-                            let max_scroll = total.saturating_sub(log_height);
-                            let offset = app.log_scroll_offset.min(max_scroll);
-                            let visible = &summary[offset..(offset + log_height).min(total)];
-                            // Render summary table
-                            let header = Row::new(vec![
-                                Cell::from(match app.log_group_mode {
-                                    LogGroupMode::Name => "Name",
-                                    LogGroupMode::PPID => "PPID",
-                                    LogGroupMode::User => "User",
-                                    LogGroupMode::None => unreachable!(),
-                                }).style(Style::default().fg(Color::Yellow)),
-                                Cell::from("Count").style(Style::default().fg(Color::Green)),
-                                Cell::from("Min Uptime").style(Style::default().fg(Color::Cyan)),
-                                Cell::from("Max Uptime").style(Style::default().fg(Color::Cyan)),
-                                Cell::from("Avg Uptime").style(Style::default().fg(Color::Cyan)),
-                                Cell::from("Most Recent Exit").style(Style::default().fg(Color::Blue)),
-                            ]);
-                            let rows: Vec<Row> = visible.iter().map(|(key, count, min, max, avg, recent)| {
-                                Row::new(vec![
-                                    Cell::from(key.clone()),
-                                    Cell::from(count.to_string()),
-                                    Cell::from(format!("{}s", min)),
-                                    Cell::from(format!("{}s", max)),
-                                    Cell::from(format!("{}s", avg)),
-                                    Cell::from(recent.clone()),
-                                ])
-                            }).collect();
-                            let table = Table::new(rows)
-                                .header(header)
-                                .block(Block::default().borders(Borders::ALL).title("Process Log (Grouped)").style(Style::default().fg(Color::Black)))
-                                .widths(&[
-                                    Constraint::Length(20),
-                                    Constraint::Length(8),
-                                    Constraint::Length(12),
-                                    Constraint::Length(12),
-                                    Constraint::Length(12),
-                                    Constraint::Length(20),
+                                // Build summary rows
+                                let mut summary: Vec<(String, usize, u64, u64, u64, String, String)> = Vec::new();
+                                for (key, entries) in grouped.iter() {
+                                    let count = entries.len();
+                                    let min_uptime = entries.iter().map(|e| e.uptime_secs).min().unwrap_or(0);
+                                    let max_uptime = entries.iter().map(|e| e.uptime_secs).max().unwrap_or(0);
+                                    let avg_uptime = if count > 0 { entries.iter().map(|e| e.uptime_secs).sum::<u64>() / count as u64 } else { 0 };
+                                    let latest = entries.iter().max_by_key(|e| e.exit_time);
+                                    let most_recent = latest.map(|e| e.exit_time.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap_or_default();
+                                    let example = latest.map(|e| format!("{} ({})", e.name, e.pid)).unwrap_or_default();
+                                    summary.push((key.clone(), count, min_uptime, max_uptime, avg_uptime, most_recent, example));
+                                }
+                                // Sort by count descending
+                                summary.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+                                let total = summary.len();
+                                let max_scroll = total.saturating_sub(log_height);
+                                let offset = app.log_scroll_offset.min(max_scroll);
+                                let visible = &summary[offset..(offset + log_height).min(total)];
+                                // Render summary table
+                                let header = Row::new(vec![
+                                    Cell::from(match app.log_group_mode {
+                                        LogGroupMode::Name => "Name",
+                                        LogGroupMode::PPID => "PPID",
+                                        LogGroupMode::User => "User",
+                                        LogGroupMode::ExitReason => "Exit Reason",
+                                        LogGroupMode::None => unreachable!(),
+                                    }).style(Style::default().fg(Color::Yellow)),
+                                    Cell::from("Count").style(Style::default().fg(Color::Green)),
+                                    Cell::from("Min Uptime").style(Style::default().fg(Color::Cyan)),
+                                    Cell::from("Max Uptime").style(Style::default().fg(Color::Cyan)),
+                                    Cell::from("Avg Uptime").style(Style::default().fg(Color::Cyan)),
+                                    Cell::from("Most Recent Exit").style(Style::default().fg(Color::Blue)),
+                                    Cell::from("Latest Process").style(Style::default().fg(Color::Blue)),
                                 ]);
-                            f.render_widget(table, chunks[1]);
-                            (&[][..], true)
+                                let rows: Vec<Row> = visible.iter().map(|(key, count, min, max, avg, recent, example)| {
+                                    Row::new(vec![
+                                        Cell::from(key.clone()),
+                                        Cell::from(count.to_string()),
+                                        Cell::from(format!("{}s", min)),
+                                        Cell::from(format!("{}s", max)),
+                                        Cell::from(format!("{}s", avg)),
+                                        Cell::from(recent.clone()),
+                                        Cell::from(example.clone()),
+                                    ])
+                                }).collect();
+                                let table = Table::new(rows)
+                                    .header(header)
+                                    .block(Block::default().borders(Borders::ALL).title("Process Log (Grouped)").style(Style::default().fg(Color::Black)))
+                                    .widths(&[
+                                        Constraint::Length(20),
+                                        Constraint::Length(8),
+                                        Constraint::Length(12),
+                                        Constraint::Length(12),
+                                        Constraint::Length(12),
+                                        Constraint::Length(20),
+                                        Constraint::Length(28),
+                                    ]);
+                                f.render_widget(table, chunks[1]);
+                                (&[][..], true)
+                            }
+                        };
+                        if !is_grouped {
+                            render_process_log_tab(f, chunks[1], visible);
                         }
-                    };
-                    if !is_grouped {
-                        render_process_log_tab(f, chunks[1], visible);
-                    }
-                },
-                ViewMode::Help => {
-                    let size = main_area;
-                    let help_text = vec![
-                        Line::from(vec![Span::styled("Linux Process Manager - Help", Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))]),
-                        Line::from(""),
-                        Line::from(vec![Span::styled("Navigation:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
-                        Line::from("  [S] - Statistics/Graphs (CPU, Memory, I/O monitoring)"),
-                        Line::from("  [1] - Filter/Sort processes"),
-                        Line::from("  [2] - Change process priority (nice value)"),
-                        Line::from("  [3] - Kill/Stop/Terminate processes"),
-                        Line::from("  [4] - Per-Process Graphs"),
-                        Line::from("  [5] - Process Exit Log"),
-                        Line::from("  [G] - Grouped View (containers/cgroups)"),
-                        Line::from("  [J] - Job Scheduler"),
-                        Line::from("  [N] - Start New Process"),
-                        Line::from("  [P] - Profile Management"),
-                        Line::from("  [A] - Alert Management"),
-                        Line::from("  [C] - Checkpoint Management (CRIU)"),
-                        Line::from("  [H] - Host Management (Multi-Host)"),
-                        Line::from(""),
-                        Line::from(vec![Span::styled("Controls:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
-                        Line::from("  ↑/↓ - Navigate up/down"),
-                        Line::from("  Enter - Select/Confirm"),
-                        Line::from("  Esc - Go back"),
-                        Line::from("  Q - Quit application"),
-                        Line::from(""),
-                        Line::from(vec![Span::styled("Press Esc or Q to return", Style::default().fg(Color::Cyan))]),
-                    ];
-                    let para = Paragraph::new(help_text)
-                        .block(Block::default().borders(Borders::ALL).title("Help - Press Esc to go back").style(Style::default().fg(Color::Black)));
-                    f.render_widget(para, size);
-                },
-            }
-        })?;
+                    },
+                    ViewMode::Help => {
+                        let size = main_area;
+                        let help_text = vec![
+                            Line::from(vec![Span::styled("Linux Process Manager - Help", Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))]),
+                            Line::from(""),
+                            Line::from(vec![Span::styled("Navigation:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
+                            Line::from("  [S] - Statistics/Graphs (CPU, Memory, I/O monitoring)"),
+                            Line::from("  [1] - Filter/Sort processes"),
+                            Line::from("  [2] - Change process priority (nice value)"),
+                            Line::from("  [3] - Kill/Stop/Terminate processes"),
+                            Line::from("  [4] - Per-Process Graphs"),
+                            Line::from("  [5] - Process Exit Log"),
+                            Line::from("  [G] - Grouped View (containers/cgroups)"),
+                            Line::from("  [J] - Job Scheduler"),
+                            Line::from("  [N] - Start New Process"),
+                            Line::from("  [L] - Tail the selected process's log file"),
+                            Line::from("  [P] - Profile Management"),
+                            Line::from("  [A] - Alert Management"),
+                            Line::from("  [C] - Checkpoint Management (CRIU)"),
+                            Line::from("  [H] - Host Management (Multi-Host)"),
+                            Line::from(""),
+                            Line::from(vec![Span::styled("Controls:", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
+                            Line::from("  ↑/↓ - Navigate up/down"),
+                            Line::from("  Enter - Select/Confirm"),
+                            Line::from("  Esc - Go back"),
+                            Line::from("  F2 - Show/hide the CPU/memory gauge header"),
+                            Line::from("  Q - Quit application"),
+                            Line::from(""),
+                            Line::from(vec![Span::styled("Diagnostics (lpm itself):", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))]),
+                            match app.self_monitor.stats() {
+                                Some(stats) => Line::from(format!(
+                                    "  CPU {:.1}%  |  RSS {}  |  Threads {}  |  Open FDs {}",
+                                    stats.cpu_percent,
+                                    crate::units::format_bytes(stats.rss_bytes),
+                                    stats.threads,
+                                    stats.open_fds
+                                )),
+                                None => Line::from("  Unavailable (/proc/self could not be read)"),
+                            },
+                            Line::from("  [b] - What started at boot"),
+                            Line::from(""),
+                            Line::from(vec![Span::styled("Press Esc or Q to return", Style::default().fg(Color::Cyan))]),
+                        ];
+                        let para = Paragraph::new(help_text)
+                            .block(Block::default().borders(Borders::ALL).title("Help - Press Esc to go back").style(Style::default().fg(Color::Black)));
+                        f.render_widget(para, size);
+                    },
+                }
+                
+                // Alert toasts are drawn on top of whatever view is active
+                draw_alert_toasts(f, &app, main_area);
+                draw_pending_actions(f, &app, main_area);
+                draw_timed_message(f, &app, main_area);
+            })?;
+            app.needs_redraw = false;
+            app.last_draw = now;
+        }
 
+        let previous_view = app.view_mode;
         if handle_events(&mut app)? {
             break;
         }
+        app.track_view_change(previous_view);
 
         sleep(Duration::from_millis(100));
     }
 
-    // Cleanup and restore terminal
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
-    
     Ok(())
 }
 
+// Draw stacked alert toasts in the top-right corner of the main area
+// Show a timed status message on the bottom line, for views without their own status area
+fn draw_timed_message(f: &mut Frame, app: &App, area: Rect) {
+    let (message, is_error) = match (&app.input_state.message, app.input_state.message_timeout) {
+        (Some(message), Some(_)) => message,
+        _ => return,
+    };
+    if area.height == 0 {
+        return;
+    }
+    let line_area = Rect {
+        x: area.x,
+        y: area.y + area.height - 1,
+        width: area.width,
+        height: 1,
+    };
+    let style = if *is_error {
+        Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White).bg(Color::Green).add_modifier(Modifier::BOLD)
+    };
+    f.render_widget(ratatui::widgets::Clear, line_area);
+    f.render_widget(Paragraph::new(format!(" {}", message)).style(style), line_area);
+}
+
+fn draw_alert_toasts(f: &mut Frame, app: &App, area: Rect) {
+    use ratatui::widgets::{Clear, Wrap};
+    
+    if app.alert_toasts.is_empty() {
+        return;
+    }
+    let width = (area.width / 2).max(30).min(area.width);
+    let toast_height = 4;
+    for (i, (message, _, recovered)) in app.alert_toasts.iter().enumerate() {
+        let y = area.y + 1 + (i as u16) * toast_height;
+        if y + toast_height > area.y + area.height {
+            break;
+        }
+        let toast_area = Rect {
+            x: area.x + area.width - width,
+            y,
+            width,
+            height: toast_height,
+        };
+        let (background, title) = if *recovered {
+            (Color::Green, "Recovered - [Esc] dismiss")
+        } else {
+            (Color::Red, "Alert - [Esc] dismiss")
+        };
+        let toast = Paragraph::new(message.as_str())
+            .style(Style::default().fg(Color::White).bg(background).add_modifier(Modifier::BOLD))
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(Clear, toast_area);
+        f.render_widget(toast, toast_area);
+    }
+}
+
+// Countdown banner for armed auto-actions, along the bottom of the view
+fn draw_pending_actions(f: &mut Frame, app: &App, area: Rect) {
+    use ratatui::widgets::Clear;
+
+    if app.pending_actions.is_empty() {
+        return;
+    }
+    let now = std::time::Instant::now();
+    let lines: Vec<Line> = app.pending_actions
+        .iter()
+        .map(|pending| {
+            let remaining = pending.fire_at.saturating_duration_since(now).as_secs() + 1;
+            Line::from(format!("{} in {}s", pending.description, remaining))
+        })
+        .collect();
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let width = (area.width * 2 / 3).max(40).min(area.width);
+    let banner_area = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height - height,
+        width,
+        height,
+    };
+    let banner = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD))
+        .block(Block::default().borders(Borders::ALL).title("Automatic action - [X] cancel"));
+    f.render_widget(Clear, banner_area);
+    f.render_widget(banner, banner_area);
+}
+
 const PROCESS_TABLE_HEIGHT: usize = 12;
 
+// Bounds of the [+/-] refresh interval; the main loop itself ticks every ~200ms
+const MIN_REFRESH_MS: u64 = 200;
+const MAX_REFRESH_MS: u64 = 10_000;
+
 fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
     let size = area;
     
@@ -791,29 +1929,29 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Update display limit based on available height
     // Height - 2 (borders) - 1 (header) = Height - 3
+    // On wide terminals the list continues in side-by-side columns, so a page holds rows * columns processes
+    let columns = list_column_count(app, chunks[1].width);
     if chunks[1].height > 3 {
-        app.display_limit = (chunks[1].height - 3) as usize;
+        app.column_rows = (chunks[1].height - 3) as usize;
+        app.display_limit = app.column_rows * columns;
     }
 
-    // Get sort indicator for each column
+    // Get sort indicator for each column (secondary key is marked with a 2)
+    let secondary_sort = app.process_manager.get_secondary_sort();
     let get_sort_indicator = |column: &str| -> &str {
-        if let Some(mode) = &app.sort_mode {
-            if mode == column {
-                if app.sort_ascending {
-                    " ↑"
-                } else {
-                    " ↓"
-                }
-            } else {
-                ""
-            }
-        } else {
-            ""
+        if let Some(mode) = &app.sort_mode
+            && mode == column
+        {
+            return if app.sort_ascending() { " ↑" } else { " ↓" };
+        }
+        match secondary_sort {
+            Some((mode, ascending)) if mode == column => if ascending { " 2↑" } else { " 2↓" },
+            _ => "",
         }
     };
 
     // Header
-    let headers = if app.multi_select_mode {
+    let mut headers = if app.multi_select_mode {
         let mut h = vec![
             "✓".to_string(),
             format!("PID{}", get_sort_indicator("pid")),
@@ -824,11 +1962,12 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
         h.extend(vec![
             format!("NAME{}", get_sort_indicator("name")),
             format!("USER{}", get_sort_indicator("user")),
+            "TTY".to_string(),
             format!("CPU%{}", get_sort_indicator("cpu")),
-            format!("MEM(MB){}", get_sort_indicator("mem")),
+            format!("MEM{}", get_sort_indicator("mem")),
             format!("START{}", get_sort_indicator("start")),
             format!("NICE{}", get_sort_indicator("nice")),
-            "STATUS".to_string(),
+            format!("STATUS{}", get_sort_indicator("status")),
             format!("PPID{}", get_sort_indicator("ppid")),
         ]);
         h
@@ -842,15 +1981,29 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
         h.extend(vec![
             format!("NAME{}", get_sort_indicator("name")),
             format!("USER{}", get_sort_indicator("user")),
+            "TTY".to_string(),
             format!("CPU%{}", get_sort_indicator("cpu")),
-            format!("MEM(MB){}", get_sort_indicator("mem")),
+            format!("MEM{}", get_sort_indicator("mem")),
             format!("START{}", get_sort_indicator("start")),
             format!("NICE{}", get_sort_indicator("nice")),
-            "STATUS".to_string(),
+            format!("STATUS{}", get_sort_indicator("status")),
             format!("PPID{}", get_sort_indicator("ppid")),
         ]);
         h
     };
+    let selection_column = usize::from(app.multi_select_mode);
+    if let Some(column) = cpu_time_column(app) {
+        headers.insert(selection_column + column, format!("TIME+{}", get_sort_indicator("time")));
+    }
+    if app.settings.show_io {
+        headers.push(format!("I/O{}", get_sort_indicator("io")));
+    }
+    if app.settings.show_thread_count {
+        headers.push(format!("THR{}", get_sort_indicator("threads")));
+    }
+    if app.settings.show_unit {
+        headers.push("UNIT".to_string());
+    }
 
     let header_cells = headers
         .iter()
@@ -879,12 +2032,12 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
     };
     
     
-    let rows: Vec<Row> = processes
+    let mut rows: Vec<Row> = processes
         .iter()
         .skip(app.scroll_offset)
         .take(app.display_limit)
         .enumerate()
-        .map(|(i, process)| {
+        .flat_map(|(i, process)| {
             let base_style = if i % 2 == 0 {
                 Style::default().fg(Color::Black)
             } else {
@@ -893,7 +2046,7 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
             
             // Check if process has active alerts
             let has_alert = app.alert_manager.get_active_alerts().iter()
-                .any(|a| a.process_pid == Some(process.pid));
+                .any(|a| a.process_pid == Some(process.pid) && a.is_firing());
             
             // Highlight if has alert
             let style = if has_alert {
@@ -902,15 +2055,12 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
                 base_style
             };
 
-            let memory_mb = process.memory_usage / (1024 * 1024);
-            let cpu_style = match process.cpu_usage {
-                c if c > 50.0 => Style::default().fg(Color::Red),
-                c if c > 25.0 => Style::default().fg(Color::Yellow),
-                _ => Style::default().fg(Color::Green),
-            };
+            let cpu_style = cpu_style(process, &app.settings);
             
             let is_selected = app.selected_processes.contains(&process.pid);
             let is_current = (app.scroll_offset + i) == app.selected_process_index;
+            let change = app.process_changes.get(&process.pid);
+            let changed_style = Style::default().fg(Color::Black).bg(Color::LightYellow).add_modifier(Modifier::BOLD);
             
             let mut cells = if app.multi_select_mode {
                 vec![
@@ -932,22 +2082,110 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
                 cells.push(Cell::from(host_name).style(Style::default().fg(Color::Cyan)));
             }
             
+            let name = if app.settings.auto_fit_columns {
+                ellipsize(&process.name, AUTO_FIT_NAME_MAX_WIDTH)
+            } else {
+                process.name.clone()
+            };
+            let name = if process::is_locked(process) {
+                format!("🔒 {}", name)
+            } else {
+                name
+            };
+            let is_pinned = app.pinned_processes.contains(&(process.name.clone(), process.start_timestamp));
+            let name = if is_pinned { format!("📌 {}", name) } else { name };
+            let name = if process_note(app, process).is_some() { format!("📝 {}", name) } else { name };
+            let name = if process.tracer_pid.is_some() { format!("🐞 {}", name) } else { name };
             cells.extend(vec![
-                Cell::from(process.name.clone()).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Black) }),
+                Cell::from(name).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Black) }),
                 Cell::from(process.user.clone().unwrap_or_default()).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Magenta) }),
-                Cell::from(format!("{:.2}%", process.cpu_usage)).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { cpu_style }),
-                Cell::from(format!("{}MB", memory_mb)).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { style }),
+                Cell::from(process.tty.clone()).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Black) }),
+                Cell::from(cpu_cell_text(process, app)).style(if is_current {
+                    Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else if change.is_some_and(|c| c.cpu) {
+                    changed_style
+                } else {
+                    cpu_style
+                }),
+                Cell::from(mem_cell_text(process, app)).style(if is_current {
+                    Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else if change.is_some_and(|c| c.memory) {
+                    changed_style
+                } else {
+                    style
+                }),
                 Cell::from(process.start_time_str.clone()).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Black) }),
                 Cell::from(process.nice.to_string()).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Black) }),
-                Cell::from(process.status.trim()).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { get_status_style(&process.status) }),
+                Cell::from(status_text(process, app)).style(if is_current {
+                    Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+                } else if stuck_in_d_state(process, app).is_some() {
+                    Style::default().fg(Color::White).bg(Color::Magenta).add_modifier(Modifier::BOLD)
+                } else if change.is_some_and(|c| c.status) {
+                    changed_style
+                } else {
+                    get_status_style(&process.status)
+                }),
                 Cell::from(process.parent_pid.unwrap_or(0).to_string()).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { style }),
             ]);
+            if let Some(column) = cpu_time_column(app) {
+                cells.insert(
+                    selection_column + column,
+                    Cell::from(process::format_cpu_time(process.cpu_time_secs)).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Black) }),
+                );
+            }
+            if app.settings.show_io {
+                cells.push(Cell::from(io_text(process)).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Black) }));
+            }
+            if app.settings.show_thread_count {
+                cells.push(Cell::from(process.thread_count.to_string()).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Black) }));
+            }
+            if app.settings.show_unit {
+                cells.push(Cell::from(unit_text(process)).style(if is_current { Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD) } else { Style::default().fg(Color::Blue) }));
+            }
 
-            Row::new(cells)
+            // Underline the last pinned row to separate the pinned section from the rest
+            let ends_pinned_section = is_pinned
+                && processes.get(app.scroll_offset + i + 1).is_none_or(|next| {
+                    !app.pinned_processes.contains(&(next.name.clone(), next.start_timestamp))
+                });
+            let mut row_style = Style::default();
+            if ends_pinned_section {
+                row_style = row_style.add_modifier(Modifier::UNDERLINED);
+            }
+            // Fade what stayed still so the moving rows stand out
+            if app.settings.change_highlight && app.settings.change_dim_unchanged && change.is_none() && !is_current {
+                row_style = row_style.add_modifier(Modifier::DIM);
+            }
+            if !is_current && list_search_matches(&app.list_search_query, process) {
+                row_style = row_style.bg(Color::LightGreen);
+            }
+            let row = Row::new(cells).style(row_style);
+            let mut process_rows = vec![row];
+            if let Some(threads) = app.thread_cache.get(&process.pid) {
+                process_rows.extend(threads.iter().map(|thread| thread_row(process, thread, app)));
+            }
+            process_rows
         })
         .collect();
 
-    let widths: Vec<Constraint> = if app.multi_select_mode {
+    // Thread rows push processes down; drop rows from the top so the selection stays visible
+    if !app.thread_cache.is_empty() {
+        let selected_row: usize = processes
+            .iter()
+            .skip(app.scroll_offset)
+            .take(app.selected_process_index.saturating_sub(app.scroll_offset))
+            .map(|p| 1 + app.thread_cache.get(&p.pid).map_or(0, |t| t.len()))
+            .sum();
+        if selected_row >= app.display_limit {
+            rows.drain(..(selected_row + 1 - app.display_limit).min(rows.len()));
+        }
+    }
+
+    // Fixed CPU%/MEM columns grow to make room for inline bars
+    let bar_width = if app.settings.inline_bars { INLINE_BAR_WIDTH as u16 + 1 } else { 0 };
+    let mut widths: Vec<Constraint> = if app.settings.auto_fit_columns {
+        auto_fit_widths(&headers, &processes, app)
+    } else if app.multi_select_mode {
         let mut w = vec![
             Constraint::Length(2),  // Selection indicator
             Constraint::Length(8),  // PID
@@ -958,8 +2196,9 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
         w.extend(vec![
             Constraint::Length(20), // NAME
             Constraint::Length(12), // USER
-            Constraint::Length(8),  // CPU%
-            Constraint::Length(10), // MEM(MB)
+            Constraint::Length(8),  // TTY
+            Constraint::Length(8 + bar_width),  // CPU%
+            Constraint::Length(10 + bar_width), // MEM
             Constraint::Length(10), // START
             Constraint::Length(6),  // NICE
             Constraint::Length(10), // STATUS
@@ -976,8 +2215,9 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
         w.extend(vec![
             Constraint::Length(20), // NAME
             Constraint::Length(12), // USER
-            Constraint::Length(8),  // CPU%
-            Constraint::Length(10), // MEM(MB)
+            Constraint::Length(8),  // TTY
+            Constraint::Length(8 + bar_width),  // CPU%
+            Constraint::Length(10 + bar_width), // MEM
             Constraint::Length(10), // START
             Constraint::Length(6),  // NICE
             Constraint::Length(10), // STATUS
@@ -985,13 +2225,114 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
         ]);
         w
     };
+    if let (Some(column), false) = (cpu_time_column(app), app.settings.auto_fit_columns) {
+        widths.insert(selection_column + column, Constraint::Length(11)); // TIME+
+    }
+    if app.settings.show_io && !app.settings.auto_fit_columns {
+        widths.push(Constraint::Length(12)); // I/O
+    }
+    if app.settings.show_thread_count && !app.settings.auto_fit_columns {
+        widths.push(Constraint::Length(6)); // THR
+    }
+    if app.settings.show_unit && !app.settings.auto_fit_columns {
+        widths.push(Constraint::Length(24)); // UNIT
+    }
 
-    let table = Table::new(rows)
-        .header(header)
-        .block(Block::default().borders(Borders::ALL))
-        .widths(&widths);
+    let mut table_titles = Vec::new();
+    if app.paused {
+        table_titles.push("⏸ [PAUSED] ([Space] resume)".to_string());
+    }
+    if !app.pinned_processes.is_empty() {
+        table_titles.push(format!("📌 {} pinned at the top ([f] unpin)", app.pinned_processes.len()));
+    }
+    if app.process_manager.is_hiding_kernel_threads() {
+        table_titles.push("Kernel threads hidden ([K] show)".to_string());
+    }
+    if let Some(mark) = app.start_mark {
+        let mark_time = chrono::DateTime::<chrono::Local>::from(std::time::UNIX_EPOCH + Duration::from_secs(mark)).format("%H:%M:%S");
+        table_titles.push(if app.process_manager.get_started_after().is_some() {
+            format!("🕒 {} started since {} ([Z] show all)", processes.len(), mark_time)
+        } else {
+            format!("🕒 Mark {} ([Z] show new only)", mark_time)
+        });
+    }
+    if let Some(namespace) = app.process_manager.get_pid_namespace() {
+        table_titles.push(format!("🔭 PID namespace {} only ([I] show all)", namespace));
+    }
+    let table_block = if table_titles.is_empty() {
+        Block::default().borders(Borders::ALL)
+    } else {
+        Block::default().borders(Borders::ALL).title(table_titles.join("  "))
+    };
+    if columns > 1 {
+        draw_process_columns(f, app, &processes, chunks[1], columns, table_block);
+    } else {
+        let table = Table::new(rows)
+            .header(header)
+            .block(table_block)
+            .widths(&widths);
+        f.render_widget(table, chunks[1]);
+    }
 
-    f.render_widget(table, chunks[1]);
+    // Incremental search prompt, same place as the PID prompt
+    if app.list_search_active {
+        let prompt_area = Rect {
+            x: chunks[1].x + 1,
+            y: chunks[1].y + chunks[1].height.saturating_sub(4),
+            width: 40.min(chunks[1].width.saturating_sub(2)),
+            height: 3.min(chunks[1].height),
+        };
+        f.render_widget(ratatui::widgets::Clear, prompt_area);
+        let prompt = Paragraph::new(format!("/{}_", app.list_search_query))
+            .style(Style::default().fg(Color::White))
+            .block(Block::default().borders(Borders::ALL).title("Search name/PID (Enter keeps, Esc clears)").style(Style::default().bg(Color::Black)));
+        f.render_widget(prompt, prompt_area);
+    }
+
+    // "Go to PID" prompt along the bottom of the table
+    if app.goto_pid_active {
+        let prompt_area = Rect {
+            x: chunks[1].x + 1,
+            y: chunks[1].y + chunks[1].height.saturating_sub(4),
+            width: 30.min(chunks[1].width.saturating_sub(2)),
+            height: 3.min(chunks[1].height),
+        };
+        f.render_widget(ratatui::widgets::Clear, prompt_area);
+        let prompt = Paragraph::new(format!(":{}_", app.goto_pid_input))
+            .style(Style::default().fg(Color::White))
+            .block(Block::default().borders(Borders::ALL).title("Go to PID (Enter/Esc)").style(Style::default().bg(Color::Black)));
+        f.render_widget(prompt, prompt_area);
+    }
+
+    // Note editor, same place as the PID prompt
+    if let Some((pid, name, _)) = &app.note_editing {
+        let prompt_area = Rect {
+            x: chunks[1].x + 1,
+            y: chunks[1].y + chunks[1].height.saturating_sub(4),
+            width: 70.min(chunks[1].width.saturating_sub(2)),
+            height: 3.min(chunks[1].height),
+        };
+        f.render_widget(ratatui::widgets::Clear, prompt_area);
+        let prompt = Paragraph::new(format!("{}_", app.note_input))
+            .style(Style::default().fg(Color::White))
+            .block(Block::default().borders(Borders::ALL).title(format!("Note for {} ({}) - Enter saves, empty removes, Esc cancels", name, pid)).style(Style::default().bg(Color::Black)));
+        f.render_widget(prompt, prompt_area);
+    }
+
+    // "Find by port" prompt, same place as the PID prompt
+    if app.port_lookup_active {
+        let prompt_area = Rect {
+            x: chunks[1].x + 1,
+            y: chunks[1].y + chunks[1].height.saturating_sub(4),
+            width: 34.min(chunks[1].width.saturating_sub(2)),
+            height: 3.min(chunks[1].height),
+        };
+        f.render_widget(ratatui::widgets::Clear, prompt_area);
+        let prompt = Paragraph::new(format!("port {}_", app.port_lookup_input))
+            .style(Style::default().fg(Color::White))
+            .block(Block::default().borders(Borders::ALL).title("Find by port (Enter/Esc)").style(Style::default().bg(Color::Black)));
+        f.render_widget(prompt, prompt_area);
+    }
 
     // Menu
     let multi_select_status = if app.multi_select_mode {
@@ -999,12 +2340,22 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
     } else {
         String::new()
     };
-    let active_alerts_count = app.alert_manager.get_active_alerts().len();
+    let active_alerts_count = app.alert_manager.get_active_alerts().iter().filter(|a| a.is_firing()).count();
     let alert_indicator = if active_alerts_count > 0 {
         format!(" [ALERTS: {}]", active_alerts_count)
     } else {
         String::new()
     };
+    let suspended_indicator = if app.suspended_by_lpm.is_empty() {
+        String::new()
+    } else {
+        format!(" [SUSPENDED BY LPM: {} - U to view]", app.suspended_by_lpm.len())
+    };
+    let blacklist_indicator = if app.kill_blacklist.is_empty() {
+        String::new()
+    } else {
+        format!(" [BLACKLIST: {} - 3 to view]", app.kill_blacklist.len())
+    };
     let active_profile_indicator = app.profile_manager.get_active_profile()
         .map(|s| format!(" [PROFILE: {}]", s))
         .unwrap_or_default();
@@ -1013,9 +2364,115 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
     let menu_text = vec![
         // Line 1: Navigation and status indicators
         Line::from(vec![
-            Span::styled("[↑/↓] Scroll  ", Style::default().fg(Color::Cyan)),
+            Span::styled(if columns > 1 { "[↑/↓/←/→] Scroll  " } else { "[↑/↓] Scroll  " }, Style::default().fg(Color::Cyan)),
             Span::raw("| "),
             Span::styled("[M] Multi-Select  ", Style::default().fg(if app.multi_select_mode { Color::Green } else { Color::Yellow })),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.hide_kernel_threads { "[K] Show Kernel Threads  " } else { "[K] Hide Kernel Threads  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.auto_fit_columns { "[W] Fixed Columns  " } else { "[W] Auto-Fit Columns  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.multi_column { "[V] Single List  " } else { "[V] Multi-Column  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.change_highlight { "[d] Changes: ON  " } else { "[d] Changes  " },
+                Style::default().fg(if app.settings.change_highlight { Color::Green } else { Color::Blue }),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.sched_aware_cpu_colors { "[x] Sched Colors: ON  " } else { "[x] Sched Colors  " },
+                Style::default().fg(if app.settings.sched_aware_cpu_colors { Color::Green } else { Color::Blue }),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.show_threads { "[t/T] Threads (all)  " } else { "[t/T] Threads  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(format!("[+/-] Refresh: {:.1}s  ", app.refresh_interval.as_secs_f64()), Style::default().fg(Color::Blue)),
+            Span::raw("| "),
+            Span::styled(
+                if app.paused { "[Space] Resume  " } else { "[Space] Pause  " },
+                Style::default().fg(if app.paused { Color::Yellow } else { Color::Blue }),
+            ),
+            Span::raw("| "),
+            Span::styled("[v] Details  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[:] Go to PID  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled(
+                if app.list_search_query.is_empty() { "[/] Search  ".to_string() } else { format!("[/] Search \"{}\" [n/N] Next/Prev  ", app.list_search_query) },
+                Style::default().fg(if app.list_search_query.is_empty() { Color::Cyan } else { Color::Green }),
+            ),
+            Span::raw("| "),
+            Span::styled("[o] Find by Port  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[u] Undo Renice/Pin  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[l] Lock/Unlock  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[f] Pin/Unpin  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[E] Note  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[z] Mark Now  [Z] Since Mark  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled(
+                if app.process_manager.get_pid_namespace().is_some() { "[I] All Namespaces  " } else { "[I] Same PID Namespace  " },
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::raw("| "),
+            Span::styled("[</>] Sort Column  [=] Reverse  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[O] Ordered Restart  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[w] By User  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled("[F] Tree  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.inline_bars { "[B] Numbers Only  " } else { "[B] Inline Bars  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.cpu_per_core { "[%] CPU: Per-Core  " } else { "[%] CPU: Of Total  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                format!("[b] Units: {}  ", app.settings.memory_units.label()),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.show_cpu_time { "[i] Hide TIME+  " } else { "[i] Show TIME+  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.show_unit { "[y] Hide UNIT  " } else { "[y] Show UNIT  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.show_io { "[D] Hide I/O  " } else { "[D] Show I/O  " },
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("| "),
+            Span::styled(
+                if app.settings.show_thread_count { "[Y] Hide THR  " } else { "[Y] Show THR  " },
+                Style::default().fg(Color::Blue),
+            ),
             if app.multi_select_mode {
                 Span::styled(multi_select_status, Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
             } else {
@@ -1031,6 +2488,8 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
             } else {
                 Span::raw("")
             },
+            Span::styled(suspended_indicator, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+            Span::styled(blacklist_indicator, Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
         ]),
         // Line 2: Main actions
         Line::from(vec![
@@ -1056,6 +2515,8 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
             Span::raw("| "),
             Span::styled("[N] New Process  ", Style::default().fg(Color::Green)),
             Span::raw("| "),
+            Span::styled("[L] Tail Log  ", Style::default().fg(Color::Blue)),
+            Span::raw("| "),
             Span::styled("[P] Profiles  ", Style::default().fg(Color::Magenta)),
             Span::raw("| "),
             Span::styled("[A] Alerts  ", Style::default().fg(Color::Red)),
@@ -1073,6 +2534,61 @@ fn draw_process_list(f: &mut Frame, app: &mut App, area: Rect) {
         .alignment(Alignment::Left);
 
     f.render_widget(menu, chunks[2]);
+
+    if let Some(pid) = app.process_detail {
+        draw_process_detail_popup(f, app, pid, area);
+    }
+}
+
+// Full command line, working directory and executable of one process
+fn draw_process_detail_popup(f: &mut Frame, app: &App, pid: u32, area: Rect) {
+    let dialog_width = 100.min(area.width);
+    let dialog_height = 14.min(area.height);
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+    let (name, command) = app.process_manager.get_processes().iter()
+        .find(|p| p.pid == pid)
+        .map(|p| (p.name.clone(), crate::process::display_cmdline(p)))
+        .unwrap_or_else(|| ("(exited)".to_string(), String::new()));
+    let (cwd, exe) = app.process_manager.process_paths(pid);
+    let affinity = app.process_manager.get_cpu_affinity(pid);
+    let ionice = app.process_manager.get_ionice(pid).ok();
+    let label = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+    let mut text = vec![
+        Line::from(vec![Span::styled("PID:     ", label), Span::raw(format!("{} ({})", pid, name))]),
+        Line::from(vec![Span::styled("Command: ", label), Span::raw(command)]),
+        Line::from(vec![Span::styled("CWD:     ", label), Span::raw(cwd.unwrap_or_else(|| "unavailable".to_string()))]),
+        Line::from(vec![Span::styled("Exe:     ", label), Span::raw(exe.unwrap_or_else(|| "unavailable".to_string()))]),
+        Line::from(vec![
+            Span::styled("CPUs:    ", label),
+            Span::raw(if affinity.is_empty() {
+                "unavailable".to_string()
+            } else {
+                format!("{} (online: {})", process::format_cpu_list(&affinity), process::format_cpu_list(&process::online_cpus()))
+            }),
+        ]),
+        Line::from(vec![
+            Span::styled("I/O:     ", label),
+            Span::raw(ionice.map_or_else(|| "unavailable".to_string(), |(class, priority)| process::format_ionice(class, priority))),
+        ]),
+    ];
+    // Where output goes for processes we launched
+    if let Some(log) = app.process_manager.get_output_log(pid) {
+        text.push(Line::from(vec![Span::styled("Logs at: ", label), Span::raw(log.display().to_string())]));
+    }
+    f.render_widget(ratatui::widgets::Clear, dialog_area);
+    let popup = Paragraph::new(text)
+        .style(Style::default().fg(Color::White))
+        .wrap(ratatui::widgets::Wrap { trim: false })
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title("Process Details (any key to close)")
+            .style(Style::default().fg(Color::White).bg(Color::Rgb(20, 20, 20))));
+    f.render_widget(popup, dialog_area);
 }
 
 fn draw_filter_sort_menu(f: &mut Frame, app: &App, area: Rect) {
@@ -1128,7 +2644,8 @@ fn draw_sort_menu(f: &mut Frame, app: &App, area: Rect) {
         .split(size);
 
     // Title
-    let title = Paragraph::new("Sort Menu")
+    let title_text = if app.editing_secondary_sort { "Sort Menu - Secondary Key" } else { "Sort Menu - Primary Key" };
+    let title = Paragraph::new(title_text)
         .style(Style::default().fg(Color::Yellow))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL));
@@ -1142,7 +2659,12 @@ fn draw_sort_menu(f: &mut Frame, app: &App, area: Rect) {
         ListItem::new(Span::styled("[4] Sort by Start Time", Style::default().fg(Color::Magenta))),
         ListItem::new(Span::styled("[5] Sort by Nice Value", Style::default().fg(Color::Cyan))),
         ListItem::new(Span::styled("[6] Sort by CPU Usage", Style::default().fg(Color::Red))),
+        ListItem::new(Span::styled("[7] Sort by CPU Time (TIME+)", Style::default().fg(Color::Red))),
+        ListItem::new(Span::styled("[8] Sort by Disk I/O Rate", Style::default().fg(Color::Magenta))),
+        ListItem::new(Span::styled("[9] Sort by Thread Count", Style::default().fg(Color::Cyan))),
         ListItem::new(Span::styled("[a] Toggle Ascending/Descending", Style::default().fg(Color::Black))),
+        ListItem::new(Span::styled("[t] Switch Primary/Secondary Key", Style::default().fg(Color::Black))),
+        ListItem::new(Span::styled("[x] Clear Secondary Key", Style::default().fg(Color::Black))),
         ListItem::new(Span::styled("[←] Back", Style::default().fg(Color::Blue))),
     ];
 
@@ -1154,7 +2676,16 @@ fn draw_sort_menu(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(menu, chunks[1]);
 
     // Status
-    let order_text = format!("Current Order: {}", if app.sort_ascending { "Ascending ↑" } else { "Descending ↓" });
+    let secondary_text = match app.process_manager.get_secondary_sort() {
+        Some((mode, ascending)) => format!("{} {}", mode, if ascending { "↑" } else { "↓" }),
+        None => "none".to_string(),
+    };
+    let order_text = format!(
+        "Primary: {} {}  |  Then by: {}  |  Finally: pid ↑",
+        app.sort_mode.as_deref().unwrap_or("none"),
+        if app.sort_ascending() { "↑" } else { "↓" },
+        secondary_text,
+    );
     let status = Paragraph::new(order_text)
         .style(Style::default())
         .alignment(Alignment::Center)
@@ -1187,6 +2718,8 @@ fn draw_filter_menu(f: &mut Frame, area: Rect) {
         ListItem::new(Span::styled("[2] Filter by Name", Style::default().fg(Color::Green))),
         ListItem::new(Span::styled("[3] Filter by PID", Style::default().fg(Color::Yellow))),
         ListItem::new(Span::styled("[4] Filter by PPID", Style::default().fg(Color::Cyan))),
+        ListItem::new(Span::styled("[5] Filter by Status", Style::default().fg(Color::Magenta))),
+        ListItem::new(Span::styled("[z] Zombies  [t] Stopped  [r] Running  [d] Disk Sleep  [g] Traced (debugger)", Style::default().fg(Color::Black))),
         ListItem::new(Span::styled("[Esc] Clear Filter", Style::default().fg(Color::Red))),
         ListItem::new(Span::styled("[←] Back", Style::default().fg(Color::Blue))),
     ];
@@ -1217,6 +2750,7 @@ fn draw_filter_input_menu(f: &mut Frame, app: &App, area: Rect) {
         Some("name") => "Process Name",
         Some("pid") => "PID",
         Some("ppid") => "Parent PID",
+        Some("status") => "Status",
         _ => "Unknown",
     };
     let title = Paragraph::new(format!("Enter {} Filter", filter_type))
@@ -1240,6 +2774,11 @@ fn draw_filter_input_menu(f: &mut Frame, app: &App, area: Rect) {
             "(Numbers only)",
             Style::default().fg(Color::Yellow)
         )));
+    } else if app.filter_mode.as_deref() == Some("status") {
+        instructions.insert(1, ListItem::new(Span::styled(
+            "(running, sleeping, disk sleep, zombie, stopped, idle - case-insensitive)",
+            Style::default().fg(Color::Yellow)
+        )));
     }
 
     let instructions_widget = List::new(instructions)
@@ -1257,36 +2796,146 @@ fn draw_filter_input_menu(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(input, chunks[2]);
 }
 
-fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
-    let size = area;
-    // Add a visually prominent title box at the top
-    let title_chunk = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // Make the title box taller
-            Constraint::Min(1),
-        ])
-        .split(size);
-    let title = Paragraph::new("Process Control Menu")
-        .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).border_type(ratatui::widgets::BorderType::Thick));
-    f.render_widget(title, title_chunk[0]);
-    let size = title_chunk[1];
-    // Add a blank line below the title for spacing
-    let spacing_chunk = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(1),
-            Constraint::Min(1),
-        ])
-        .split(size);
-    let size = spacing_chunk[1];
-
-    let process_table_width = (size.width as f32 * 0.55) as u16;
-    let right_panel_width = size.width - process_table_width;
-    let process_table_height = size.height - 2;
-
+// Rows of a process-picking view: the main list (rule filter and profile hiding applied),
+// narrowed and re-sorted by that view's own preferences. The main list itself has none.
+fn view_processes(app: &App, mode: ViewMode) -> Vec<process::ProcessInfo> {
+    let base = if app.rule_engine.active_rule.is_some() {
+        app.process_manager.get_filtered_processes()
+    } else {
+        app.process_manager.get_processes()
+    };
+    let hiding = app.profile_manager.get_active_profile().is_some();
+    let mut processes: Vec<process::ProcessInfo> = base.iter()
+        .filter(|p| !hiding || !app.profile_manager.should_hide_process(&p.name))
+        .cloned()
+        .collect();
+    let Some(prefs) = app.view_prefs.get(&mode) else {
+        return processes;
+    };
+    if !prefs.filter.is_empty() {
+        let needle = prefs.filter.to_lowercase();
+        processes.retain(|p| {
+            p.name.to_lowercase().contains(&needle)
+                || p.user.as_deref().is_some_and(|user| user.to_lowercase().contains(&needle))
+        });
+    }
+    if let Some((sort_mode, ascending)) = prefs.sort {
+        processes.sort_by(|a, b| {
+            let ord = process::compare_processes(sort_mode, a, b);
+            (if ascending { ord } else { ord.reverse() }).then_with(|| a.pid.cmp(&b.pid))
+        });
+    }
+    processes
+}
+
+// " | sort: cpu ↓ | filter: fire" suffix for a view's table title
+fn view_prefs_label(app: &App, mode: ViewMode) -> String {
+    let prefs = app.view_prefs.get(&mode).cloned().unwrap_or_default();
+    let mut label = String::new();
+    if let Some((sort_mode, ascending)) = prefs.sort {
+        label.push_str(&format!(" | sort: {} {}", sort_mode, if ascending { "↑" } else { "↓" }));
+    }
+    if app.view_filter_editing == Some(mode) {
+        label.push_str(&format!(" | filter: {}_", prefs.filter));
+    } else if !prefs.filter.is_empty() {
+        label.push_str(&format!(" | filter: {}", prefs.filter));
+    }
+    label
+}
+
+// [o] cycles the view's sort, [O] reverses it, [/] edits its filter; true when the key was used
+fn handle_view_prefs_key(key: KeyEvent, app: &mut App, mode: ViewMode) -> bool {
+    let selected_pid = view_processes(app, mode).get(app.selected_process_index).map(|p| p.pid);
+    let prefs = app.view_prefs.entry(mode).or_default();
+    if app.view_filter_editing == Some(mode) {
+        match key.code {
+            KeyCode::Char(c) => prefs.filter.push(c),
+            KeyCode::Backspace => {
+                prefs.filter.pop();
+            }
+            KeyCode::Enter => app.view_filter_editing = None,
+            KeyCode::Esc => {
+                prefs.filter.clear();
+                app.view_filter_editing = None;
+            }
+            _ => return true, // Typing owns the keyboard until Enter/Esc
+        }
+    } else {
+        match key.code {
+            KeyCode::Char('o') => {
+                let next = match prefs.sort {
+                    None => VIEW_SORT_MODES.first().copied(),
+                    Some((current, _)) => VIEW_SORT_MODES.iter().position(|m| *m == current).and_then(|i| VIEW_SORT_MODES.get(i + 1).copied()),
+                };
+                // Usage columns start with the heaviest first
+                prefs.sort = next.map(|sort_mode| (sort_mode, !matches!(sort_mode, "cpu" | "mem")));
+            }
+            KeyCode::Char('O') => {
+                if let Some((_, ascending)) = &mut prefs.sort {
+                    *ascending = !*ascending;
+                }
+            }
+            KeyCode::Char('/') => app.view_filter_editing = Some(mode),
+            _ => return false,
+        }
+    }
+    reselect_pid(app, mode, selected_pid);
+    true
+}
+
+// Point the shared selection at `pid` in a view's order (first row when it is not listed) and scroll to it
+fn reselect_pid(app: &mut App, mode: ViewMode, pid: Option<u32>) {
+    let index = pid
+        .and_then(|pid| view_processes(app, mode).iter().position(|p| p.pid == pid))
+        .unwrap_or(0);
+    app.selected_process_index = index;
+    let (offset, rows) = match mode {
+        ViewMode::ChangeNice => (&mut app.change_nice_scroll_offset, PROCESS_TABLE_HEIGHT - 2),
+        ViewMode::PerProcessGraph => (&mut app.per_process_graph_scroll_offset, PROCESS_TABLE_HEIGHT - 2),
+        _ => (&mut app.scroll_offset, app.display_limit.max(1)),
+    };
+    if index < *offset || index >= *offset + rows {
+        *offset = index.saturating_sub(rows / 2);
+    }
+}
+
+// Move between the main list and a process-picking view, keeping the same process selected
+fn switch_process_view(app: &mut App, to: ViewMode) {
+    let selected_pid = view_processes(app, app.view_mode).get(app.selected_process_index).map(|p| p.pid);
+    app.view_mode = to;
+    reselect_pid(app, to, selected_pid);
+}
+
+fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
+    let size = area;
+    // Add a visually prominent title box at the top
+    let title_chunk = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Make the title box taller
+            Constraint::Min(1),
+        ])
+        .split(size);
+    let title = Paragraph::new("Process Control Menu")
+        .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).border_type(ratatui::widgets::BorderType::Thick));
+    f.render_widget(title, title_chunk[0]);
+    let size = title_chunk[1];
+    // Add a blank line below the title for spacing
+    let spacing_chunk = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+        ])
+        .split(size);
+    let size = spacing_chunk[1];
+
+    let process_table_width = (size.width as f32 * 0.55) as u16;
+    let right_panel_width = size.width - process_table_width;
+    let process_table_height = size.height - 2;
+
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -1296,17 +2945,12 @@ fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
         .split(size);
 
     // --- LEFT: Process Table with highlight ---
-    // let processes = app.process_manager.get_processes();
-
-    let processes = if app.rule_engine.active_rule.is_some() {
+    if app.rule_engine.active_rule.is_some() {
         app.process_manager.apply_rules(&mut app.rule_engine);
-        app.process_manager.get_filtered_processes()
-    } else {
-        app.process_manager.get_processes()
-    };
-    
+    }
+    let processes = view_processes(app, ViewMode::KillStop);
 
-    let headers = ["PID", "NAME", "STATUS", "CPU%", "MEM(MB)", "USER"];
+    let headers = ["PID", "NAME", "STATUS", "CPU%", "MEM", "USER"];
     let header_cells = headers
         .iter()
         .map(|h| Cell::from(*h).style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD)));
@@ -1329,13 +2973,12 @@ fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
             } else {
                 Style::default().fg(Color::Blue)
             };
-            let memory_mb = process.memory_usage / (1024 * 1024);
             Row::new(vec![
                 Cell::from(process.pid.to_string()).style(style),
                 Cell::from(process.name.clone()).style(Style::default().fg(Color::Green)),
                 Cell::from(process.status.trim()).style(get_status_style(&process.status)),
                 Cell::from(format!("{:.1}%", process.cpu_usage)).style(style),
-                Cell::from(format!("{}", memory_mb)).style(style),
+                Cell::from(crate::units::format_bytes(process.memory_usage)).style(style),
                 Cell::from(process.user.clone().unwrap_or_default()).style(Style::default().fg(Color::Magenta)),
             ])
         })
@@ -1343,13 +2986,13 @@ fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
 
     let process_table = Table::new(visible_processes)
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("Processes (↑↓ to move, Enter to select)").style(Style::default().fg(Color::Black)))
+        .block(Block::default().borders(Borders::ALL).title(format!("Processes (↑↓ to move, Enter to select){}", view_prefs_label(app, ViewMode::KillStop))).style(Style::default().fg(Color::Black)))
         .widths(&[
             Constraint::Length(8),   // PID
             Constraint::Length(20),  // NAME
             Constraint::Length(10),  // STATUS
             Constraint::Length(8),   // CPU%
-            Constraint::Length(10),  // MEM(MB)
+            Constraint::Length(10),  // MEM
             Constraint::Length(12),  // USER
         ]);
     f.render_widget(process_table, chunks[0]);
@@ -1385,13 +3028,19 @@ fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
     // Input box for action
     let input_text = match &app.kill_stop_input_state {
         KillStopInputState::EnteringAction => {
-            "Enter action: [k] Kill, [s] Stop, [c] Continue, [t] Terminate, [Esc] Cancel".to_string()
+            "Enter action: [k] Kill, [s] Stop, [c] Continue, [t] Terminate, [b] Kill & Blacklist, [g] Signal..., [Esc] Cancel".to_string()
         }
         KillStopInputState::ConfirmingAction { .. } => {
             "Confirming action...".to_string()
         }
+        KillStopInputState::PickingSignal { query, .. } => {
+            format!("Signal: {}_", query)
+        }
+        KillStopInputState::EnteringBlacklistDuration { process_name, minutes, .. } => {
+            format!("Kill '{}' and respawns for how many minutes? (empty = until cleared): {}_", process_name, minutes)
+        }
         _ => {
-            "Press Enter to select action".to_string()
+            "Press Enter to select action, [o]/[O] sort, [/] filter".to_string()
         }
     };
     let input_box = Paragraph::new(input_text)
@@ -1407,8 +3056,22 @@ fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
         Line::from(vec![Span::raw("- Use ↑/↓ to move selection in the process list.")]),
         Line::from(vec![Span::raw("- Press Enter to select a process and input an action.")]),
         Line::from(vec![Span::raw("- Type k/s/c/t for Kill/Stop/Continue/Terminate, then Esc to cancel or return." )]),
+        Line::from(vec![Span::raw("- Press b to kill and blacklist the name, x to clear the blacklist.")]),
         Line::from(vec![Span::raw("- Press Esc to cancel and return.")]),
     ];
+    if !app.kill_blacklist.is_empty() {
+        info.push(Line::from(vec![Span::styled("Blacklist:", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))]));
+        for entry in &app.kill_blacklist {
+            let remaining = match entry.until {
+                Some(until) => {
+                    let secs = until.saturating_duration_since(std::time::Instant::now()).as_secs();
+                    format!("{}:{:02} left", secs / 60, secs % 60)
+                }
+                None => "until cleared".to_string(),
+            };
+            info.push(Line::from(format!("  {} - {} ({} respawn(s) killed)", entry.name, remaining, entry.kills)));
+        }
+    }
     if let Some((msg, is_error)) = &app.input_state.message {
         info.push(Line::from(vec![Span::styled(
             msg,
@@ -1424,9 +3087,14 @@ fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
         draw_confirmation_dialog(f, *pid, process_name, action_type, area);
     }
     
+    // Draw signal picker if choosing a signal
+    if let KillStopInputState::PickingSignal { pid, process_name, query, selected_index } = &app.kill_stop_input_state {
+        draw_signal_picker(f, *pid, process_name, query, *selected_index, area);
+    }
+    
     // Draw dependency warning dialog if in dependency warning state
-    if let KillStopInputState::DependencyWarning { pid, process_name, action_type, child_count, children } = &app.kill_stop_input_state {
-        draw_dependency_warning_dialog(f, *pid, process_name, action_type, *child_count, children, area);
+    if let KillStopInputState::DependencyWarning(warning) = &app.kill_stop_input_state {
+        draw_dependency_warning_dialog(f, warning, area);
     }
     
     // Draw batch confirmation dialog if in batch confirmation state
@@ -1435,6 +3103,99 @@ fn draw_kill_stop_menu(f: &mut Frame, app: &mut App, area: Rect) {
     }
 }
 
+// Show a relaunch command in a wrapped box so it can also be selected by hand
+fn draw_command_popup(f: &mut Frame, command: &str, area: Rect) {
+    let dialog_width = 90.min(area.width);
+    let dialog_height = 12.min(area.height);
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+    f.render_widget(ratatui::widgets::Clear, dialog_area);
+    let popup = Paragraph::new(command.to_string())
+        .style(Style::default().fg(Color::White))
+        .wrap(ratatui::widgets::Wrap { trim: false })
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title("Launch Command (any key to close)")
+            .style(Style::default().fg(Color::White).bg(Color::Rgb(20, 20, 20))));
+    f.render_widget(popup, dialog_area);
+}
+
+// Copy text to the terminal's clipboard with an OSC 52 escape (works over SSH and in tmux with set-clipboard)
+fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", base64_encode(text.as_bytes()))?;
+    stdout.flush()
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+// Draw the signal reference panel used to pick a signal to send
+fn draw_signal_picker(f: &mut Frame, pid: u32, process_name: &str, query: &str, selected_index: usize, area: Rect) {
+    let signals = crate::signals::filter_signals(query);
+    let dialog_width = 64.min(area.width);
+    let dialog_height = 20.min(area.height);
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+    f.render_widget(ratatui::widgets::Clear, dialog_area);
+
+    // Keep the selection visible
+    let visible = dialog_height.saturating_sub(3) as usize;
+    let offset = selected_index.saturating_sub(visible.saturating_sub(1));
+    let items: Vec<ListItem> = signals
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, signal)| {
+            let style = if i == selected_index {
+                Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            ListItem::new(Span::styled(
+                format!("{:>2}  {:<10} {}", signal.number, signal.name, signal.description),
+                style
+            ))
+        })
+        .collect();
+
+    let title = format!("Send signal to {} ({}) - filter: {}_", process_name, pid, query);
+    let list = List::new(items)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Thick)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(title)
+            .title(ratatui::widgets::block::Title::from("[type] Filter  [↑/↓] Move  [Enter] Send  [Esc] Back")
+                .position(ratatui::widgets::block::Position::Bottom)))
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(list, dialog_area);
+}
+
 // Draw confirmation dialog for process control actions
 fn draw_confirmation_dialog(f: &mut Frame, pid: u32, process_name: &str, action_type: &str, area: Rect) {
     use ratatui::layout::Rect;
@@ -1523,15 +3284,18 @@ fn draw_confirmation_dialog(f: &mut Frame, pid: u32, process_name: &str, action_
 }
 
 // Draw dependency warning dialog for processes with children
-fn draw_dependency_warning_dialog(f: &mut Frame, pid: u32, process_name: &str, action_type: &str, child_count: usize, children: &[(u32, String)], area: Rect) {
+fn draw_dependency_warning_dialog(f: &mut Frame, warning: &DependencyWarningState, area: Rect) {
     use ratatui::layout::Rect;
+
+    let (pid, process_name, action_type) = (warning.pid, warning.process_name.as_str(), warning.action_type.as_str());
+    let (child_count, children, descendant_count) = (warning.child_count, &warning.children, warning.descendants.len());
     
     let size = area;
     
     // Create a larger dialog box for dependency warning
     let dialog_width = 70;
     // Increase height to ensure options are visible: base height + children + extra space for options
-    let dialog_height = (15 + child_count.min(5)) as u16; // Show up to 5 children + room for options
+    let dialog_height = (17 + child_count.min(5)) as u16; // Show up to 5 children + room for options
     let x = (size.width.saturating_sub(dialog_width)) / 2;
     let y = (size.height.saturating_sub(dialog_height)) / 2;
     
@@ -1569,6 +3333,9 @@ fn draw_dependency_warning_dialog(f: &mut Frame, pid: u32, process_name: &str, a
             format!("This process has {} child process(es)!", child_count),
             Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
         )]),
+        Line::from(vec![Span::raw(
+            format!("{} descendant(s) in total, including grandchildren", descendant_count)
+        )]),
         Line::from(""),
     ];
     
@@ -1597,7 +3364,7 @@ fn draw_dependency_warning_dialog(f: &mut Frame, pid: u32, process_name: &str, a
     )]));
     dialog_content.push(Line::from(""));
     dialog_content.push(Line::from(vec![Span::styled(
-        "[1] Kill parent only  |  [2] Kill parent + all children  |  [n/Esc] Cancel",
+        "[1] Parent only  |  [2] Parent + direct children  |  [3] Entire subtree  |  [n/Esc] Cancel",
         Style::default().fg(Color::Cyan)
     )]));
     
@@ -1649,6 +3416,8 @@ fn draw_batch_confirmation_dialog(f: &mut Frame, pids: &[u32], process_names: &[
         "stop" => "Stop processes",
         "terminate" => "Terminate processes",
         "continue" => "Continue processes",
+        "renice" => "Renice processes",
+        "tune" => "Renice / set CPU affinity of processes",
         _ => "Perform action on processes",
     };
     
@@ -1737,12 +3506,11 @@ fn draw_change_nice_menu(f: &mut Frame, app: &mut App, area: Rect) {
         .split(size);
 
     // --- LEFT: Process Table with highlight ---
-    let processes = if app.rule_engine.active_rule.is_some() {
+    if app.rule_engine.active_rule.is_some() {
         app.process_manager.apply_rules(&mut app.rule_engine);
-        app.process_manager.get_filtered_processes()
-    } else {
-        app.process_manager.get_processes()
-    };    let headers = ["PID", "NAME", "NICE", "CPU%", "USER"];
+    }
+    let processes = view_processes(app, ViewMode::ChangeNice);
+    let headers = ["PID", "NAME", "NICE", "CPU%", "USER"];
     let header_cells = headers
         .iter()
         .map(|h| Cell::from(*h).style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD)));
@@ -1777,7 +3545,7 @@ fn draw_change_nice_menu(f: &mut Frame, app: &mut App, area: Rect) {
 
     let process_table = Table::new(visible_processes)
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("Processes (↑↓ to move, Enter to select)").style(Style::default().fg(Color::Black)))
+        .block(Block::default().borders(Borders::ALL).title(format!("Processes (↑↓ to move, Enter to select){}", view_prefs_label(app, ViewMode::ChangeNice))).style(Style::default().fg(Color::Black)))
         .widths(&[
             Constraint::Length(8),   // PID
             Constraint::Length(20),  // NAME
@@ -1816,10 +3584,32 @@ fn draw_change_nice_menu(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_widget(details_box, right_chunks[0]);
 
     // Input box for nice value
-    let input_text = if app.nice_input_state == NiceInputState::EnteringNice {
-        format!("New nice value (-20 to 19): {}", app.input_state.nice_input)
-    } else {
-        "Press Enter to change nice value".to_string()
+    let input_text = match app.nice_input_state {
+        NiceInputState::EnteringNice => format!("New nice value (-20 to 19): {}", app.input_state.nice_input),
+        NiceInputState::EnteringUserNice | NiceInputState::ConfirmingUserNice => format!(
+            "Nice value for all of {}'s processes (-20 to 19): {}",
+            app.renice_user.as_deref().unwrap_or("?"),
+            app.input_state.nice_input
+        ),
+        NiceInputState::EnteringFilterTune | NiceInputState::ConfirmingFilterTune => format!(
+            "For all processes matching the filter - nice, @cpus or both (e.g. 10 @0-3): {}",
+            app.input_state.nice_input
+        ),
+        NiceInputState::EnteringAffinity => format!(
+            "CPUs for PID {} (now {}, online {}), e.g. 0,1,4-7: {}",
+            proc.map_or(0, |p| p.pid),
+            proc.map_or_else(String::new, |p| process::format_cpu_list(&app.process_manager.get_cpu_affinity(p.pid))),
+            process::format_cpu_list(&process::online_cpus()),
+            app.input_state.nice_input
+        ),
+        NiceInputState::EnteringIonice => format!(
+            "I/O class for PID {} (now {}) - idle, be 0-7 or rt 0-7: {}",
+            proc.map_or(0, |p| p.pid),
+            proc.and_then(|p| app.process_manager.get_ionice(p.pid).ok())
+                .map_or_else(|| "unknown".to_string(), |(class, priority)| process::format_ionice(class, priority)),
+            app.input_state.nice_input
+        ),
+        NiceInputState::SelectingPid => "Press Enter to change nice value, [a] CPU affinity, [i] I/O priority, [u] for all of the user's processes, [f] for all matching the filter, [o]/[O] sort, [/] filter".to_string(),
     };
     // If in selection mode or after a message, use yellow (neutral) for input box
     let input_style = if app.nice_input_state == NiceInputState::SelectingPid {
@@ -1846,6 +3636,10 @@ fn draw_change_nice_menu(f: &mut Frame, app: &mut App, area: Rect) {
         Line::from(vec![Span::raw("- Use ↑/↓ to move selection in the process list.")]),
         Line::from(vec![Span::raw("- Press Enter to select a process and input a new nice value.")]),
         Line::from(vec![Span::raw("- Type the new nice value, then Enter to apply." )]),
+        Line::from(vec![Span::raw("- Press u to renice every process of the selected process's user.")]),
+        Line::from(vec![Span::raw("- Press f to renice and/or pin to CPUs every process matching the active filter.")]),
+        Line::from(vec![Span::raw("- Press a to pin the selected process to a list of CPUs.")]),
+        Line::from(vec![Span::raw("- Press i to set the selected process's I/O class and priority (ionice).")]),
         Line::from(vec![Span::raw("- Press Esc to cancel and return.")]),
     ];
     if let Some((msg, is_error)) = &app.input_state.message {
@@ -1857,6 +3651,59 @@ fn draw_change_nice_menu(f: &mut Frame, app: &mut App, area: Rect) {
     let info_box = Paragraph::new(info)
         .block(Block::default().borders(Borders::ALL).title("Help & Status").style(Style::default().fg(Color::Black)));
     f.render_widget(info_box, right_chunks[2]);
+
+    // Confirm a user-wide renice with the affected processes listed
+    if app.nice_input_state == NiceInputState::ConfirmingUserNice {
+        let pids: Vec<u32> = app.renice_targets.iter().map(|(pid, _)| *pid).collect();
+        let names: Vec<String> = app.renice_targets.iter().map(|(_, name)| name.clone()).collect();
+        draw_batch_confirmation_dialog(f, &pids, &names, "renice", area);
+    }
+    if app.nice_input_state == NiceInputState::ConfirmingFilterTune {
+        let pids: Vec<u32> = app.renice_targets.iter().map(|(pid, _)| *pid).collect();
+        let names: Vec<String> = app.renice_targets.iter().map(|(_, name)| name.clone()).collect();
+        draw_batch_confirmation_dialog(f, &pids, &names, "tune", area);
+    }
+}
+
+// Processes matching the active filter or rule as (pid, name), or None when nothing is filtered.
+// lpm itself and the shell that launched it are left out, as for a user-wide renice.
+fn filter_tune_targets(app: &App) -> Option<Vec<(u32, String)>> {
+    let base = if app.rule_engine.active_rule.is_some() {
+        app.process_manager.get_filtered_processes()
+    } else if app.process_manager.has_filter() {
+        app.process_manager.get_processes()
+    } else {
+        return None;
+    };
+    let own_pid = std::process::id();
+    let parent_pid = unsafe { libc::getppid() } as u32;
+    Some(
+        base.iter()
+            .filter(|p| p.pid != own_pid && p.pid != parent_pid)
+            .map(|p| (p.pid, p.name.clone()))
+            .collect(),
+    )
+}
+
+// "10", "@0-3" or "10 @0-3" -> (nice, CPUs)
+fn parse_filter_tune(input: &str) -> Result<(Option<i32>, Option<Vec<usize>>), String> {
+    let (nice_part, cpu_part) = match input.split_once('@') {
+        Some((nice, cpus)) => (nice.trim(), Some(cpus.trim())),
+        None => (input.trim(), None),
+    };
+    let nice = if nice_part.is_empty() {
+        None
+    } else {
+        match nice_part.parse::<i32>() {
+            Ok(nice) if (-20..=19).contains(&nice) => Some(nice),
+            _ => return Err("Error: Nice value must be between -20 and 19".to_string()),
+        }
+    };
+    let cpus = cpu_part.map(process::parse_cpu_list).transpose()?;
+    if nice.is_none() && cpus.is_none() {
+        return Err("Enter a nice value, @cpus or both".to_string());
+    }
+    Ok((nice, cpus))
 }
 
 //scripting ui
@@ -1881,45 +3728,630 @@ fn draw_rule_input(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(input, chunks[0]);
 }
 
-fn get_status_style(status: &str) -> Style {
-    match status.trim().to_lowercase().as_str() {
-        "running" | "run" | "waking" => Style::default().fg(Color::Black).add_modifier(Modifier::BOLD),
-        "sleeping" | "idle" | "parked" => Style::default().fg(Color::Blue),
-        "disk sleep" => Style::default().fg(Color::Magenta),
-        "stopped" | "tracing stop" => Style::default().fg(Color::Yellow),
-        "zombie" | "dead" | "wakekill" => Style::default().fg(Color::Red),
-        _ => Style::default().fg(Color::Black),
+// Upper bounds for auto-fit column widths; NAME gets more room since it is often long
+const AUTO_FIT_MAX_WIDTH: usize = 24;
+const AUTO_FIT_NAME_MAX_WIDTH: usize = 40;
+
+// Cells taken by an inline CPU%/MEM bar, plus the space separating it from the number
+const INLINE_BAR_WIDTH: usize = 5;
+
+// Width one compact process list column needs (without inline bars), and the most columns shown
+const LIST_COLUMN_MIN_WIDTH: u16 = 70;
+const MAX_LIST_COLUMNS: u16 = 3;
+
+// Side-by-side process list columns that fit in `width`; 1 unless multi-column mode is on
+fn list_column_count(app: &App, width: u16) -> usize {
+    if !app.settings.multi_column {
+        return 1;
     }
+    let bar_width = if app.settings.inline_bars { 2 * (INLINE_BAR_WIDTH as u16 + 1) } else { 0 };
+    (width / (LIST_COLUMN_MIN_WIDTH + bar_width)).clamp(1, MAX_LIST_COLUMNS) as usize
 }
 
-fn handle_events(app: &mut App) -> Result<bool, Box<dyn Error>> {
-    if event::poll(Duration::from_millis(100))? {
-        if let Event::Key(key) = event::read()? {
-            match app.view_mode {
-                ViewMode::ProcessList => {
-                    if handle_process_list_input(key, app)? {
-                        return Ok(true);
-                    }
-                }
-                ViewMode::Statistics => {
-                    if handle_statistics_input(key, app)? {
-                        return Ok(true);
-                    }
+// Process list split into `columns` compact tables, filled top-to-bottom then left-to-right.
+// Thread rows are not shown here; the single list has room for them.
+fn draw_process_columns(f: &mut Frame, app: &App, processes: &[&process::ProcessInfo], area: Rect, columns: usize, block: Block) {
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let column_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+        .split(inner);
+
+    let bar_width = if app.settings.inline_bars { INLINE_BAR_WIDTH as u16 + 1 } else { 0 };
+    let mut widths = Vec::new();
+    if app.multi_select_mode {
+        widths.push(Constraint::Length(2)); // Selection indicator
+    }
+    widths.extend([
+        Constraint::Length(8),              // PID
+        Constraint::Min(12),                // NAME
+        Constraint::Length(8 + bar_width),  // CPU%
+        Constraint::Length(10 + bar_width), // MEM
+        Constraint::Length(10),             // STATUS
+    ]);
+    let mut headers = Vec::new();
+    if app.multi_select_mode {
+        headers.push("✓");
+    }
+    headers.extend(["PID", "NAME", "CPU%", "MEM", "STATUS"]);
+    let current_style = Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD);
+
+    for (column, column_area) in column_areas.iter().enumerate() {
+        let first = app.scroll_offset + column * app.column_rows;
+        let rows: Vec<Row> = processes
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(app.column_rows)
+            .map(|(index, process)| {
+                let has_alert = app.alert_manager.get_active_alerts().iter()
+                    .any(|a| a.process_pid == Some(process.pid) && a.is_firing());
+                let name_style = if has_alert {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Black)
+                };
+                let cpu_style = cpu_style(process, &app.settings);
+                let status_style = if stuck_in_d_state(process, app).is_some() {
+                    Style::default().fg(Color::White).bg(Color::Magenta).add_modifier(Modifier::BOLD)
+                } else {
+                    get_status_style(&process.status)
+                };
+                let mut cells = Vec::new();
+                if app.multi_select_mode {
+                    let is_selected = app.selected_processes.contains(&process.pid);
+                    cells.push(Cell::from(if is_selected { "✓" } else { " " }).style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)));
                 }
-                ViewMode::FilterSort => {
-                    if handle_filter_sort_input(key, app)? {
-                        return Ok(true);
-                    }
+                let name = if process::is_locked(process) {
+                    format!("🔒 {}", process.name)
+                } else {
+                    process.name.clone()
+                };
+                cells.extend([
+                    Cell::from(process.pid.to_string()).style(Style::default().fg(Color::Black)),
+                    Cell::from(name).style(name_style),
+                    Cell::from(cpu_cell_text(process, app)).style(cpu_style),
+                    Cell::from(mem_cell_text(process, app)).style(Style::default().fg(Color::Black)),
+                    Cell::from(status_text(process, app)).style(status_style),
+                ]);
+                let row = Row::new(cells);
+                if index == app.selected_process_index { row.style(current_style) } else { row }
+            })
+            .collect();
+        let header = Row::new(headers.iter().map(|h| Cell::from(*h).style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD))))
+            .style(Style::default().bg(Color::Black))
+            .height(1);
+        // A gap between columns keeps adjacent STATUS and PID values apart
+        let table_area = Rect { width: column_area.width.saturating_sub(1), ..*column_area };
+        let table = Table::new(rows).header(header).widths(&widths);
+        f.render_widget(table, table_area);
+    }
+}
+
+// Proportional bar of `width` cells using eighth-block characters for sub-cell precision
+fn inline_bar(value: f64, max: f64, width: usize) -> String {
+    const PARTIALS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+    let fraction = if max > 0.0 { (value / max).clamp(0.0, 1.0) } else { 0.0 };
+    let eighths = (fraction * (width * 8) as f64).round() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if !eighths.is_multiple_of(8) {
+        bar.push(PARTIALS[eighths % 8]);
+    }
+    let filled = bar.chars().count();
+    bar.push_str(&" ".repeat(width - filled));
+    bar
+}
+
+// CPU% cell text, with a bar relative to all cores busy when inline bars are on
+fn cpu_cell_text(process: &process::ProcessInfo, app: &App) -> String {
+    if app.settings.inline_bars {
+        let full_scale = app.process_manager.cpu_full_scale() as f64;
+        format!("{} {:.2}%", inline_bar(process.cpu_usage as f64, full_scale, INLINE_BAR_WIDTH), process.cpu_usage)
+    } else {
+        format!("{:.2}%", process.cpu_usage)
+    }
+}
+
+// MEM cell text, with a bar relative to the configured ceiling (or total RAM) when inline bars are on
+fn mem_cell_text(process: &process::ProcessInfo, app: &App) -> String {
+    let memory = crate::units::format_bytes(process.memory_usage);
+    if app.settings.inline_bars {
+        // The ceiling setting is in MiB
+        let ceiling = match app.settings.memory_bar_ceiling_mb {
+            0 => app.process_manager.get_total_memory(),
+            mib => mib * 1024 * 1024,
+        };
+        format!("{} {}", inline_bar(process.memory_usage as f64, ceiling as f64, INLINE_BAR_WIDTH), memory)
+    } else {
+        memory
+    }
+}
+
+// Scheduler actions that kill, stop, renice or signal processes; only these are armed
+fn is_destructive_schedule_action(action: &crate::scheduler::ScheduleAction) -> bool {
+    use crate::scheduler::ScheduleAction;
+    !matches!(action, ScheduleAction::StartProcess { .. } | ScheduleAction::ApplyRule { .. } | ScheduleAction::ContinueProcess { .. })
+}
+
+// Countdown text for a scheduler action, naming the target process when it is running
+fn describe_schedule_action(action: &crate::scheduler::ScheduleAction, processes: &[process::ProcessInfo]) -> String {
+    use crate::scheduler::ScheduleAction;
+    let target = |pid: &u32| match processes.iter().find(|p| p.pid == *pid) {
+        Some(process) => format!("{} ({})", process.name, pid),
+        None => format!("PID {}", pid),
+    };
+    match action {
+        ScheduleAction::KillProcess { pid } => format!("Killing {}", target(pid)),
+        ScheduleAction::StopProcess { pid } => format!("Stopping {}", target(pid)),
+        ScheduleAction::ReniceProcess { pid, nice } => format!("Renicing {} to {}", target(pid), nice),
+        ScheduleAction::SendSignal { pid, signal } => format!("Sending {} to {}", signal, target(pid)),
+        ScheduleAction::RestartProcess { pattern } => format!("Restarting processes matching '{}'", pattern),
+        ScheduleAction::CleanupIdle { action, .. } => format!("Idle cleanup ({})", action),
+        ScheduleAction::StartProcess { program, .. } => format!("Starting {}", program),
+        ScheduleAction::ApplyRule { rule } => format!("Applying rule {}", rule),
+        ScheduleAction::ContinueProcess { pid } => format!("Continuing {}", target(pid)),
+    }
+}
+
+// Remember processes lpm stopped so they can be resumed later
+fn track_suspension(
+    suspended: &mut std::collections::HashMap<u32, (String, std::time::Instant)>,
+    processes: &[process::ProcessInfo],
+    pid: u32,
+    action: &str,
+) {
+    match action {
+        "stop" => {
+            let name = processes.iter()
+                .find(|p| p.pid == pid)
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| "unknown".to_string());
+            suspended.insert(pid, (name, std::time::Instant::now()));
+        }
+        "continue" | "kill" => {
+            suspended.remove(&pid);
+        }
+        _ => {}
+    }
+}
+
+// STATUS column text; processes lpm stopped itself are marked so they aren't forgotten
+fn status_text(process: &process::ProcessInfo, app: &App) -> String {
+    if app.suspended_by_lpm.contains_key(&process.pid) {
+        format!("{} [lpm]", process.status.trim())
+    } else if let Some(secs) = stuck_in_d_state(process, app) {
+        format!("{} {}s", process.status.trim(), secs)
+    } else {
+        process.status.trim().to_string()
+    }
+}
+
+// CPU cell color: flat 25/50% thresholds, or by scheduling class when sched_aware_cpu_colors is on
+fn cpu_style(process: &process::ProcessInfo, settings: &crate::settings::Settings) -> Style {
+    let busy = match process.cpu_usage {
+        c if c > 50.0 => Color::Red,
+        c if c > 25.0 => Color::Yellow,
+        _ => Color::Green,
+    };
+    if !settings.sched_aware_cpu_colors || process.cpu_usage <= 25.0 {
+        return Style::default().fg(busy);
+    }
+    match process::sched_class(process.sched_policy) {
+        process::SchedClass::Background => Style::default().fg(Color::Green),
+        process::SchedClass::Realtime => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        process::SchedClass::Normal => Style::default().fg(busy),
+    }
+}
+
+// Seconds a process has been continuously in D state, once past settings.d_state_warn_secs
+fn stuck_in_d_state(process: &process::ProcessInfo, app: &App) -> Option<u64> {
+    app.process_manager
+        .d_state_duration(process.pid)
+        .map(|duration| duration.as_secs())
+        .filter(|secs| *secs >= app.settings.d_state_warn_secs)
+}
+
+// Texts shown in the process list columns after PID (HOST if enabled, then NAME..PPID)
+fn process_column_texts(process: &process::ProcessInfo, app: &App) -> Vec<String> {
+    let mut texts = vec![process.pid.to_string()];
+    if app.multi_host_mode {
+        texts.push(process.host.clone().unwrap_or_else(|| "local".to_string()));
+    }
+    texts.extend(vec![
+        process.name.clone(),
+        process.user.clone().unwrap_or_default(),
+        process.tty.clone(),
+        cpu_cell_text(process, app),
+        mem_cell_text(process, app),
+        process.start_time_str.clone(),
+        process.nice.to_string(),
+        status_text(process, app),
+        process.parent_pid.unwrap_or(0).to_string(),
+    ]);
+    if let Some(column) = cpu_time_column(app) {
+        texts.insert(column, process::format_cpu_time(process.cpu_time_secs));
+    }
+    if app.settings.show_io {
+        texts.push(io_text(process));
+    }
+    if app.settings.show_thread_count {
+        texts.push(process.thread_count.to_string());
+    }
+    if app.settings.show_unit {
+        texts.push(unit_text(process));
+    }
+    texts
+}
+
+// I/O column text: disk bytes read + written per second since the previous refresh
+// (0 when /proc/<pid>/io is unreadable, e.g. another user's process)
+fn io_text(process: &process::ProcessInfo) -> String {
+    format!("{}/s", crate::units::format_bytes((process.io_read_rate + process.io_write_rate) as u64))
+}
+
+// UNIT column text; "-" for processes outside any systemd unit (or hosts without systemd)
+fn unit_text(process: &process::ProcessInfo) -> String {
+    process.unit.clone().unwrap_or_else(|| "-".to_string())
+}
+
+// Revert the most recent reversible change by reapplying the recorded old values
+fn undo_last_action(app: &mut App) {
+    let action = match app.last_reversible_action.take() {
+        Some(action) => action,
+        None => {
+            app.input_state.message = Some(("Nothing to undo".to_string(), true));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            return;
+        }
+    };
+    match action {
+        ReversibleAction::Renice { changes, new_nice } => {
+            let mut restored = Vec::new();
+            let mut failed = 0;
+            for change in &changes {
+                // Only touch the same process, and only if nothing else has reniced it since
+                // (the list may not have refreshed yet, so the old value also counts)
+                let unchanged = app.process_manager.get_processes().iter().any(|p| {
+                    p.pid == change.pid
+                        && p.start_timestamp == change.start_timestamp
+                        && (p.nice == new_nice || p.nice == change.old_nice)
+                });
+                if !unchanged {
+                    continue;
                 }
-                ViewMode::Sort => {
-                    if handle_sort_input(key, app)? {
-                        return Ok(true);
-                    }
+                match app.process_manager.set_niceness(change.pid, change.old_nice) {
+                    Ok(_) => restored.push(change),
+                    Err(_) => failed += 1,
                 }
-                ViewMode::Filter => {
-                    if handle_filter_input(key, app)? {
-                        return Ok(true);
-                    }
+            }
+            let message = match restored.as_slice() {
+                [] if failed == 0 => "Undo: no reniced processes left to restore".to_string(),
+                [change] if failed == 0 => format!(
+                    "Undid renice of {} (PID {}): nice {} -> {}",
+                    change.name, change.pid, new_nice, change.old_nice
+                ),
+                _ => format!(
+                    "Undid renice to {}: restored {} process(es), {} failed",
+                    new_nice,
+                    restored.len(),
+                    failed
+                ),
+            };
+            app.input_state.message = Some((message, failed > 0));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+        }
+        ReversibleAction::Affinity { pid, name, start_timestamp, previous } => {
+            let same_process = app.process_manager.get_processes()
+                .iter()
+                .any(|p| p.pid == pid && p.start_timestamp == start_timestamp);
+            // CPUs taken offline since the edit can no longer be restored
+            let online = process::online_cpus();
+            let previous: Vec<usize> = previous.into_iter().filter(|cpu| online.contains(cpu)).collect();
+            let (message, is_error) = if !same_process {
+                (format!("Undo: {} (PID {}) has exited", name, pid), true)
+            } else {
+                match app.process_manager.set_cpu_affinity(pid, &previous) {
+                    Ok(()) => (format!("Undid CPU pinning of {} (PID {}): CPUs {}", name, pid, process::format_cpu_list(&previous)), false),
+                    Err(e) => (format!("Undo failed for {} (PID {}): {}", name, pid, e), true),
+                }
+            };
+            app.input_state.message = Some((message, is_error));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+        }
+    }
+}
+
+// Order-independent hash of the process set, with CPU% and memory (% of RAM) bucketed by `tolerance`
+fn process_fingerprint(processes: &[process::ProcessInfo], tolerance: f32, total_memory: u64) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let tolerance = tolerance.max(0.01) as f64;
+    let total_memory = total_memory.max(1) as f64;
+    processes.iter().fold(processes.len() as u64, |acc, p| {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        p.pid.hash(&mut hasher);
+        p.status.hash(&mut hasher);
+        p.nice.hash(&mut hasher);
+        ((p.cpu_usage as f64 / tolerance).round() as i64).hash(&mut hasher);
+        ((p.memory_usage as f64 / total_memory * 100.0 / tolerance).round() as i64).hash(&mut hasher);
+        acc.wrapping_add(hasher.finish())
+    })
+}
+
+// Operator note attached to this process instance, if any
+fn process_note<'a>(app: &'a App, process: &process::ProcessInfo) -> Option<&'a str> {
+    app.process_notes
+        .get(&(process.name.clone(), process.start_timestamp))
+        .map(|(_, text)| text.as_str())
+}
+
+// Show only the processes of one PID namespace in the main list
+fn focus_pid_namespace(app: &mut App, namespace: u64) {
+    app.process_manager.set_pid_namespace(Some(namespace));
+    app.process_manager.resort();
+    app.selected_process_index = 0;
+    app.scroll_offset = 0;
+    app.view_mode = ViewMode::ProcessList;
+    app.input_state.message = Some((format!("Showing PID namespace {} only - [I] shows all", namespace), false));
+    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+}
+
+// Compare a process with its previous snapshot against the change-highlight thresholds
+fn process_change(prev: &process::ProcessInfo, current: &process::ProcessInfo, settings: &crate::settings::Settings) -> ProcessChange {
+    let memory_delta = current.memory_usage.abs_diff(prev.memory_usage) as f64;
+    ProcessChange {
+        cpu: (current.cpu_usage - prev.cpu_usage).abs() >= settings.change_cpu_points,
+        memory: memory_delta > 0.0 && memory_delta >= prev.memory_usage as f64 * settings.change_memory_percent as f64 / 100.0,
+        status: current.status != prev.status,
+    }
+}
+
+// Select a PID in the current (filtered) list and scroll it into view
+fn jump_to_pid(app: &mut App, pid: u32) {
+    match app.process_manager.get_processes().iter().position(|p| p.pid == pid) {
+        Some(index) => {
+            app.selected_process_index = index;
+            if index < app.scroll_offset || index >= app.scroll_offset + app.display_limit {
+                app.scroll_offset = index.saturating_sub(app.display_limit / 2);
+            }
+        }
+        None => {
+            app.input_state.message = Some((format!("PID {} not found", pid), true));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        }
+    }
+}
+
+// Case-insensitive name substring or PID prefix; an empty query matches nothing
+fn list_search_matches(query: &str, process: &process::ProcessInfo) -> bool {
+    !query.is_empty()
+        && (process.name.to_lowercase().contains(&query.to_lowercase()) || process.pid.to_string().starts_with(query))
+}
+
+#[derive(Clone, Copy)]
+enum SearchStep {
+    First,
+    Next,
+    Previous,
+}
+
+// Move the process list selection to a search match, wrapping around at either end
+fn jump_to_search_match(app: &mut App, step: SearchStep) {
+    let listed = view_processes(app, ViewMode::ProcessList);
+    let matches: Vec<usize> = listed
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| list_search_matches(&app.list_search_query, p))
+        .map(|(i, _)| i)
+        .collect();
+    let current = app.selected_process_index;
+    let target = match step {
+        SearchStep::First => matches.first(),
+        SearchStep::Next => matches.iter().find(|&&i| i > current).or(matches.first()),
+        SearchStep::Previous => matches.iter().rev().find(|&&i| i < current).or(matches.last()),
+    };
+    match target {
+        Some(&index) => reselect_pid(app, ViewMode::ProcessList, Some(listed[index].pid)),
+        None if !app.list_search_query.is_empty() => {
+            app.input_state.message = Some((format!("No process matches '{}'", app.list_search_query), true));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        }
+        None => {}
+    }
+}
+
+// Jump to the process holding a TCP port; several owners are shown with a PID filter
+fn find_by_port(app: &mut App, port: u16) {
+    let owners = ports::find_port_owners(app.process_manager.get_proc_root(), port);
+    let mut pids: Vec<u32> = owners.iter().map(|o| o.pid).collect();
+    pids.dedup();
+    if pids.is_empty() {
+        app.input_state.message = Some((format!("No process holds port {} (or its sockets are not readable - try sudo)", port), true));
+        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+        return;
+    }
+    if pids.len() > 1 {
+        app.input_state.advanced_filter_input = pids
+            .iter()
+            .map(|pid| format!("pid == {}", pid))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        apply_advanced_filter(app);
+    }
+    jump_to_pid(app, pids[0]);
+    let summary = owners
+        .iter()
+        .map(|o| format!("{} {} ({})", o.pid, o.socket.state.label(), o.socket.protocol))
+        .collect::<Vec<_>>()
+        .join(", ");
+    app.input_state.message = Some((format!("Port {}: {}", port, summary), false));
+    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(5));
+}
+
+// Sub-row for one thread of a process, laid out in the process table's columns
+fn thread_row<'a>(process: &process::ProcessInfo, thread: &process::ThreadInfo, app: &App) -> Row<'a> {
+    let style = Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC);
+    let mut cells = Vec::new();
+    if app.multi_select_mode {
+        cells.push(Cell::from(""));
+    }
+    cells.push(Cell::from(format!("└{}", thread.tid)));
+    if app.multi_host_mode {
+        cells.push(Cell::from(""));
+    }
+    cells.extend(vec![
+        Cell::from(format!(" └─ {}", thread.name)),
+        Cell::from(""),
+        Cell::from(""),
+        Cell::from(format!("{:.2}%", thread.cpu_usage)),
+        Cell::from(""),
+        Cell::from(""),
+        Cell::from(""),
+        Cell::from(thread.status.clone()),
+        Cell::from(process.pid.to_string()),
+    ]);
+    if let Some(column) = cpu_time_column(app) {
+        cells.insert(usize::from(app.multi_select_mode) + column, Cell::from(""));
+    }
+    if app.settings.show_io {
+        cells.push(Cell::from(""));
+    }
+    if app.settings.show_thread_count {
+        cells.push(Cell::from(""));
+    }
+    if app.settings.show_unit {
+        cells.push(Cell::from(""));
+    }
+    Row::new(cells).style(style)
+}
+
+// Index of the TIME+ column (right after MEM, not counting the selection column), when shown
+// Sort keys in the order their columns appear, for cycling with </>
+fn sortable_columns(app: &App) -> Vec<&'static str> {
+    let mut columns = vec!["pid", "name", "user", "cpu", "mem"];
+    if app.settings.show_cpu_time {
+        columns.push("time");
+    }
+    columns.extend(["start", "nice", "status", "ppid"]);
+    if app.settings.show_io {
+        columns.push("io");
+    }
+    if app.settings.show_thread_count {
+        columns.push("threads");
+    }
+    columns
+}
+
+fn cpu_time_column(app: &App) -> Option<usize> {
+    // PID, [HOST], NAME, USER, TTY, CPU%, MEM
+    app.settings.show_cpu_time.then(|| 6 + usize::from(app.multi_host_mode))
+}
+
+// Compute column widths from the widest header/cell in the visible window
+fn auto_fit_widths(headers: &[String], processes: &[&process::ProcessInfo], app: &App) -> Vec<Constraint> {
+    let mut widths = Vec::new();
+    if app.multi_select_mode {
+        widths.push(Constraint::Length(2)); // Selection indicator
+    }
+    let name_column = if app.multi_host_mode { 2 } else { 1 };
+    let header_offset = widths.len();
+    let visible: Vec<Vec<String>> = processes
+        .iter()
+        .skip(app.scroll_offset)
+        .take(app.display_limit)
+        .map(|p| process_column_texts(p, app))
+        .collect();
+
+    for (column, header) in headers.iter().skip(header_offset).enumerate() {
+        let max = if column == name_column { AUTO_FIT_NAME_MAX_WIDTH } else { AUTO_FIT_MAX_WIDTH };
+        let content = visible
+            .iter()
+            .filter_map(|texts| texts.get(column))
+            .map(|text| text.chars().count())
+            .max()
+            .unwrap_or(0);
+        let width = content.max(header.chars().count()).min(max) + 1; // +1 for spacing
+        widths.push(Constraint::Length(width as u16));
+    }
+    widths
+}
+
+// Truncate text to at most `max` characters, marking the cut with an ellipsis
+fn ellipsize(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        let mut truncated: String = text.chars().take(max.saturating_sub(1)).collect();
+        truncated.push('…');
+        truncated
+    }
+}
+
+fn get_status_style(status: &str) -> Style {
+    match status.trim().to_lowercase().as_str() {
+        "running" | "run" | "waking" => Style::default().fg(Color::Black).add_modifier(Modifier::BOLD),
+        "sleeping" | "idle" | "parked" => Style::default().fg(Color::Blue),
+        "disk sleep" => Style::default().fg(Color::Magenta),
+        "stopped" | "tracing stop" => Style::default().fg(Color::Yellow),
+        "zombie" | "dead" | "wakekill" => Style::default().fg(Color::Red),
+        _ => Style::default().fg(Color::Black),
+    }
+}
+
+fn handle_events(app: &mut App) -> Result<bool, Box<dyn Error>> {
+    if event::poll(Duration::from_millis(100))? {
+        // Any input (keys, resize, mouse) may change what is on screen
+        app.needs_redraw = true;
+        if let Event::Key(key) = event::read()? {
+            // Esc dismisses any visible alert toasts before reaching the view
+            if key.code == KeyCode::Esc && !app.alert_toasts.is_empty() {
+                app.alert_toasts.clear();
+                return Ok(false);
+            }
+            // X vetoes armed auto-actions while their countdown is showing
+            if key.code == KeyCode::Char('X') && !app.pending_actions.is_empty() {
+                app.cancel_pending_actions();
+                return Ok(false);
+            }
+            // F2 shows/hides the gauge header (persisted)
+            if key.code == KeyCode::F(2) {
+                app.settings.show_gauge_header = !app.settings.show_gauge_header;
+                let _ = app.settings.save();
+                return Ok(false);
+            }
+            // Home jumps back to the process list (Ctrl+Home in statistics, where Home scrolls)
+            if key.code == KeyCode::Home
+                && app.view_mode != ViewMode::ProcessList
+                && (app.view_mode != ViewMode::Statistics || key.modifiers.contains(KeyModifiers::CONTROL))
+            {
+                app.return_to_process_list();
+                return Ok(false);
+            }
+            match app.view_mode {
+                ViewMode::ProcessList => {
+                    if handle_process_list_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::Statistics => {
+                    if handle_statistics_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::FilterSort => {
+                    if handle_filter_sort_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::Sort => {
+                    if handle_sort_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::Filter => {
+                    if handle_filter_input(key, app)? {
+                        return Ok(true);
+                    }
                 }
                 ViewMode::FilterInput => {
                     if handle_filter_input(key, app)? {
@@ -1962,6 +4394,10 @@ fn handle_events(app: &mut App) -> Result<bool, Box<dyn Error>> {
                         KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
                             app.view_mode = ViewMode::ProcessList;
                         }
+                        KeyCode::Char('b') => {
+                            app.view_mode = ViewMode::BootReport;
+                            app.boot_report_scroll = 0;
+                        }
                         _ => {}
                     }
                     return Ok(false);
@@ -2033,6 +4469,46 @@ fn handle_events(app: &mut App) -> Result<bool, Box<dyn Error>> {
                         return Ok(true);
                     }
                 }
+                ViewMode::Suspended => {
+                    if handle_suspended_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::LogTail => {
+                    if handle_log_tail_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::OrderedRestart => {
+                    if handle_ordered_restart_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::UserSummary => {
+                    if handle_user_summary_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::HostDiff => {
+                    if handle_host_diff_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::Dashboard => {
+                    if handle_dashboard_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::ProcessTree => {
+                    if handle_process_tree_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
+                ViewMode::BootReport => {
+                    if handle_boot_report_input(key, app)? {
+                        return Ok(true);
+                    }
+                }
             }
         }
     }
@@ -2040,72 +4516,473 @@ fn handle_events(app: &mut App) -> Result<bool, Box<dyn Error>> {
 }
 
 fn handle_process_list_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
-    match key.code {
-        KeyCode::Char('a') => {
-            app.sort_ascending = !app.sort_ascending;
-            if let Some(mode) = &app.sort_mode {
-                app.process_manager.set_sort(mode, app.sort_ascending);
+    // Any key closes the detail popup
+    if app.process_detail.take().is_some() {
+        return Ok(false);
+    }
+    if app.goto_pid_active {
+        match key.code {
+            KeyCode::Esc => {
+                app.goto_pid_active = false;
+                app.goto_pid_input.clear();
             }
-        }        
-        KeyCode::Char('q') => return Ok(true),
-        KeyCode::Char('s') | KeyCode::Char('S') => app.view_mode = ViewMode::Statistics,
-        KeyCode::Up => {
-            if app.selected_process_index > 0 {
-                app.selected_process_index -= 1;
-                // Adjust scroll if needed
-                if app.selected_process_index < app.scroll_offset {
-                    app.scroll_offset = app.selected_process_index;
+            KeyCode::Enter => {
+                app.goto_pid_active = false;
+                let input = std::mem::take(&mut app.goto_pid_input);
+                match input.trim().parse::<u32>() {
+                    Ok(pid) => jump_to_pid(app, pid),
+                    Err(_) => {
+                        app.input_state.message = Some((format!("Invalid PID: {}", input.trim()), true));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                    }
                 }
             }
+            KeyCode::Backspace => {
+                app.goto_pid_input.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                app.goto_pid_input.push(c);
+            }
+            _ => {}
         }
-        KeyCode::Down => {
-            let process_len = app.process_manager.get_processes().len();
-            if app.selected_process_index + 1 < process_len {
-                app.selected_process_index += 1;
-                // Adjust scroll if needed
-                let bottom = app.scroll_offset + app.display_limit;
-                if app.selected_process_index >= bottom {
-                    app.scroll_offset = app.selected_process_index - app.display_limit + 1;
+        return Ok(false);
+    }
+    if let Some((pid, name, start_timestamp)) = app.note_editing.clone() {
+        match key.code {
+            KeyCode::Esc => {
+                app.note_editing = None;
+                app.note_input.clear();
+            }
+            KeyCode::Enter => {
+                app.note_editing = None;
+                let text = std::mem::take(&mut app.note_input).trim().to_string();
+                // An empty note removes it
+                if text.is_empty() {
+                    app.process_notes.remove(&(name, start_timestamp));
+                } else {
+                    app.process_notes.insert((name, start_timestamp), (pid, text));
                 }
+                app.save_notes();
+            }
+            KeyCode::Backspace => {
+                app.note_input.pop();
             }
+            KeyCode::Char(c) => {
+                app.note_input.push(c);
+            }
+            _ => {}
         }
-        KeyCode::Char('1') => app.view_mode = ViewMode::FilterSort,
-        KeyCode::Char('2') => app.view_mode = ViewMode::ChangeNice,
-        KeyCode::Char('3') => {
-            app.view_mode = ViewMode::KillStop;
-            if !app.selected_processes.is_empty() {
-                // If we have selected processes, skip selection and go to action
-                app.kill_stop_input_state = KillStopInputState::EnteringAction;
-            } else {
-                // Otherwise start selecting a PID
-                app.kill_stop_input_state = KillStopInputState::SelectingPid;
+        return Ok(false);
+    }
+    if app.list_search_active {
+        match key.code {
+            KeyCode::Esc => {
+                app.list_search_active = false;
+                app.list_search_query.clear();
             }
-        },
-        KeyCode::Char('4') => {
-            app.view_mode = ViewMode::PerProcessGraph;
+            KeyCode::Enter => {
+                app.list_search_active = false;
+            }
+            KeyCode::Backspace => {
+                app.list_search_query.pop();
+                jump_to_search_match(app, SearchStep::First);
+            }
+            KeyCode::Char(c) => {
+                app.list_search_query.push(c);
+                jump_to_search_match(app, SearchStep::First);
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+    // With a search kept, n/N cycle its matches and Esc drops it
+    if !app.list_search_query.is_empty() {
+        match key.code {
+            KeyCode::Char('n') => {
+                jump_to_search_match(app, SearchStep::Next);
+                return Ok(false);
+            }
+            KeyCode::Char('N') => {
+                jump_to_search_match(app, SearchStep::Previous);
+                return Ok(false);
+            }
+            KeyCode::Esc => {
+                app.list_search_query.clear();
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+    if app.port_lookup_active {
+        match key.code {
+            KeyCode::Esc => {
+                app.port_lookup_active = false;
+                app.port_lookup_input.clear();
+            }
+            KeyCode::Enter => {
+                app.port_lookup_active = false;
+                let input = std::mem::take(&mut app.port_lookup_input);
+                match input.trim().parse::<u16>() {
+                    Ok(port) => find_by_port(app, port),
+                    Err(_) => {
+                        app.input_state.message = Some((format!("Invalid port: {}", input.trim()), true));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                    }
+                }
+            }
+            KeyCode::Backspace => {
+                app.port_lookup_input.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                app.port_lookup_input.push(c);
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Char(':') | KeyCode::Char('#') => {
+            app.goto_pid_active = true;
+            app.goto_pid_input.clear();
+        }
+        KeyCode::Char('/') => {
+            app.list_search_active = true;
+            app.list_search_query.clear();
+        }
+        KeyCode::Char('E') => {
+            // Attach a note to the selected process (persisted until it exits)
+            if let Some(process) = app.process_manager.get_processes().get(app.selected_process_index) {
+                let key = (process.name.clone(), process.start_timestamp);
+                app.note_input = app.process_notes.get(&key).map(|(_, text)| text.clone()).unwrap_or_default();
+                app.note_editing = Some((process.pid, key.0, key.1));
+            }
+        }
+        KeyCode::Char('o') => {
+            app.port_lookup_active = true;
+            app.port_lookup_input.clear();
+        }
+        KeyCode::Char('K') => {
+            // Toggle kernel thread visibility (persisted)
+            app.settings.hide_kernel_threads = !app.settings.hide_kernel_threads;
+            app.process_manager.set_hide_kernel_threads(app.settings.hide_kernel_threads);
+            let _ = app.settings.save();
             app.selected_process_index = 0;
-            app.per_process_graph_scroll_offset = 0;
-            app.selected_process_for_graph = None;
+            app.scroll_offset = 0;
+        }
+        KeyCode::Char('L') => {
+            // Tail the selected process's log file
+            if let Some(process) = app.process_manager.get_processes().get(app.selected_process_index) {
+                let pid = process.pid;
+                app.log_tail_candidates = app.process_manager.find_log_files(pid);
+                if app.log_tail_candidates.is_empty() {
+                    app.input_state.message = Some((format!("No log files found for PID {}", pid), true));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                } else {
+                    open_log_tail(app, 0);
+                }
+            }
         }
-        KeyCode::Char('5') => app.view_mode = ViewMode::ProcessLog,
-        KeyCode::Char('6') => app.view_mode = ViewMode::Help,
-        KeyCode::Char('g') | KeyCode::Char('G') => {
-            app.view_mode = ViewMode::GroupedView;
-            app.grouped_view_type = crate::process_group::GroupType::Cgroup;
-            app.selected_group_index = 0;
-            app.grouped_view_scroll_offset = 0;
-        },
-        KeyCode::Char('j') | KeyCode::Char('J') => {
-            app.view_mode = ViewMode::Scheduler;
-            app.selected_task_index = 0;
-            app.scheduler_scroll_offset = 0;
-        },
+        KeyCode::Char('U') => {
+            app.view_mode = ViewMode::Suspended;
+            app.selected_suspended_index = 0;
+        }
+        KeyCode::Char('w') => {
+            // Who is using the machine: totals per user
+            app.view_mode = ViewMode::UserSummary;
+            app.selected_user_index = 0;
+        }
+        KeyCode::Char('F') => {
+            // Parent/child hierarchy, starting with the selected process highlighted
+            let selected = view_processes(app, ViewMode::ProcessList).get(app.selected_process_index).map(|p| p.pid);
+            app.view_mode = ViewMode::ProcessTree;
+            app.selected_tree_index = selected
+                .and_then(|pid| process_tree_rows(app.process_manager.get_processes(), &app.tree_collapsed).iter().position(|row| row.pid == pid))
+                .unwrap_or(0);
+        }
+        KeyCode::Char('t') => {
+            // Expand/collapse the threads of the selected process
+            if let Some(process) = app.process_manager.get_processes().get(app.selected_process_index) {
+                let pid = process.pid;
+                if !app.thread_expanded.remove(&pid) {
+                    app.thread_expanded.insert(pid);
+                }
+                app.refresh_threads();
+            }
+        }
+        KeyCode::Char('T') => {
+            // Show threads under every visible process (reads /proc/<pid>/task for each)
+            app.show_threads = !app.show_threads;
+            app.refresh_threads();
+        }
+        KeyCode::Char('u') => undo_last_action(app),
+        KeyCode::Char('O') => begin_ordered_restart(app),
+        KeyCode::Char('z') => {
+            // Mark now; a running "since mark" view follows the new mark
+            let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_secs();
+            app.start_mark = Some(now);
+            if app.process_manager.get_started_after().is_some() {
+                app.process_manager.set_started_after(Some(now));
+                app.selected_process_index = 0;
+                app.scroll_offset = 0;
+            }
+            app.input_state.message = Some(("Marked now - [Z] lists processes started since".to_string(), false));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        }
+        KeyCode::Char('I') => {
+            // Scope the list to the selected process's PID namespace (e.g. its container), or back to everything
+            if app.process_manager.get_pid_namespace().is_some() {
+                app.process_manager.set_pid_namespace(None);
+                app.process_manager.resort();
+            } else if let Some(pid) = app.process_manager.get_processes().get(app.selected_process_index).map(|p| p.pid) {
+                match app.process_manager.pid_namespace_of(pid) {
+                    Some(namespace) => focus_pid_namespace(app, namespace),
+                    None => {
+                        app.input_state.message = Some((format!("Cannot read the PID namespace of PID {}", pid), true));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                    }
+                }
+            }
+        }
+        KeyCode::Char('Z') => match app.start_mark {
+            Some(mark) if app.process_manager.get_started_after().is_none() => {
+                // Newest arrivals first
+                app.settings.sort_directions.insert("start".to_string(), false);
+                let _ = app.settings.save();
+                app.sort_mode = Some("start".to_string());
+                app.process_manager.set_started_after(Some(mark));
+                app.process_manager.set_sort("start", false);
+                app.selected_process_index = 0;
+                app.scroll_offset = 0;
+            }
+            Some(_) => {
+                app.process_manager.set_started_after(None);
+                app.process_manager.resort();
+            }
+            None => {
+                app.input_state.message = Some(("No mark yet - press [z] to mark the current time".to_string(), true));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            }
+        },
+        KeyCode::Char('<') | KeyCode::Char('>') => {
+            // Move the primary sort key one sortable column left/right (wrapping)
+            let columns = sortable_columns(app);
+            let current = app.sort_mode.as_deref().and_then(|mode| columns.iter().position(|c| *c == mode));
+            let next = match (current, key.code) {
+                (Some(i), KeyCode::Char('<')) => (i + columns.len() - 1) % columns.len(),
+                (Some(i), _) => (i + 1) % columns.len(),
+                (None, _) => 0,
+            };
+            let mode = columns[next];
+            app.sort_mode = Some(mode.to_string());
+            app.process_manager.set_sort(mode, app.settings.sort_ascending(mode));
+        }
+        KeyCode::Char('=') => app.toggle_sort_direction(),
+        KeyCode::Char('f') => {
+            // Pin/unpin the selected process's name to the top of the list (persisted)
+            if let Some(process) = app.process_manager.get_processes().get(app.selected_process_index) {
+                let (pid, name) = (process.pid, process.name.clone());
+                let message = if let Some(pos) = app.settings.pinned_names.iter().position(|n| *n == name) {
+                    app.settings.pinned_names.remove(pos);
+                    format!("Unpinned {}", name)
+                } else {
+                    app.settings.pinned_names.push(name.clone());
+                    format!("Pinned {} to the top", name)
+                };
+                let _ = app.settings.save();
+                app.sync_pinned();
+                // Keep the cursor on the process that just moved
+                if let Some(index) = app.process_manager.get_processes().iter().position(|p| p.pid == pid) {
+                    app.selected_process_index = index;
+                    if index < app.scroll_offset {
+                        app.scroll_offset = index;
+                    } else if index >= app.scroll_offset + app.display_limit {
+                        app.scroll_offset = index + 1 - app.display_limit;
+                    }
+                }
+                app.input_state.message = Some((message, false));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            }
+        }
+        KeyCode::Char('l') => {
+            // Lock/unlock the selected process against kill and stop
+            if let Some(process) = app.process_manager.get_processes().get(app.selected_process_index) {
+                let message = if process::toggle_lock(process) {
+                    format!("Locked {} (PID {}) - kill and stop are refused until unlocked", process.name, process.pid)
+                } else {
+                    format!("Unlocked {} (PID {})", process.name, process.pid)
+                };
+                app.input_state.message = Some((message, false));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            }
+        }
+        KeyCode::Char('%') => {
+            // Switch CPU% between per-core and share-of-system (persisted)
+            app.settings.cpu_per_core = !app.settings.cpu_per_core;
+            app.process_manager.set_cpu_per_core(app.settings.cpu_per_core);
+            // Old samples are in the other unit
+            app.graph_data.clear_process_cpu_history();
+            let _ = app.settings.save();
+        }
+        KeyCode::Char('b') => {
+            // Switch memory sizes between binary and decimal units (persisted)
+            app.settings.memory_units = match app.settings.memory_units {
+                crate::units::MemoryUnits::Binary => crate::units::MemoryUnits::Decimal,
+                crate::units::MemoryUnits::Decimal => crate::units::MemoryUnits::Binary,
+            };
+            crate::units::set_memory_units(app.settings.memory_units);
+            let _ = app.settings.save();
+            app.input_state.message = Some((format!("Memory units: {}", app.settings.memory_units.label()), false));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        }
+        KeyCode::Char('i') => {
+            // Toggle the cumulative CPU time column (persisted)
+            app.settings.show_cpu_time = !app.settings.show_cpu_time;
+            let _ = app.settings.save();
+        }
+        KeyCode::Char(c @ ('+' | '-')) => {
+            // Adjust the refresh interval (persisted): 100ms steps below a second, 500ms above
+            let current = app.refresh_interval.as_millis() as u64;
+            let next = match (c, current < 1000) {
+                ('+', true) => current + 100,
+                ('+', false) => current + 500,
+                (_, true) => current.saturating_sub(100),
+                (_, false) => current.saturating_sub(if current == 1000 { 100 } else { 500 }),
+            };
+            app.set_refresh_interval(next);
+            app.settings.refresh_interval_ms = app.refresh_interval.as_millis() as u64;
+            let _ = app.settings.save();
+            app.input_state.message = Some((format!("Refresh interval: {}ms", app.settings.refresh_interval_ms), false));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        }
+        KeyCode::Char('v') => {
+            // Show the full command line, cwd and executable of the selected process
+            let selected = view_processes(app, ViewMode::ProcessList).get(app.selected_process_index).map(|p| p.pid);
+            app.process_detail = selected;
+        }
+        KeyCode::Char('Y') => {
+            // Toggle the thread count column (persisted)
+            app.settings.show_thread_count = !app.settings.show_thread_count;
+            let _ = app.settings.save();
+        }
+        KeyCode::Char('D') => {
+            // Toggle the disk I/O rate column (persisted)
+            app.settings.show_io = !app.settings.show_io;
+            let _ = app.settings.save();
+        }
+        KeyCode::Char('y') => {
+            // Toggle the systemd UNIT column (persisted)
+            app.settings.show_unit = !app.settings.show_unit;
+            let _ = app.settings.save();
+        }
+        KeyCode::Char('B') => {
+            // Toggle inline CPU%/MEM bars (persisted)
+            app.settings.inline_bars = !app.settings.inline_bars;
+            let _ = app.settings.save();
+        }
+        KeyCode::Char('W') => {
+            // Toggle between fixed and content-sized columns (persisted)
+            app.settings.auto_fit_columns = !app.settings.auto_fit_columns;
+            let _ = app.settings.save();
+        }
+        KeyCode::Char('V') => {
+            // Toggle splitting the list into side-by-side columns on wide terminals (persisted)
+            app.settings.multi_column = !app.settings.multi_column;
+            let _ = app.settings.save();
+        }
+        KeyCode::Char('x') => {
+            // Toggle scheduling-class-aware CPU colors (persisted)
+            app.settings.sched_aware_cpu_colors = !app.settings.sched_aware_cpu_colors;
+            let _ = app.settings.save();
+        }
+        KeyCode::Char('d') => {
+            // Toggle highlighting what changed since the previous refresh (persisted)
+            app.settings.change_highlight = !app.settings.change_highlight;
+            let _ = app.settings.save();
+            if !app.settings.change_highlight {
+                app.process_changes.clear();
+            }
+        }
+        KeyCode::Left if app.display_limit > app.column_rows => {
+            // Same row, previous column
+            app.selected_process_index = app.selected_process_index.saturating_sub(app.column_rows);
+            if app.selected_process_index < app.scroll_offset {
+                app.scroll_offset = app.selected_process_index;
+            }
+        }
+        KeyCode::Right if app.display_limit > app.column_rows => {
+            // Same row, next column (or the last process)
+            let process_len = app.process_manager.get_processes().len();
+            app.selected_process_index = (app.selected_process_index + app.column_rows).min(process_len.saturating_sub(1));
+            let bottom = app.scroll_offset + app.display_limit;
+            if app.selected_process_index >= bottom {
+                app.scroll_offset = app.selected_process_index + 1 - app.display_limit;
+            }
+        }
+        KeyCode::Char('a') => app.toggle_sort_direction(),        
+        KeyCode::Char('q') => return Ok(true),
+        KeyCode::Char('s') | KeyCode::Char('S') => app.view_mode = ViewMode::Statistics,
+        KeyCode::Up => {
+            if app.selected_process_index > 0 {
+                app.selected_process_index -= 1;
+                // Adjust scroll if needed
+                if app.selected_process_index < app.scroll_offset {
+                    app.scroll_offset = app.selected_process_index;
+                }
+            }
+        }
+        KeyCode::Down => {
+            let process_len = app.process_manager.get_processes().len();
+            if app.selected_process_index + 1 < process_len {
+                app.selected_process_index += 1;
+                // Adjust scroll if needed
+                let bottom = app.scroll_offset + app.display_limit;
+                if app.selected_process_index >= bottom {
+                    app.scroll_offset = app.selected_process_index - app.display_limit + 1;
+                }
+            }
+        }
+        KeyCode::Char('1') => app.view_mode = ViewMode::FilterSort,
+        KeyCode::Char('2') => switch_process_view(app, ViewMode::ChangeNice),
+        KeyCode::Char('3') => {
+            switch_process_view(app, ViewMode::KillStop);
+            if !app.selected_processes.is_empty() {
+                // If we have selected processes, skip selection and go to action
+                app.kill_stop_input_state = KillStopInputState::EnteringAction;
+            } else {
+                // Otherwise start selecting a PID
+                app.kill_stop_input_state = KillStopInputState::SelectingPid;
+            }
+        },
+        KeyCode::Char('4') => {
+            app.view_mode = ViewMode::PerProcessGraph;
+            app.selected_process_index = 0;
+            app.per_process_graph_scroll_offset = 0;
+            app.selected_process_for_graph = None;
+        }
+        KeyCode::Char('5') => app.view_mode = ViewMode::ProcessLog,
+        KeyCode::Char('6') => app.view_mode = ViewMode::Help,
+        KeyCode::Char('g') | KeyCode::Char('G') => {
+            app.view_mode = ViewMode::GroupedView;
+            app.grouped_view_type = crate::process_group::GroupType::Cgroup;
+            app.selected_group_index = 0;
+            app.grouped_view_scroll_offset = 0;
+        },
+        KeyCode::Char('j') | KeyCode::Char('J') => {
+            app.view_mode = ViewMode::Scheduler;
+            app.selected_task_index = 0;
+            app.scheduler_scroll_offset = 0;
+        },
         KeyCode::Char('n') | KeyCode::Char('N') => {
             app.view_mode = ViewMode::StartProcess;
             app.input_state.program_path.clear();
             app.input_state.working_dir.clear();
             app.input_state.arguments.clear();
+            app.input_state.output_log.clear();
+            app.input_state.run_as_input.clear();
             app.input_state.env_vars.clear();
+            app.input_state.env_input.clear();
+            app.input_state.env_inherit = true;
+            app.input_state.restart_target = None;
             app.input_state.current_start_input_field = 0;
         },
         KeyCode::Char('p') | KeyCode::Char('P') => {
@@ -2137,6 +5014,10 @@ fn handle_process_list_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
                 app.selected_processes.clear();
             }
         },
+        KeyCode::Char(' ') if !app.multi_select_mode => {
+            // Freeze the list so it stops re-sorting under the cursor
+            app.paused = !app.paused;
+        }
         KeyCode::Char(' ') | KeyCode::Enter => {
             // Toggle selection of current process in multi-select mode
             if app.multi_select_mode {
@@ -2194,6 +5075,26 @@ fn handle_statistics_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
             app.current_stats_tab = StatisticsTab::Help;
             app.stats_scroll_offset = 0;  // Reset scroll when switching tabs
         }
+        KeyCode::Char('e') => {
+            // Save the current tab's graphs as an SVG picture
+            match graph::export_snapshot_svg(&app.graph_data, &app.current_stats_tab) {
+                Ok(path) => {
+                    app.input_state.message = Some((format!("Graph snapshot saved to {}", path.display()), false));
+                }
+                Err(e) => {
+                    app.input_state.message = Some((format!("Failed to save graph snapshot: {}", e), true));
+                }
+            }
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+        }
+        KeyCode::Char('d') => {
+            app.view_mode = ViewMode::Dashboard;
+            app.dashboard_picker = None;
+        }
+        KeyCode::Char('v') if app.current_stats_tab == StatisticsTab::Disk => {
+            // Show or hide loop/ram devices in the device table
+            app.graph_data.toggle_virtual_devices();
+        }
         KeyCode::Up => {
             if app.current_stats_tab == StatisticsTab::CPU {
                 // Smooth scrolling - move up by 1/4 of the viewport
@@ -2245,6 +5146,7 @@ fn handle_filter_sort_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
         KeyCode::Char('2') => app.view_mode = ViewMode::Filter,
         KeyCode::Char('3') => {
             app.input_state.advanced_filter_input.clear();
+            app.filter_builder = None;
             app.view_mode = ViewMode::AdvancedFilter;
         }
         KeyCode::Char('x') => {
@@ -2259,44 +5161,54 @@ fn handle_filter_sort_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
 }
 
 fn handle_sort_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
-    match key.code {
-        KeyCode::Char('1') => {
-            app.sort_mode = Some("pid".to_string());
-            app.process_manager.set_sort("pid", app.sort_ascending);
-            app.view_mode = ViewMode::ProcessList;
-        }
-        KeyCode::Char('2') => {
-            app.sort_mode = Some("mem".to_string());
-            app.process_manager.set_sort("mem", app.sort_ascending);
-            app.view_mode = ViewMode::ProcessList;
-        }
-        KeyCode::Char('3') => {
-            app.sort_mode = Some("ppid".to_string());
-            app.process_manager.set_sort("ppid", app.sort_ascending);
-            app.view_mode = ViewMode::ProcessList;
+    let selected_mode = match key.code {
+        KeyCode::Char('1') => Some("pid"),
+        KeyCode::Char('2') => Some("mem"),
+        KeyCode::Char('3') => Some("ppid"),
+        KeyCode::Char('4') => Some("start"),
+        KeyCode::Char('5') => Some("nice"),
+        KeyCode::Char('6') => Some("cpu"),
+        KeyCode::Char('7') => Some("time"),
+        KeyCode::Char('8') => Some("io"),
+        KeyCode::Char('9') => Some("threads"),
+        _ => None,
+    };
+    if let Some(mode) = selected_mode {
+        if app.editing_secondary_sort {
+            app.process_manager.set_secondary_sort(Some(mode), app.secondary_sort_ascending);
+            app.editing_secondary_sort = false;
+        } else {
+            // Each column comes back in the direction last used for it
+            app.sort_mode = Some(mode.to_string());
+            app.process_manager.set_sort(mode, app.settings.sort_ascending(mode));
         }
-        KeyCode::Char('4') => {
-            app.sort_mode = Some("start".to_string());
-            app.process_manager.set_sort("start", app.sort_ascending);
-            app.view_mode = ViewMode::ProcessList;
+        app.view_mode = ViewMode::ProcessList;
+        return Ok(false);
+    }
+    match key.code {
+        KeyCode::Char('a') => {
+            if app.editing_secondary_sort {
+                app.secondary_sort_ascending = !app.secondary_sort_ascending;
+                if let Some((mode, _)) = app.process_manager.get_secondary_sort() {
+                    let mode = mode.to_string();
+                    app.process_manager.set_secondary_sort(Some(&mode), app.secondary_sort_ascending);
+                }
+            } else {
+                app.toggle_sort_direction();
+            }
         }
-        KeyCode::Char('5') => {
-            app.sort_mode = Some("nice".to_string());
-            app.process_manager.set_sort("nice", app.sort_ascending);
-            app.view_mode = ViewMode::ProcessList;
+        KeyCode::Char('t') => {
+            // Switch between configuring the primary and secondary key
+            app.editing_secondary_sort = !app.editing_secondary_sort;
         }
-        KeyCode::Char('6') => {
-            app.sort_mode = Some("cpu".to_string());
-            app.process_manager.set_sort("cpu", app.sort_ascending);
-            app.view_mode = ViewMode::ProcessList;
+        KeyCode::Char('x') => {
+            // Clear secondary key (PID remains the final tiebreaker)
+            app.process_manager.set_secondary_sort(None, app.secondary_sort_ascending);
         }
-        KeyCode::Char('a') => {
-            app.sort_ascending = !app.sort_ascending;
-            if let Some(mode) = &app.sort_mode {
-                app.process_manager.set_sort(mode, app.sort_ascending);
-            }
+        KeyCode::Backspace | KeyCode::Esc => {
+            app.editing_secondary_sort = false;
+            app.view_mode = ViewMode::FilterSort;
         }
-        KeyCode::Backspace | KeyCode::Esc => app.view_mode = ViewMode::FilterSort,
         _ => {}
     }
     Ok(false)
@@ -2326,6 +5238,31 @@ fn handle_filter_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Err
                     app.input_state.filter_input.clear();
                     app.view_mode = ViewMode::FilterInput;
                 }
+                KeyCode::Char('5') => {
+                    app.filter_mode = Some("status".to_string());
+                    app.input_state.filter_input.clear();
+                    app.view_mode = ViewMode::FilterInput;
+                }
+                KeyCode::Char('g') => {
+                    // Quick filter: processes with a debugger or strace attached
+                    app.filter_mode = Some("traced".to_string());
+                    app.input_state.filter_input = "yes".to_string();
+                    app.process_manager.set_filter(Some("traced".to_string()), Some("yes".to_string()));
+                    app.view_mode = ViewMode::ProcessList;
+                }
+                KeyCode::Char(c @ ('z' | 't' | 'r' | 'd')) => {
+                    // Quick status filters
+                    let status = match c {
+                        'z' => "zombie",
+                        't' => "stopped",
+                        'r' => "running",
+                        _ => "disk sleep",
+                    };
+                    app.filter_mode = Some("status".to_string());
+                    app.input_state.filter_input = status.to_string();
+                    app.process_manager.set_filter(Some("status".to_string()), Some(status.to_string()));
+                    app.view_mode = ViewMode::ProcessList;
+                }
                 KeyCode::Esc => {
                     app.filter_mode = None;
                     app.input_state.filter_input.clear();
@@ -2379,6 +5316,10 @@ fn handle_filter_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Err
 }
 
 fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    if matches!(app.kill_stop_input_state, KillStopInputState::SelectingPid) && handle_view_prefs_key(key, app, ViewMode::KillStop) {
+        return Ok(false);
+    }
+    let listed = view_processes(app, ViewMode::KillStop);
     let processes = app.process_manager.get_processes();
     match &mut app.kill_stop_input_state {
         KillStopInputState::SelectingPid => {
@@ -2391,24 +5332,26 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                         }
                     }
                 }
-                KeyCode::Down => {
-                    if app.selected_process_index + 1 < processes.len() {
-                        app.selected_process_index += 1;
-                        let bottom = app.scroll_offset + app.display_limit;
-                        if app.selected_process_index >= bottom {
-                            app.scroll_offset = app.selected_process_index - app.display_limit + 1;
-                        }
+                KeyCode::Down if app.selected_process_index + 1 < listed.len() => {
+                    app.selected_process_index += 1;
+                    let bottom = app.scroll_offset + app.display_limit;
+                    if app.selected_process_index >= bottom {
+                        app.scroll_offset = app.selected_process_index - app.display_limit + 1;
                     }
                 }
-                KeyCode::Enter => {
-                    if !processes.is_empty() {
-                        app.kill_stop_input_state = KillStopInputState::EnteringAction;
-                        app.input_state.pid_input.clear();
-                        app.input_state.message = None;
-                    }
+                KeyCode::Enter if !listed.is_empty() => {
+                    app.kill_stop_input_state = KillStopInputState::EnteringAction;
+                    app.input_state.pid_input.clear();
+                    app.input_state.message = None;
+                }
+                KeyCode::Char('x') => {
+                    let count = app.kill_blacklist.len();
+                    app.kill_blacklist.clear();
+                    app.input_state.message = Some((format!("Cleared {} blacklist entr{}", count, if count == 1 { "y" } else { "ies" }), false));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
                 }
                 KeyCode::Esc => {
-                    app.view_mode = ViewMode::ProcessList;
+                    switch_process_view(app, ViewMode::ProcessList);
                     app.input_state = InputState::default();
                     app.kill_stop_input_state = KillStopInputState::SelectingPid;
                 }
@@ -2426,6 +5369,27 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                         _ => return Ok(false),
                     };
                     
+                    // Locked processes may only be continued
+                    if action_type != "continue" {
+                        let locked: Vec<String> = processes.iter()
+                            .filter(|p| process::is_locked(p))
+                            .filter(|p| if app.selected_processes.is_empty() {
+                                listed.get(app.selected_process_index).map(|s| s.pid) == Some(p.pid)
+                            } else {
+                                app.selected_processes.contains(&p.pid)
+                            })
+                            .map(|p| format!("{} ({})", p.name, p.pid))
+                            .collect();
+                        if !locked.is_empty() {
+                            app.input_state.message = Some((
+                                format!("Refusing to {} locked process(es): {} - unlock with [l] first", action_type, locked.join(", ")),
+                                true
+                            ));
+                            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                            return Ok(false);
+                        }
+                    }
+
                     // Check if we have selected processes for batch operation
                     if !app.selected_processes.is_empty() {
                         let selected_pids: Vec<u32> = app.selected_processes.iter().copied().collect();
@@ -2439,7 +5403,7 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                             process_names: selected_names,
                             action_type: action_type.to_string(),
                         };
-                    } else if let Some(process) = processes.get(app.selected_process_index) {
+                    } else if let Some(process) = listed.get(app.selected_process_index) {
                         // Single process operation
                         // Check for child processes (only for kill/terminate actions)
                         let children = app.process_manager.get_child_processes(process.pid);
@@ -2448,13 +5412,14 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                             let children_list: Vec<(u32, String)> = children.iter()
                                 .map(|c| (c.pid, c.name.clone()))
                                 .collect();
-                            app.kill_stop_input_state = KillStopInputState::DependencyWarning {
+                            app.kill_stop_input_state = KillStopInputState::DependencyWarning(DependencyWarningState {
                                 pid: process.pid,
                                 process_name: process.name.clone(),
                                 action_type: action_type.to_string(),
                                 child_count: children.len(),
                                 children: children_list,
-                            };
+                                descendants: app.process_manager.descendants(process.pid),
+                            });
                         } else {
                             // No children, go directly to confirmation
                             app.kill_stop_input_state = KillStopInputState::ConfirmingAction {
@@ -2465,6 +5430,35 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                         }
                     }
                 }
+                KeyCode::Char('b') => {
+                    // Kill and keep killing respawns by name; ask how long first
+                    if let Some(process) = listed.get(app.selected_process_index) {
+                        if process::is_locked(process) {
+                            app.input_state.message = Some((
+                                format!("Refusing to blacklist locked process {} ({}) - unlock with [l] first", process.name, process.pid),
+                                true
+                            ));
+                            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                            return Ok(false);
+                        }
+                        app.kill_stop_input_state = KillStopInputState::EnteringBlacklistDuration {
+                            pid: process.pid,
+                            process_name: process.name.clone(),
+                            minutes: String::new(),
+                        };
+                    }
+                }
+                KeyCode::Char('g') => {
+                    // Pick an arbitrary signal for the current process
+                    if let Some(process) = listed.get(app.selected_process_index) {
+                        app.kill_stop_input_state = KillStopInputState::PickingSignal {
+                            pid: process.pid,
+                            process_name: process.name.clone(),
+                            query: String::new(),
+                            selected_index: 0,
+                        };
+                    }
+                }
                 KeyCode::Esc => {
                     app.kill_stop_input_state = KillStopInputState::SelectingPid;
                     app.input_state.pid_input.clear();
@@ -2472,7 +5466,90 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                 _ => {}
             }
         }
-        KillStopInputState::DependencyWarning { pid, process_name, action_type, child_count, children } => {
+        KillStopInputState::EnteringBlacklistDuration { pid, process_name, minutes } => {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => minutes.push(c),
+                KeyCode::Backspace => {
+                    minutes.pop();
+                }
+                KeyCode::Enter => {
+                    let duration_mins = minutes.parse::<u64>().unwrap_or(0);
+                    let until = (duration_mins > 0).then(|| std::time::Instant::now() + Duration::from_secs(duration_mins * 60));
+                    let (msg, is_error) = match app.process_manager.kill_process(*pid) {
+                        Ok(_) => {
+                            track_suspension(&mut app.suspended_by_lpm, processes, *pid, "continue");
+                            let name = process_name.clone();
+                            app.kill_blacklist.retain(|e| e.name != name);
+                            app.kill_blacklist.push(BlacklistEntry { name: name.clone(), until, kills: 0 });
+                            let scope = if duration_mins > 0 { format!("for {} min", duration_mins) } else { "until cleared".to_string() };
+                            (format!("Killed {} ({}) and blacklisted '{}' {}", name, pid, name, scope), false)
+                        }
+                        Err(e) => (format!("Error killing process {}: {} - not blacklisted", pid, e), true),
+                    };
+                    app.input_state.message = Some((msg, is_error));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                    app.kill_stop_input_state = KillStopInputState::SelectingPid;
+                }
+                KeyCode::Esc => {
+                    app.kill_stop_input_state = KillStopInputState::EnteringAction;
+                }
+                _ => {}
+            }
+        }
+        KillStopInputState::PickingSignal { pid, process_name: _, query, selected_index } => {
+            let matches = crate::signals::filter_signals(query);
+            match key.code {
+                KeyCode::Up => {
+                    *selected_index = selected_index.saturating_sub(1);
+                }
+                KeyCode::Down if *selected_index + 1 < matches.len() => {
+                    *selected_index += 1;
+                }
+                KeyCode::Char(c) if c.is_ascii_alphanumeric() => {
+                    query.push(c);
+                    *selected_index = 0;
+                }
+                KeyCode::Backspace => {
+                    query.pop();
+                    *selected_index = 0;
+                }
+                KeyCode::Enter => {
+                    if let Some(signal) = matches.get(*selected_index) {
+                        let target_locked = processes.iter().any(|p| p.pid == *pid && process::is_locked(p));
+                        if target_locked && signal.number != libc::SIGCONT {
+                            app.input_state.message = Some((
+                                format!("Refusing to send {} to locked process {} - unlock with [l] first", signal.name, pid),
+                                true
+                            ));
+                            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                            return Ok(false);
+                        }
+                        let (msg, is_error) = match app.process_manager.send_signal(*pid, signal.number) {
+                            Ok(_) => (format!("Sent {} to process {}", signal.name, pid), false),
+                            Err(e) => (format!("Error sending {} to process {}: {}", signal.name, pid, e), true),
+                        };
+                        // Keep the lpm-suspended set in sync with stop/continue signals
+                        if !is_error && signal.number == libc::SIGSTOP {
+                            track_suspension(&mut app.suspended_by_lpm, processes, *pid, "stop");
+                        } else if !is_error && (signal.number == libc::SIGCONT || signal.number == libc::SIGKILL) {
+                            track_suspension(&mut app.suspended_by_lpm, processes, *pid, "continue");
+                        }
+                        app.input_state.message = Some((msg, is_error));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                        app.kill_stop_input_state = KillStopInputState::SelectingPid;
+                    } else {
+                        // Nothing in the signal table matches what was typed; stay in the picker
+                        app.input_state.message = Some((format!("Unknown signal: '{}'", query.trim()), true));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                    }
+                }
+                KeyCode::Esc => {
+                    app.kill_stop_input_state = KillStopInputState::EnteringAction;
+                }
+                _ => {}
+            }
+        }
+        KillStopInputState::DependencyWarning(DependencyWarningState { pid, process_name, action_type, child_count, children, descendants }) => {
             match key.code {
                 KeyCode::Char('p') | KeyCode::Char('1') => {
                     // Kill parent only - proceed to confirmation
@@ -2482,14 +5559,52 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                         action_type: action_type.clone(),
                     };
                 }
+                KeyCode::Char('t') | KeyCode::Char('3') => {
+                    // Signal the whole subtree, unless something in it is locked
+                    let locked: Vec<String> = descendants.iter()
+                        .filter_map(|descendant| processes.iter().find(|p| p.pid == *descendant && process::is_locked(p)))
+                        .map(|p| format!("{} ({})", p.name, p.pid))
+                        .collect();
+                    if !locked.is_empty() {
+                        app.input_state.message = Some((
+                            format!("Refusing to {} locked descendant(s): {} - unlock with [l] first", action_type, locked.join(", ")),
+                            true
+                        ));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                        return Ok(false);
+                    }
+                    let signal = if action_type == "kill" { libc::SIGKILL } else { libc::SIGTERM };
+                    let verb = if action_type == "kill" { "killed" } else { "terminated" };
+                    app.input_state.message = Some(match app.process_manager.kill_tree(*pid, signal) {
+                        Ok(signaled) => (format!("Successfully {} {} processes (entire subtree of {})", verb, signaled.len(), pid), false),
+                        Err(e) => (format!("Error signaling the subtree of {}: {}", pid, e), true),
+                    });
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                    app.kill_stop_input_state = KillStopInputState::SelectingPid;
+                }
                 KeyCode::Char('a') | KeyCode::Char('2') => {
-                    // Kill parent and all children
+                    // Kill parent and its direct children, unless one of the children is locked
+                    let locked_children: Vec<String> = children.iter()
+                        .filter(|(child_pid, _)| processes.iter().any(|p| p.pid == *child_pid && process::is_locked(p)))
+                        .map(|(child_pid, name)| format!("{} ({})", name, child_pid))
+                        .collect();
+                    if !locked_children.is_empty() {
+                        app.input_state.message = Some((
+                            format!("Refusing to {} locked child process(es): {} - unlock with [l] first", action_type, locked_children.join(", ")),
+                            true
+                        ));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                        return Ok(false);
+                    }
                     if action_type == "kill" {
-                        match app.process_manager.kill_process_and_children(*pid) {
-                            Ok(killed_pids) => {
+                        let result = children.iter()
+                            .try_for_each(|(child_pid, _)| app.process_manager.kill_process(*child_pid))
+                            .and_then(|_| app.process_manager.kill_process(*pid));
+                        match result {
+                            Ok(()) => {
                                 app.input_state.message = Some((
                                     format!("Successfully killed {} processes (parent + {} children)", 
-                                        killed_pids.len(), child_count),
+                                        *child_count + 1, child_count),
                                     false
                                 ));
                                 app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
@@ -2553,13 +5668,19 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                         }
                         "stop" => {
                             match app.process_manager.stop_process(*pid) {
-                                Ok(_) => Some(("Successfully stopped process".to_string(), false)),
+                                Ok(_) => {
+                                    track_suspension(&mut app.suspended_by_lpm, processes, *pid, "stop");
+                                    Some(("Successfully stopped process".to_string(), false))
+                                }
                                 Err(e) => Some((format!("Error stopping process: {}", e), true)),
                             }
                         }
                         "continue" => {
                             match app.process_manager.continue_process(*pid) {
-                                Ok(_) => Some(("Successfully continued process".to_string(), false)),
+                                Ok(_) => {
+                                    track_suspension(&mut app.suspended_by_lpm, processes, *pid, "continue");
+                                    Some(("Successfully continued process".to_string(), false))
+                                }
                                 Err(e) => Some((format!("Error continuing process: {}", e), true)),
                             }
                         }
@@ -2607,6 +5728,7 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                         };
                         
                         if result.is_ok() {
+                            track_suspension(&mut app.suspended_by_lpm, processes, *pid, action_type);
                             success_count += 1;
                         } else {
                             error_count += 1;
@@ -2633,6 +5755,10 @@ fn handle_kill_stop_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
 }
 
 fn handle_change_nice_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    if matches!(app.nice_input_state, NiceInputState::SelectingPid) && handle_view_prefs_key(key, app, ViewMode::ChangeNice) {
+        return Ok(false);
+    }
+    let listed = view_processes(app, ViewMode::ChangeNice);
     let processes = app.process_manager.get_processes();
     match app.nice_input_state {
         NiceInputState::SelectingPid => {
@@ -2645,24 +5771,49 @@ fn handle_change_nice_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
                         }
                     }
                 }
-                KeyCode::Down => {
-                    if app.selected_process_index + 1 < processes.len() {
-                        app.selected_process_index += 1;
-                        let bottom = app.change_nice_scroll_offset + (PROCESS_TABLE_HEIGHT - 2);
-                        if app.selected_process_index >= bottom {
-                            app.change_nice_scroll_offset += 1;
-                        }
+                KeyCode::Down if app.selected_process_index + 1 < listed.len() => {
+                    app.selected_process_index += 1;
+                    let bottom = app.change_nice_scroll_offset + (PROCESS_TABLE_HEIGHT - 2);
+                    if app.selected_process_index >= bottom {
+                        app.change_nice_scroll_offset += 1;
                     }
                 }
-                KeyCode::Enter => {
-                    if !processes.is_empty() {
-                        app.nice_input_state = NiceInputState::EnteringNice;
+                KeyCode::Enter if !listed.is_empty() => {
+                    app.nice_input_state = NiceInputState::EnteringNice;
+                    app.input_state.nice_input.clear();
+                    app.input_state.message = None;
+                }
+                KeyCode::Char('u') => {
+                    // Renice every process of the selected process's user
+                    if let Some(user) = listed.get(app.selected_process_index).and_then(|p| p.user.clone()) {
+                        app.renice_user = Some(user);
+                        app.nice_input_state = NiceInputState::EnteringUserNice;
+                        app.input_state.nice_input.clear();
+                        app.input_state.message = None;
+                    }
+                }
+                KeyCode::Char('a') if !listed.is_empty() => {
+                    app.nice_input_state = NiceInputState::EnteringAffinity;
+                    app.input_state.nice_input.clear();
+                    app.input_state.message = None;
+                }
+                KeyCode::Char('i') if !listed.is_empty() => {
+                    app.nice_input_state = NiceInputState::EnteringIonice;
+                    app.input_state.nice_input.clear();
+                    app.input_state.message = None;
+                }
+                KeyCode::Char('f') => {
+                    // Renice / pin every process the active filter matches
+                    if filter_tune_targets(app).is_some() {
+                        app.nice_input_state = NiceInputState::EnteringFilterTune;
                         app.input_state.nice_input.clear();
                         app.input_state.message = None;
+                    } else {
+                        app.input_state.message = Some(("No active filter - set one with [1] or an advanced filter first".to_string(), true));
                     }
                 }
                 KeyCode::Esc => {
-                    app.view_mode = ViewMode::ProcessList;
+                    switch_process_view(app, ViewMode::ProcessList);
                     app.input_state = InputState::default();
                     app.nice_input_state = NiceInputState::SelectingPid;
                 }
@@ -2682,12 +5833,21 @@ fn handle_change_nice_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
                 KeyCode::Enter => {
                     if !app.input_state.nice_input.is_empty() {
                         if let (Some(proc), Ok(nice)) = (
-                            processes.get(app.selected_process_index),
+                            listed.get(app.selected_process_index),
                             app.input_state.nice_input.parse::<i32>(),
                         ) {
                             if nice >= -20 && nice <= 19 {
                                 match app.process_manager.set_niceness(proc.pid, nice) {
                                     Ok(_) => {
+                                        app.last_reversible_action = Some(ReversibleAction::Renice {
+                                            changes: vec![NiceChange {
+                                                pid: proc.pid,
+                                                name: proc.name.clone(),
+                                                start_timestamp: proc.start_timestamp,
+                                                old_nice: proc.nice,
+                                            }],
+                                            new_nice: nice,
+                                        });
                                         app.input_state.message = Some((
                                             format!("Successfully changed nice value of process {} to {}", proc.pid, nice),
                                             false
@@ -2723,16 +5883,254 @@ fn handle_change_nice_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
                 _ => {}
             }
         }
-    }
-    Ok(false)
-}
+        NiceInputState::EnteringUserNice => {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() || (c == '-' && app.input_state.nice_input.is_empty()) => {
+                    app.input_state.nice_input.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input_state.nice_input.pop();
+                }
+                KeyCode::Enter => {
+                    match app.input_state.nice_input.parse::<i32>() {
+                        Ok(nice) if (-20..=19).contains(&nice) => {
+                            let user = app.renice_user.clone().unwrap_or_default();
+                            app.renice_targets = app.process_manager.processes_of_user(&user);
+                            if app.renice_targets.is_empty() {
+                                app.input_state.message = Some((format!("No processes of user {} to renice", user), true));
+                                app.nice_input_state = NiceInputState::SelectingPid;
+                            } else {
+                                app.nice_input_state = NiceInputState::ConfirmingUserNice;
+                            }
+                        }
+                        _ => {
+                            app.input_state.message = Some((
+                                "Error: Nice value must be between -20 and 19".to_string(),
+                                true
+                            ));
+                        }
+                    }
+                }
+                KeyCode::Esc => {
+                    app.nice_input_state = NiceInputState::SelectingPid;
+                    app.input_state.nice_input.clear();
+                    app.renice_user = None;
+                }
+                _ => {}
+            }
+        }
+        NiceInputState::ConfirmingUserNice => {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Enter => {
+                    let nice = app.input_state.nice_input.parse::<i32>().unwrap_or(0);
+                    let pids: Vec<u32> = app.renice_targets.iter().map(|(pid, _)| *pid).collect();
+                    let (succeeded, failed) = app.process_manager.renice_pids(&pids, nice);
+                    if succeeded > 0 {
+                        // Failed targets are skipped at undo time since their nice value never changed
+                        let changes = processes
+                            .iter()
+                            .filter(|p| pids.contains(&p.pid))
+                            .map(|p| NiceChange {
+                                pid: p.pid,
+                                name: p.name.clone(),
+                                start_timestamp: p.start_timestamp,
+                                old_nice: p.nice,
+                            })
+                            .collect();
+                        app.last_reversible_action = Some(ReversibleAction::Renice { changes, new_nice: nice });
+                    }
+                    app.input_state.message = Some((
+                        format!(
+                            "Reniced {} process(es) of {} to {}, {} failed",
+                            succeeded,
+                            app.renice_user.as_deref().unwrap_or("?"),
+                            nice,
+                            failed
+                        ),
+                        failed > 0,
+                    ));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                    app.nice_input_state = NiceInputState::SelectingPid;
+                    app.input_state.nice_input.clear();
+                    app.renice_targets.clear();
+                    app.renice_user = None;
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    app.nice_input_state = NiceInputState::EnteringUserNice;
+                    app.renice_targets.clear();
+                }
+                _ => {}
+            }
+        }
+        NiceInputState::EnteringAffinity => {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() || matches!(c, '-' | ',' | ' ') => {
+                    app.input_state.nice_input.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input_state.nice_input.pop();
+                }
+                KeyCode::Enter => {
+                    let Some(proc) = listed.get(app.selected_process_index) else {
+                        return Ok(false);
+                    };
+                    // Stay in the editor on a bad list so it can be corrected
+                    let previous = app.process_manager.get_cpu_affinity(proc.pid);
+                    let (msg, is_error) = match process::parse_cpu_list(&app.input_state.nice_input) {
+                        Err(e) => (e, true),
+                        Ok(cpus) => match app.process_manager.set_cpu_affinity(proc.pid, &cpus) {
+                            Ok(_) => {
+                                if !previous.is_empty() {
+                                    app.last_reversible_action = Some(ReversibleAction::Affinity {
+                                        pid: proc.pid,
+                                        name: proc.name.clone(),
+                                        start_timestamp: proc.start_timestamp,
+                                        previous,
+                                    });
+                                }
+                                app.nice_input_state = NiceInputState::SelectingPid;
+                                app.input_state.nice_input.clear();
+                                (format!("Pinned {} ({}) to CPUs {}", proc.name, proc.pid, process::format_cpu_list(&cpus)), false)
+                            }
+                            Err(e) => (format!("Error setting CPU affinity: {}", e), true),
+                        },
+                    };
+                    app.input_state.message = Some((msg, is_error));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                }
+                KeyCode::Esc => {
+                    app.nice_input_state = NiceInputState::SelectingPid;
+                    app.input_state.nice_input.clear();
+                }
+                _ => {}
+            }
+        }
+        NiceInputState::EnteringIonice => {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_alphanumeric() || matches!(c, '-' | ' ') => {
+                    app.input_state.nice_input.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input_state.nice_input.pop();
+                }
+                KeyCode::Enter => {
+                    let Some(proc) = listed.get(app.selected_process_index) else {
+                        return Ok(false);
+                    };
+                    // Stay in the editor on bad input so it can be corrected
+                    let (msg, is_error) = match process::parse_ionice(&app.input_state.nice_input) {
+                        Err(e) => (e, true),
+                        Ok((class, priority)) => match app.process_manager.set_ionice(proc.pid, class, priority) {
+                            Ok(_) => {
+                                app.nice_input_state = NiceInputState::SelectingPid;
+                                app.input_state.nice_input.clear();
+                                (format!("Set I/O class of {} ({}) to {}", proc.name, proc.pid, process::format_ionice(class, priority)), false)
+                            }
+                            Err(e) => (format!("Error setting I/O priority: {}", e), true),
+                        },
+                    };
+                    app.input_state.message = Some((msg, is_error));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                }
+                KeyCode::Esc => {
+                    app.nice_input_state = NiceInputState::SelectingPid;
+                    app.input_state.nice_input.clear();
+                }
+                _ => {}
+            }
+        }
+        NiceInputState::EnteringFilterTune => {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() || matches!(c, '-' | ',' | '@' | ' ') => {
+                    app.input_state.nice_input.push(c);
+                }
+                KeyCode::Backspace => {
+                    app.input_state.nice_input.pop();
+                }
+                KeyCode::Enter => {
+                    match parse_filter_tune(&app.input_state.nice_input) {
+                        Ok(tune) => {
+                            app.renice_targets = filter_tune_targets(app).unwrap_or_default();
+                            if app.renice_targets.is_empty() {
+                                app.input_state.message = Some(("No processes match the filter".to_string(), true));
+                                app.nice_input_state = NiceInputState::SelectingPid;
+                            } else {
+                                app.filter_tune = tune;
+                                app.nice_input_state = NiceInputState::ConfirmingFilterTune;
+                            }
+                        }
+                        Err(e) => {
+                            app.input_state.message = Some((e, true));
+                        }
+                    }
+                }
+                KeyCode::Esc => {
+                    app.nice_input_state = NiceInputState::SelectingPid;
+                    app.input_state.nice_input.clear();
+                }
+                _ => {}
+            }
+        }
+        NiceInputState::ConfirmingFilterTune => {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Enter => {
+                    let (nice, cpus) = std::mem::take(&mut app.filter_tune);
+                    let pids: Vec<u32> = app.renice_targets.iter().map(|(pid, _)| *pid).collect();
+                    let (succeeded, failed) = app.process_manager.tune_pids(&pids, nice, cpus.as_deref());
+                    if let (Some(nice), true) = (nice, succeeded > 0) {
+                        // Only the nice values of a filter-wide tune are undoable; single-process pinning is
+                        let changes = processes
+                            .iter()
+                            .filter(|p| pids.contains(&p.pid))
+                            .map(|p| NiceChange {
+                                pid: p.pid,
+                                name: p.name.clone(),
+                                start_timestamp: p.start_timestamp,
+                                old_nice: p.nice,
+                            })
+                            .collect();
+                        app.last_reversible_action = Some(ReversibleAction::Renice { changes, new_nice: nice });
+                    }
+                    let change = match (nice, &cpus) {
+                        (Some(nice), Some(cpus)) => format!("nice {} and CPUs {:?}", nice, cpus),
+                        (Some(nice), None) => format!("nice {}", nice),
+                        (None, Some(cpus)) => format!("CPUs {:?}", cpus),
+                        (None, None) => String::new(),
+                    };
+                    app.input_state.message = Some((
+                        format!("Set {} on {} matching process(es), {} failed", change, succeeded, failed),
+                        failed > 0,
+                    ));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                    app.nice_input_state = NiceInputState::SelectingPid;
+                    app.input_state.nice_input.clear();
+                    app.renice_targets.clear();
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    app.nice_input_state = NiceInputState::EnteringFilterTune;
+                    app.renice_targets.clear();
+                    app.filter_tune = (None, None);
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(false)
+}
 
 fn handle_per_process_graph_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
-    let processes = app.process_manager.get_processes();
+    // Any key dismisses the command popup
+    if app.command_popup.take().is_some() {
+        return Ok(false);
+    }
+    if app.selected_process_for_graph.is_none() && handle_view_prefs_key(key, app, ViewMode::PerProcessGraph) {
+        return Ok(false);
+    }
+    let processes = view_processes(app, ViewMode::PerProcessGraph);
     match key.code {
         KeyCode::Char('q') => {
-            app.view_mode = ViewMode::ProcessList;
             app.selected_process_for_graph = None;
+            switch_process_view(app, ViewMode::ProcessList);
             Ok(true)
         }
         KeyCode::Left => {
@@ -2792,11 +6190,41 @@ fn handle_per_process_graph_input(key: KeyEvent, app: &mut App) -> Result<bool,
             }
             Ok(false)
         }
+        KeyCode::Char('R') => {
+            // Restart the selected process with modified arguments
+            if let Some(pid) = app.selected_process_for_graph {
+                begin_restart_with_args(app, pid);
+            }
+            Ok(false)
+        }
+        KeyCode::Char('c') => {
+            // Build a shell command that relaunches the process and copy it
+            if let Some(pid) = app.selected_process_for_graph {
+                match app.process_manager.get_launch_spec(pid) {
+                    Some(spec) => {
+                        let own_env: std::collections::HashMap<String, String> = std::env::vars().collect();
+                        let command = spec.to_shell_command(&own_env);
+                        let message = match copy_to_clipboard(&command) {
+                            Ok(()) => "Command copied to the clipboard".to_string(),
+                            Err(e) => format!("Could not copy to the clipboard: {}", e),
+                        };
+                        app.input_state.message = Some((message, false));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                        app.command_popup = Some(command);
+                    }
+                    None => {
+                        app.input_state.message = Some((format!("Error: cannot read command line of PID {}", pid), true));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                    }
+                }
+            }
+            Ok(false)
+        }
         KeyCode::Esc => {
             if app.selected_process_for_graph.is_some() {
                 app.selected_process_for_graph = None;
             } else {
-                app.view_mode = ViewMode::ProcessList;
+                switch_process_view(app, ViewMode::ProcessList);
             }
             Ok(false)
         }
@@ -2827,12 +6255,141 @@ fn handle_script_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Err
 }
 
 
+// Description, kind heuristic and executable/package for the explain panel
+fn explanation_lines(app: &App, pid: u32) -> Vec<Line<'static>> {
+    let Some((_, _, explanation)) = app.process_explanation.as_ref().filter(|(explained, _, _)| *explained == pid) else {
+        return vec![Line::from(Span::styled("Looking up process...", Style::default().fg(Color::Gray)))];
+    };
+    let description = match explanation.description {
+        Some(text) => Span::styled(text.to_string(), Style::default().fg(Color::Green)),
+        None => Span::styled("Not a well-known process".to_string(), Style::default().fg(Color::Gray)),
+    };
+    let exe = explanation.exe.clone().unwrap_or_else(|| "-".to_string());
+    let mut origin = vec![Span::styled(format!("Executable: {}", exe), Style::default().fg(Color::Black))];
+    if let Some(package) = &explanation.package {
+        origin.push(Span::raw("  "));
+        origin.push(Span::styled(format!("Package: {}", package), Style::default().fg(Color::Blue)));
+    }
+    vec![
+        Line::from(description),
+        Line::from(vec![
+            Span::styled(format!("Kind: {}", explanation.kind.label()), Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+            Span::styled(format!(" ({})", explanation.reason), Style::default().fg(Color::Black)),
+        ]),
+        Line::from(origin),
+    ]
+}
+
+// "In D state for 42s, wchan: nfs_wait_on_request" for a process in uninterruptible sleep
+fn d_state_span(process: &process::ProcessInfo, app: &App) -> Span<'static> {
+    let Some(duration) = app.process_manager.d_state_duration(process.pid) else {
+        return Span::raw("");
+    };
+    let wchan = app.process_manager.wchan(process.pid).unwrap_or_else(|| "?".to_string());
+    let secs = duration.as_secs();
+    let style = if secs >= app.settings.d_state_warn_secs {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Magenta)
+    };
+    Span::styled(format!("In D state for {}s, wchan: {}", secs, wchan), style)
+}
+
+// "Listening: tcp 0.0.0.0:8080, ..." plus established connections and the first few peers
+fn listening_ports_line(sockets: &[ports::SocketEntry]) -> Line<'static> {
+    const SHOWN_PEERS: usize = 3;
+    let endpoint = |protocol: &str, addr: &str, port: u16| {
+        if protocol == "tcp6" { format!("[{}]:{}", addr, port) } else { format!("{}:{}", addr, port) }
+    };
+    let listening: Vec<String> = sockets
+        .iter()
+        .filter(|s| s.state == ports::SocketState::Listen)
+        .map(|s| format!("{} {}", s.protocol, endpoint(s.protocol, &s.local_addr, s.local_port)))
+        .collect();
+    let peers: Vec<String> = sockets
+        .iter()
+        .filter(|s| s.state == ports::SocketState::Established)
+        .map(|s| endpoint(s.protocol, &s.remote_addr, s.remote_port))
+        .collect();
+    let listening = if listening.is_empty() { "none".to_string() } else { listening.join(", ") };
+    let established = match peers.len() {
+        0 => "0".to_string(),
+        n if n > SHOWN_PEERS => format!("{} (to {}, +{} more)", n, peers[..SHOWN_PEERS].join(", "), n - SHOWN_PEERS),
+        n => format!("{} (to {})", n, peers.join(", ")),
+    };
+    Line::from(vec![
+        Span::styled(format!("Listening: {}", listening), Style::default().fg(Color::Magenta)),
+        Span::raw("  "),
+        Span::styled(format!("Established: {}", established), Style::default().fg(Color::Cyan)),
+    ])
+}
+
+// Effective capabilities, dangerous ones in red; dormant (permitted only) ones are counted
+fn capabilities_line(process: &process::ProcessInfo) -> Line<'static> {
+    let Some(caps) = process.capabilities else {
+        return Line::from(Span::styled("Capabilities: N/A", Style::default().fg(Color::DarkGray)));
+    };
+    let mut spans = vec![Span::styled("Capabilities: ", Style::default().fg(Color::Black))];
+    let effective = caps.effective_names();
+    if caps.is_full() {
+        spans.push(Span::styled("all (unrestricted root)", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)));
+    } else if effective.is_empty() {
+        spans.push(Span::styled("none", Style::default().fg(Color::Green)));
+    } else {
+        for name in effective {
+            let style = if crate::capabilities::is_dangerous(&name) {
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Black)
+            };
+            spans.push(Span::styled(name, style));
+            spans.push(Span::raw(" "));
+        }
+    }
+    let dormant = caps.dormant_names().len();
+    if dormant > 0 {
+        spans.push(Span::styled(format!("  (+{} permitted, not effective)", dormant), Style::default().fg(Color::DarkGray)));
+    }
+    Line::from(spans)
+}
+
+// SELinux context / AppArmor profile; omitted entirely when no MAC system is active
+fn security_label_line(process: &process::ProcessInfo) -> Option<Line<'static>> {
+    let mac = crate::security::mac_system()?;
+    let label = process.security_label.clone().unwrap_or_else(|| "N/A".to_string());
+    let style = if crate::security::is_unconfined(&label) {
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    } else if crate::security::apparmor_mode(&label) == Some("complain") {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Green)
+    };
+    let label = if crate::security::is_unconfined(&label) { format!("{} (UNCONFINED)", label) } else { label };
+    Some(Line::from(vec![
+        Span::styled(format!("{}: ", mac.label()), Style::default().fg(Color::Black)),
+        Span::styled(label, style),
+    ]))
+}
+
 fn render_per_process_graph_tab(frame: &mut ratatui::Frame, area: Rect, app: &App) {
+    let note = app.selected_process_for_graph
+        .and_then(|pid| app.process_manager.get_processes().iter().find(|p| p.pid == pid))
+        .and_then(|process| process_note(app, process));
+    let traced = app.selected_process_for_graph
+        .and_then(|pid| app.process_manager.get_processes().iter().find(|p| p.pid == pid))
+        .is_some_and(|process| process.tracer_pid.is_some());
+    let output_log = app.selected_process_for_graph.and_then(|pid| app.process_manager.get_output_log(pid));
+    let info_height = 8
+        + u16::from(crate::security::mac_system().is_some())
+        + u16::from(note.is_some())
+        + u16::from(traced)
+        + u16::from(output_log.is_some());
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),  // Title
-            Constraint::Length(5),  // Process info
+            Constraint::Length(info_height), // Process info (+ security label, note)
+            Constraint::Length(5),  // About (explain panel)
             Constraint::Min(0),     // Content
             Constraint::Length(2),  // Help line
         ])
@@ -2849,16 +6406,61 @@ fn render_per_process_graph_tab(frame: &mut ratatui::Frame, area: Rect, app: &Ap
         let processes = app.process_manager.get_processes();
         if let Some(process) = processes.iter().find(|p| p.pid == pid) {
             // Process info box
-            let info_lines = vec![
+            let mut info_lines = vec![
                 Line::from(vec![Span::styled(format!("Name: {}", process.name), Style::default().fg(Color::Green))]),
                 Line::from(vec![Span::styled(format!("PID: {}", process.pid), Style::default().fg(Color::Yellow)), Span::raw("  "), Span::styled(format!("User: {}", process.user.clone().unwrap_or_default()), Style::default().fg(Color::Magenta))]),
-                Line::from(vec![Span::styled(format!("PPID: {}", process.parent_pid.unwrap_or(0)), Style::default().fg(Color::Cyan)), Span::raw("  "), Span::styled(format!("Status: {}", process.status), Style::default().fg(Color::Black))]),
-                Line::from(vec![Span::styled(format!("Start: {}", process.start_time_str), Style::default().fg(Color::Black))]),
+                Line::from(vec![
+                    Span::styled(format!("PPID: {}", process.parent_pid.unwrap_or(0)), Style::default().fg(Color::Cyan)),
+                    Span::raw("  "),
+                    Span::styled(format!("Status: {}", process.status), Style::default().fg(Color::Black)),
+                    Span::raw("  "),
+                    d_state_span(process, app),
+                ]),
+                Line::from(vec![
+                    Span::styled(format!("Start: {}", process.start_time_str), Style::default().fg(Color::Black)),
+                    Span::raw("  "),
+                    Span::styled(
+                        format!(
+                            "Memory (current / peak): {} / {}  Kernel peak (VmHWM): {}",
+                            crate::units::format_bytes(process.memory_usage),
+                            crate::units::format_bytes(process.peak_memory),
+                            process.vm_hwm.map(crate::units::format_bytes).unwrap_or_else(|| "N/A".to_string()),
+                        ),
+                        Style::default().fg(Color::Blue),
+                    ),
+                ]),
+                listening_ports_line(&app.process_sockets),
+                capabilities_line(process),
             ];
+            info_lines.extend(security_label_line(process));
+            if let Some(tracer) = process.tracer_pid {
+                // A tracer stops the process on every signal, which explains a "Stopped" state
+                let tracer_name = app.process_manager.process_name(tracer).unwrap_or_else(|| "?".to_string());
+                info_lines.push(Line::from(vec![Span::styled(
+                    format!("🐞 Traced by {} ({}) - stops are likely the debugger's", tracer, tracer_name),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )]));
+            }
+            if let Some(note) = note {
+                info_lines.push(Line::from(vec![
+                    Span::styled("Note: ", Style::default().fg(Color::Black).add_modifier(Modifier::BOLD)),
+                    Span::styled(note.to_string(), Style::default().fg(Color::Magenta)),
+                ]));
+            }
+            // Where output goes for processes we launched
+            if let Some(log) = output_log {
+                info_lines.push(Line::from(vec![Span::styled(format!("Logs at {}", log.display()), Style::default().fg(Color::Blue))]));
+            }
             let info_box = Paragraph::new(info_lines)
                 .block(Block::default().borders(Borders::ALL).title("Process Info").style(Style::default().fg(Color::Black)));
             frame.render_widget(info_box, chunks[1]);
 
+            // What the process is
+            let about = Paragraph::new(explanation_lines(app, pid))
+                .wrap(ratatui::widgets::Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL).title("About").style(Style::default().fg(Color::Black)));
+            frame.render_widget(about, chunks[2]);
+
             // Graphs
             let graph_chunks = Layout::default()
                 .direction(Direction::Vertical)
@@ -2866,7 +6468,7 @@ fn render_per_process_graph_tab(frame: &mut ratatui::Frame, area: Rect, app: &Ap
                     Constraint::Percentage(50),  // CPU Graph
                     Constraint::Percentage(50),  // Memory Graph
                 ])
-                .split(chunks[2]);
+                .split(chunks[3]);
 
             if let Some((cpu_history, mem_history)) = app.graph_data.get_process_history(pid) {
                 // Live stats for CPU
@@ -2881,6 +6483,12 @@ fn render_per_process_graph_tab(frame: &mut ratatui::Frame, area: Rect, app: &Ap
                     .enumerate()
                     .map(|(i, &usage)| (i as f64, usage as f64))
                     .collect();
+                // Per-core CPU% can exceed 100, so grow the axis in whole cores
+                let cpu_axis_max = if app.process_manager.is_cpu_per_core() {
+                    ((max_cpu as f64 / 100.0).ceil() * 100.0).max(100.0)
+                } else {
+                    100.0
+                };
                 let cpu_dataset = Dataset::default()
                     .name("CPU Usage")
                     .marker(ratatui::symbols::Marker::Braille)
@@ -2896,25 +6504,24 @@ fn render_per_process_graph_tab(frame: &mut ratatui::Frame, area: Rect, app: &Ap
                         .bounds([0.0, cpu_history.len() as f64])
                         .labels(vec![]))
                     .y_axis(ratatui::widgets::Axis::default()
-                        .bounds([0.0, 100.0])
-                        .labels(vec!["0%".into(), "50%".into(), "100%".into()]));
+                        .bounds([0.0, cpu_axis_max])
+                        .labels(vec!["0%".into(), format!("{:.0}%", cpu_axis_max / 2.0).into(), format!("{:.0}%", cpu_axis_max).into()]));
                 frame.render_widget(cpu_chart, graph_chunks[0]);
 
                 // Live stats for MEM
-                let current_mem = mem_history.back().copied().unwrap_or(0) as f64 / (1024.0 * 1024.0);
-                let min_mem = mem_history.iter().cloned().min().unwrap_or(0) as f64 / (1024.0 * 1024.0);
-                let max_mem = mem_history.iter().cloned().max().unwrap_or(0) as f64 / (1024.0 * 1024.0);
+                let current_mem = mem_history.back().copied().unwrap_or(0);
+                let min_mem = mem_history.iter().cloned().min().unwrap_or(0);
+                let max_mem = mem_history.iter().cloned().max().unwrap_or(0);
                 let avg_mem = if !mem_history.is_empty() {
-                    mem_history.iter().sum::<u64>() as f64 / mem_history.len() as f64 / (1024.0 * 1024.0)
-                } else { 0.0 };
+                    mem_history.iter().sum::<u64>() / mem_history.len() as u64
+                } else { 0 };
+                // Plot in the unit that suits the largest sample
+                let (unit_bytes, unit) = crate::units::scale_for(max_mem as f64);
                 let memory_data: Vec<(f64, f64)> = mem_history.iter()
                     .enumerate()
-                    .map(|(i, &usage)| (i as f64, usage as f64 / (1024.0 * 1024.0)))
+                    .map(|(i, &usage)| (i as f64, usage as f64 / unit_bytes))
                     .collect();
-                let max_memory = memory_data.iter()
-                    .map(|&(_, y)| y)
-                    .fold(0.0, f64::max)
-                    .max(1.0);
+                let max_memory = (max_mem as f64 / unit_bytes).max(1.0);
                 let memory_dataset = Dataset::default()
                     .name("Memory Usage")
                     .marker(ratatui::symbols::Marker::Braille)
@@ -2923,7 +6530,15 @@ fn render_per_process_graph_tab(frame: &mut ratatui::Frame, area: Rect, app: &Ap
                     .data(&memory_data);
                 let memory_chart = Chart::new(vec![memory_dataset])
                     .block(Block::default()
-                        .title(format!("Memory Usage for {} (PID: {}) | Now: {:.2} MB  Min: {:.2} MB  Max: {:.2} MB  Avg: {:.2} MB", process.name, pid, current_mem, min_mem, max_mem, avg_mem))
+                        .title(format!(
+                            "Memory Usage for {} (PID: {}) | Now: {}  Min: {}  Max: {}  Avg: {}",
+                            process.name,
+                            pid,
+                            crate::units::format_bytes(current_mem),
+                            crate::units::format_bytes(min_mem),
+                            crate::units::format_bytes(max_mem),
+                            crate::units::format_bytes(avg_mem)
+                        ))
                         .borders(Borders::ALL)
                         .style(Style::default().fg(Color::Green)))
                     .x_axis(ratatui::widgets::Axis::default()
@@ -2932,23 +6547,27 @@ fn render_per_process_graph_tab(frame: &mut ratatui::Frame, area: Rect, app: &Ap
                     .y_axis(ratatui::widgets::Axis::default()
                         .bounds([0.0, max_memory * 1.2])
                         .labels(vec![
-                            "0 MB".into(),
-                            format!("{:.1} MB", max_memory / 2.0).into(),
-                            format!("{:.1} MB", max_memory).into(),
+                            format!("0 {}", unit).into(),
+                            format!("{:.1} {}", max_memory / 2.0, unit).into(),
+                            format!("{:.1} {}", max_memory, unit).into(),
                         ]));
                 frame.render_widget(memory_chart, graph_chunks[1]);
             }
         }
         // Help line
-        let help = Paragraph::new("←/→: Next/Prev process  ↑/↓: Back to list  Enter: Select  Esc: Back  Q: Quit")
+        let help = Paragraph::new("←/→: Next/Prev process  ↑/↓: Back to list  R: Restart with modified args  c: Copy as command  Esc: Back  Q: Quit")
             .style(Style::default().fg(Color::Black))
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL));
-        frame.render_widget(help, chunks[3]);
+        frame.render_widget(help, chunks[4]);
+
+        if let Some(command) = &app.command_popup {
+            draw_command_popup(frame, command, area);
+        }
     } else {
         // Show process selection list
-        let processes = app.process_manager.get_processes();
-        let headers = ["PID", "NAME", "CPU%", "MEM(MB)", "USER"];
+        let processes = view_processes(app, ViewMode::PerProcessGraph);
+        let headers = ["PID", "NAME", "CPU%", "MEM", "USER"];
         let header_cells = headers
             .iter()
             .map(|h| Cell::from(*h).style(Style::default().fg(Color::Black).add_modifier(Modifier::BOLD)));
@@ -2970,33 +6589,32 @@ fn render_per_process_graph_tab(frame: &mut ratatui::Frame, area: Rect, app: &Ap
                 } else {
                     Style::default().fg(Color::Blue)
                 };
-                let memory_mb = process.memory_usage / (1024 * 1024);
                 Row::new(vec![
                     Cell::from(process.pid.to_string()).style(style),
                     Cell::from(process.name.clone()).style(Style::default().fg(Color::Green)),
                     Cell::from(format!("{:.1}%", process.cpu_usage)).style(style),
-                    Cell::from(format!("{}", memory_mb)).style(style),
+                    Cell::from(crate::units::format_bytes(process.memory_usage)).style(style),
                     Cell::from(process.user.clone().unwrap_or_default()).style(Style::default().fg(Color::Magenta)),
                 ])
             })
             .collect();
         let table = Table::new(rows)
             .header(header)
-            .block(Block::default().borders(Borders::ALL).title("Select a Process (↑↓ to move, Enter to select, Esc to return)").style(Style::default().fg(Color::Black)))
+            .block(Block::default().borders(Borders::ALL).title(format!("Select a Process (↑↓ to move, Enter to select, Esc to return){}", view_prefs_label(app, ViewMode::PerProcessGraph))).style(Style::default().fg(Color::Black)))
             .widths(&[
                 Constraint::Length(8),   // PID
                 Constraint::Length(20),  // NAME
                 Constraint::Length(8),   // CPU%
-                Constraint::Length(10),  // MEM(MB)
+                Constraint::Length(10),  // MEM
                 Constraint::Length(12),  // USER
             ]);
-        frame.render_widget(table, chunks[2]);
+        frame.render_widget(table, chunks[3]);
         // Help line
         let help = Paragraph::new("↑/↓: Move  Enter: Select  Esc: Back  Q: Quit")
             .style(Style::default().fg(Color::Black))
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::ALL));
-        frame.render_widget(help, chunks[3]);
+        frame.render_widget(help, chunks[4]);
     }
 }
 
@@ -3062,7 +6680,8 @@ fn handle_process_log_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
                     LogGroupMode::None => LogGroupMode::Name,
                     LogGroupMode::Name => LogGroupMode::PPID,
                     LogGroupMode::PPID => LogGroupMode::User,
-                    LogGroupMode::User => LogGroupMode::None,
+                    LogGroupMode::User => LogGroupMode::ExitReason,
+                    LogGroupMode::ExitReason => LogGroupMode::None,
                 };
                 app.log_scroll_offset = 0;
             }
@@ -3070,6 +6689,19 @@ fn handle_process_log_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
                 app.log_group_mode = LogGroupMode::None;
                 app.log_scroll_offset = 0;
             }
+            KeyCode::Char('i') => {
+                // Toggle logging of processes below the minimum lifetime (persisted)
+                app.settings.exit_log_include_short_lived = !app.settings.exit_log_include_short_lived;
+                let _ = app.settings.save();
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                app.settings.exit_log_min_lifetime_secs += 1;
+                let _ = app.settings.save();
+            }
+            KeyCode::Char('-') => {
+                app.settings.exit_log_min_lifetime_secs = app.settings.exit_log_min_lifetime_secs.saturating_sub(1);
+                let _ = app.settings.save();
+            }
             KeyCode::Char('/') => {
                 app.log_filter_active = true;
                 app.log_filter_input.clear();
@@ -3110,6 +6742,7 @@ fn draw_container_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
             Constraint::Length(3),  // Header
             Constraint::Length(6),  // Container info
             Constraint::Min(0),     // Process list
+            Constraint::Length(10), // Container CPU/memory history
             Constraint::Length(3),  // Menu
         ])
         .split(size);
@@ -3123,19 +6756,24 @@ fn draw_container_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
 
     let processes = app.process_manager.get_processes();
     if let Some(container_id) = &app.selected_container_id {
-        if let Some(container) = get_container_details(processes, container_id) {
+        if let Some(mut container) = get_container_details(processes, container_id) {
+            if app.settings.cgroup_accounting {
+                container.use_cgroup_accounting(&mut app.cgroup_accounting, app.process_manager.cpu_divisor() as f32);
+            }
             // Container info
-            let memory_mb = container.memory_usage / (1024 * 1024);
+            let memory = crate::units::format_bytes(container.memory_usage);
             let process_count_str = container.process_count().to_string();
             let info_lines = vec![
                 Line::from(vec![Span::styled("Container ID: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::raw(&container.id)]),
                 Line::from(vec![Span::styled("Name: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::raw(&container.name)]),
                 Line::from(vec![Span::styled("Total CPU: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::styled(format!("{:.1}%", container.cpu_usage), Style::default().fg(Color::Cyan))]),
-                Line::from(vec![Span::styled("Total Memory: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::styled(format!("{} MB", memory_mb), Style::default().fg(Color::Green))]),
+                Line::from(vec![Span::styled("Total Memory: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::styled(memory.clone(), Style::default().fg(Color::Green))]),
                 Line::from(vec![Span::styled("Process Count: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::raw(&process_count_str)]),
             ];
             let info = Paragraph::new(info_lines)
-                .block(Block::default().borders(Borders::ALL).title("Container Information").style(Style::default().fg(Color::Black)));
+                .block(Block::default().borders(Borders::ALL)
+                    .title(format!("Container Information (totals: {})", container.source.label()))
+                    .style(Style::default().fg(Color::Black)));
             f.render_widget(info, chunks[1]);
 
             // Process list
@@ -3145,7 +6783,7 @@ fn draw_container_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
                     .block(Block::default().borders(Borders::ALL).title("Processes in Container").style(Style::default().fg(Color::Black)));
                 f.render_widget(empty_msg, chunks[2]);
             } else {
-                let headers = ["PID", "NAME", "CPU%", "MEM(MB)", "USER"];
+                let headers = ["PID", "NAME", "CPU%", "MEM", "USER"];
                 let header_cells = headers.iter().map(|h| Cell::from(*h).style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD)));
                 let header = Row::new(header_cells).style(Style::default().bg(Color::Blue)).height(1);
 
@@ -3159,7 +6797,7 @@ fn draw_container_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
                             Cell::from(proc.pid.to_string()),
                             Cell::from(proc.name.clone()),
                             Cell::from(format!("{:.1}%", proc.cpu_usage)),
-                            Cell::from(format!("{}", proc.memory_usage / (1024 * 1024))),
+                            Cell::from(crate::units::format_bytes(proc.memory_usage)),
                             Cell::from(proc.user.clone().unwrap_or_default()),
                         ])
                     })
@@ -3202,12 +6840,15 @@ fn draw_container_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
         f.render_widget(empty_msg, chunks[2]);
     }
 
+    // Container history graph (recorded while this view is open)
+    graph::render_group_graph(f, chunks[3], &app.graph_data, "Container");
+
     // Menu
     let menu = Paragraph::new("↑/↓: Scroll  |  [Esc] Back")
         .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
         .style(Style::default().fg(Color::Black))
         .alignment(Alignment::Left);
-    f.render_widget(menu, chunks[3]);
+    f.render_widget(menu, chunks[4]);
 }
 
 // Draw namespace detail view
@@ -3236,14 +6877,14 @@ fn draw_namespace_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
     if let Some((ns_type, ns_id)) = &app.selected_namespace {
         if let Some(group) = get_namespace_group_details(processes, ns_type, *ns_id) {
             // Namespace info
-            let memory_mb = group.memory_usage / (1024 * 1024);
+            let memory = crate::units::format_bytes(group.memory_usage);
             let ns_id_str = ns_id.to_string();
             let process_count_str = group.process_count().to_string();
             let info_lines = vec![
                 Line::from(vec![Span::styled("Namespace Type: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::raw(ns_type)]),
                 Line::from(vec![Span::styled("Namespace ID: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::raw(&ns_id_str)]),
                 Line::from(vec![Span::styled("Total CPU: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::styled(format!("{:.1}%", group.cpu_usage), Style::default().fg(Color::Cyan))]),
-                Line::from(vec![Span::styled("Total Memory: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::styled(format!("{} MB", memory_mb), Style::default().fg(Color::Green))]),
+                Line::from(vec![Span::styled("Total Memory: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::styled(memory.clone(), Style::default().fg(Color::Green))]),
                 Line::from(vec![Span::styled("Process Count: ", Style::default().fg(Color::White).add_modifier(Modifier::BOLD)), Span::raw(&process_count_str)]),
             ];
             let info = Paragraph::new(info_lines)
@@ -3251,7 +6892,7 @@ fn draw_namespace_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
             f.render_widget(info, chunks[1]);
 
             // Process list
-            let headers = ["PID", "NAME", "CPU%", "MEM(MB)", "USER"];
+            let headers = ["PID", "NAME", "CPU%", "MEM", "USER"];
             let header_cells = headers.iter().map(|h| Cell::from(*h).style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD)));
             let header = Row::new(header_cells).style(Style::default().bg(Color::Blue)).height(1);
 
@@ -3265,7 +6906,7 @@ fn draw_namespace_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
                         Cell::from(proc.pid.to_string()),
                         Cell::from(proc.name.clone()),
                         Cell::from(format!("{:.1}%", proc.cpu_usage)),
-                        Cell::from(format!("{}", proc.memory_usage / (1024 * 1024))),
+                        Cell::from(crate::units::format_bytes(proc.memory_usage)),
                         Cell::from(proc.user.clone().unwrap_or_default()),
                     ])
                 })
@@ -3286,7 +6927,7 @@ fn draw_namespace_detail_view(f: &mut Frame, app: &mut App, area: Rect) {
     }
 
     // Menu
-    let menu = Paragraph::new("↑/↓: Scroll  |  [Esc] Back")
+    let menu = Paragraph::new("↑/↓: Scroll  |  [f] Focus PID Namespace  |  [Esc] Back")
         .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
         .style(Style::default().fg(Color::Black))
         .alignment(Alignment::Left);
@@ -3313,8 +6954,20 @@ fn draw_grouped_view(f: &mut Frame, app: &mut App, area: Rect) {
         GroupType::Container => "Container",
         GroupType::Namespace(ref ns) => ns,
         GroupType::Username => "Username",
+        GroupType::Tty => "TTY",
+        GroupType::SystemdUnit => "Systemd Unit",
     };
-    let title = Paragraph::new(format!("Grouped View: {}", group_type_name))
+    // Show which cgroup layout the host uses so cgroup group ids make sense
+    let layout_suffix = if app.grouped_view_type == GroupType::Cgroup {
+        match crate::process_group::host_cgroup_version() {
+            crate::process_group::CgroupVersion::V1 => " (cgroup v1)",
+            crate::process_group::CgroupVersion::V2 => " (cgroup v2)",
+            crate::process_group::CgroupVersion::Hybrid => " (cgroup hybrid)",
+        }
+    } else {
+        ""
+    };
+    let title = Paragraph::new(format!("Grouped View: {}{}", group_type_name, layout_suffix))
         .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL));
@@ -3322,12 +6975,15 @@ fn draw_grouped_view(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Get grouped processes
     let processes = app.process_manager.get_processes();
-    let groups: Vec<crate::process_group::ProcessGroup> = match app.grouped_view_type {
+    let mut groups: Vec<crate::process_group::ProcessGroup> = match app.grouped_view_type {
         GroupType::Cgroup => ProcessGroupManager::group_by_cgroup(processes),
         GroupType::Container => ProcessGroupManager::group_by_container(processes),
         GroupType::Namespace(ref ns_type) => ProcessGroupManager::group_by_namespace(processes, ns_type),
         GroupType::Username => ProcessGroupManager::group_by_username(processes),
+        GroupType::Tty => ProcessGroupManager::group_by_tty(processes),
+        GroupType::SystemdUnit => ProcessGroupManager::group_by_systemd_unit(processes),
     };
+    apply_cgroup_accounting(&mut groups, &mut app.cgroup_accounting, &app.process_manager, &app.settings);
 
     // Sort groups - maintain stability for expanded groups to prevent jumping
     let mut sorted_groups = groups;
@@ -3380,6 +7036,8 @@ fn draw_grouped_view(f: &mut Frame, app: &mut App, area: Rect) {
         // Update frozen order when groups change (for future stability)
         app.frozen_group_order = sorted_groups.iter().map(|g| g.group_id.clone()).collect();
     }
+    // Narrow after ordering so the frozen order still covers every group
+    retain_matching_groups(&mut sorted_groups, &app.grouped_view_type, &app.group_filter_input);
 
     // Build list items for groups
     // Note: Scroll offset is based on groups, expanded processes are shown inline
@@ -3394,38 +7052,16 @@ fn draw_grouped_view(f: &mut Frame, app: &mut App, area: Rect) {
         let is_selected = idx_in_visible == app.selected_group_index;
         
         let expand_indicator = if is_expanded { "▼" } else { "▶" };
-        let memory_mb = group.total_memory / (1024 * 1024);
+        let memory = crate::units::format_bytes(group.total_memory);
         
-        // Get display name for container groups, namespace groups, and username groups
-        let display_name = match &app.grouped_view_type {
-            GroupType::Container => {
-                if group.group_id == "No container" {
-                    "No container".to_string()
-                } else {
-                    use crate::container_view::get_container_name;
-                    get_container_name(&group.group_id)
-                }
-            }
-            GroupType::Namespace(ns_type) => {
-                // For namespace groups, show a cleaner format
-                // group_id format is "namespace_type:namespace_id"
-                // Note: "None" groups are no longer created to avoid namespace ID 0 collision
-                if let Some(id_str) = group.group_id.split(':').nth(1) {
-                    format!("{}: {}", ns_type, id_str)
-                } else {
-                    // Fallback to full group_id if parsing fails (shouldn't happen)
-                    group.group_id.clone()
-                }
-            }
-            GroupType::Username => {
-                // For username groups, the group_id is already the username
-                group.group_id.clone()
-            }
-            _ => group.group_id.clone(),
-        };
+        let display_name = group_display_name(&app.grouped_view_type, group);
         
-        let line = format!("{} {} | CPU: {:.1}% | MEM: {}MB | Processes: {}", 
-            expand_indicator, display_name, group.total_cpu, memory_mb, group.process_count());
+        let mut line = format!("{} {} | CPU: {:.1}% | MEM: {} | Processes: {}", 
+            expand_indicator, display_name, group.total_cpu, memory, group.process_count());
+        // Which method produced the totals, for the group types that map to a cgroup
+        if matches!(app.grouped_view_type, GroupType::Cgroup | GroupType::Container | GroupType::SystemdUnit) {
+            line.push_str(&format!(" | {}", group.source.label()));
+        }
         
         let style = if is_selected {
             Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD)
@@ -3440,24 +7076,40 @@ fn draw_grouped_view(f: &mut Frame, app: &mut App, area: Rect) {
             let mut sorted_procs = group.processes.clone();
             sorted_procs.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
             for process in &sorted_procs {
-                let proc_line = format!("  └─ {} (PID: {}) | CPU: {:.1}% | MEM: {}MB",
-                    process.name, process.pid, process.cpu_usage, process.memory_usage / (1024 * 1024));
+                let proc_line = format!("  └─ {} (PID: {}) | CPU: {:.1}% | MEM: {}",
+                    process.name, process.pid, process.cpu_usage, crate::units::format_bytes(process.memory_usage));
                 items.push(ListItem::new(Span::styled(proc_line, Style::default().fg(Color::Cyan))));
             }
         }
     }
 
-    // Update title to show freeze status
-    let title_text = if app.group_view_frozen {
-        "Groups (Enter: expand/collapse, 1/2/3: switch type, [f]: freeze/unfreeze) [FROZEN]"
+    // Update title to show freeze status and the active search
+    let mut title_text = if app.group_view_frozen {
+        "Groups (Enter: expand/collapse, 1-5: switch type, [f]: freeze/unfreeze) [FROZEN]".to_string()
     } else {
-        "Groups (Enter: expand/collapse, 1/2/3: switch type, [f]: freeze/unfreeze)"
+        "Groups (Enter: expand/collapse, 1-5: switch type, [f]: freeze/unfreeze)".to_string()
     };
+    if app.group_filter_active {
+        title_text.push_str(&format!(" /{}", app.group_filter_input));
+    } else if !app.group_filter_input.is_empty() {
+        title_text.push_str(&format!(" [Search: {} - {} match(es), Esc clears]", app.group_filter_input, sorted_groups.len()));
+    }
     
     let list = List::new(items)
         .block(Block::default().borders(Borders::ALL).title(title_text))
         .style(Style::default());
-    f.render_widget(list, chunks[1]);
+    
+    // Show history for the tracked cgroup below the group list
+    if let Some((GroupType::Cgroup, cgroup_id)) = app.graph_data.get_tracked_group() {
+        let content_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(10)])
+            .split(chunks[1]);
+        f.render_widget(list, content_chunks[0]);
+        graph::render_group_graph(f, content_chunks[1], &app.graph_data, &format!("Cgroup {}", cgroup_id));
+    } else {
+        f.render_widget(list, chunks[1]);
+    }
 
     // Menu
     let menu_text = vec![
@@ -3474,8 +7126,19 @@ fn draw_grouped_view(f: &mut Frame, app: &mut App, area: Rect) {
             Span::raw("| "),
             Span::styled("[4] Username  ", Style::default().fg(Color::Cyan)),
             Span::raw("| "),
+            Span::styled("[5] TTY  ", Style::default().fg(Color::Green)),
+            Span::raw("| "),
+            Span::styled("[6] Systemd Unit  ", Style::default().fg(Color::Blue)),
+            Span::raw("| "),
             Span::styled("[f] Freeze  ", Style::default().fg(Color::Red)),
             Span::raw("| "),
+            Span::styled(
+                if app.settings.cgroup_accounting { "[a] Cgroup Accounting: ON  " } else { "[a] Cgroup Accounting  " },
+                Style::default().fg(if app.settings.cgroup_accounting { Color::Green } else { Color::Blue }),
+            ),
+            Span::raw("| "),
+            Span::styled("[/] Search  ", Style::default().fg(Color::Cyan)),
+            Span::raw("| "),
             Span::styled("[Esc] Back", Style::default().fg(Color::Black)),
         ]),
     ];
@@ -3485,6 +7148,68 @@ fn draw_grouped_view(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_widget(menu, chunks[2]);
 }
 
+// Swap summed group totals for the cgroup's own accounting when enabled; groups whose files can't be read keep the sums
+fn apply_cgroup_accounting(
+    groups: &mut [crate::process_group::ProcessGroup],
+    accounting: &mut crate::process_group::CgroupAccounting,
+    process_manager: &ProcessManager,
+    settings: &crate::settings::Settings,
+) {
+    if !settings.cgroup_accounting {
+        return;
+    }
+    let cpu_divisor = process_manager.cpu_divisor() as f32;
+    for group in groups {
+        group.use_cgroup_accounting(accounting, cpu_divisor);
+    }
+}
+
+// Name shown for a group: container name, "type: id" for namespaces, otherwise the group id
+fn group_display_name(group_type: &crate::process_group::GroupType, group: &crate::process_group::ProcessGroup) -> String {
+    use crate::process_group::GroupType;
+    match group_type {
+        GroupType::Container => {
+            if group.group_id == "No container" {
+                "No container".to_string()
+            } else {
+                use crate::container_view::get_container_name;
+                get_container_name(&group.group_id)
+            }
+        }
+        GroupType::Namespace(ns_type) => {
+            // group_id format is "namespace_type:namespace_id"
+            // Note: "None" groups are no longer created to avoid namespace ID 0 collision
+            if let Some(id_str) = group.group_id.split(':').nth(1) {
+                format!("{}: {}", ns_type, id_str)
+            } else {
+                // Fallback to full group_id if parsing fails (shouldn't happen)
+                group.group_id.clone()
+            }
+        }
+        GroupType::Tty => {
+            if group.group_id == "?" {
+                "? (no terminal)".to_string()
+            } else {
+                group.group_id.clone()
+            }
+        }
+        // For cgroup, username and unit groups the group_id is already the name
+        GroupType::Cgroup | GroupType::Username | GroupType::SystemdUnit => group.group_id.clone(),
+    }
+}
+
+// Keep groups whose display name or id contains the search term (case-insensitive)
+fn retain_matching_groups(groups: &mut Vec<crate::process_group::ProcessGroup>, group_type: &crate::process_group::GroupType, query: &str) {
+    if query.is_empty() {
+        return;
+    }
+    let query = query.to_lowercase();
+    groups.retain(|group| {
+        group.group_id.to_lowercase().contains(&query)
+            || group_display_name(group_type, group).to_lowercase().contains(&query)
+    });
+}
+
 // Handle keyboard input for grouped view
 fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
     use crate::process_group::{ProcessGroupManager, GroupType};
@@ -3495,7 +7220,10 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
         GroupType::Container => ProcessGroupManager::group_by_container(processes),
         GroupType::Namespace(ref ns_type) => ProcessGroupManager::group_by_namespace(processes, ns_type),
         GroupType::Username => ProcessGroupManager::group_by_username(processes),
+        GroupType::Tty => ProcessGroupManager::group_by_tty(processes),
+        GroupType::SystemdUnit => ProcessGroupManager::group_by_systemd_unit(processes),
     };
+    apply_cgroup_accounting(&mut groups, &mut app.cgroup_accounting, &app.process_manager, &app.settings);
     
     // Sort groups the same way as in draw_grouped_view to ensure index matching
     if app.group_view_frozen && !app.frozen_group_order.is_empty() {
@@ -3531,18 +7259,51 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
         // Normal sort by CPU usage
         groups.sort_by(|a, b| b.total_cpu.partial_cmp(&a.total_cpu).unwrap_or(std::cmp::Ordering::Equal));
     }
+    retain_matching_groups(&mut groups, &app.grouped_view_type, &app.group_filter_input);
     
     let num_groups = groups.len();
     
     // Convert visible index to actual index in sorted groups (accounting for scroll offset)
     let actual_selected_index = app.grouped_view_scroll_offset + app.selected_group_index;
+
+    if app.group_filter_active {
+        match key.code {
+            KeyCode::Esc => {
+                app.group_filter_active = false;
+                app.group_filter_input.clear();
+            }
+            KeyCode::Enter => app.group_filter_active = false,
+            KeyCode::Backspace => {
+                app.group_filter_input.pop();
+            }
+            KeyCode::Char(c) => app.group_filter_input.push(c),
+            _ => return Ok(false),
+        }
+        // The matching set changed; start again from the top
+        app.selected_group_index = 0;
+        app.grouped_view_scroll_offset = 0;
+        return Ok(false);
+    }
     
     match key.code {
-        KeyCode::Esc => {
-            app.view_mode = ViewMode::ProcessList;
+        KeyCode::Esc if !app.group_filter_input.is_empty() => {
+            // First Esc clears the search, the next one leaves the view
+            app.group_filter_input.clear();
             app.selected_group_index = 0;
             app.grouped_view_scroll_offset = 0;
-            app.expanded_groups.clear();
+        }
+        KeyCode::Char('/') => {
+            app.group_filter_active = true;
+            app.group_filter_input.clear();
+            app.selected_group_index = 0;
+            app.grouped_view_scroll_offset = 0;
+        }
+        KeyCode::Esc => {
+            app.view_mode = ViewMode::ProcessList;
+            app.selected_group_index = 0;
+            app.grouped_view_scroll_offset = 0;
+            app.expanded_groups.clear();
+            app.graph_data.track_group(None);
             app.group_view_frozen = false;
             app.frozen_group_order.clear(); // Clear frozen groups when leaving grouped view
         }
@@ -3578,6 +7339,7 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
                     GroupType::Container => {
                         // Drill down to container detail
                         app.selected_container_id = Some(group.group_id.clone());
+                        app.graph_data.track_group(Some((GroupType::Container, group.group_id.clone())));
                         app.view_mode = ViewMode::ContainerDetail;
                         app.detail_view_scroll_offset = 0;
                     }
@@ -3605,17 +7367,30 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
                             ));
                         }
                     }
-                    GroupType::Cgroup | GroupType::Username => {
-                        // Toggle expand/collapse for cgroups and username groups
+                    GroupType::Cgroup | GroupType::Username | GroupType::Tty | GroupType::SystemdUnit => {
+                        // Toggle expand/collapse for cgroups, username, tty and unit groups
                         if app.expanded_groups.contains(&group.group_id) {
                             app.expanded_groups.remove(&group.group_id);
+                            // Stop recording history when the tracked cgroup is collapsed
+                            if app.graph_data.get_tracked_group().is_some_and(|(_, id)| id == &group.group_id) {
+                                app.graph_data.track_group(None);
+                            }
                         } else {
                             app.expanded_groups.insert(group.group_id.clone());
+                            // Record history for the most recently opened cgroup
+                            if app.grouped_view_type == GroupType::Cgroup {
+                                app.graph_data.track_group(Some((GroupType::Cgroup, group.group_id.clone())));
+                            }
                         }
                     }
                 }
             }
         }
+        KeyCode::Char('a') => {
+            // Toggle reading group totals from cgroup accounting files (persisted)
+            app.settings.cgroup_accounting = !app.settings.cgroup_accounting;
+            let _ = app.settings.save();
+        }
         KeyCode::Char('f') | KeyCode::Char('F') => {
             // Toggle freeze/unfreeze group order
             app.group_view_frozen = !app.group_view_frozen;
@@ -3626,8 +7401,11 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
                     GroupType::Container => ProcessGroupManager::group_by_container(processes),
                     GroupType::Namespace(ref ns_type) => ProcessGroupManager::group_by_namespace(processes, ns_type),
                     GroupType::Username => ProcessGroupManager::group_by_username(processes),
+                    GroupType::Tty => ProcessGroupManager::group_by_tty(processes),
+                    GroupType::SystemdUnit => ProcessGroupManager::group_by_systemd_unit(processes),
                 };
                 let mut sorted = current_groups;
+                apply_cgroup_accounting(&mut sorted, &mut app.cgroup_accounting, &app.process_manager, &app.settings);
                 sorted.sort_by(|a, b| b.total_cpu.partial_cmp(&a.total_cpu).unwrap_or(std::cmp::Ordering::Equal));
                 app.frozen_group_order = sorted.iter().map(|g| g.group_id.clone()).collect();
                 app.input_state.message = Some(("Group order frozen - expanded groups will stay in place".to_string(), false));
@@ -3637,6 +7415,7 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
             }
         }
         KeyCode::Char('1') => {
+            app.graph_data.track_group(None);
             app.grouped_view_type = GroupType::Cgroup;
             app.selected_group_index = 0;
             app.grouped_view_scroll_offset = 0;
@@ -3645,6 +7424,7 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
             app.frozen_group_order.clear();
         }
         KeyCode::Char('2') => {
+            app.graph_data.track_group(None);
             app.grouped_view_type = GroupType::Container;
             app.selected_group_index = 0;
             app.grouped_view_scroll_offset = 0;
@@ -3653,6 +7433,7 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
             app.frozen_group_order.clear();
         }
         KeyCode::Char('4') => {
+            app.graph_data.track_group(None);
             app.grouped_view_type = GroupType::Username;
             app.selected_group_index = 0;
             app.grouped_view_scroll_offset = 0;
@@ -3660,8 +7441,27 @@ fn handle_grouped_view_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<d
             app.group_view_frozen = false;
             app.frozen_group_order.clear();
         }
+        KeyCode::Char('5') => {
+            app.graph_data.track_group(None);
+            app.grouped_view_type = GroupType::Tty;
+            app.selected_group_index = 0;
+            app.grouped_view_scroll_offset = 0;
+            app.current_namespace_type = None;
+            app.group_view_frozen = false;
+            app.frozen_group_order.clear();
+        }
+        KeyCode::Char('6') => {
+            app.graph_data.track_group(None);
+            app.grouped_view_type = GroupType::SystemdUnit;
+            app.selected_group_index = 0;
+            app.grouped_view_scroll_offset = 0;
+            app.current_namespace_type = None;
+            app.group_view_frozen = false;
+            app.frozen_group_order.clear();
+        }
         KeyCode::Char('3') => {
             // Switch to namespace grouping - cycle through available namespace types
+            app.graph_data.track_group(None);
             let ns_types = ProcessGroupManager::get_available_namespace_types(processes);
             if ns_types.is_empty() {
                 app.input_state.message = Some(("No namespace types available".to_string(), true));
@@ -3706,6 +7506,7 @@ fn handle_container_detail_input(key: KeyEvent, app: &mut App) -> Result<bool, B
             // Always go back to grouped view when Esc is pressed
             app.view_mode = ViewMode::GroupedView;
             app.detail_view_scroll_offset = 0;
+            app.graph_data.track_group(None);
             return Ok(false); // Key was handled, but don't exit app
         }
         KeyCode::Up => {
@@ -3765,6 +7566,26 @@ fn handle_container_detail_input(key: KeyEvent, app: &mut App) -> Result<bool, B
 fn handle_namespace_detail_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
     use crate::namespace_view::get_namespace_group_details;
     
+    if key.code == KeyCode::Char('f') {
+        // A PID namespace is focused directly; for other types, the PID namespace of their first process
+        let namespace = app.selected_namespace.as_ref().and_then(|(ns_type, ns_id)| {
+            if ns_type == "pid" {
+                Some(*ns_id)
+            } else {
+                get_namespace_group_details(app.process_manager.get_processes(), ns_type, *ns_id)
+                    .and_then(|group| group.processes.first().map(|p| p.pid))
+                    .and_then(|pid| app.process_manager.pid_namespace_of(pid))
+            }
+        });
+        match namespace {
+            Some(namespace) => focus_pid_namespace(app, namespace),
+            None => {
+                app.input_state.message = Some(("No PID namespace to focus".to_string(), true));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            }
+        }
+        return Ok(false);
+    }
     let processes = app.process_manager.get_processes();
     if let Some((ns_type, ns_id)) = &app.selected_namespace {
         if let Some(group) = get_namespace_group_details(processes, ns_type, *ns_id) {
@@ -3853,15 +7674,16 @@ fn draw_scheduler_view(f: &mut Frame, app: &mut App, area: Rect) {
                     format!("Start: {} {}", program, args.join(" "))
                 }
             }
-            ScheduleAction::CleanupIdle { cpu_threshold, memory_threshold, action, .. } => {
-                format!("Cleanup: CPU<{}%, MEM>{}MB, {}", 
-                    cpu_threshold, memory_threshold / (1024*1024), action)
+            ScheduleAction::CleanupIdle { cpu_threshold, memory_threshold, duration_seconds, action } => {
+                format!("Cleanup: CPU<{}% for {}s, MEM>{}, {}", 
+                    cpu_threshold, duration_seconds, crate::units::format_bytes(*memory_threshold), action)
             }
             ScheduleAction::ApplyRule { rule } => format!("Rule: {}", rule),
             ScheduleAction::KillProcess { pid } => format!("Kill PID: {}", pid),
             ScheduleAction::StopProcess { pid } => format!("Stop PID: {}", pid),
             ScheduleAction::ContinueProcess { pid } => format!("Continue PID: {}", pid),
             ScheduleAction::ReniceProcess { pid, nice } => format!("Renice PID: {} to {}", pid, nice),
+            ScheduleAction::SendSignal { pid, signal } => format!("Signal {} to PID: {}", signal, pid),
         };
         
         let line = format!("{} {} | {} | {}", status, task.name, schedule_str, action_str);
@@ -3877,7 +7699,7 @@ fn draw_scheduler_view(f: &mut Frame, app: &mut App, area: Rect) {
     }
 
     let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("Scheduled Tasks (Enter: toggle, A/+: add, -: delete)").style(Style::default().fg(Color::Black)))
+        .block(Block::default().borders(Borders::ALL).title("Scheduled Tasks (Enter: toggle, A/+: add, E: edit, -: delete)").style(Style::default().fg(Color::Black)))
         .style(Style::default());
     f.render_widget(list, chunks[1]);
 
@@ -3896,8 +7718,13 @@ fn draw_scheduler_view(f: &mut Frame, app: &mut App, area: Rect) {
         .style(Style::default());
     f.render_widget(log_list, chunks[2]);
 
-    // Menu
-    let menu = Paragraph::new("↑/↓: Navigate  |  [Enter] Toggle  |  [A/+] Add  |  [-] Delete  |  [Esc] Back  |  [S] Save")
+    // Menu (or the export/import path prompt)
+    let menu_text = match app.task_file_prompt {
+        Some(TaskFileOp::Export) => format!("Export tasks to JSON file: {}_  (Enter: export, Esc: cancel)", app.task_file_input),
+        Some(TaskFileOp::Import) => format!("Import tasks from JSON file: {}_  (Enter: import, Esc: cancel)", app.task_file_input),
+        None => "↑/↓: Navigate  |  [Enter] Toggle  |  [A/+] Add  |  [E] Edit  |  [-] Delete  |  [X] Export  |  [I] Import  |  [Esc] Back  |  [S] Save".to_string(),
+    };
+    let menu = Paragraph::new(menu_text)
         .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
         .style(Style::default().fg(Color::Black))
         .alignment(Alignment::Left);
@@ -3906,6 +7733,22 @@ fn draw_scheduler_view(f: &mut Frame, app: &mut App, area: Rect) {
 
 // Handle keyboard input for scheduler view
 fn handle_scheduler_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    if let Some(op) = app.task_file_prompt {
+        match key.code {
+            KeyCode::Esc => app.task_file_prompt = None,
+            KeyCode::Enter => {
+                app.task_file_prompt = None;
+                transfer_task_file(app, op);
+            }
+            KeyCode::Backspace => {
+                app.task_file_input.pop();
+            }
+            KeyCode::Char(c) => app.task_file_input.push(c),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
     let tasks = app.scheduler.get_tasks();
     let num_tasks = tasks.len();
     
@@ -3944,8 +7787,39 @@ fn handle_scheduler_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
             app.input_state.task_schedule_value.clear();
             app.input_state.task_action_type.clear();
             app.input_state.task_action_value.clear();
+            app.input_state.task_once_confirmed = None;
+            app.input_state.editing_task_index = None;
             app.input_state.current_task_field = 0;
         }
+        KeyCode::Char('e') | KeyCode::Char('E') => {
+            // Open task editor pre-filled with the selected task
+            if let Some(task) = tasks.get(app.selected_task_index) {
+                match task_editor_fields(task) {
+                    Some((schedule_type, schedule_value, action_type, action_value)) => {
+                        // A "once" time loads as already confirmed so an unchanged value saves as-is
+                        app.input_state.task_once_confirmed = match &task.schedule {
+                            crate::scheduler::ScheduleType::Once(time) => Some((schedule_value.clone(), *time)),
+                            _ => None,
+                        };
+                        app.input_state.task_name = task.name.clone();
+                        app.input_state.task_schedule_type = schedule_type;
+                        app.input_state.task_schedule_value = schedule_value;
+                        app.input_state.task_action_type = action_type;
+                        app.input_state.task_action_value = action_value;
+                        app.input_state.editing_task_index = Some(app.selected_task_index);
+                        app.input_state.current_task_field = 0;
+                        app.view_mode = ViewMode::TaskEditor;
+                    }
+                    None => {
+                        app.input_state.message = Some((
+                            format!("Task '{}' uses an action the editor cannot change - delete and re-add it", task.name),
+                            true
+                        ));
+                        app.input_state.message_timeout = Some(std::time::Instant::now() + std::time::Duration::from_secs(3));
+                    }
+                }
+            }
+        }
         KeyCode::Char('-') => {
             // Delete selected task
             if app.selected_task_index < num_tasks {
@@ -3955,6 +7829,14 @@ fn handle_scheduler_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
                 }
             }
         }
+        KeyCode::Char('x') | KeyCode::Char('X') => {
+            app.task_file_prompt = Some(TaskFileOp::Export);
+            app.task_file_input = "~/lpm-tasks.json".to_string();
+        }
+        KeyCode::Char('i') | KeyCode::Char('I') => {
+            app.task_file_prompt = Some(TaskFileOp::Import);
+            app.task_file_input = "~/lpm-tasks.json".to_string();
+        }
         KeyCode::Char('s') | KeyCode::Char('S') => {
             // Save tasks to config file
             let tasks = app.scheduler.get_tasks();
@@ -3972,6 +7854,34 @@ fn handle_scheduler_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
     Ok(false)
 }
 
+// Export the task list to, or merge tasks from, the JSON file typed in the prompt
+fn transfer_task_file(app: &mut App, op: TaskFileOp) {
+    let input = app.task_file_input.trim();
+    let path = match (input.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => std::path::PathBuf::from(input),
+    };
+    let (message, is_error) = match op {
+        TaskFileOp::Export => match app.scheduler.export_tasks(&path) {
+            Ok(()) => (format!("Exported {} task(s) to {}", app.scheduler.get_tasks().len(), path.display()), false),
+            Err(e) => (format!("Export failed: {}", e), true),
+        },
+        TaskFileOp::Import => match app.scheduler.import_tasks(&path) {
+            Ok(report) => {
+                // Imported tasks are kept like ones added by hand
+                let saved = crate::scheduler::save_tasks(app.scheduler.get_tasks());
+                match saved {
+                    Ok(()) => (report.summary(), !report.rejected.is_empty()),
+                    Err(e) => (format!("{} (not saved: {})", report.summary(), e), true),
+                }
+            }
+            Err(e) => (format!("Import failed: {}", e), true),
+        },
+    };
+    app.input_state.message = Some((message, is_error));
+    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(5));
+}
+
 // Draw start process menu
 fn draw_start_process_menu(f: &mut Frame, app: &mut App, area: Rect) {
     use ratatui::layout::Rect;
@@ -3981,14 +7891,18 @@ fn draw_start_process_menu(f: &mut Frame, app: &mut App, area: Rect) {
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),  // Title
-            Constraint::Length(10), // Input fields - increased from 8 to 10
-            Constraint::Min(5),     // Instructions
+            Constraint::Length(18), // Input fields (program, working dir, arguments, env var, output log, run as)
+            Constraint::Min(5),     // Environment list and instructions
             Constraint::Length(3),  // Menu
         ])
         .split(size);
 
     // Title
-    let title = Paragraph::new("Start New Process")
+    let title_text = match app.input_state.restart_target {
+        Some(pid) => format!("Restart PID {} With Modified Arguments", pid),
+        None => "Start New Process".to_string(),
+    };
+    let title = Paragraph::new(title_text)
         .style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL).border_type(ratatui::widgets::BorderType::Thick));
@@ -4001,7 +7915,9 @@ fn draw_start_process_menu(f: &mut Frame, app: &mut App, area: Rect) {
             Constraint::Length(3),  // Program path - increased to 3 for text visibility
             Constraint::Length(3),  // Working directory - increased to 3
             Constraint::Length(3),  // Arguments - increased to 3
-            Constraint::Length(0),  // Removed extra spacer
+            Constraint::Length(3),  // Environment variable entry
+            Constraint::Length(3),  // Output log path
+            Constraint::Length(3),  // Run as user
         ])
         .split(chunks[1]);
 
@@ -4009,6 +7925,9 @@ fn draw_start_process_menu(f: &mut Frame, app: &mut App, area: Rect) {
         ("Program Path", &app.input_state.program_path, 0),
         ("Working Directory (optional)", &app.input_state.working_dir, 1),
         ("Arguments (space-separated)", &app.input_state.arguments, 2),
+        ("Env Var (KEY=VALUE)", &app.input_state.env_input, 3),
+        ("Output Log (optional, default ~/.local/share/lpm/logs)", &app.input_state.output_log, 4),
+        ("Run As (optional, user or uid[:group], needs root)", &app.input_state.run_as_input, 5),
     ];
 
     for (i, (label, value, field_idx)) in fields.iter().enumerate() {
@@ -4027,26 +7946,77 @@ fn draw_start_process_menu(f: &mut Frame, app: &mut App, area: Rect) {
         f.render_widget(para, field_chunks[i]);
     }
 
+    let lower_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[2]);
+
+    // Pending environment variables
+    let env_mode = if app.input_state.env_inherit {
+        "Inherit current environment"
+    } else {
+        "Clean environment"
+    };
+    let mut env_lines = vec![Line::from(vec![
+        Span::styled("Mode: ", Style::default().fg(Color::Black).add_modifier(Modifier::BOLD)),
+        Span::styled(env_mode, Style::default().fg(if app.input_state.env_inherit { Color::Green } else { Color::Magenta })),
+    ])];
+    if app.input_state.env_vars.is_empty() {
+        env_lines.push(Line::from(Span::styled("(no extra variables)", Style::default().fg(Color::DarkGray))));
+    } else {
+        for (key, value) in &app.input_state.env_vars {
+            env_lines.push(Line::from(vec![
+                Span::styled(key.clone(), Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)),
+                Span::raw(format!("={}", value)),
+            ]));
+        }
+    }
+    let env_para = Paragraph::new(env_lines)
+        .block(Block::default().borders(Borders::ALL).title(format!("Environment ({})", app.input_state.env_vars.len())).style(Style::default().fg(Color::Black)));
+    f.render_widget(env_para, lower_chunks[0]);
+
     // Instructions
     let instructions = vec![
         Line::from(vec![Span::styled("Instructions:", Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))]),
         Line::from(vec![Span::raw("1. Enter program path (e.g., /usr/bin/sleep)")]),
         Line::from(vec![Span::raw("2. Optionally enter working directory")]),
         Line::from(vec![Span::raw("3. Optionally enter command-line arguments")]),
-        Line::from(vec![Span::raw("4. Press [Tab] to switch fields, [Enter] to start process")]),
-        Line::from(vec![Span::raw("5. Press [Esc] to cancel")]),
+        Line::from(vec![Span::raw("4. In Env Var, type KEY=VALUE and press [Enter] to add it")]),
+        Line::from(vec![Span::raw("   [Del] removes the last variable, [Ctrl+E] toggles clean env")]),
+        Line::from(vec![Span::raw("5. Optionally enter a file for stdout/stderr")]),
+        Line::from(vec![Span::raw("6. Optionally enter a user to run as (e.g. nobody, 1000:1000)")]),
+        Line::from(vec![Span::raw("7. Press [Tab] to switch fields, [Enter] to start process")]),
+        Line::from(vec![Span::raw("8. Press [Esc] to cancel")]),
+        Line::from(vec![Span::raw("   [Ctrl+T] loads a saved template, [Ctrl+S] saves this form as one")]),
     ];
     let inst_para = Paragraph::new(instructions)
         .block(Block::default().borders(Borders::ALL).title("Instructions").style(Style::default().fg(Color::Black)));
-    f.render_widget(inst_para, chunks[2]);
+    f.render_widget(inst_para, lower_chunks[1]);
 
     // Menu
-    let menu = Paragraph::new("[Tab] Next field  |  [Enter] Start  |  [Esc] Cancel")
+    let menu = Paragraph::new("[Tab] Next field  |  [Enter] Start/Add var  |  [Del] Remove var  |  [Ctrl+E] Env mode  |  [Ctrl+T] Templates  |  [Ctrl+S] Save as template  |  [Esc] Cancel")
         .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
         .style(Style::default().fg(Color::Black))
         .alignment(Alignment::Left);
     f.render_widget(menu, chunks[3]);
 
+    if let Some(selected) = app.template_picker {
+        draw_template_picker(f, app, selected, size);
+    }
+    if let Some(name) = &app.template_name_input {
+        let prompt_area = Rect {
+            x: size.x + size.width.saturating_sub(50) / 2,
+            y: size.y + size.height.saturating_sub(3) / 2,
+            width: 50.min(size.width),
+            height: 3.min(size.height),
+        };
+        f.render_widget(ratatui::widgets::Clear, prompt_area);
+        let prompt = Paragraph::new(format!("{}_", name))
+            .style(Style::default().fg(Color::White))
+            .block(Block::default().borders(Borders::ALL).title("Save as template - name (Enter/Esc)").style(Style::default().bg(Color::Black)));
+        f.render_widget(prompt, prompt_area);
+    }
+
     // Show message if any
     if let Some((msg, is_error)) = &app.input_state.message {
         let msg_para = Paragraph::new(msg.as_str())
@@ -4062,12 +8032,148 @@ fn draw_start_process_menu(f: &mut Frame, app: &mut App, area: Rect) {
     }
 }
 
+// Saved templates over the Start Process form; missing programs are flagged
+fn draw_template_picker(f: &mut Frame, app: &App, selected: usize, area: Rect) {
+    let templates = app.template_store.get_templates();
+    let dialog_width = 80.min(area.width);
+    let dialog_height = (templates.len() as u16 + 4).clamp(6, 20).min(area.height);
+    let dialog_area = Rect {
+        x: area.x + (area.width.saturating_sub(dialog_width)) / 2,
+        y: area.y + (area.height.saturating_sub(dialog_height)) / 2,
+        width: dialog_width,
+        height: dialog_height,
+    };
+    f.render_widget(ratatui::widgets::Clear, dialog_area);
+
+    let items: Vec<ListItem> = if templates.is_empty() {
+        vec![ListItem::new(Span::styled("(no templates - press Ctrl+S in the form to save one)", Style::default().fg(Color::DarkGray)))]
+    } else {
+        templates
+            .iter()
+            .enumerate()
+            .map(|(i, template)| {
+                let style = if i == selected {
+                    Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                let missing = if template.program_exists() { "" } else { "  [missing]" };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:<18}", template.name), style),
+                    Span::styled(format!(" {} {}", template.program, template.arguments), Style::default().fg(Color::Cyan)),
+                    Span::styled(missing, Style::default().fg(Color::Red)),
+                ]))
+            })
+            .collect()
+    };
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Templates ([Enter] Load  [d] Delete  [Esc] Close)")
+            .style(Style::default().bg(Color::Black)),
+    );
+    f.render_widget(list, dialog_area);
+}
+
+// Fill the Start Process form from a template, warning when its program no longer exists
+fn load_start_template(app: &mut App, template: crate::start_templates::StartTemplate) {
+    app.input_state.program_path = template.program.clone();
+    app.input_state.arguments = template.arguments.clone();
+    app.input_state.working_dir = template.working_dir.clone();
+    app.input_state.env_vars = template.env_vars.clone();
+    app.input_state.env_inherit = template.env_inherit;
+    app.input_state.env_input.clear();
+    app.input_state.current_start_input_field = 0;
+    app.input_state.message = if template.program_exists() {
+        Some((format!("Loaded template '{}'", template.name), false))
+    } else {
+        Some((format!("Warning: template '{}' program {} not found", template.name, template.program), true))
+    };
+    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+}
+
+// Keys while the template picker or the template name prompt is open
+fn handle_template_input(key: KeyEvent, app: &mut App) {
+    if let Some(name) = &mut app.template_name_input {
+        match key.code {
+            KeyCode::Esc => app.template_name_input = None,
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Char(c) => name.push(c),
+            KeyCode::Enter => {
+                let name = name.trim().to_string();
+                if name.is_empty() {
+                    return;
+                }
+                app.template_store.add_template(crate::start_templates::StartTemplate {
+                    name: name.clone(),
+                    program: app.input_state.program_path.clone(),
+                    arguments: app.input_state.arguments.clone(),
+                    working_dir: app.input_state.working_dir.clone(),
+                    env_vars: app.input_state.env_vars.clone(),
+                    env_inherit: app.input_state.env_inherit,
+                });
+                app.template_name_input = None;
+                app.input_state.message = Some((format!("Saved template '{}'", name), false));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    let Some(selected) = app.template_picker else {
+        return;
+    };
+    let count = app.template_store.get_templates().len();
+    match key.code {
+        KeyCode::Esc => app.template_picker = None,
+        KeyCode::Up => app.template_picker = Some(selected.saturating_sub(1)),
+        KeyCode::Down => app.template_picker = Some((selected + 1).min(count.saturating_sub(1))),
+        KeyCode::Enter => {
+            if let Some(template) = app.template_store.get_templates().get(selected).cloned() {
+                app.template_picker = None;
+                load_start_template(app, template);
+            }
+        }
+        KeyCode::Char('d') | KeyCode::Delete => {
+            if let Some(name) = app.template_store.get_templates().get(selected).map(|t| t.name.clone()) {
+                app.template_store.remove_template(&name);
+                app.template_picker = Some(selected.min(count.saturating_sub(2)));
+                app.input_state.message = Some((format!("Deleted template '{}'", name), false));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            }
+        }
+        _ => {}
+    }
+}
+
 // Handle keyboard input for start process view
 fn handle_start_process_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    if app.template_picker.is_some() || app.template_name_input.is_some() {
+        handle_template_input(key, app);
+        return Ok(false);
+    }
     match key.code {
+        KeyCode::Char('t') if key.modifiers == KeyModifiers::CONTROL => {
+            app.template_picker = Some(0);
+        }
+        KeyCode::Char('s') if key.modifiers == KeyModifiers::CONTROL => {
+            if app.input_state.program_path.trim().is_empty() {
+                app.input_state.message = Some(("Error: enter a program path before saving a template".to_string(), true));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            } else {
+                app.template_name_input = Some(String::new());
+            }
+        }
         KeyCode::Tab => {
             // Switch to next field
-            app.input_state.current_start_input_field = (app.input_state.current_start_input_field + 1) % 3;
+            app.input_state.current_start_input_field = (app.input_state.current_start_input_field + 1) % 6;
+        }
+        KeyCode::Char('e') if key.modifiers == KeyModifiers::CONTROL => {
+            // Toggle between inheriting the current environment and a clean one
+            app.input_state.env_inherit = !app.input_state.env_inherit;
         }
         KeyCode::Char(c) if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT => {
             // Add character to current field (only if no Ctrl/Alt modifiers)
@@ -4075,6 +8181,9 @@ fn handle_start_process_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<
                 0 => app.input_state.program_path.push(c),
                 1 => app.input_state.working_dir.push(c),
                 2 => app.input_state.arguments.push(c),
+                3 => app.input_state.env_input.push(c),
+                4 => app.input_state.output_log.push(c),
+                5 => app.input_state.run_as_input.push(c),
                 _ => {}
             }
         }
@@ -4084,9 +8193,34 @@ fn handle_start_process_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<
                 0 => { app.input_state.program_path.pop(); }
                 1 => { app.input_state.working_dir.pop(); }
                 2 => { app.input_state.arguments.pop(); }
+                3 => { app.input_state.env_input.pop(); }
+                4 => { app.input_state.output_log.pop(); }
+                5 => { app.input_state.run_as_input.pop(); }
                 _ => {}
             }
         }
+        KeyCode::Delete => {
+            // Remove the most recently added environment variable
+            if let Some((key, _)) = app.input_state.env_vars.pop() {
+                app.input_state.message = Some((format!("Removed {}", key), false));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            }
+        }
+        KeyCode::Enter if app.input_state.current_start_input_field == 3 && !app.input_state.env_input.is_empty() => {
+            // Add the pending KEY=VALUE pair
+            match parse_env_assignment(&app.input_state.env_input) {
+                Ok((env_key, env_value)) => {
+                    // Replace an existing entry with the same key
+                    app.input_state.env_vars.retain(|(k, _)| k != &env_key);
+                    app.input_state.env_vars.push((env_key, env_value));
+                    app.input_state.env_input.clear();
+                }
+                Err(e) => {
+                    app.input_state.message = Some((format!("Error: {}", e), true));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                }
+            }
+        }
         KeyCode::Enter => {
             // Start the process
             if app.input_state.program_path.is_empty() {
@@ -4096,30 +8230,42 @@ fn handle_start_process_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<
                 ));
                 app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
             } else {
-                // Parse arguments
-                let args: Vec<&str> = if app.input_state.arguments.is_empty() {
-                    vec![]
-                } else {
-                    app.input_state.arguments.split_whitespace().collect()
-                };
-                
-                // Parse working directory
-                let working_dir = if app.input_state.working_dir.is_empty() {
+                // Resolve the target user up front so a typo fails before anything is killed
+                let run_as = if app.input_state.run_as_input.trim().is_empty() {
                     None
                 } else {
-                    Some(app.input_state.working_dir.as_str())
+                    match process::resolve_run_as(&app.input_state.run_as_input) {
+                        Ok(run_as) => Some(run_as),
+                        Err(e) => {
+                            app.input_state.message = Some((format!("Error: {}", e), true));
+                            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+                            return Ok(false);
+                        }
+                    }
+                };
+
+                let spec = process::LaunchSpec {
+                    program: app.input_state.program_path.clone(),
+                    args: app.input_state.arguments.split_whitespace().map(str::to_string).collect(),
+                    working_dir: Some(app.input_state.working_dir.clone()).filter(|dir| !dir.is_empty()),
+                    env_vars: app.input_state.env_vars.clone(),
+                    inherit_env: app.input_state.env_inherit,
+                    output_path: Some(app.input_state.output_log.trim().to_string()).filter(|path| !path.is_empty()),
+                    run_as,
                 };
                 
-                // Start the process
-                match app.process_manager.start_process(
-                    &app.input_state.program_path,
-                    &args,
-                    working_dir,
-                    &app.input_state.env_vars,
-                ) {
+                // Start the process, replacing the original when restarting with modified arguments
+                let result = match app.input_state.restart_target {
+                    Some(old_pid) => app.process_manager.restart_with(old_pid, &spec),
+                    None => app.process_manager.launch(&spec),
+                };
+                match result {
                     Ok(pid) => {
+                        let log_info = app.process_manager.get_output_log(pid)
+                            .map(|p| format!(", logs at {}", p.display()))
+                            .unwrap_or_default();
                         app.input_state.message = Some((
-                            format!("Successfully started process with PID: {}", pid),
+                            format!("Successfully started process with PID: {}{}", pid, log_info),
                             false
                         ));
                         app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
@@ -4127,7 +8273,12 @@ fn handle_start_process_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<
                         app.input_state.program_path.clear();
                         app.input_state.working_dir.clear();
                         app.input_state.arguments.clear();
+                        app.input_state.output_log.clear();
+                        app.input_state.run_as_input.clear();
                         app.input_state.env_vars.clear();
+                        app.input_state.env_input.clear();
+                        app.input_state.env_inherit = true;
+                        app.input_state.restart_target = None;
                         app.input_state.current_start_input_field = 0;
                     }
                     Err(e) => {
@@ -4141,12 +8292,20 @@ fn handle_start_process_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<
             }
         }
         KeyCode::Esc => {
-            // Cancel and return to process list
+            // Cancel and return to process list (a restart target is left running untouched)
+            if let Some(pid) = app.input_state.restart_target.take() {
+                app.input_state.message = Some((format!("Restart cancelled, PID {} left running", pid), false));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+            }
             app.view_mode = ViewMode::ProcessList;
             app.input_state.program_path.clear();
             app.input_state.working_dir.clear();
             app.input_state.arguments.clear();
+            app.input_state.output_log.clear();
+            app.input_state.run_as_input.clear();
             app.input_state.env_vars.clear();
+            app.input_state.env_input.clear();
+            app.input_state.env_inherit = true;
             app.input_state.current_start_input_field = 0;
         }
         _ => {}
@@ -4154,6 +8313,44 @@ fn handle_start_process_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<
     Ok(false)
 }
 
+// Pre-fill the StartProcess form with a running process's command line, cwd and environment
+fn begin_restart_with_args(app: &mut App, pid: u32) {
+    let Some(spec) = app.process_manager.get_launch_spec(pid) else {
+        app.input_state.message = Some((format!("Error: cannot read command line of PID {}", pid), true));
+        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        return;
+    };
+    app.input_state.program_path = spec.program;
+    app.input_state.arguments = spec.args.join(" ");
+    app.input_state.working_dir = spec.working_dir.unwrap_or_default();
+    app.input_state.output_log.clear();
+    app.input_state.run_as_input.clear();
+    app.input_state.env_input.clear();
+    // Use exactly the original environment so it can be edited rather than layered on ours
+    app.input_state.env_inherit = spec.env_vars.is_empty();
+    app.input_state.env_vars = spec.env_vars;
+    app.input_state.current_start_input_field = 2;
+    app.input_state.restart_target = Some(pid);
+    app.view_mode = ViewMode::StartProcess;
+}
+
+// Parse a KEY=VALUE environment assignment
+fn parse_env_assignment(input: &str) -> Result<(String, String), String> {
+    let (key, value) = input
+        .split_once('=')
+        .ok_or_else(|| "Expected KEY=VALUE".to_string())?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err("Variable name cannot be empty".to_string());
+    }
+    if key.chars().next().is_some_and(|c| c.is_ascii_digit())
+        || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(format!("Invalid variable name '{}'", key));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
 // Draw advanced filter input menu
 fn draw_advanced_filter_input(f: &mut Frame, app: &mut App, area: Rect) {
     use ratatui::layout::Rect;
@@ -4170,53 +8367,60 @@ fn draw_advanced_filter_input(f: &mut Frame, app: &mut App, area: Rect) {
         .split(size);
 
     // Title
-    let title = Paragraph::new("Advanced Filter")
+    let title_text = if app.filter_builder.is_some() { "Advanced Filter - Guided Builder" } else { "Advanced Filter" };
+    let title = Paragraph::new(title_text)
         .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL).border_type(ratatui::widgets::BorderType::Thick));
     f.render_widget(title, chunks[0]);
 
-    // Input field
+    // Input field (in builder mode: the generated expression, updated live)
     let input_text = if app.input_state.advanced_filter_input.is_empty() {
-        "Enter filter expression...".to_string()
+        if app.filter_builder.is_some() { "(no clauses yet)".to_string() } else { "Enter filter expression...".to_string() }
     } else {
         app.input_state.advanced_filter_input.clone()
     };
+    let input_title = if app.filter_builder.is_some() { "Generated Expression" } else { "Filter Expression" };
     let input_para = Paragraph::new(input_text)
         .style(Style::default().fg(Color::Black))
-        .block(Block::default().borders(Borders::ALL).title("Filter Expression").style(Style::default().fg(Color::Black)));
+        .wrap(ratatui::widgets::Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(input_title).style(Style::default().fg(Color::Black)));
     f.render_widget(input_para, chunks[1]);
 
-    // Help and examples
-    let help_text = vec![
-        Line::from(vec![Span::styled("Syntax Help:", Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))]),
-        Line::from(""),
-        Line::from(vec![Span::styled("Fields:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  String: name, user, status"),
-        Line::from("  Numeric: pid, ppid, cpu, memory, nice"),
-        Line::from(""),
-        Line::from(vec![Span::styled("Operators:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
-        Line::from("  String: ==, !=, ~ (regex)"),
-        Line::from("  Numeric: ==, !=, >, <, >=, <="),
-        Line::from("  Boolean: AND, OR, NOT"),
-        Line::from(""),
-        Line::from(vec![Span::styled("Examples:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))]),
-        Line::from("  name ~ \"firefox|chrome\" AND cpu > 10"),
-        Line::from("  user == \"root\" OR (memory > 5000 AND status == \"running\")"),
-        Line::from("  NOT (pid == 1234) AND ppid == 1"),
-        Line::from("  cpu > 50 AND memory < 1000"),
-    ];
-    let help_para = Paragraph::new(help_text)
-        .block(Block::default().borders(Borders::ALL).title("Help & Examples").style(Style::default().fg(Color::Black)))
-        .wrap(ratatui::widgets::Wrap { trim: true });
-    f.render_widget(help_para, chunks[2]);
-
-    // Menu
-    let menu = Paragraph::new("[Enter] Apply  |  [Esc] Cancel  |  [Backspace] Delete")
-        .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
-        .style(Style::default().fg(Color::Black))
-        .alignment(Alignment::Left);
-    f.render_widget(menu, chunks[3]);
+    if let Some(builder) = &app.filter_builder {
+        draw_filter_builder(f, builder, chunks[2], chunks[3]);
+    } else {
+        // Help and examples
+        let help_text = vec![
+            Line::from(vec![Span::styled("Syntax Help:", Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))]),
+            Line::from(""),
+            Line::from(vec![Span::styled("Fields:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
+            Line::from("  String: name, user, status"),
+            Line::from("  Numeric: pid, ppid, cpu, memory, peak_memory (MiB), nice"),
+            Line::from(""),
+            Line::from(vec![Span::styled("Operators:", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))]),
+            Line::from("  String: ==, !=, ~ (regex)"),
+            Line::from("  Numeric: ==, !=, >, <, >=, <="),
+            Line::from("  Boolean: AND, OR, NOT"),
+            Line::from(""),
+            Line::from(vec![Span::styled("Examples:", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))]),
+            Line::from("  name ~ \"firefox|chrome\" AND cpu > 10"),
+            Line::from("  user == \"root\" OR (memory > 5000 AND status == \"running\")"),
+            Line::from("  NOT (pid == 1234) AND ppid == 1"),
+            Line::from("  cpu > 50 AND memory < 1000"),
+        ];
+        let help_para = Paragraph::new(help_text)
+            .block(Block::default().borders(Borders::ALL).title("Help & Examples").style(Style::default().fg(Color::Black)))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        f.render_widget(help_para, chunks[2]);
+
+        // Menu
+        let menu = Paragraph::new("[Enter] Apply  |  [Tab] Guided Builder  |  [Esc] Cancel  |  [Backspace] Delete")
+            .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
+            .style(Style::default().fg(Color::Black))
+            .alignment(Alignment::Left);
+        f.render_widget(menu, chunks[3]);
+    }
 
     // Show message if any
     if let Some((msg, is_error)) = &app.input_state.message {
@@ -4233,53 +8437,144 @@ fn draw_advanced_filter_input(f: &mut Frame, app: &mut App, area: Rect) {
     }
 }
 
+// Choices for the builder's current step, plus the clause taking shape
+fn draw_filter_builder(f: &mut Frame, builder: &FilterBuilder, area: Rect, menu_area: Rect) {
+    use crate::filter_parser::{FILTER_FIELDS, Join};
+
+    let join_text = match builder.join {
+        Join::And => "AND",
+        Join::Or => "OR",
+    };
+    let prefix = if builder.clauses.is_empty() { String::new() } else { format!("{} ", join_text) };
+    let pending = match builder.step {
+        BuilderStep::Field => format!("{}?", prefix),
+        BuilderStep::Operator => format!("{}{} ?", prefix, builder.field().name),
+        BuilderStep::Value => format!("{}{} {} {}_", prefix, builder.field().name, builder.operator(), builder.value),
+        BuilderStep::Next => String::new(),
+    };
+
+    let highlight = Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    if !pending.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Building: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::raw(pending),
+        ]));
+        lines.push(Line::from(""));
+    }
+    let (title, menu_text) = match builder.step {
+        BuilderStep::Field => {
+            for (i, field) in FILTER_FIELDS.iter().enumerate() {
+                let kind = match field.kind {
+                    crate::filter_parser::FieldKind::Text => "text",
+                    crate::filter_parser::FieldKind::Numeric => "number",
+                };
+                let text = format!("  {:<12} {:<7} {}", field.name, kind, field.description);
+                lines.push(if i == builder.field_index { Line::from(Span::styled(text, highlight)) } else { Line::from(text) });
+            }
+            ("Pick a Field", "[↑/↓] Select  |  [Enter] Choose  |  [Tab] Raw Text  |  [Esc] Back")
+        }
+        BuilderStep::Operator => {
+            for (i, op) in builder.field().kind.operators().iter().enumerate() {
+                let meaning = match *op {
+                    "==" => "equals",
+                    "!=" => "does not equal",
+                    "~=" => "matches regular expression",
+                    ">" => "greater than",
+                    ">=" => "greater than or equal",
+                    "<" => "less than",
+                    "<=" => "less than or equal",
+                    _ => "",
+                };
+                let text = format!("  {:<3} {}", op, meaning);
+                lines.push(if i == builder.operator_index { Line::from(Span::styled(text, highlight)) } else { Line::from(text) });
+            }
+            ("Pick an Operator", "[↑/↓] Select  |  [Enter] Choose  |  [Tab] Raw Text  |  [Esc] Back")
+        }
+        BuilderStep::Value => {
+            lines.push(Line::from(format!("Type the value for {} ({})", builder.field().name, builder.field().description)));
+            ("Enter a Value", "[Enter] Add Clause  |  [Backspace] Delete  |  [Tab] Raw Text  |  [Esc] Back")
+        }
+        BuilderStep::Next => {
+            lines.push(Line::from(format!("{} clause(s). AND binds tighter than OR.", builder.clauses.len())));
+            lines.push(Line::from(""));
+            lines.push(Line::from("  [a] add a clause joined with AND"));
+            lines.push(Line::from("  [o] add a clause joined with OR"));
+            lines.push(Line::from("  [Backspace] remove the last clause"));
+            lines.push(Line::from("  [Enter] apply the filter"));
+            ("Next", "[a] AND  |  [o] OR  |  [Backspace] Undo Clause  |  [Enter] Apply  |  [Tab] Raw Text  |  [Esc] Cancel")
+        }
+    };
+    let body = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(title).style(Style::default().fg(Color::Black)));
+    f.render_widget(body, area);
+
+    let menu = Paragraph::new(menu_text)
+        .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
+        .style(Style::default().fg(Color::Black))
+        .alignment(Alignment::Left);
+    f.render_widget(menu, menu_area);
+}
+
+// Apply the expression in the advanced filter input, or clear the filter when it is empty
+fn apply_advanced_filter(app: &mut App) {
+    let filter_str = app.input_state.advanced_filter_input.trim().to_string();
+    if filter_str.is_empty() {
+        // Clear filter
+        if let Err(e) = app.process_manager.set_advanced_filter_string("") {
+            app.input_state.message = Some((
+                format!("Error: {}", e),
+                true
+            ));
+        } else {
+            app.input_state.message = Some((
+                "Filter cleared".to_string(),
+                false
+            ));
+        }
+        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        app.filter_builder = None;
+        app.view_mode = ViewMode::ProcessList;
+    } else {
+        match app.process_manager.set_advanced_filter_string(&filter_str) {
+            Ok(_) => {
+                app.input_state.message = Some((
+                    format!("Filter applied: {}", filter_str),
+                    false
+                ));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                app.filter_builder = None;
+                app.view_mode = ViewMode::ProcessList;
+            }
+            Err(e) => {
+                app.input_state.message = Some((
+                    format!("Filter error: {}", e),
+                    true
+                ));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+            }
+        }
+    }
+}
+
 // Handle keyboard input for advanced filter
 fn handle_advanced_filter_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    if app.filter_builder.is_some() {
+        handle_filter_builder_input(key, app);
+        return Ok(false);
+    }
     match key.code {
+        KeyCode::Tab => {
+            // The builder starts from scratch; the raw text is replaced once a clause is added
+            app.filter_builder = Some(FilterBuilder::new());
+        }
         KeyCode::Char(c) => {
             app.input_state.advanced_filter_input.push(c);
         }
         KeyCode::Backspace => {
             app.input_state.advanced_filter_input.pop();
         }
-        KeyCode::Enter => {
-            // Apply filter
-            let filter_str = app.input_state.advanced_filter_input.trim();
-            if filter_str.is_empty() {
-                // Clear filter
-                if let Err(e) = app.process_manager.set_advanced_filter_string("") {
-                    app.input_state.message = Some((
-                        format!("Error: {}", e),
-                        true
-                    ));
-                } else {
-                    app.input_state.message = Some((
-                        "Filter cleared".to_string(),
-                        false
-                    ));
-                }
-                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
-                app.view_mode = ViewMode::ProcessList;
-            } else {
-                match app.process_manager.set_advanced_filter_string(filter_str) {
-                    Ok(_) => {
-                        app.input_state.message = Some((
-                            format!("Filter applied: {}", filter_str),
-                            false
-                        ));
-                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
-                        app.view_mode = ViewMode::ProcessList;
-                    }
-                    Err(e) => {
-                        app.input_state.message = Some((
-                            format!("Filter error: {}", e),
-                            true
-                        ));
-                        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
-                    }
-                }
-            }
-        }
+        KeyCode::Enter => apply_advanced_filter(app),
         KeyCode::Esc => {
             // Cancel and return
             app.view_mode = ViewMode::FilterSort;
@@ -4290,17 +8585,122 @@ fn handle_advanced_filter_input(key: KeyEvent, app: &mut App) -> Result<bool, Bo
     Ok(false)
 }
 
-// Draw profile management view
-fn draw_profile_management(f: &mut Frame, app: &mut App, area: Rect) {
-    let size = area;
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3),  // Title
-            Constraint::Min(10),    // Profile list
-            Constraint::Length(3),  // Menu
-        ])
-        .split(size);
+fn handle_filter_builder_input(key: KeyEvent, app: &mut App) {
+    use crate::filter_parser::{FILTER_FIELDS, FieldKind, FilterClause, Join, build_expression};
+
+    if key.code == KeyCode::Tab {
+        // Keep the generated expression as the starting point for raw editing
+        app.filter_builder = None;
+        return;
+    }
+    let Some(builder) = app.filter_builder.as_mut() else {
+        return;
+    };
+    match builder.step {
+        BuilderStep::Field => match key.code {
+            KeyCode::Up => builder.field_index = builder.field_index.saturating_sub(1),
+            KeyCode::Down => builder.field_index = (builder.field_index + 1).min(FILTER_FIELDS.len() - 1),
+            KeyCode::Enter => {
+                builder.operator_index = 0;
+                builder.step = BuilderStep::Operator;
+            }
+            KeyCode::Esc => {
+                if builder.clauses.is_empty() {
+                    app.filter_builder = None;
+                    app.input_state.advanced_filter_input.clear();
+                    app.view_mode = ViewMode::FilterSort;
+                } else {
+                    builder.step = BuilderStep::Next;
+                }
+            }
+            _ => {}
+        },
+        BuilderStep::Operator => match key.code {
+            KeyCode::Up => builder.operator_index = builder.operator_index.saturating_sub(1),
+            KeyCode::Down => {
+                builder.operator_index = (builder.operator_index + 1).min(builder.field().kind.operators().len() - 1);
+            }
+            KeyCode::Enter => {
+                builder.value.clear();
+                builder.step = BuilderStep::Value;
+            }
+            KeyCode::Esc => builder.step = BuilderStep::Field,
+            _ => {}
+        },
+        BuilderStep::Value => match key.code {
+            KeyCode::Char(c) => builder.value.push(c),
+            KeyCode::Backspace => {
+                builder.value.pop();
+            }
+            KeyCode::Enter => {
+                let value = builder.value.trim().to_string();
+                let field = builder.field();
+                let error = if value.is_empty() {
+                    Some("Enter a value first".to_string())
+                } else if field.kind == FieldKind::Numeric && value.parse::<f64>().is_err() {
+                    Some(format!("{} needs a number, got '{}'", field.name, value))
+                } else if builder.operator() == "~=" && regex::Regex::new(&value).is_err() {
+                    Some(format!("Invalid regular expression: {}", value))
+                } else if value.contains('"') {
+                    Some("Values cannot contain double quotes".to_string())
+                } else {
+                    None
+                };
+                if let Some(error) = error {
+                    app.input_state.message = Some((error, true));
+                    app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                    return;
+                }
+                builder.clauses.push(FilterClause {
+                    join: builder.join,
+                    field: field.name,
+                    operator: builder.operator(),
+                    value,
+                });
+                builder.step = BuilderStep::Next;
+                app.input_state.advanced_filter_input = build_expression(&builder.clauses);
+            }
+            KeyCode::Esc => builder.step = BuilderStep::Operator,
+            _ => {}
+        },
+        BuilderStep::Next => match key.code {
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                builder.join = Join::And;
+                builder.step = BuilderStep::Field;
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                builder.join = Join::Or;
+                builder.step = BuilderStep::Field;
+            }
+            KeyCode::Backspace => {
+                builder.clauses.pop();
+                if builder.clauses.is_empty() {
+                    builder.step = BuilderStep::Field;
+                }
+                app.input_state.advanced_filter_input = build_expression(&builder.clauses);
+            }
+            KeyCode::Enter => apply_advanced_filter(app),
+            KeyCode::Esc => {
+                app.filter_builder = None;
+                app.input_state.advanced_filter_input.clear();
+                app.view_mode = ViewMode::FilterSort;
+            }
+            _ => {}
+        },
+    }
+}
+
+// Draw profile management view
+fn draw_profile_management(f: &mut Frame, app: &mut App, area: Rect) {
+    let size = area;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Title
+            Constraint::Min(10),    // Profile list
+            Constraint::Length(3),  // Menu
+        ])
+        .split(size);
 
     // Title
     let active_profile = app.profile_manager.get_active_profile()
@@ -4548,6 +8948,85 @@ fn handle_profile_editor_input(key: KeyEvent, app: &mut App) -> Result<bool, Box
     Ok(false)
 }
 
+// "Alerts", plus the learning countdown or how many baselines are stored
+fn baseline_title(app: &App) -> String {
+    match app.alert_manager.baseline_learning_remaining() {
+        Some(remaining) => format!("Alerts - learning baselines ({}s left)", remaining.as_secs()),
+        None if app.alert_manager.get_baselines().is_empty() => "Alerts".to_string(),
+        None => format!("Alerts - {} baselines", app.alert_manager.get_baselines().len()),
+    }
+}
+
+// Active alerts narrowed by the pane's filter (alert, process, message or severity) and sorted
+fn sorted_active_alerts(app: &App) -> Vec<&crate::alert::ActiveAlert> {
+    let prefs = app.view_prefs.get(&ViewMode::AlertManagement).cloned().unwrap_or_default();
+    let filter = prefs.filter.to_lowercase();
+    let manager = &app.alert_manager;
+    let mut alerts: Vec<_> = manager.get_active_alerts().iter()
+        .filter(|alert| {
+            filter.is_empty()
+                || [
+                    alert.alert_name.as_str(),
+                    alert.process_name.as_deref().unwrap_or(""),
+                    alert.message.as_str(),
+                    manager.severity_of(alert).label(),
+                ].iter().any(|field| field.to_lowercase().contains(&filter))
+        })
+        .collect();
+    // Newest first; the sort is stable, so ties stay that way
+    alerts.reverse();
+    if let Some((sort_mode, ascending)) = prefs.sort {
+        alerts.sort_by(|a, b| {
+            let ordering = match sort_mode {
+                "severity" => manager.severity_of(a).cmp(&manager.severity_of(b)),
+                "process" => a.process_name.cmp(&b.process_name),
+                _ => a.triggered_at.cmp(&b.triggered_at),
+            };
+            if ascending { ordering } else { ordering.reverse() }
+        });
+    }
+    alerts
+}
+
+// [o] cycles the active alerts' sort, [O] reverses it, [/] edits their filter; true when the key was used
+fn handle_alert_prefs_key(key: KeyEvent, app: &mut App) -> bool {
+    let mode = ViewMode::AlertManagement;
+    let prefs = app.view_prefs.entry(mode).or_default();
+    if app.view_filter_editing == Some(mode) {
+        match key.code {
+            KeyCode::Char(c) => prefs.filter.push(c),
+            KeyCode::Backspace => {
+                prefs.filter.pop();
+            }
+            KeyCode::Enter => app.view_filter_editing = None,
+            KeyCode::Esc => {
+                prefs.filter.clear();
+                app.view_filter_editing = None;
+            }
+            _ => {} // Typing owns the keyboard until Enter/Esc
+        }
+        return true;
+    }
+    match key.code {
+        KeyCode::Char('o') => {
+            let next = match prefs.sort {
+                None => ALERT_SORT_MODES.first().copied(),
+                Some((current, _)) => ALERT_SORT_MODES.iter().position(|m| *m == current).and_then(|i| ALERT_SORT_MODES.get(i + 1).copied()),
+            };
+            // Newest and most severe first; process names alphabetically
+            prefs.sort = next.map(|sort_mode| (sort_mode, sort_mode == "process"));
+        }
+        KeyCode::Char('O') => {
+            if let Some((_, ascending)) = &mut prefs.sort {
+                *ascending = !*ascending;
+            }
+        }
+        KeyCode::Char('/') => app.view_filter_editing = Some(mode),
+        _ => return false,
+    }
+    true
+}
+
 // Draw alert management view
 fn draw_alert_management(f: &mut Frame, app: &mut App, area: Rect) {
     let size = area;
@@ -4562,7 +9041,7 @@ fn draw_alert_management(f: &mut Frame, app: &mut App, area: Rect) {
         .split(size);
 
     // Title
-    let active_count = app.alert_manager.get_active_alerts().len();
+    let active_count = app.alert_manager.get_active_alerts().iter().filter(|a| a.is_firing()).count();
     let title_text = if active_count > 0 {
         format!("Alert Management ({} Active)", active_count)
     } else {
@@ -4586,7 +9065,7 @@ fn draw_alert_management(f: &mut Frame, app: &mut App, area: Rect) {
                     format!("CPU > {}% for {}s", threshold, duration_secs)
                 }
                 crate::alert::AlertCondition::MemoryGreaterThan { threshold_mb, duration_secs } => {
-                    format!("Memory > {}MB for {}s", threshold_mb, duration_secs)
+                    format!("Memory > {} MiB for {}s", threshold_mb, duration_secs)
                 }
                 crate::alert::AlertCondition::IoGreaterThan { threshold_mb_per_sec, duration_secs } => {
                     format!("I/O > {}MB/s for {}s", threshold_mb_per_sec, duration_secs)
@@ -4594,6 +9073,18 @@ fn draw_alert_management(f: &mut Frame, app: &mut App, area: Rect) {
                 crate::alert::AlertCondition::ProcessDied { pattern } => {
                     format!("Process died: {}", pattern)
                 }
+                crate::alert::AlertCondition::StuckInDState { duration_secs } => {
+                    format!("Stuck in D state for {}s", duration_secs)
+                }
+                crate::alert::AlertCondition::AnomalyVsBaseline { factor, duration_secs } => {
+                    format!("{}x baseline CPU/Mem for {}s", factor, duration_secs)
+                }
+                crate::alert::AlertCondition::CpuSpike { delta_percent } => {
+                    format!("CPU jumps > +{}% between refreshes", delta_percent)
+                }
+                crate::alert::AlertCondition::ProcessCountOutsideRange { pattern, min, max } => {
+                    format!("Count of '{}' outside {}-{}", pattern, min, max)
+                }
             };
             let style = if is_selected {
                 Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
@@ -4602,39 +9093,73 @@ fn draw_alert_management(f: &mut Frame, app: &mut App, area: Rect) {
             } else {
                 Style::default().fg(Color::Black)
             };
+            let recovery = if alert.notify_recovery { "" } else { " (no recovery notice)" };
             ListItem::new(Span::styled(
-                format!("{} {}: {}", status, alert.name, condition_str),
+                format!("{} {}: {}{}", status, alert.name, condition_str, recovery),
                 style
             ))
         })
         .collect();
 
     let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("Alerts").style(Style::default().fg(Color::Black)))
+        .block(Block::default().borders(Borders::ALL).title(baseline_title(app)).style(Style::default().fg(Color::Black)))
         .style(Style::default());
     f.render_widget(list, chunks[1]);
 
-    // Active alerts
-    let active_alerts = app.alert_manager.get_active_alerts();
-    // Show newest first
-    let alert_items: Vec<ListItem> = active_alerts.iter()
-        .rev() // Reverse iterator
-        .take(50) // Limit to 50 most recent
+    // Active alerts, filtered and sorted ([o]/[O]/[/])
+    let active_alerts = sorted_active_alerts(app);
+    let shown_count = active_alerts.len();
+    let alert_items: Vec<ListItem> = active_alerts.into_iter()
+        .take(50) // Limit to the first 50 in the chosen order
         .map(|alert| {
-            ListItem::new(Span::styled(
-                format!("⚠️  {}: {}", alert.alert_name, alert.message),
-                Style::default().fg(Color::Black).add_modifier(Modifier::BOLD)
-            ))
+            if alert.is_firing() {
+                let severity = app.alert_manager.severity_of(alert);
+                let style = match severity {
+                    crate::alert::Severity::Critical => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    crate::alert::Severity::Warning => Style::default().fg(Color::Black).add_modifier(Modifier::BOLD),
+                    crate::alert::Severity::Info => Style::default().fg(Color::Blue),
+                };
+                ListItem::new(Span::styled(
+                    format!("⚠️  [{}] {}: {}", severity.label(), alert.alert_name, alert.message),
+                    style
+                ))
+            } else {
+                ListItem::new(Span::styled(
+                    format!("✅ {}: recovered - {}", alert.alert_name, alert.message),
+                    Style::default().fg(Color::Green)
+                ))
+            }
         })
         .collect();
 
+    let alert_panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[2]);
     let alert_list = List::new(alert_items)
-        .block(Block::default().borders(Borders::ALL).title("Active Alerts").style(Style::default().fg(Color::Black)))
+        .block(Block::default().borders(Borders::ALL)
+            .title(format!("Active Alerts ({}){}", shown_count, view_prefs_label(app, ViewMode::AlertManagement)))
+            .style(Style::default().fg(Color::Black)))
         .style(Style::default());
-    f.render_widget(alert_list, chunks[2]);
+    f.render_widget(alert_list, alert_panes[0]);
+
+    // History of fire/recover events, newest first
+    let history_items: Vec<ListItem> = app.alert_manager.get_history()
+        .iter()
+        .rev()
+        .take(50)
+        .map(|event| {
+            let time = chrono::DateTime::<chrono::Local>::from(event.at).format("%H:%M:%S");
+            let (marker, color) = if event.recovered { ("✅", Color::Green) } else { ("⚠️ ", Color::Red) };
+            ListItem::new(Span::styled(format!("[{}] {} {}", time, marker, event.message), Style::default().fg(color)))
+        })
+        .collect();
+    let history_list = List::new(history_items)
+        .block(Block::default().borders(Borders::ALL).title("Alert History").style(Style::default().fg(Color::Black)));
+    f.render_widget(history_list, alert_panes[1]);
 
     // Menu
-    let menu = Paragraph::new("[c] CPU | [m] Mem | [d] Death | [Enter] Toggle | [e] Edit | [-] Delete | [C] Clear Active | [Esc] Back")
+    let menu = Paragraph::new("[c] CPU | [m] Mem | [d] Death | [s] Stuck in D | [p] CPU Spike | [n] Process Count | [a] Anomaly | [b] Learn Baselines | [r] Recovery Notice | [Enter] Toggle | [e] Edit | [-] Delete | [C] Clear Active | [o/O] Sort Active | [/] Filter Active | [Esc] Back")
         .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
         .alignment(Alignment::Left);
     f.render_widget(menu, chunks[3]);
@@ -4642,6 +9167,9 @@ fn draw_alert_management(f: &mut Frame, app: &mut App, area: Rect) {
 
 // Handle keyboard input for alert management
 fn handle_alert_management_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    if handle_alert_prefs_key(key, app) {
+        return Ok(false);
+    }
     let alerts = app.alert_manager.get_alerts();
     let num_alerts = alerts.len();
     
@@ -4669,6 +9197,7 @@ fn handle_alert_management_input(key: KeyEvent, app: &mut App) -> Result<bool, B
                 },
                 target: crate::alert::AlertTarget::All,
                 enabled: true,
+                notify_recovery: true,
             };
             app.alert_manager.add_alert(new_alert);
             app.selected_alert_index = app.alert_manager.get_alerts().len() - 1;
@@ -4683,6 +9212,7 @@ fn handle_alert_management_input(key: KeyEvent, app: &mut App) -> Result<bool, B
                 },
                 target: crate::alert::AlertTarget::All,
                 enabled: true,
+                notify_recovery: true,
             };
             app.alert_manager.add_alert(new_alert);
             app.selected_alert_index = app.alert_manager.get_alerts().len() - 1;
@@ -4696,177 +9226,1238 @@ fn handle_alert_management_input(key: KeyEvent, app: &mut App) -> Result<bool, B
                 },
                 target: crate::alert::AlertTarget::Pattern("sleep".to_string()),
                 enabled: true,
+                notify_recovery: true,
+            };
+            app.alert_manager.add_alert(new_alert);
+            app.selected_alert_index = app.alert_manager.get_alerts().len() - 1;
+        }
+        KeyCode::Char('b') => {
+            // Learn normal per-name CPU/memory; the averages replace stored baselines when the window ends
+            let window = app.settings.baseline_learning_secs;
+            app.alert_manager.start_baseline_learning(Duration::from_secs(window));
+            app.input_state.message = Some((format!("Learning baselines for {}s", window), false));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+        }
+        KeyCode::Char('a') => {
+            // Create baseline anomaly alert (3x normal usage)
+            let new_alert = crate::alert::Alert {
+                name: format!("Anomaly Alert {}", alerts.len() + 1),
+                condition: crate::alert::AlertCondition::AnomalyVsBaseline {
+                    factor: 3.0,
+                    duration_secs: 10,
+                },
+                target: crate::alert::AlertTarget::All,
+                enabled: true,
+                notify_recovery: true,
+            };
+            app.alert_manager.add_alert(new_alert);
+            app.selected_alert_index = app.alert_manager.get_alerts().len() - 1;
+        }
+        KeyCode::Char('r') => {
+            // Turn recovery notices on/off for the selected alert
+            app.alert_manager.toggle_recovery_notification(app.selected_alert_index);
+        }
+        KeyCode::Char('p') => {
+            // Create CPU spike alert (jump between two refreshes)
+            let new_alert = crate::alert::Alert {
+                name: format!("CPU Spike Alert {}", alerts.len() + 1),
+                condition: crate::alert::AlertCondition::CpuSpike {
+                    delta_percent: 50.0,
+                },
+                target: crate::alert::AlertTarget::All,
+                enabled: true,
+                notify_recovery: true,
+            };
+            app.alert_manager.add_alert(new_alert);
+            app.selected_alert_index = app.alert_manager.get_alerts().len() - 1;
+        }
+        KeyCode::Char('n') => {
+            // Create process count alert for the selected process's name, expecting as many as run now
+            let pattern = view_processes(app, ViewMode::ProcessList)
+                .get(app.selected_process_index)
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| "sleep".to_string());
+            let count = app.process_manager.get_processes().iter().filter(|p| p.name.contains(&pattern)).count();
+            let new_alert = crate::alert::Alert {
+                name: format!("{} Count Alert {}", pattern, alerts.len() + 1),
+                condition: crate::alert::AlertCondition::ProcessCountOutsideRange {
+                    pattern: pattern.clone(),
+                    min: count,
+                    max: count,
+                },
+                target: crate::alert::AlertTarget::Pattern(pattern),
+                enabled: true,
+                notify_recovery: true,
+            };
+            app.alert_manager.add_alert(new_alert);
+            app.selected_alert_index = app.alert_manager.get_alerts().len() - 1;
+        }
+        KeyCode::Char('s') => {
+            // Create stuck-in-D-state alert (disk/NFS waits)
+            let new_alert = crate::alert::Alert {
+                name: format!("Stuck I/O Alert {}", alerts.len() + 1),
+                condition: crate::alert::AlertCondition::StuckInDState {
+                    duration_secs: 30,
+                },
+                target: crate::alert::AlertTarget::All,
+                enabled: true,
+                notify_recovery: true,
             };
             app.alert_manager.add_alert(new_alert);
             app.selected_alert_index = app.alert_manager.get_alerts().len() - 1;
         }
         KeyCode::Enter => {
-            // Toggle alert
-            app.alert_manager.toggle_alert(app.selected_alert_index);
-        }
-        KeyCode::Char('e') => {
-            // Edit alert
-            if let Some(alert) = alerts.get(app.selected_alert_index) {
-                app.alert_edit_mode = true;
-                app.alert_edit_name = alert.name.clone();
-                app.alert_edit_current_field = 0;
-                
-                match &alert.condition {
-                    crate::alert::AlertCondition::CpuGreaterThan { threshold, duration_secs } => {
-                        app.alert_edit_threshold = threshold.to_string();
-                        app.alert_edit_duration = duration_secs.to_string();
-                    }
-                    crate::alert::AlertCondition::MemoryGreaterThan { threshold_mb, duration_secs } => {
-                        app.alert_edit_threshold = threshold_mb.to_string();
-                        app.alert_edit_duration = duration_secs.to_string();
-                    }
-                    crate::alert::AlertCondition::ProcessDied { pattern: _ } => {
-                        app.alert_edit_threshold = "N/A".to_string();
-                        app.alert_edit_duration = "N/A".to_string();
-                    }
-                    crate::alert::AlertCondition::IoGreaterThan { threshold_mb_per_sec, duration_secs } => {
-                        app.alert_edit_threshold = threshold_mb_per_sec.to_string();
-                        app.alert_edit_duration = duration_secs.to_string();
-                    }
-                }
-                app.view_mode = ViewMode::AlertEditor;
+            // Toggle alert
+            app.alert_manager.toggle_alert(app.selected_alert_index);
+        }
+        KeyCode::Char('e') => {
+            // Edit alert
+            if let Some(alert) = alerts.get(app.selected_alert_index) {
+                app.alert_edit_mode = true;
+                app.alert_edit_name = alert.name.clone();
+                app.alert_edit_current_field = 0;
+                app.alert_edit_pattern = "N/A".to_string();
+                
+                match &alert.condition {
+                    crate::alert::AlertCondition::CpuGreaterThan { threshold, duration_secs } => {
+                        app.alert_edit_threshold = threshold.to_string();
+                        app.alert_edit_duration = duration_secs.to_string();
+                    }
+                    crate::alert::AlertCondition::MemoryGreaterThan { threshold_mb, duration_secs } => {
+                        app.alert_edit_threshold = threshold_mb.to_string();
+                        app.alert_edit_duration = duration_secs.to_string();
+                    }
+                    crate::alert::AlertCondition::ProcessDied { pattern } => {
+                        app.alert_edit_threshold = "N/A".to_string();
+                        app.alert_edit_duration = "N/A".to_string();
+                        app.alert_edit_pattern = pattern.clone();
+                    }
+                    crate::alert::AlertCondition::IoGreaterThan { threshold_mb_per_sec, duration_secs } => {
+                        app.alert_edit_threshold = threshold_mb_per_sec.to_string();
+                        app.alert_edit_duration = duration_secs.to_string();
+                    }
+                    crate::alert::AlertCondition::StuckInDState { duration_secs } => {
+                        app.alert_edit_threshold = "N/A".to_string();
+                        app.alert_edit_duration = duration_secs.to_string();
+                    }
+                    crate::alert::AlertCondition::AnomalyVsBaseline { factor, duration_secs } => {
+                        app.alert_edit_threshold = factor.to_string();
+                        app.alert_edit_duration = duration_secs.to_string();
+                    }
+                    crate::alert::AlertCondition::CpuSpike { delta_percent } => {
+                        app.alert_edit_threshold = delta_percent.to_string();
+                        app.alert_edit_duration = "N/A".to_string();
+                    }
+                    crate::alert::AlertCondition::ProcessCountOutsideRange { pattern, min, max } => {
+                        app.alert_edit_threshold = format!("{}-{}", min, max);
+                        app.alert_edit_duration = "N/A".to_string();
+                        app.alert_edit_pattern = pattern.clone();
+                    }
+                }
+                app.alert_test_report = None;
+                app.view_mode = ViewMode::AlertEditor;
+            }
+        }
+        KeyCode::Char('-') => {
+            // Delete alert
+            app.alert_manager.remove_alert(app.selected_alert_index);
+            if app.selected_alert_index >= app.alert_manager.get_alerts().len() && app.selected_alert_index > 0 {
+                app.selected_alert_index -= 1;
+            }
+        }
+        KeyCode::Char('C') => {
+            // Clear all active alerts
+            app.alert_manager.clear_all_active_alerts();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+
+
+fn draw_alert_editor(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Name
+            Constraint::Length(3), // Threshold
+            Constraint::Length(3), // Duration
+            Constraint::Length(3), // Pattern
+            Constraint::Min(1),    // Instructions
+        ])
+        .split(area);
+
+    let title = Paragraph::new("Edit Alert")
+        .style(Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)));
+    f.render_widget(title, chunks[0]);
+
+    let get_style = |idx: usize, color: Color| {
+        if app.alert_edit_current_field == idx {
+            Style::default().fg(Color::Black).bg(color).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Black)
+        }
+    };
+
+    let name_blk = Block::default().borders(Borders::ALL)
+        .title(" Name ").style(Style::default().fg(Color::Black))
+        .border_style(get_style(0, Color::Cyan));
+    let name = Paragraph::new(app.alert_edit_name.as_str())
+        .block(name_blk).style(get_style(0, Color::Cyan));
+    f.render_widget(name, chunks[1]);
+
+    let thresh_blk = Block::default().borders(Borders::ALL)
+        .title(" Threshold (CPU %, Mem MiB, spike +CPU %, baseline factor or count range min-max) ").style(Style::default().fg(Color::Black))
+        .border_style(get_style(1, Color::Green));
+    let thresh = Paragraph::new(app.alert_edit_threshold.as_str())
+        .block(thresh_blk).style(get_style(1, Color::Green));
+    f.render_widget(thresh, chunks[2]);
+
+    let dur_blk = Block::default().borders(Borders::ALL)
+        .title(" Duration (seconds) ").style(Style::default().fg(Color::Black))
+        .border_style(get_style(2, Color::Magenta));
+    let dur = Paragraph::new(app.alert_edit_duration.as_str())
+        .block(dur_blk).style(get_style(2, Color::Magenta));
+    f.render_widget(dur, chunks[3]);
+
+    let pattern_blk = Block::default().borders(Borders::ALL)
+        .title(" Process Pattern (death and count alerts) ").style(Style::default().fg(Color::Black))
+        .border_style(get_style(3, Color::Yellow));
+    let pattern = Paragraph::new(app.alert_edit_pattern.as_str())
+        .block(pattern_blk).style(get_style(3, Color::Yellow));
+    f.render_widget(pattern, chunks[4]);
+
+    let mut inst_lines = vec![Line::from("Type to edit. [Tab] Next Field. [Ctrl+T] Test against current processes  |  [Enter] Save  |  [Esc] Cancel")];
+    if let Some(report) = &app.alert_test_report {
+        inst_lines.push(Line::from(""));
+        let summary = match &report.note {
+            Some(note) => format!("{} process(es) match now. {}", report.matches.len(), note),
+            None => format!(
+                "{} process(es) over the threshold now, {} over it for the full duration (would fire)",
+                report.matches.len(),
+                report.sustained
+            ),
+        };
+        let color = if report.matches.is_empty() { Color::Red } else { Color::Green };
+        inst_lines.push(Line::from(Span::styled(summary, Style::default().fg(color).add_modifier(Modifier::BOLD))));
+        for (pid, name, value) in report.matches.iter().take(5) {
+            inst_lines.push(Line::from(format!("  {} (PID {}): {}", name, pid, value)));
+        }
+        if report.matches.len() > 5 {
+            inst_lines.push(Line::from(format!("  ... and {} more", report.matches.len() - 5)));
+        }
+    }
+    let inst = Paragraph::new(inst_lines)
+    .block(Block::default().borders(Borders::ALL).title(" Instructions ").style(Style::default().fg(Color::Black)))
+    .style(Style::default().fg(Color::Black));
+    f.render_widget(inst, chunks[5]);
+}
+
+// The selected alert with the editor's (unsaved) name, threshold, duration and pattern applied
+fn edited_alert(app: &App) -> Option<crate::alert::Alert> {
+    let mut alert = app.alert_manager.get_alerts().get(app.selected_alert_index)?.clone();
+    alert.name = app.alert_edit_name.clone();
+
+    // Parse threshold and duration
+    let threshold_val = app.alert_edit_threshold.parse::<f32>().unwrap_or(0.0);
+    let duration_val = app.alert_edit_duration.parse::<u64>().unwrap_or(0);
+
+    match &mut alert.condition {
+        crate::alert::AlertCondition::CpuGreaterThan { threshold, duration_secs } => {
+            *threshold = threshold_val;
+            *duration_secs = duration_val;
+        }
+        crate::alert::AlertCondition::MemoryGreaterThan { threshold_mb, duration_secs } => {
+            *threshold_mb = threshold_val as u64;
+            *duration_secs = duration_val;
+        }
+        crate::alert::AlertCondition::StuckInDState { duration_secs } => {
+            *duration_secs = duration_val;
+        }
+        crate::alert::AlertCondition::AnomalyVsBaseline { factor, duration_secs } => {
+            *factor = threshold_val;
+            *duration_secs = duration_val;
+        }
+        crate::alert::AlertCondition::CpuSpike { delta_percent } => {
+            *delta_percent = threshold_val;
+        }
+        crate::alert::AlertCondition::ProcessDied { pattern } => {
+            // Keep a pattern target in step with the watched pattern
+            if let crate::alert::AlertTarget::Pattern(target) = &mut alert.target {
+                *target = app.alert_edit_pattern.clone();
+            }
+            *pattern = app.alert_edit_pattern.clone();
+        }
+        crate::alert::AlertCondition::ProcessCountOutsideRange { pattern, min, max } => {
+            // "min-max", or a single number for an exact count
+            let (low, high) = app.alert_edit_threshold.split_once('-').unwrap_or((&app.alert_edit_threshold, &app.alert_edit_threshold));
+            *min = low.trim().parse().unwrap_or(0);
+            *max = high.trim().parse().unwrap_or(*min).max(*min);
+            if let crate::alert::AlertTarget::Pattern(target) = &mut alert.target {
+                *target = app.alert_edit_pattern.clone();
+            }
+            *pattern = app.alert_edit_pattern.clone();
+        }
+        _ => {} // I/O alerts don't use these fields currently
+    }
+    Some(alert)
+}
+
+fn handle_alert_editor_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    match key.code {
+        KeyCode::Esc => {
+            app.view_mode = ViewMode::AlertManagement;
+            app.alert_edit_mode = false;
+        }
+        KeyCode::Tab => {
+            app.alert_edit_current_field = (app.alert_edit_current_field + 1) % 4;
+        }
+        KeyCode::BackTab => {
+            if app.alert_edit_current_field == 0 {
+                app.alert_edit_current_field = 3;
+            } else {
+                app.alert_edit_current_field -= 1;
+            }
+        }
+        KeyCode::Char('t') if key.modifiers == KeyModifiers::CONTROL => {
+            // One-shot evaluation of the edited alert against the current snapshot
+            if let Some(mut alert) = edited_alert(app) {
+                // Keep the saved name so time already spent over the threshold is found
+                if let Some(saved) = app.alert_manager.get_alerts().get(app.selected_alert_index) {
+                    alert.name = saved.name.clone();
+                }
+                app.alert_test_report = Some(app.alert_manager.test_alert(&alert, app.process_manager.get_processes()));
+            }
+        }
+        KeyCode::Enter => {
+            // Save changes
+            if let Some(edited) = edited_alert(app)
+                && let Some(alert) = app.alert_manager.get_alerts_mut().get_mut(app.selected_alert_index)
+            {
+                *alert = edited;
+            }
+            app.view_mode = ViewMode::AlertManagement;
+            app.alert_edit_mode = false;
+        }
+        KeyCode::Char(c) => {
+            match app.alert_edit_current_field {
+                0 => app.alert_edit_name.push(c),
+                1 => app.alert_edit_threshold.push(c),
+                2 => app.alert_edit_duration.push(c),
+                3 => app.alert_edit_pattern.push(c),
+                _ => {}
+            }
+        }
+        KeyCode::Backspace => {
+            match app.alert_edit_current_field {
+                0 => { app.alert_edit_name.pop(); },
+                1 => { app.alert_edit_threshold.pop(); },
+                2 => { app.alert_edit_duration.pop(); },
+                3 => { app.alert_edit_pattern.pop(); },
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+// Draw checkpoint management view
+// Open the tail view on one of the candidate log files
+fn open_log_tail(app: &mut App, index: usize) {
+    let Some(path) = app.log_tail_candidates.get(index) else {
+        return;
+    };
+    match crate::log_tail::LogTail::open(path) {
+        Ok(tail) => {
+            app.log_tail = Some(tail);
+            app.view_mode = ViewMode::LogTail;
+        }
+        Err(e) => {
+            app.input_state.message = Some((format!("Error opening {}: {}", path.display(), e), true));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        }
+    }
+}
+
+// Draw the auto-following tail of a log file
+fn draw_log_tail(f: &mut Frame, app: &mut App, area: Rect) {
+    let candidate_count = app.log_tail_candidates.len();
+    let Some(tail) = app.log_tail.as_mut() else {
+        return;
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),     // Log lines
+            Constraint::Length(3),  // Menu
+        ])
+        .split(area);
+
+    let height = chunks[0].height.saturating_sub(2) as usize;
+    let max_offset = tail.lines.len().saturating_sub(height);
+    if tail.follow {
+        tail.scroll_offset = max_offset;
+    } else {
+        tail.scroll_offset = tail.scroll_offset.min(max_offset);
+    }
+    let lines: Vec<Line> = tail.lines.iter()
+        .skip(tail.scroll_offset)
+        .take(height)
+        .map(|line| Line::from(line.as_str()))
+        .collect();
+
+    let index = app.log_tail_candidates.iter().position(|p| *p == tail.path).unwrap_or(0);
+    let title = format!(
+        "{} [{}/{}]{}",
+        tail.path.display(),
+        index + 1,
+        candidate_count,
+        if tail.follow { " (following)" } else { "" }
+    );
+    let log = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(title).style(Style::default().fg(Color::Black)));
+    f.render_widget(log, chunks[0]);
+
+    let menu = Paragraph::new("[↑/↓/PgUp/PgDn] Scroll  |  [End/f] Follow  |  [Tab] Next File  |  [Esc] Back")
+        .style(Style::default().fg(Color::Black))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Left);
+    f.render_widget(menu, chunks[1]);
+}
+
+// Handle keyboard input for the log tail view
+fn handle_log_tail_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    match key.code {
+        KeyCode::Esc => {
+            app.log_tail = None;
+            app.view_mode = app.view_stack.last().copied().unwrap_or(ViewMode::ProcessList);
+        }
+        KeyCode::Tab => {
+            // Switch to the next log file of the same process
+            let current = app.log_tail.as_ref()
+                .and_then(|tail| app.log_tail_candidates.iter().position(|p| *p == tail.path))
+                .unwrap_or(0);
+            if !app.log_tail_candidates.is_empty() {
+                open_log_tail(app, (current + 1) % app.log_tail_candidates.len());
+            }
+        }
+        KeyCode::Up | KeyCode::PageUp | KeyCode::Down | KeyCode::PageDown | KeyCode::End | KeyCode::Char('f') => {
+            if let Some(tail) = app.log_tail.as_mut() {
+                let page = app.display_limit.max(1);
+                match key.code {
+                    KeyCode::Up => tail.scroll_offset = tail.scroll_offset.saturating_sub(1),
+                    KeyCode::PageUp => tail.scroll_offset = tail.scroll_offset.saturating_sub(page),
+                    KeyCode::Down => tail.scroll_offset += 1,
+                    KeyCode::PageDown => tail.scroll_offset += page,
+                    _ => {}
+                }
+                // Scrolling up leaves follow mode; End/f re-enters it
+                tail.follow = matches!(key.code, KeyCode::End | KeyCode::Char('f'));
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+// Plan an ordered restart of the multi-selected processes, or reopen the current one
+fn begin_ordered_restart(app: &mut App) {
+    if app.ordered_restart.as_ref().is_some_and(|r| r.is_running() || r.is_planning()) {
+        app.view_mode = ViewMode::OrderedRestart;
+        return;
+    }
+    if app.selected_processes.is_empty() {
+        app.input_state.message = Some(("Select processes with multi-select [M] first".to_string(), true));
+        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+        return;
+    }
+    // Capture each command up front: it cannot be read once the process is stopped
+    let mut steps = Vec::new();
+    let mut skipped = Vec::new();
+    for process in app.process_manager.get_processes() {
+        if !app.selected_processes.contains(&process.pid) {
+            continue;
+        }
+        if process::is_locked(process) {
+            skipped.push(format!("{} (locked)", process.pid));
+            continue;
+        }
+        match app.process_manager.get_launch_spec(process.pid) {
+            Some(spec) => steps.push(crate::ordered_restart::RestartStep {
+                pid: process.pid,
+                name: process.name.clone(),
+                spec,
+                state: crate::ordered_restart::StepState::Pending,
+            }),
+            None => skipped.push(format!("{} (no command line)", process.pid)),
+        }
+    }
+    if steps.is_empty() {
+        app.input_state.message = Some((format!("Nothing to restart, skipped: {}", skipped.join(", ")), true));
+        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+        return;
+    }
+    if !skipped.is_empty() {
+        app.input_state.message = Some((format!("Skipped PID {}", skipped.join(", ")), true));
+        app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+    }
+    app.ordered_restart = Some(crate::ordered_restart::OrderedRestart::new(steps));
+    app.selected_restart_step = 0;
+    app.view_mode = ViewMode::OrderedRestart;
+}
+
+// Draw the restart order, per-step state and progress log
+fn draw_ordered_restart(f: &mut Frame, app: &mut App, area: Rect) {
+    use crate::ordered_restart::StepState;
+    let Some(restart) = &app.ordered_restart else {
+        return;
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Title
+            Constraint::Min(5),     // Steps
+            Constraint::Length(10), // Progress log
+            Constraint::Length(3),  // Menu
+        ])
+        .split(area);
+
+    let title = Paragraph::new(format!("Ordered Restart - {}", restart.status()))
+        .style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).border_type(ratatui::widgets::BorderType::Thick));
+    f.render_widget(title, chunks[0]);
+
+    let items: Vec<ListItem> = restart.steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| {
+            let (state, color) = match &step.state {
+                StepState::Pending => ("pending".to_string(), Color::Black),
+                StepState::Stopping => ("stopping...".to_string(), Color::Yellow),
+                StepState::Stopped => ("stopped".to_string(), Color::Magenta),
+                StepState::Starting(pid) => (format!("starting as PID {}...", pid), Color::Yellow),
+                StepState::Running(pid) => (format!("running as PID {}", pid), Color::Green),
+                StepState::Failed(reason) => (format!("FAILED: {}", reason), Color::Red),
+            };
+            let command = std::iter::once(step.spec.program.as_str())
+                .chain(step.spec.args.iter().map(|s| s.as_str()))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let line = format!(
+                "{}. {} (PID {}) | {} | {} | cwd: {}",
+                i + 1,
+                step.name,
+                step.pid,
+                state,
+                command,
+                step.spec.working_dir.as_deref().unwrap_or("?")
+            );
+            let style = if i == app.selected_restart_step && restart.is_planning() {
+                Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(color)
+            };
+            ListItem::new(Span::styled(line, style))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Start order (stopped in reverse)").style(Style::default().fg(Color::Black)))
+        .style(Style::default());
+    f.render_widget(list, chunks[1]);
+
+    let log_height = chunks[2].height.saturating_sub(2) as usize;
+    let log_items: Vec<ListItem> = restart.log
+        .iter()
+        .skip(restart.log.len().saturating_sub(log_height))
+        .map(|line| ListItem::new(Span::styled(line.clone(), Style::default().fg(Color::Cyan))))
+        .collect();
+    let log_list = List::new(log_items)
+        .block(Block::default().borders(Borders::ALL).title("Progress").style(Style::default().fg(Color::Black)));
+    f.render_widget(log_list, chunks[2]);
+
+    let menu_text = if restart.is_planning() {
+        "↑/↓: Select  |  [[/]] Move Earlier/Later  |  [Enter] Run  |  [Esc] Cancel"
+    } else if restart.is_running() {
+        "[x] Abort Remaining Steps  |  [Esc] Back (restart continues)"
+    } else {
+        "[Esc] Close"
+    };
+    let menu = Paragraph::new(menu_text)
+        .style(Style::default().fg(Color::Black))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Left);
+    f.render_widget(menu, chunks[3]);
+}
+
+// Handle keyboard input for the ordered restart view
+fn handle_ordered_restart_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    let Some(restart) = &mut app.ordered_restart else {
+        app.view_mode = ViewMode::ProcessList;
+        return Ok(false);
+    };
+    match key.code {
+        KeyCode::Esc => {
+            // A running restart keeps going in the background; anything else is discarded
+            if !restart.is_running() {
+                app.ordered_restart = None;
+            }
+            app.view_mode = ViewMode::ProcessList;
+        }
+        KeyCode::Up if app.selected_restart_step > 0 => {
+            app.selected_restart_step -= 1;
+        }
+        KeyCode::Down if app.selected_restart_step + 1 < restart.steps.len() => {
+            app.selected_restart_step += 1;
+        }
+        KeyCode::Char('[') => {
+            app.selected_restart_step = restart.move_up(app.selected_restart_step);
+        }
+        KeyCode::Char(']') => {
+            app.selected_restart_step = restart.move_down(app.selected_restart_step);
+        }
+        KeyCode::Enter if restart.is_planning() => {
+            restart.start(&app.process_manager);
+            app.selected_processes.clear();
+        }
+        KeyCode::Char('x') | KeyCode::Char('X') => restart.abort(),
+        _ => {}
+    }
+    Ok(false)
+}
+
+// Draw CPU/memory/process totals per user, with each user's share of RAM
+fn draw_user_summary(f: &mut Frame, app: &mut App, area: Rect) {
+    use crate::process_group::ProcessGroupManager;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Title
+            Constraint::Min(5),     // Users
+            Constraint::Length(3),  // Menu
+        ])
+        .split(area);
+
+    let users = ProcessGroupManager::users_by(app.process_manager.get_processes(), app.user_summary_sort);
+    app.selected_user_index = app.selected_user_index.min(users.len().saturating_sub(1));
+
+    let title = Paragraph::new(format!("Resource Usage by User ({} users, by {})", users.len(), app.user_summary_sort.label()))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(title, chunks[0]);
+
+    let total_memory = app.process_manager.get_total_memory().max(1);
+    let visible = chunks[1].height.saturating_sub(3) as usize;
+    let offset = (app.selected_user_index + 1).saturating_sub(visible.max(1));
+    let rows: Vec<Row> = users
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, user)| {
+            let ram_share = user.total_memory as f64 / total_memory as f64 * 100.0;
+            let top = user.processes.iter()
+                .max_by(|a, b| a.cpu_usage.partial_cmp(&b.cpu_usage).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|p| format!("{} ({})", p.name, p.pid))
+                .unwrap_or_default();
+            let style = if i == app.selected_user_index {
+                Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Black)
+            };
+            Row::new(vec![
+                Cell::from(user.group_id.clone()),
+                Cell::from(user.process_count().to_string()),
+                Cell::from(format!("{:.1}%", user.total_cpu)),
+                Cell::from(crate::units::format_bytes(user.total_memory)),
+                Cell::from(format!("{} {:.1}%", inline_bar(ram_share, 100.0, 10), ram_share)),
+                Cell::from(top),
+            ])
+            .style(style)
+        })
+        .collect();
+    let header = Row::new(["USER", "PROCS", "CPU%", "MEM", "% OF RAM", "TOP BY CPU"].map(|h| {
+        Cell::from(h).style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD))
+    }))
+    .style(Style::default().bg(Color::Black));
+    let table = Table::new(rows)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title("Users").style(Style::default().fg(Color::Black)))
+        .widths(&[
+            Constraint::Length(16),
+            Constraint::Length(7),
+            Constraint::Length(9),
+            Constraint::Length(11),
+            Constraint::Length(18),
+            Constraint::Min(20),
+        ]);
+    f.render_widget(table, chunks[1]);
+
+    let menu = Paragraph::new("[↑/↓] Navigate  |  [Enter] Show User's Processes  |  Sort: [c] CPU [m] Memory [n] Count  |  [Esc] Back")
+        .style(Style::default().fg(Color::Black))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Left);
+    f.render_widget(menu, chunks[2]);
+}
+
+// Processes started within settings.boot_window_secs of boot, grouped by user, cgroup or systemd unit
+fn draw_boot_report(f: &mut Frame, app: &mut App, area: Rect) {
+    use crate::process_group::{GroupType, ProcessGroupManager};
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Title
+            Constraint::Min(5),     // Report
+            Constraint::Length(3),  // Menu
+        ])
+        .split(area);
+
+    let window = app.settings.boot_window_secs;
+    let boot_time = app.process_manager.boot_time();
+    // Kernel threads all start at boot and say nothing about configured services
+    let at_boot: Vec<process::ProcessInfo> = match boot_time {
+        Some(boot_time) => app.process_manager.get_processes().iter()
+            .filter(|p| p.start_timestamp <= boot_time + window && !process::is_kernel_thread(p))
+            .cloned()
+            .collect(),
+        None => Vec::new(),
+    };
+    let (mut groups, group_label) = match app.boot_report_group {
+        GroupType::Cgroup => (ProcessGroupManager::group_by_cgroup(&at_boot), "cgroup"),
+        GroupType::SystemdUnit => (ProcessGroupManager::group_by_systemd_unit(&at_boot), "unit"),
+        _ => (ProcessGroupManager::group_by_username(&at_boot), "user"),
+    };
+    groups.sort_by(|a, b| a.group_id.cmp(&b.group_id));
+
+    let booted_at = boot_time
+        .and_then(|boot_time| chrono::DateTime::from_timestamp(boot_time as i64, 0))
+        .map(|dt| dt.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let title = Paragraph::new(format!(
+        "What Started at Boot - {} processes within {}s of boot ({}), by {}",
+        at_boot.len(), window, booted_at, group_label
+    ))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(title, chunks[0]);
+
+    let mut lines = Vec::new();
+    if boot_time.is_none() {
+        lines.push(Line::from("Boot time unavailable (/proc/uptime could not be read)"));
+    }
+    for group in &mut groups {
+        group.processes.sort_by_key(|p| (p.start_timestamp, p.pid));
+        lines.push(Line::from(Span::styled(
+            format!("{} ({} processes)", group.group_id, group.process_count()),
+            Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD),
+        )));
+        for p in &group.processes {
+            let after_boot = p.start_timestamp.saturating_sub(boot_time.unwrap_or(0));
+            lines.push(Line::from(format!(
+                "  {:>7}  {:<24} +{:>3}s  {}",
+                p.pid, p.name, after_boot, p.user.as_deref().unwrap_or("?")
+            )));
+        }
+    }
+    app.boot_report_scroll = app.boot_report_scroll.min(lines.len().saturating_sub(1));
+    let report = Paragraph::new(lines)
+        .scroll((app.boot_report_scroll as u16, 0))
+        .block(Block::default().borders(Borders::ALL).title("PID / Name / Started after boot / User").style(Style::default().fg(Color::Black)));
+    f.render_widget(report, chunks[1]);
+
+    let menu = Paragraph::new("[↑/↓/PgUp/PgDn] Scroll  |  [g] Group by user/cgroup/unit  |  [Esc] Back")
+        .style(Style::default().fg(Color::Black))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Left);
+    f.render_widget(menu, chunks[2]);
+}
+
+// Handle keyboard input for the boot report
+fn handle_boot_report_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    use crate::process_group::GroupType;
+
+    match key.code {
+        KeyCode::Esc => app.view_mode = ViewMode::Help,
+        KeyCode::Up => app.boot_report_scroll = app.boot_report_scroll.saturating_sub(1),
+        KeyCode::Down => app.boot_report_scroll += 1, // Clamped when drawn
+        KeyCode::PageUp => app.boot_report_scroll = app.boot_report_scroll.saturating_sub(10),
+        KeyCode::PageDown => app.boot_report_scroll += 10,
+        KeyCode::Char('g') => {
+            app.boot_report_group = match app.boot_report_group {
+                GroupType::Username => GroupType::Cgroup,
+                GroupType::Cgroup => GroupType::SystemdUnit,
+                _ => GroupType::Username,
+            };
+            app.boot_report_scroll = 0;
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+// Entries of the "add graph" picker: every system metric, then CPU and memory of the
+// process selected in the process list (if any)
+fn dashboard_choices(app: &App) -> Vec<crate::dashboard::DashboardWidget> {
+    use crate::dashboard::{DashboardWidget, Metric, SYSTEM_METRICS};
+
+    let mut choices: Vec<DashboardWidget> = SYSTEM_METRICS.into_iter().map(DashboardWidget::system).collect();
+    if let Some(process) = view_processes(app, ViewMode::ProcessList).get(app.selected_process_index) {
+        for metric in [Metric::ProcessCpu, Metric::ProcessMemory] {
+            choices.push(DashboardWidget { metric, process: Some(process.name.clone()) });
+        }
+    }
+    choices
+}
+
+fn draw_dashboard(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),     // Grid
+            Constraint::Length(3),  // Menu
+        ])
+        .split(area);
+
+    app.dashboard_selected = app.dashboard_selected.min(app.dashboard.widgets.len().saturating_sub(1));
+    crate::dashboard::render_dashboard(
+        f,
+        chunks[0],
+        &app.dashboard,
+        app.dashboard_selected,
+        &app.graph_data,
+        app.process_manager.get_processes(),
+    );
+
+    let menu = Paragraph::new(format!(
+        "[←→↑↓] Select | [a] Add Graph | [x/Del] Remove | [</>] Move | [+/-] Columns ({}) | [Esc] Statistics",
+        app.dashboard.columns
+    ))
+        .style(Style::default().fg(Color::Black))
+        .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)));
+    f.render_widget(menu, chunks[1]);
+
+    // "Add graph" picker over the grid
+    if let Some(highlighted) = app.dashboard_picker {
+        let choices = dashboard_choices(app);
+        let height = (choices.len() as u16 + 2).min(area.height);
+        let width = 50.min(area.width);
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+        let items: Vec<ListItem> = choices
+            .iter()
+            .enumerate()
+            .map(|(i, choice)| {
+                let style = if i == highlighted {
+                    Style::default().fg(Color::White).bg(Color::Blue).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Black)
+                };
+                ListItem::new(Span::styled(choice.title(), style))
+            })
+            .collect();
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Add Graph ([Enter] add, [Esc] cancel) ")
+                .style(Style::default().fg(Color::Black).bg(Color::White)),
+        );
+        f.render_widget(ratatui::widgets::Clear, popup);
+        f.render_widget(list, popup);
+    }
+}
+
+fn handle_dashboard_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    if let Some(highlighted) = app.dashboard_picker {
+        let choices = dashboard_choices(app);
+        match key.code {
+            KeyCode::Esc => app.dashboard_picker = None,
+            KeyCode::Up => app.dashboard_picker = Some(highlighted.saturating_sub(1)),
+            KeyCode::Down => app.dashboard_picker = Some((highlighted + 1).min(choices.len().saturating_sub(1))),
+            KeyCode::Enter => {
+                if let Some(choice) = choices.into_iter().nth(highlighted) {
+                    app.dashboard.add(choice);
+                    app.dashboard_selected = app.dashboard.widgets.len() - 1;
+                }
+                app.dashboard_picker = None;
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    let count = app.dashboard.widgets.len();
+    let columns = app.dashboard.columns;
+    match key.code {
+        KeyCode::Esc => app.view_mode = ViewMode::Statistics,
+        KeyCode::Left => app.dashboard_selected = app.dashboard_selected.saturating_sub(1),
+        KeyCode::Right => app.dashboard_selected = (app.dashboard_selected + 1).min(count.saturating_sub(1)),
+        KeyCode::Up => app.dashboard_selected = app.dashboard_selected.saturating_sub(columns),
+        KeyCode::Down if app.dashboard_selected + columns < count => {
+            app.dashboard_selected += columns;
+        }
+        KeyCode::Char('a') => app.dashboard_picker = Some(0),
+        KeyCode::Char('x') | KeyCode::Delete => app.dashboard.remove(app.dashboard_selected),
+        KeyCode::Char('<') => app.dashboard_selected = app.dashboard.shift(app.dashboard_selected, false),
+        KeyCode::Char('>') => app.dashboard_selected = app.dashboard.shift(app.dashboard_selected, true),
+        KeyCode::Char('+') => app.dashboard.set_columns(columns + 1),
+        KeyCode::Char('-') => app.dashboard.set_columns(columns.saturating_sub(1)),
+        _ => {}
+    }
+    Ok(false)
+}
+
+// Handle keyboard input for the by-user summary
+fn handle_user_summary_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    use crate::process_group::{ProcessGroupManager, UserSortKey};
+
+    match key.code {
+        KeyCode::Esc => app.view_mode = ViewMode::ProcessList,
+        KeyCode::Up => app.selected_user_index = app.selected_user_index.saturating_sub(1),
+        KeyCode::Down => app.selected_user_index += 1, // Clamped when drawn
+        KeyCode::Char('c') => app.user_summary_sort = UserSortKey::Cpu,
+        KeyCode::Char('m') => app.user_summary_sort = UserSortKey::Memory,
+        KeyCode::Char('n') => app.user_summary_sort = UserSortKey::Count,
+        KeyCode::Enter => {
+            // Drill down: the process list filtered to this user
+            let users = ProcessGroupManager::users_by(app.process_manager.get_processes(), app.user_summary_sort);
+            if let Some(user) = users.get(app.selected_user_index) {
+                let name = user.group_id.clone();
+                app.filter_mode = Some("user".to_string());
+                app.input_state.filter_input = name.clone();
+                app.process_manager.set_filter(Some("user".to_string()), Some(name));
+                app.selected_process_index = 0;
+                app.scroll_offset = 0;
+                app.view_mode = ViewMode::ProcessList;
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+// One visible line of the process tree
+struct TreeRow {
+    pid: u32,
+    prefix: String, // Box-drawing guides leading up to the node
+    has_children: bool,
+}
+
+// Flatten the parent_pid hierarchy into visible rows, PID 1 and other parentless processes as roots.
+// A visited set stops ppid cycles; processes only reachable through a cycle become roots of their own.
+fn process_tree_rows(processes: &[process::ProcessInfo], collapsed: &HashSet<u32>) -> Vec<TreeRow> {
+    let present: HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+    let mut children: std::collections::HashMap<u32, Vec<u32>> = std::collections::HashMap::new();
+    let mut roots = Vec::new();
+    for process in processes {
+        match process.parent_pid {
+            Some(ppid) if ppid != process.pid && present.contains(&ppid) => children.entry(ppid).or_default().push(process.pid),
+            _ => roots.push(process.pid),
+        }
+    }
+    for kids in children.values_mut() {
+        kids.sort_unstable();
+    }
+    roots.sort_unstable();
+
+    fn walk(
+        pid: u32,
+        lead: &str,
+        branch: &str,
+        children: &std::collections::HashMap<u32, Vec<u32>>,
+        collapsed: &HashSet<u32>,
+        visited: &mut HashSet<u32>,
+        rows: &mut Vec<TreeRow>,
+    ) {
+        if !visited.insert(pid) {
+            return;
+        }
+        let kids: Vec<u32> = children.get(&pid).map_or_else(Vec::new, |k| k.iter().copied().filter(|k| !visited.contains(k)).collect());
+        rows.push(TreeRow { pid, prefix: format!("{}{}", lead, branch), has_children: !kids.is_empty() });
+        if collapsed.contains(&pid) {
+            return;
+        }
+        // Children line up under their parent's branch
+        let child_lead = match branch {
+            "" => String::new(),
+            "└─ " => format!("{}   ", lead),
+            _ => format!("{}│  ", lead),
+        };
+        for (i, kid) in kids.iter().enumerate() {
+            let kid_branch = if i + 1 == kids.len() { "└─ " } else { "├─ " };
+            walk(*kid, &child_lead, kid_branch, children, collapsed, visited, rows);
+        }
+    }
+
+    // Mark a process and its whole subtree, collapsed or not
+    fn reach(pid: u32, children: &std::collections::HashMap<u32, Vec<u32>>, reached: &mut HashSet<u32>) {
+        let mut stack = vec![pid];
+        while let Some(current) = stack.pop() {
+            if reached.insert(current) {
+                stack.extend(children.get(&current).into_iter().flatten().copied());
+            }
+        }
+    }
+
+    let mut reached = HashSet::new();
+    for root in &roots {
+        reach(*root, &children, &mut reached);
+    }
+    let mut visited = HashSet::new();
+    let mut rows = Vec::new();
+    for root in roots {
+        walk(root, "", "", &children, collapsed, &mut visited, &mut rows);
+    }
+    // Only processes no root leads to (a ppid cycle) are stranded; hidden children of a collapsed node are not
+    let mut stranded: Vec<u32> = processes.iter().map(|p| p.pid).filter(|pid| !reached.contains(pid)).collect();
+    stranded.sort_unstable();
+    for pid in stranded {
+        if reached.contains(&pid) {
+            continue;
+        }
+        reach(pid, &children, &mut reached);
+        walk(pid, "", "", &children, collapsed, &mut visited, &mut rows);
+    }
+    rows
+}
+
+fn draw_process_tree(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Title
+            Constraint::Min(5),     // Tree
+            Constraint::Length(3),  // Menu
+        ])
+        .split(area);
+
+    let processes = app.process_manager.get_processes();
+    let rows = process_tree_rows(processes, &app.tree_collapsed);
+    app.selected_tree_index = app.selected_tree_index.min(rows.len().saturating_sub(1));
+
+    let title = Paragraph::new(format!("Process Tree ({} processes, {} collapsed)", processes.len(), app.tree_collapsed.len()))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(title, chunks[0]);
+
+    let visible = chunks[1].height.saturating_sub(2) as usize;
+    let offset = (app.selected_tree_index + 1).saturating_sub(visible.max(1));
+    let items: Vec<ListItem> = rows
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .filter_map(|(i, row)| {
+            let process = processes.iter().find(|p| p.pid == row.pid)?;
+            let marker = match (row.has_children, app.tree_collapsed.contains(&row.pid)) {
+                (false, _) => "  ",
+                (true, true) => "▶ ",
+                (true, false) => "▼ ",
+            };
+            let style = if i == app.selected_tree_index {
+                Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Black)
+            };
+            Some(ListItem::new(Line::from(vec![
+                Span::styled(row.prefix.clone(), Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!(
+                        "{}{} ({})  {:.1}%  {}  {}",
+                        marker,
+                        process.name,
+                        process.pid,
+                        process.cpu_usage,
+                        crate::units::format_bytes(process.memory_usage),
+                        process.user.as_deref().unwrap_or("?"),
+                    ),
+                    style,
+                ),
+            ])))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("PID 1 and its descendants").style(Style::default().fg(Color::Black)));
+    f.render_widget(list, chunks[1]);
+
+    let menu = Paragraph::new("[↑/↓] Navigate  |  [Enter] Expand/Collapse  |  [c] Collapse All  [x] Expand All  |  [k] Kill/Stop  [n] Renice  |  [Esc] Back")
+        .style(Style::default().fg(Color::Black))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Left);
+    f.render_widget(menu, chunks[2]);
+}
+
+fn handle_process_tree_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    let rows = process_tree_rows(app.process_manager.get_processes(), &app.tree_collapsed);
+    let selected = rows.get(app.selected_tree_index).map(|row| (row.pid, row.has_children));
+    match key.code {
+        KeyCode::Esc => app.view_mode = ViewMode::ProcessList,
+        KeyCode::Up => app.selected_tree_index = app.selected_tree_index.saturating_sub(1),
+        KeyCode::Down if app.selected_tree_index + 1 < rows.len() => {
+            app.selected_tree_index += 1;
+        }
+        KeyCode::Enter => {
+            if let Some((pid, true)) = selected
+                && !app.tree_collapsed.remove(&pid)
+            {
+                app.tree_collapsed.insert(pid);
             }
         }
-        KeyCode::Char('-') => {
-            // Delete alert
-            app.alert_manager.remove_alert(app.selected_alert_index);
-            if app.selected_alert_index >= app.alert_manager.get_alerts().len() && app.selected_alert_index > 0 {
-                app.selected_alert_index -= 1;
+        KeyCode::Char('c') => {
+            // Collapse every parent; a selection that ends up hidden falls back to the top
+            let parents: Vec<u32> = rows.iter().filter(|row| row.has_children).map(|row| row.pid).collect();
+            app.tree_collapsed.extend(parents);
+            let pid = selected.map(|(pid, _)| pid);
+            let collapsed_rows = process_tree_rows(app.process_manager.get_processes(), &app.tree_collapsed);
+            app.selected_tree_index = pid.and_then(|pid| collapsed_rows.iter().position(|row| row.pid == pid)).unwrap_or(0);
+        }
+        KeyCode::Char('x') => app.tree_collapsed.clear(),
+        KeyCode::Char(c @ ('k' | 'n')) => {
+            // Hand the selected node to the Kill/Stop or Change Nice action menu
+            let Some((pid, _)) = selected else {
+                return Ok(false);
+            };
+            let mode = if c == 'k' { ViewMode::KillStop } else { ViewMode::ChangeNice };
+            if !view_processes(app, mode).iter().any(|p| p.pid == pid) {
+                // Those menus pick from their own filtered list; never let them fall back to another process
+                app.input_state.message = Some((format!("PID {} is hidden by the active filter - clear it first", pid), true));
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                return Ok(false);
+            }
+            app.view_mode = mode;
+            reselect_pid(app, mode, Some(pid));
+            app.input_state.message = None;
+            if mode == ViewMode::KillStop {
+                app.kill_stop_input_state = KillStopInputState::EnteringAction;
+            } else {
+                app.nice_input_state = NiceInputState::EnteringNice;
+                app.input_state.nice_input.clear();
             }
-        }
-        KeyCode::Char('C') => {
-            // Clear all active alerts
-            app.alert_manager.clear_all_active_alerts();
         }
         _ => {}
     }
     Ok(false)
 }
 
+// PIDs lpm has suspended, longest-stopped first
+fn suspended_pids(app: &App) -> Vec<u32> {
+    let mut pids: Vec<u32> = app.suspended_by_lpm.keys().copied().collect();
+    pids.sort_by_key(|pid| app.suspended_by_lpm[pid].1);
+    pids
+}
 
-
-fn draw_alert_editor(f: &mut Frame, app: &mut App, area: Rect) {
+// Draw the list of processes lpm stopped and has not resumed yet
+fn draw_suspended_view(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Title
-            Constraint::Length(3), // Name
-            Constraint::Length(3), // Threshold
-            Constraint::Length(3), // Duration
-            Constraint::Min(1),    // Instructions
+            Constraint::Length(3),  // Title
+            Constraint::Min(5),     // Suspended list
+            Constraint::Length(3),  // Menu
         ])
         .split(area);
 
-    let title = Paragraph::new("Edit Alert")
-        .style(Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))
+    let title = Paragraph::new(format!("Suspended by lpm ({})", app.suspended_by_lpm.len()))
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)));
+        .block(Block::default().borders(Borders::ALL).border_type(ratatui::widgets::BorderType::Thick));
     f.render_widget(title, chunks[0]);
 
-    let get_style = |idx: usize, color: Color| {
-        if app.alert_edit_current_field == idx {
-            Style::default().fg(Color::Black).bg(color).add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::Black)
-        }
-    };
-
-    let name_blk = Block::default().borders(Borders::ALL)
-        .title(" Name ").style(Style::default().fg(Color::Black))
-        .border_style(get_style(0, Color::Cyan));
-    let name = Paragraph::new(app.alert_edit_name.as_str())
-        .block(name_blk).style(get_style(0, Color::Cyan));
-    f.render_widget(name, chunks[1]);
-
-    let thresh_blk = Block::default().borders(Borders::ALL)
-        .title(" Threshold (CPU % or Mem MB) ").style(Style::default().fg(Color::Black))
-        .border_style(get_style(1, Color::Green));
-    let thresh = Paragraph::new(app.alert_edit_threshold.as_str())
-        .block(thresh_blk).style(get_style(1, Color::Green));
-    f.render_widget(thresh, chunks[2]);
+    let items: Vec<ListItem> = suspended_pids(app)
+        .iter()
+        .enumerate()
+        .map(|(i, pid)| {
+            let (name, stopped_at) = &app.suspended_by_lpm[pid];
+            let stopped_secs = stopped_at.elapsed().as_secs();
+            let style = if i == app.selected_suspended_index {
+                Style::default().fg(Color::White).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Black)
+            };
+            ListItem::new(Span::styled(
+                format!("PID: {} | {} | stopped {}m {}s ago", pid, name, stopped_secs / 60, stopped_secs % 60),
+                style
+            ))
+        })
+        .collect();
 
-    let dur_blk = Block::default().borders(Borders::ALL)
-        .title(" Duration (seconds) ").style(Style::default().fg(Color::Black))
-        .border_style(get_style(2, Color::Magenta));
-    let dur = Paragraph::new(app.alert_edit_duration.as_str())
-        .block(dur_blk).style(get_style(2, Color::Magenta));
-    f.render_widget(dur, chunks[3]);
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title("Processes").style(Style::default().fg(Color::Black)))
+        .style(Style::default());
+    f.render_widget(list, chunks[1]);
 
-    let inst = Paragraph::new(
-        "Type to edit. [Tab] Next Field. [Enter] Save  |  [Esc] Cancel"
-    )
-    .block(Block::default().borders(Borders::ALL).title(" Instructions ").style(Style::default().fg(Color::Black)))
-    .style(Style::default().fg(Color::Black));
-    f.render_widget(inst, chunks[4]);
+    let menu = Paragraph::new("[Enter/r] Resume  |  [R] Resume All  |  [Esc] Back")
+        .style(Style::default().fg(Color::Black))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Left);
+    f.render_widget(menu, chunks[2]);
 }
 
-fn handle_alert_editor_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+// Handle keyboard input for the suspended-by-lpm view
+fn handle_suspended_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    let pids = suspended_pids(app);
     match key.code {
         KeyCode::Esc => {
-            app.view_mode = ViewMode::AlertManagement;
-            app.alert_edit_mode = false;
+            app.view_mode = ViewMode::ProcessList;
         }
-        KeyCode::Tab => {
-            app.alert_edit_current_field = (app.alert_edit_current_field + 1) % 3;
+        KeyCode::Up if app.selected_suspended_index > 0 => {
+            app.selected_suspended_index -= 1;
         }
-        KeyCode::BackTab => {
-            if app.alert_edit_current_field == 0 {
-                app.alert_edit_current_field = 2;
-            } else {
-                app.alert_edit_current_field -= 1;
-            }
+        KeyCode::Down if app.selected_suspended_index + 1 < pids.len() => {
+            app.selected_suspended_index += 1;
         }
-        KeyCode::Enter => {
-            // Save changes
-            if let Some(alert) = app.alert_manager.get_alerts_mut().get_mut(app.selected_alert_index) {
-                alert.name = app.alert_edit_name.clone();
-                
-                // Parse threshold and duration
-                let threshold_val = app.alert_edit_threshold.parse::<f32>().unwrap_or(0.0);
-                let duration_val = app.alert_edit_duration.parse::<u64>().unwrap_or(0);
-                
-                match &mut alert.condition {
-                    crate::alert::AlertCondition::CpuGreaterThan { threshold, duration_secs } => {
-                        *threshold = threshold_val;
-                        *duration_secs = duration_val;
+        KeyCode::Enter | KeyCode::Char('r') => {
+            if let Some(&pid) = pids.get(app.selected_suspended_index) {
+                match app.process_manager.continue_process(pid) {
+                    Ok(_) => {
+                        app.suspended_by_lpm.remove(&pid);
+                        app.input_state.message = Some((format!("Resumed process {}", pid), false));
                     }
-                    crate::alert::AlertCondition::MemoryGreaterThan { threshold_mb, duration_secs } => {
-                        *threshold_mb = threshold_val as u64;
-                        *duration_secs = duration_val;
+                    Err(e) => {
+                        app.input_state.message = Some((format!("Error resuming process {}: {}", pid, e), true));
                     }
-                    _ => {} // ProcessDied doesn't use these fields currently
                 }
-            }
-            app.view_mode = ViewMode::AlertManagement;
-            app.alert_edit_mode = false;
-        }
-        KeyCode::Char(c) => {
-            match app.alert_edit_current_field {
-                0 => app.alert_edit_name.push(c),
-                1 => app.alert_edit_threshold.push(c),
-                2 => app.alert_edit_duration.push(c),
-                _ => {}
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                if app.selected_suspended_index >= app.suspended_by_lpm.len() && app.selected_suspended_index > 0 {
+                    app.selected_suspended_index -= 1;
+                }
             }
         }
-        KeyCode::Backspace => {
-            match app.alert_edit_current_field {
-                0 => { app.alert_edit_name.pop(); },
-                1 => { app.alert_edit_threshold.pop(); },
-                2 => { app.alert_edit_duration.pop(); },
-                _ => {}
+        KeyCode::Char('R') => {
+            // Resume everything lpm suspended
+            let mut error_count = 0;
+            for pid in &pids {
+                if app.process_manager.continue_process(*pid).is_ok() {
+                    app.suspended_by_lpm.remove(pid);
+                } else {
+                    error_count += 1;
+                }
             }
+            app.input_state.message = Some((
+                format!("Resumed {} processes, {} failed", pids.len() - error_count, error_count),
+                error_count > 0,
+            ));
+            app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
+            app.selected_suspended_index = 0;
         }
         _ => {}
     }
     Ok(false)
 }
 
-// Draw checkpoint management view
 fn draw_checkpoint_management(f: &mut Frame, app: &mut App, area: Rect) {
     let size = area;
     let chunks = Layout::default()
@@ -5079,9 +10670,16 @@ fn draw_host_management(f: &mut Frame, app: &mut App, area: Rect) {
             } else {
                 Style::default().fg(Color::Black)
             };
+            let diff_marker = if app.diff_base_host.as_deref() == Some(host.address.as_str()) { " [DIFF BASE]" } else { "" };
+            // Show what a hostname resolved to, and why the last attempt failed
+            let resolved = host.resolved.as_ref()
+                .filter(|resolved| **resolved != host.address)
+                .map(|resolved| format!(" → {}", resolved))
+                .unwrap_or_default();
+            let error = host.last_error.as_ref().map(|e| format!(" - {}", e)).unwrap_or_default();
             ListItem::new(Span::styled(
-                format!("{} {} ({}) - {}", status, host.name, host.address, 
-                    if host.connected { "Connected" } else { "Not Connected" }),
+                format!("{} {} ({}{}) - {}{}{}", status, host.name, host.address, resolved,
+                    if host.connected { "Connected" } else { "Not Connected" }, error, diff_marker),
                 style
             ))
         })
@@ -5094,7 +10692,7 @@ fn draw_host_management(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Input field
     let input_text = if app.host_input.is_empty() {
-        "Enter host address (IP:port or hostname:port)...".to_string()
+        "Enter host address (IP:port, [IPv6]:port or hostname:port)...".to_string()
     } else {
         app.host_input.clone()
     };
@@ -5104,7 +10702,7 @@ fn draw_host_management(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_widget(input_para, chunks[2]);
 
     // Menu
-    let menu = Paragraph::new("[+] Add Host  |  [Enter] Add  |  [-] Remove  |  [T] Toggle Multi-Host  |  [Esc] Back")
+    let menu = Paragraph::new("[+] Add Host  |  [Enter] Add  |  [-] Remove  |  [=] Compare (pick two)  |  [T] Toggle Multi-Host  |  [Esc] Back")
         .block(Block::default().borders(Borders::ALL).style(Style::default().fg(Color::Black)))
         .style(Style::default().fg(Color::Black))
         .alignment(Alignment::Left);
@@ -5134,16 +10732,26 @@ fn handle_host_management_input(key: KeyEvent, app: &mut App) -> Result<bool, Bo
         KeyCode::Enter => {
             // Add host
             if !app.host_input.trim().is_empty() {
-                let address = app.host_input.trim().to_string();
-                let name = address.clone();
-                app.coordinator.add_host(address.clone(), name);
-                app.host_input.clear();
-                
-                app.input_state.message = Some((
-                    format!("Host added: {}. Connection will be tested on refresh.", address),
-                    false
-                ));
-                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(2));
+                let input = app.host_input.trim().to_string();
+                let message = match app.coordinator.add_host(input.clone(), input) {
+                    Ok(address) => {
+                        app.host_input.clear();
+                        // Resolve now so a typo in the name shows up straight away
+                        match crate::coordinator::resolve_host_blocking(&address) {
+                            Ok(resolved) => {
+                                app.coordinator.set_resolved(&address, resolved);
+                                (format!("Host added: {} ({}). Connection will be tested on refresh.", address, resolved), false)
+                            }
+                            Err(e) => {
+                                app.coordinator.mark_host_disconnected(&address, e.clone());
+                                (format!("Host added: {}, but {}", address, e), true)
+                            }
+                        }
+                    }
+                    Err(e) => (format!("Invalid host address: {}", e), true),
+                };
+                app.input_state.message = Some(message);
+                app.input_state.message_timeout = Some(std::time::Instant::now() + Duration::from_secs(3));
             }
         }
         KeyCode::Char(c) => {
@@ -5165,6 +10773,23 @@ fn handle_host_management_input(key: KeyEvent, app: &mut App) -> Result<bool, Bo
                             }
                         }
                     }
+                    '=' => {
+                        // First press picks the base host, a second press on another host shows the diff
+                        let Some(address) = hosts.get(app.selected_host_index).map(|h| h.address.clone()) else {
+                            return Ok(false);
+                        };
+                        match app.diff_base_host.take() {
+                            Some(base) if base != address => {
+                                // Shown at once from the last data, and recomputed as fresh data arrives
+                                app.host_diff = Some(app.coordinator.diff_hosts(&base, &address));
+                                app.host_diff_scroll = 0;
+                                app.view_mode = ViewMode::HostDiff;
+                                app.fetch_hosts();
+                            }
+                            Some(_) => {} // Same host again: unpick it
+                            None => app.diff_base_host = Some(address),
+                        }
+                    }
                     't' | 'T' => {
                         // Toggle multi-host mode
                         app.multi_host_mode = !app.multi_host_mode;
@@ -5194,6 +10819,114 @@ fn handle_host_management_input(key: KeyEvent, app: &mut App) -> Result<bool, Bo
     Ok(false)
 }
 
+// Unified diff of two hosts' process sets: - only on the base host, + only on the other, ~ big differences
+fn host_diff_lines(diff: &crate::coordinator::HostDiff) -> Vec<Line<'static>> {
+    use crate::units::format_bytes;
+    let mut lines = vec![
+        Line::from(Span::styled(format!("--- {}", diff.host_a), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))),
+        Line::from(Span::styled(format!("+++ {}", diff.host_b), Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))),
+    ];
+    for (name, usage) in &diff.only_a {
+        lines.push(Line::from(Span::styled(
+            format!("- {} x{}  CPU {:.1}%  MEM {}", name, usage.count, usage.cpu, format_bytes(usage.memory)),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    for (name, usage) in &diff.only_b {
+        lines.push(Line::from(Span::styled(
+            format!("+ {} x{}  CPU {:.1}%  MEM {}", name, usage.count, usage.cpu, format_bytes(usage.memory)),
+            Style::default().fg(Color::Green),
+        )));
+    }
+    for (name, a, b) in &diff.changed {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "~ {}  x{} -> x{}  CPU {:.1}% -> {:.1}%  MEM {} -> {}",
+                name, a.count, b.count, a.cpu, b.cpu, format_bytes(a.memory), format_bytes(b.memory)
+            ),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    if diff.only_a.is_empty() && diff.only_b.is_empty() && diff.changed.is_empty() {
+        lines.push(Line::from("No differences"));
+    }
+    lines
+}
+
+// Draw the comparison of two hosts picked in host management
+fn draw_host_diff(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Title
+            Constraint::Min(5),     // Diff
+            Constraint::Length(3),  // Menu
+        ])
+        .split(area);
+
+    let Some(diff) = &app.host_diff else {
+        app.view_mode = ViewMode::HostManagement;
+        return;
+    };
+    let title = Paragraph::new(format!(
+        "Host Diff: {} only on {}, {} only on {}, {} differ",
+        diff.only_a.len(), diff.host_a, diff.only_b.len(), diff.host_b, diff.changed.len()
+    ))
+    .style(Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))
+    .alignment(Alignment::Center)
+    .block(Block::default().borders(Borders::ALL).border_type(ratatui::widgets::BorderType::Thick));
+    f.render_widget(title, chunks[0]);
+
+    // An empty process list would look like "no differences"; say which host is missing instead
+    let silent: Vec<Line> = [&diff.host_a, &diff.host_b]
+        .into_iter()
+        .filter_map(|address| app.coordinator.get_hosts().iter().find(|h| &h.address == address))
+        .filter(|host| host.last_update.is_none())
+        .map(|host| {
+            let reason = host.last_error.as_deref().map_or_else(|| "waiting for data".to_string(), |e| e.to_string());
+            Line::from(Span::styled(format!("Host {} has not answered ({})", host.address, reason), Style::default().fg(Color::Red)))
+        })
+        .collect();
+    let lines = if silent.is_empty() { host_diff_lines(diff) } else { silent };
+    app.host_diff_scroll = app.host_diff_scroll.min(lines.len().saturating_sub(1));
+    let body = Paragraph::new(lines)
+        .scroll((app.host_diff_scroll as u16, 0))
+        .block(Block::default().borders(Borders::ALL).title("Processes by name").style(Style::default().fg(Color::Black)));
+    f.render_widget(body, chunks[1]);
+
+    let menu = Paragraph::new("[↑/↓] Scroll  |  [r] Recompare  |  [s] Swap Sides  |  [Esc] Back")
+        .style(Style::default().fg(Color::Black))
+        .block(Block::default().borders(Borders::ALL))
+        .alignment(Alignment::Left);
+    f.render_widget(menu, chunks[2]);
+}
+
+// Handle keyboard input for the host diff view
+fn handle_host_diff_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn Error>> {
+    match key.code {
+        KeyCode::Esc => {
+            app.host_diff = None;
+            app.view_mode = ViewMode::HostManagement;
+        }
+        KeyCode::Up => app.host_diff_scroll = app.host_diff_scroll.saturating_sub(1),
+        KeyCode::Down => app.host_diff_scroll += 1, // Clamped when drawn
+        KeyCode::Char('r') | KeyCode::Char('s') => {
+            // Recompute from the latest fetched data (and fetch again), swapping sides for 's'
+            if let Some(diff) = &app.host_diff {
+                let (a, b) = if key.code == KeyCode::Char('s') {
+                    (diff.host_b.clone(), diff.host_a.clone())
+                } else {
+                    (diff.host_a.clone(), diff.host_b.clone())
+                };
+                app.host_diff = Some(app.coordinator.diff_hosts(&a, &b));
+                app.fetch_hosts();
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
 // Draw task editor view
 fn draw_task_editor(f: &mut Frame, app: &mut App, area: Rect) {
     let size = area;
@@ -5208,7 +10941,12 @@ fn draw_task_editor(f: &mut Frame, app: &mut App, area: Rect) {
         .split(size);
 
     // Title
-    let title = Paragraph::new("Create Scheduled Task")
+    let title_text = if app.input_state.editing_task_index.is_some() {
+        "Edit Scheduled Task"
+    } else {
+        "Create Scheduled Task"
+    };
+    let title = Paragraph::new(title_text)
         .style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL).border_type(ratatui::widgets::BorderType::Thick));
@@ -5229,8 +10967,8 @@ fn draw_task_editor(f: &mut Frame, app: &mut App, area: Rect) {
     let fields = [
         ("Task Name", &app.input_state.task_name, 0),
         ("Schedule Type (cron/interval/once)", &app.input_state.task_schedule_type, 1),
-        ("Schedule Value (e.g., '0 * * * *' or '60')", &app.input_state.task_schedule_value, 2),
-        ("Action Type (restart/start/cleanup/rule)", &app.input_state.task_action_type, 3),
+        ("Schedule Value (e.g., '0 * * * *', '60' or 'in 5m')", &app.input_state.task_schedule_value, 2),
+        ("Action Type (restart/start/cleanup/rule/signal)", &app.input_state.task_action_type, 3),
         ("Action Value (pattern/program/params/rule)", &app.input_state.task_action_value, 4),
     ];
 
@@ -5250,15 +10988,26 @@ fn draw_task_editor(f: &mut Frame, app: &mut App, area: Rect) {
     }
 
     // Instructions
-    let instructions = vec![
+    let mut instructions = vec![
         Line::from(vec![Span::styled("Instructions:", Style::default().fg(Color::Black).add_modifier(Modifier::BOLD))]),
         Line::from(vec![Span::raw("1. Enter task name (e.g., 'Test Restart')")]),
-        Line::from(vec![Span::raw("2. Schedule Type: 'cron' (e.g., '0 * * * *'), 'interval' (seconds), or 'once' (timestamp)")]),
-        Line::from(vec![Span::raw("3. Schedule Value: cron expression, interval in seconds, or timestamp")]),
+        Line::from(vec![Span::raw("2. Schedule Type: 'cron' (e.g., '0 * * * *'), 'interval' (seconds), or 'once' (one-off)")]),
+        Line::from(vec![Span::raw("3. Schedule Value: cron expression, interval in seconds, or a time: 'in 5m', 'in 2h', 'tomorrow 09:00', '2024-06-01 14:30'")]),
         Line::from(vec![Span::raw("4. Action Type: 'restart' (kill process), 'start' (start process), 'cleanup' (cleanup idle), or 'rule' (apply rule)")]),
         Line::from(vec![Span::raw("5. Action Value: pattern (restart), program name/path (start), cleanup params, or rule expression")]),
         Line::from(vec![Span::raw("6. Press [Tab] to switch fields, [Enter] to save task, [Esc] to cancel")]),
     ];
+    // Preview what a one-off schedule value resolves to
+    if app.input_state.task_schedule_type.trim().eq_ignore_ascii_case("once") && !app.input_state.task_schedule_value.trim().is_empty() {
+        let preview = match crate::scheduler::parse_once_time(&app.input_state.task_schedule_value) {
+            Ok(time) => {
+                let resolved: chrono::DateTime<Local> = time.into();
+                Span::styled(format!("Resolves to: {}", resolved.format("%Y-%m-%d %H:%M:%S")), Style::default().fg(Color::Green))
+            }
+            Err(e) => Span::styled(e, Style::default().fg(Color::Red)),
+        };
+        instructions.push(Line::from(vec![preview]));
+    }
     let inst_para = Paragraph::new(instructions)
         .block(Block::default().borders(Borders::ALL).title("Instructions").style(Style::default().fg(Color::Black)));
     f.render_widget(inst_para, chunks[2]);
@@ -5329,13 +11078,24 @@ fn handle_task_editor_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
                     }
                 }
                 "once" => {
-                    match app.input_state.task_schedule_value.trim().parse::<u64>() {
-                        Ok(timestamp) => {
-                            use std::time::{UNIX_EPOCH, Duration};
-                            crate::scheduler::ScheduleType::Once(UNIX_EPOCH + Duration::from_secs(timestamp))
-                        }
-                        Err(_) => {
-                            app.input_state.message = Some(("Invalid timestamp value (must be a number)".to_string(), true));
+                    let value = app.input_state.task_schedule_value.trim().to_string();
+                    match &app.input_state.task_once_confirmed {
+                        // Second Enter on the same value: save with the time shown to the user
+                        Some((confirmed, time)) if *confirmed == value => crate::scheduler::ScheduleType::Once(*time),
+                        _ => {
+                            match crate::scheduler::parse_once_time(&value) {
+                                Ok(time) => {
+                                    let resolved: chrono::DateTime<Local> = time.into();
+                                    app.input_state.message = Some((
+                                        format!("Runs at {} - press Enter again to save", resolved.format("%Y-%m-%d %H:%M:%S")),
+                                        false
+                                    ));
+                                    app.input_state.task_once_confirmed = Some((value, time));
+                                }
+                                Err(e) => {
+                                    app.input_state.message = Some((e, true));
+                                }
+                            }
                             return Ok(false);
                         }
                     }
@@ -5402,22 +11162,44 @@ fn handle_task_editor_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
                         rule: app.input_state.task_action_value.trim().to_string()
                     }
                 }
+                "signal" => {
+                    // Parse signal params: "pid,signal" where signal is a name or number
+                    let parts: Vec<&str> = app.input_state.task_action_value.split(',').map(|s| s.trim()).collect();
+                    let pid = parts.first().and_then(|p| p.parse::<u32>().ok());
+                    match (pid, parts.get(1)) {
+                        (Some(pid), Some(signal)) if crate::signals::parse_signal(signal).is_some() => {
+                            crate::scheduler::ScheduleAction::SendSignal { pid, signal: signal.to_string() }
+                        }
+                        _ => {
+                            app.input_state.message = Some(("Signal requires: pid,signal (e.g. 1234,SIGHUP)".to_string(), true));
+                            return Ok(false);
+                        }
+                    }
+                }
                 _ => {
-                    app.input_state.message = Some(("Invalid action type (must be 'restart', 'start', 'cleanup', or 'rule')".to_string(), true));
+                    app.input_state.message = Some(("Invalid action type (must be 'restart', 'start', 'cleanup', 'rule', or 'signal')".to_string(), true));
                     return Ok(false);
                 }
             };
 
-            // Create and add task
+            // Create the task, or replace the one being edited
             let task = crate::scheduler::ScheduledTask::new(
                 app.input_state.task_name.trim().to_string(),
                 schedule,
                 action,
             );
-            app.scheduler.add_task(task.clone());
+            let name = task.name.clone();
+            match app.input_state.editing_task_index.take() {
+                Some(index) if app.scheduler.update_task(index, task.clone()) => {
+                    app.input_state.message = Some((format!("Task '{}' updated successfully", name), false));
+                }
+                _ => {
+                    app.scheduler.add_task(task);
+                    app.input_state.message = Some((format!("Task '{}' created successfully", name), false));
+                }
+            }
             
             app.view_mode = ViewMode::Scheduler;
-            app.input_state.message = Some((format!("Task '{}' created successfully", task.name), false));
             
             // Clear fields
             app.input_state.task_name.clear();
@@ -5425,6 +11207,7 @@ fn handle_task_editor_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
             app.input_state.task_schedule_value.clear();
             app.input_state.task_action_type.clear();
             app.input_state.task_action_value.clear();
+            app.input_state.task_once_confirmed = None;
         }
         KeyCode::Esc => {
             app.view_mode = ViewMode::Scheduler;
@@ -5434,12 +11217,43 @@ fn handle_task_editor_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dy
             app.input_state.task_schedule_value.clear();
             app.input_state.task_action_type.clear();
             app.input_state.task_action_value.clear();
+            app.input_state.task_once_confirmed = None;
+            app.input_state.editing_task_index = None;
         }
         _ => {}
     }
     Ok(false)
 }
 
+// Split a task back into the editor's text fields (the reverse of the parsing on save).
+// Returns None for actions the editor has no input format for.
+fn task_editor_fields(task: &crate::scheduler::ScheduledTask) -> Option<(String, String, String, String)> {
+    use crate::scheduler::{ScheduleAction, ScheduleType};
+    let (schedule_type, schedule_value) = match &task.schedule {
+        ScheduleType::Cron(expr) => ("cron", expr.clone()),
+        ScheduleType::Interval(secs) => ("interval", secs.to_string()),
+        ScheduleType::Once(time) => {
+            let local: chrono::DateTime<Local> = (*time).into();
+            ("once", local.format("%Y-%m-%d %H:%M").to_string())
+        }
+    };
+    let (action_type, action_value) = match &task.action {
+        ScheduleAction::RestartProcess { pattern } => ("restart", pattern.clone()),
+        ScheduleAction::StartProcess { program, args } => {
+            let mut parts = vec![program.clone()];
+            parts.extend(args.iter().cloned());
+            ("start", parts.join(" "))
+        }
+        ScheduleAction::CleanupIdle { cpu_threshold, memory_threshold, duration_seconds, action } => {
+            ("cleanup", format!("{},{},{},{}", cpu_threshold, memory_threshold, duration_seconds, action))
+        }
+        ScheduleAction::ApplyRule { rule } => ("rule", rule.clone()),
+        ScheduleAction::SendSignal { pid, signal } => ("signal", format!("{},{}", pid, signal)),
+        _ => return None,
+    };
+    Some((schedule_type.to_string(), schedule_value, action_type.to_string(), action_value))
+}
+
 // Draw multi-host view (shows processes from all hosts)
 fn draw_multi_host_view(f: &mut Frame, app: &mut App, area: Rect) {
     // Redirect to process list with multi-host mode enabled
@@ -5451,3 +11265,36 @@ fn handle_multi_host_input(key: KeyEvent, app: &mut App) -> Result<bool, Box<dyn
     // Redirect to process list handling
     handle_process_list_input(key, app)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Processes shaped like `tree`: (pid, parent pid)
+    fn processes_with(tree: &[(u32, Option<u32>)]) -> Vec<process::ProcessInfo> {
+        let mut manager = ProcessManager::new();
+        manager.refresh();
+        let template = manager.get_processes().first().cloned().expect("at least one running process");
+        tree.iter()
+            .map(|&(pid, parent_pid)| process::ProcessInfo { pid, parent_pid, ..template.clone() })
+            .collect()
+    }
+
+    #[test]
+    fn collapsed_subtree_is_hidden_not_moved() {
+        // 1 -> 10 -> 11 -> 12, 1 -> 20
+        let processes = processes_with(&[(1, None), (10, Some(1)), (11, Some(10)), (12, Some(11)), (20, Some(1))]);
+        let collapsed = HashSet::from([10]);
+        let rows: Vec<u32> = process_tree_rows(&processes, &collapsed).iter().map(|row| row.pid).collect();
+        assert_eq!(rows, vec![1, 10, 20]);
+        let expanded: Vec<u32> = process_tree_rows(&processes, &HashSet::new()).iter().map(|row| row.pid).collect();
+        assert_eq!(expanded, vec![1, 10, 11, 12, 20]);
+    }
+
+    #[test]
+    fn ppid_cycle_is_listed_once_as_its_own_root() {
+        let processes = processes_with(&[(1, None), (30, Some(31)), (31, Some(30))]);
+        let rows: Vec<u32> = process_tree_rows(&processes, &HashSet::new()).iter().map(|row| row.pid).collect();
+        assert_eq!(rows, vec![1, 30, 31]);
+    }
+}
@@ -0,0 +1,70 @@
+//! Memory size formatting in binary (KiB/MiB/GiB) or decimal (kB/MB/GB) units
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryUnits {
+    #[default]
+    Binary,  // Powers of 1024: KiB, MiB, GiB
+    Decimal, // Powers of 1000: kB, MB, GB
+}
+
+impl MemoryUnits {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MemoryUnits::Binary => "MiB/GiB",
+            MemoryUnits::Decimal => "MB/GB",
+        }
+    }
+}
+
+// Display preference shared by every view; set from Settings at startup and on toggle
+static USE_DECIMAL: AtomicBool = AtomicBool::new(false);
+
+pub fn set_memory_units(units: MemoryUnits) {
+    USE_DECIMAL.store(units == MemoryUnits::Decimal, Ordering::Relaxed);
+}
+
+pub fn memory_units() -> MemoryUnits {
+    if USE_DECIMAL.load(Ordering::Relaxed) {
+        MemoryUnits::Decimal
+    } else {
+        MemoryUnits::Binary
+    }
+}
+
+const BINARY_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+const DECIMAL_UNITS: [&str; 5] = ["B", "kB", "MB", "GB", "TB"];
+
+/// Largest unit that keeps `bytes` at 1 or more, as (bytes per unit, unit name)
+pub fn scale_for(bytes: f64) -> (f64, &'static str) {
+    let (base, names) = match memory_units() {
+        MemoryUnits::Binary => (1024.0, &BINARY_UNITS),
+        MemoryUnits::Decimal => (1000.0, &DECIMAL_UNITS),
+    };
+    let mut divisor = 1.0;
+    let mut index = 0;
+    while index + 1 < names.len() && bytes >= divisor * base {
+        divisor *= base;
+        index += 1;
+    }
+    (divisor, names[index])
+}
+
+/// Format a byte count with automatic scaling, e.g. "512 KiB" or "2.3 GiB"
+pub fn format_bytes(bytes: u64) -> String {
+    let (divisor, unit) = scale_for(bytes as f64);
+    let value = bytes as f64 / divisor;
+    if divisor == 1.0 || value >= 100.0 {
+        format!("{:.0} {}", value, unit)
+    } else {
+        format!("{:.1} {}", value, unit)
+    }
+}
+
+/// `format_bytes` for sizes the kernel reports in KiB (/proc/meminfo, VmHWM)
+pub fn format_kib(kib: u64) -> String {
+    format_bytes(kib.saturating_mul(1024))
+}